@@ -0,0 +1,6 @@
+//! Admin module for maintenance routes.
+//!
+//! This module provides the handlers for the maintenance tasks of the application,
+//! such as checking the stored balances against the transaction ledger.
+
+pub mod reconcile;
@@ -0,0 +1,95 @@
+//! Routes for reconciling the user balances with the transaction ledger
+
+use crate::utils::openapi::ADMIN_TAG;
+use axum::{Json, extract::State, response::IntoResponse};
+use entity::{
+    error::AppError,
+    response::reconcile::{BalanceDriftResponse, ReconcileResponse},
+};
+use extractor::profile::admin::Admin;
+use service::{Connection, reconcile::ReconcileReport};
+
+/// Converts a reconciliation report into its response.
+fn to_response(report: ReconcileReport, fixed: bool) -> Result<ReconcileResponse, AppError> {
+    let drifts = report
+        .drifts
+        .into_iter()
+        .map(|drift| {
+            BalanceDriftResponse::try_new(
+                drift.user_id,
+                drift.currency,
+                drift.balance,
+                drift.expected,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ReconcileResponse {
+        checked: report.checked,
+        drifts,
+        fixed,
+    })
+}
+
+/// Check the balance of every user against the transaction ledger.
+///
+/// Each balance is recomputed from the completed transactions of its user,
+/// every user whose stored balance doesn't match is reported, nothing is modified.
+#[utoipa::path(
+    get,
+    path = "/reconcile",
+    tag = ADMIN_TAG,
+    responses(
+        (status = 500, description = "An internal error occured, probably database related"),
+        (status = 403, description = "You are not an admin"),
+        (status = 200, description = "The balances are checked", body = ReconcileResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_reconcile(
+    _admin: Admin,
+    State(conn): State<Connection>,
+) -> Result<impl IntoResponse, AppError> {
+    let report = service::Query::reconcile_balances(&conn).await?;
+
+    Ok(Json(to_response(report, false)?))
+}
+
+/// Rewrite the balance of every user drifting from the transaction ledger.
+///
+/// Returns the drifts found, each stored balance is then replaced by the one computed
+/// from the ledger, unless it was modified in the meantime.
+#[utoipa::path(
+    post,
+    path = "/reconcile",
+    tag = ADMIN_TAG,
+    responses(
+        (status = 500, description = "An internal error occured, probably database related"),
+        (status = 403, description = "You are not an admin"),
+        (status = 200, description = "The balances are fixed", body = ReconcileResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn post_reconcile(
+    admin: Admin,
+    State(conn): State<Connection>,
+) -> Result<impl IntoResponse, AppError> {
+    let report = service::Query::reconcile_balances(&conn).await?;
+
+    for drift in &report.drifts {
+        if service::Mutation::fix_balance_drift(&conn, drift).await? {
+            log::warn!(
+                "{admin} rewrote the balance of user \"{}\" from {} to {}",
+                drift.user_id,
+                drift.balance,
+                drift.expected
+            );
+        }
+    }
+
+    Ok(Json(to_response(report, true)?))
+}
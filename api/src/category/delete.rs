@@ -0,0 +1,76 @@
+//! This module defines the API endpoint to delete a category by its ID.
+//!
+//! Only an admin can delete a category.
+
+use crate::utils::openapi::CATEGORY_TAG;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::category,
+    request::category::CategoryRequestError,
+};
+use extractor::profile::admin::Admin;
+use sea_orm::ColumnTrait;
+use service::Connection;
+
+/// Deletes a category by its database ID.
+///
+/// Its products are kept, without a category. A category still containing subcategories can't
+/// be deleted, they must be moved or deleted first. Only an admin can perform this action.
+///
+/// - **Path Parameters:**
+///   - `id`: The unique ID of the category in the database.
+///
+/// - **Responses:**
+///   - `500`: Internal error, likely related to the database.
+///   - `404`: The category doesn't exist.
+///   - `400`: The category still contains subcategories.
+///   - `200`: The category has been successfully deleted.
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tag = CATEGORY_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "category database id to delete category for"),
+    ),
+    responses(
+        (status = 500, description = "An internal error occured, probably databse related"),
+        (status = 404, description = "The category doesn't exist"),
+        (status = 400, description = "The category still contains subcategories", body = ErrorResponse),
+        (status = 200, description = "The category is deleted")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn delete_category(
+    admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+) -> Result<impl IntoResponse, AppError> {
+    if service::Query::find_category_by_id(&conn, id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "The category with id: {id} doesn't exist"
+        )));
+    }
+
+    let children =
+        service::Query::count_categories_with_condition(&conn, category::Column::ParentId.eq(id))
+            .await?;
+    if children > 0 {
+        return Err(CategoryRequestError::CategoryHasChildren(id).into());
+    }
+
+    let result = service::Mutation::delete_category(&conn, id).await?;
+
+    log::info!("{admin} just deleted the category \"{id}\" - {result:?}");
+
+    Ok((StatusCode::OK, ""))
+}
@@ -0,0 +1,76 @@
+//! Route for editing an existing category.
+
+use crate::utils::openapi::CATEGORY_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::category::ActiveModel,
+    request::category::{CategoryRequestError, EditCategoryRequest},
+};
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Edit an existing category by ID.
+///
+/// A category can't be nested inside itself, nor inside one of its own subcategories.
+///
+/// Returns an error if the category doesn't exist, if there is a validation issue, or if a database operation fails.
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tag = CATEGORY_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "category database id to edit category for"),
+    ),
+    request_body(content = EditCategoryRequest, content_type = "application/json"),
+    responses(
+       (status = 500, description = "An internal error occured, probably database related"),
+       (status = 404, description = "The category doesn't exist"),
+       (status = 400, description = "Your request is not correctly formatted", body = ErrorResponse),
+       (status = 200, description = "The category is correctly edited")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn edit_category(
+    admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+    Json(edit_category): Json<EditCategoryRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if service::Query::find_category_by_id(&conn, id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "The category with id: {id} doesn't exist"
+        )));
+    }
+
+    if let Some(Some(parent)) = edit_category.parent {
+        if service::Query::find_category_by_id(&conn, parent)
+            .await?
+            .is_none()
+        {
+            return Err(CategoryRequestError::ParentDoesntExist(parent).into());
+        }
+
+        let descendants = service::Query::category_descendants(&conn, id).await?;
+        if descendants.contains(&parent) {
+            return Err(CategoryRequestError::ParentCreatesCycle(id).into());
+        }
+    }
+
+    let edit_category: ActiveModel = edit_category.try_into()?;
+    let result = service::Mutation::update_category(&conn, id, edit_category).await?;
+
+    log::info!("{admin} successfully edited category \"{id}\" - {result:?}");
+
+    Ok((StatusCode::OK, ""))
+}
@@ -0,0 +1,101 @@
+//! This module contains the route handlers for retrieving category information.
+
+use crate::utils::openapi::CATEGORY_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use axum_extra::extract::Query;
+use entity::{
+    error::AppError,
+    models::category::{CategoryFilterQuery, CategorySortQuery},
+    response::category::{CategoryListResponse, CategoryResponse},
+};
+use extractor::query::Pagination;
+use service::Connection;
+
+/// Handles the request to fetch a category by its unique identifier.
+///
+/// - **Path Parameters**:  
+///   `id` (UUID): The database ID of the category to retrieve.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: The category was successfully retrieved.
+///   - `404 Not Found`: The category doesn't exist.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tag = CATEGORY_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the category to retrieve."),
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The category doesn't exist."),
+        (status = 200, description = "The category was successfully retrieved.", body = CategoryResponse)
+    )
+)]
+pub async fn get_category(
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+) -> Result<Json<CategoryResponse>, AppError> {
+    let result = service::Query::find_category_by_id(&conn, id).await?;
+
+    match result {
+        Some(category) => Ok(Json(category.into())),
+        None => Err(AppError::NotFound(format!(
+            "The category with id: {id} doesn't exist"
+        ))),
+    }
+}
+
+/// Handles the request to retrieve a paginated list of categories.
+///
+/// The subcategories of a category can be listed with `parent_id_eq={id}`.
+///
+/// - **Query Parameters**:  
+///   - `page` (Optional, u64): The page index, default is 0.
+///   - `per_page` (Optional, u64): The number of categories per page, default is 20.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: Successfully retrieved a list of categories.
+///   - `400 Bad Request`: The request is improperly formatted.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = CATEGORY_TAG,
+    params(
+        Pagination,
+        CategoryFilterQuery,
+        CategorySortQuery,
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 400, description = "The request is improperly formatted."),
+        (status = 200, description = "Successfully retrieved a list of categories.", body = CategoryListResponse)
+    )
+)]
+pub async fn get_all_categories(
+    Query(pagination): Query<Pagination>,
+    Query(filter): Query<CategoryFilterQuery>,
+    Query(sort): Query<CategorySortQuery>,
+    State(conn): State<Connection>,
+) -> Result<Json<CategoryListResponse>, AppError> {
+    let page = pagination.page.unwrap_or(0);
+    let per_page = pagination.per_page.unwrap_or(20);
+
+    let result =
+        service::Query::list_categories_with_condition(&conn, filter.clone(), sort, page, per_page)
+            .await?;
+
+    let total_categories = service::Query::count_categories_with_condition(&conn, filter).await?;
+    let total_page = ((total_categories.max(1) - 1) / per_page) + 1;
+
+    Ok(Json(CategoryListResponse {
+        current_page: page,
+        total_page,
+        categories: result.into_iter().map(Into::into).collect(),
+    }))
+}
@@ -0,0 +1,21 @@
+//! Category module for managing the categories the products are grouped in.
+//!
+//! This module provides handlers for the endpoints related to creating,
+//! retrieving, nesting, updating, and deleting product categories, and the
+//! check every handler assigning a category to a product runs.
+
+use entity::{error::AppError, request::product::ProductRequestError};
+use service::Connection;
+
+pub mod delete;
+pub mod edit;
+pub mod get;
+pub mod new;
+
+/// Checks that the category `id` exists, returns a `CategoryDoesntExist` bad request otherwise.
+pub async fn check_category(conn: &Connection, id: uuid::Uuid) -> Result<(), AppError> {
+    match service::Query::find_category_by_id(conn, id).await? {
+        Some(_) => Ok(()),
+        None => Err(ProductRequestError::CategoryDoesntExist(id).into()),
+    }
+}
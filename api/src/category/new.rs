@@ -0,0 +1,64 @@
+//! This module contains the route handler for creating a new category.
+//!
+//! The handler will be accessible via a POST request to the `/category` endpoint.
+//! Admin privileges are required to access this route.
+
+use crate::utils::openapi::CATEGORY_TAG;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::category::ActiveModel,
+    request::category::{CategoryRequestError, NewCategoryRequest},
+};
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Handler for creating a new category.
+///
+/// - **Admin privileges** are required to access this route.
+/// - Returns a `201 Created` status upon successful creation along with the category's ID.
+///
+/// Path: `/category`
+///
+/// - **Request Body:** Expects a `NewCategoryRequest` JSON object.
+/// - **Responses:**
+///     - 500: Internal server error (likely database related).
+///     - 400: Bad request (invalid name or unknown parent).
+///     - 201: Successfully created the category, returns its ID as a string.
+#[utoipa::path(
+    post,
+    path = "",
+    tag = CATEGORY_TAG,
+    request_body(content = NewCategoryRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 400, description = "The request is improperly formatted.", body = ErrorResponse),
+        (status = 201, description = "Successfully created the category, returns its ID as a string.", body = uuid::Uuid)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn post_new_category(
+    admin: Admin,
+    State(conn): State<Connection>,
+    Json(category): Json<NewCategoryRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if let Some(parent) = category.parent {
+        if service::Query::find_category_by_id(&conn, parent)
+            .await?
+            .is_none()
+        {
+            return Err(CategoryRequestError::ParentDoesntExist(parent).into());
+        }
+    }
+
+    let category: ActiveModel = category.try_into()?;
+
+    let result = service::Mutation::create_category(&conn, category).await?;
+
+    let id = result.id;
+    log::info!("{admin} added a new category \"{id}\" - {result:?}");
+
+    Ok((StatusCode::CREATED, id.to_string()).into_response())
+}
@@ -0,0 +1,76 @@
+//! This module defines the API endpoint to delete a currency from the registry.
+//!
+//! Only an admin can delete a currency, and only while nothing refers to it.
+
+use crate::utils::openapi::CURRENCY_TAG;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{
+    error::{AppError, ErrorResponse},
+    request::currency::CurrencyRequestError,
+};
+use extractor::profile::admin::Admin;
+use sea_orm::SqlErr;
+use service::Connection;
+
+/// Deletes a currency by its code.
+///
+/// A currency still used by a product, a refill, a balance, an exchange rate or a
+/// transaction cannot be deleted.
+/// Only an admin can perform this action.
+///
+/// - **Path Parameters:**
+///   - `code`: The code of the currency.
+///
+/// - **Responses:**
+///   - `500`: Internal error, likely related to the database.
+///   - `404`: The currency isn't registered.
+///   - `400`: The currency is still in use.
+///   - `200`: The currency has been successfully deleted.
+#[utoipa::path(
+    delete,
+    path = "/{code}",
+    tag = CURRENCY_TAG,
+    params(
+        ("code" = String, Path, description = "code of the currency to delete"),
+    ),
+    responses(
+        (status = 500, description = "An internal error occured, probably databse related"), 
+        (status = 404, description = "The currency isn't registered"), 
+        (status = 400, description = "The currency is still in use", body = ErrorResponse), 
+        (status = 200, description = "The currency is deleted")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn delete_currency(
+    admin: Admin,
+    Path(code): Path<String>,
+    State(conn): State<Connection>,
+) -> Result<impl IntoResponse, AppError> {
+    let result = service::Query::find_currency_by_code(&conn, &code).await?;
+
+    match result {
+        Some(currency) => {
+            if let Err(err) = service::Mutation::delete_currency(&conn, &code).await {
+                return match err.sql_err() {
+                    Some(SqlErr::ForeignKeyConstraintViolation(_)) => {
+                        Err(CurrencyRequestError::CurrencyIsInUse(code).into())
+                    }
+                    _ => Err(err.into()),
+                };
+            }
+
+            log::info!("{admin} just deleted the currency \"{code}\" - {currency:?}");
+
+            Ok((StatusCode::OK, ""))
+        }
+        None => Err(AppError::NotFound(format!(
+            "The currency with code: {code} doesn't exist"
+        ))),
+    }
+}
@@ -0,0 +1,64 @@
+//! Route for editing a registered currency.
+
+use crate::utils::openapi::CURRENCY_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::currency::ActiveModel,
+    request::currency::EditCurrencyRequest,
+};
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Edit a registered currency by its code.
+///
+/// The admin can change the symbol, the decimal places and whether the currency is internal,
+/// the code can't be changed.
+///
+/// Returns an error if the currency doesn't exist, if there is a validation issue, or if a database operation fails.
+#[utoipa::path(
+    put,
+    path = "/{code}",
+    tag = CURRENCY_TAG,
+    params(
+        ("code" = String, Path, description = "code of the currency to edit"),
+    ),
+    request_body(content = EditCurrencyRequest, content_type = "application/json"), 
+    responses(
+       (status = 500, description = "An internal error occured, probably database related"), 
+       (status = 404, description = "The currency isn't registered"), 
+       (status = 400, description = "Your request is not correctly formatted", body = ErrorResponse), 
+       (status = 200, description = "The currency is correctly edited")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn edit_currency(
+    admin: Admin,
+    Path(code): Path<String>,
+    State(conn): State<Connection>,
+    Json(edit_currency): Json<EditCurrencyRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let result = service::Query::find_currency_by_code(&conn, &code).await?;
+
+    match result {
+        Some(_existing_currency) => {
+            let edit_currency: ActiveModel = edit_currency.try_into()?;
+
+            let result = service::Mutation::update_currency(&conn, &code, edit_currency).await?;
+
+            log::info!("{admin} successfully edited currency \"{code}\" - {result:?}");
+
+            Ok((StatusCode::OK, ""))
+        }
+        None => Err(AppError::NotFound(format!(
+            "The currency with code: {code} doesn't exist"
+        ))),
+    }
+}
@@ -0,0 +1,106 @@
+//! This module contains the route handler for retrieving currency information.
+
+use crate::utils::openapi::CURRENCY_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use axum_extra::extract::Query;
+use entity::{
+    error::AppError,
+    models::currency::{CurrencyFilterQuery, CurrencySortQuery},
+    response::currency::{CurrencyDetailResponse, CurrencyListResponse},
+};
+use extractor::query::Pagination;
+use service::Connection;
+
+/// Handles the request to fetch a currency by its code.
+///
+/// - **Path Parameters**:  
+///   `code` (String): The code of the currency to retrieve.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: The currency was successfully retrieved.
+///   - `404 Not Found`: The currency isn't registered.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+#[utoipa::path(
+    get,
+    path = "/{code}", 
+    tag = CURRENCY_TAG,
+    params(
+        ("code" = String, Path, description = "The code of the currency to retrieve."),
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."), 
+        (status = 404, description = "The currency isn't registered."), 
+        (status = 200, description = "The currency was successfully retrieved.", body = CurrencyDetailResponse)
+    ),
+    security(
+        (),
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_currency(
+    Path(code): Path<String>,
+    State(conn): State<Connection>,
+) -> Result<Json<CurrencyDetailResponse>, AppError> {
+    let result = service::Query::find_currency_by_code(&conn, &code).await?;
+
+    match result {
+        Some(currency) => Ok(Json(currency.into())),
+        None => Err(AppError::NotFound(format!(
+            "The currency with code: {code} doesn't exist"
+        ))),
+    }
+}
+
+/// Handles the request to retrieve a paginated list of currencies.
+///
+/// - **Query Parameters**:  
+///   - `page` (Optional, u64): The page index, default is 0.
+///   - `per_page` (Optional, u64): The number of currency per page, default is 20.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: Successfully retrieved a list of currencies.
+///   - `400 Bad Request`: The request is improperly formatted.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = CURRENCY_TAG,
+    params(
+        Pagination,
+        CurrencyFilterQuery,
+        CurrencySortQuery,
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."), 
+        (status = 200, description = "Successfully retrieved a list of currencies.", body = CurrencyListResponse)
+    ),
+    security(
+        (),
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_all_currencies(
+    Query(pagination): Query<Pagination>,
+    Query(filter): Query<CurrencyFilterQuery>,
+    Query(sort): Query<CurrencySortQuery>,
+    State(conn): State<Connection>,
+) -> Result<Json<CurrencyListResponse>, AppError> {
+    let page = pagination.page.unwrap_or(0);
+    let per_page = pagination.per_page.unwrap_or(20);
+
+    let result =
+        service::Query::list_currencies_with_condition(&conn, filter.clone(), sort, page, per_page)
+            .await?;
+
+    let total_currencies = service::Query::count_currencies_with_condition(&conn, filter).await?;
+    let total_page = ((total_currencies.max(1) - 1) / per_page) + 1;
+
+    Ok(Json(CurrencyListResponse {
+        current_page: page,
+        total_page,
+        currencies: result.into_iter().map(Into::into).collect(),
+    }))
+}
@@ -0,0 +1,24 @@
+//! Currency module for managing the currency registry.
+//!
+//! This module provides handlers for the endpoints related to registering,
+//! retrieving, updating, and deleting currencies, and the check every handler
+//! receiving a currency runs against the registry.
+
+use entity::{
+    error::AppError,
+    request::{currency::CurrencyRequestError, r#enum::CurrencyRequest},
+};
+use service::Connection;
+
+pub mod delete;
+pub mod edit;
+pub mod get;
+pub mod new;
+
+/// Checks that `currency` is registered, returns a `CurrencyDoesntExist` bad request otherwise.
+pub async fn check_currency(conn: &Connection, currency: &CurrencyRequest) -> Result<(), AppError> {
+    match service::Query::find_currency_by_code(conn, &currency.0).await? {
+        Some(_) => Ok(()),
+        None => Err(CurrencyRequestError::CurrencyDoesntExist(currency.0.clone()).into()),
+    }
+}
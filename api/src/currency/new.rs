@@ -0,0 +1,69 @@
+//! This module contains the route handler for registering a new currency.
+//!
+//! The handler will be accessible via a POST request to the `/currency` endpoint.
+//! Admin privileges are required to access this route.
+
+use crate::utils::openapi::CURRENCY_TAG;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::currency::ActiveModel,
+    request::currency::{CurrencyRequestError, NewCurrencyRequest},
+};
+use extractor::profile::admin::Admin;
+use sea_orm::ActiveValue::Set;
+use service::Connection;
+
+/// Handler for registering a new currency.
+///
+/// Once registered, the currency can be used by products, refills, balances and exchange rates.
+///
+/// - **Admin privileges** are required to access this route.
+/// - Returns a `201 Created` status upon successful creation along with the currency's code.
+///
+/// Path: `/currency`
+///
+/// - **Request Body:** Expects a `NewCurrencyRequest` JSON object.
+/// - **Responses:**
+///     - 500: Internal server error (likely database related).
+///     - 400: Bad request (invalid input data, or code already registered).
+///     - 201: Successfully registered a new currency, returns its code.
+#[utoipa::path(
+    post,
+    path = "", 
+    tag = CURRENCY_TAG,
+    request_body(content = NewCurrencyRequest, content_type = "application/json"), 
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."), 
+        (status = 400, description = "The request is improperly formatted, or the code is already registered.", body = ErrorResponse), 
+        (status = 201, description = "Successfully registered a new currency, returns its code.", body = String)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn post_new_currency(
+    admin: Admin,
+    State(conn): State<Connection>,
+    Json(currency): Json<NewCurrencyRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let currency: ActiveModel = currency.try_into()?;
+
+    if let Set(code) = &currency.code {
+        if service::Query::find_currency_by_code(&conn, code)
+            .await?
+            .is_some()
+        {
+            return Err(CurrencyRequestError::CurrencyAlreadyExist(code.clone()).into());
+        }
+    }
+
+    let result = service::Mutation::create_currency(&conn, currency).await?;
+
+    log::info!(
+        "{admin} registered a new currency \"{}\" - {result:?}",
+        result.code
+    );
+
+    Ok((StatusCode::CREATED, result.code).into_response())
+}
@@ -0,0 +1,66 @@
+//! This module defines the API endpoint to delete an exchange rate by its ID.
+//!
+//! Only an admin can delete an exchange rate.
+
+use crate::utils::openapi::EXCHANGE_RATE_TAG;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::error::AppError;
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Deletes an exchange rate by its database ID.
+///
+/// The previous rate of the same currency pair, if any, applies again.
+/// Only an admin can perform this action.
+///
+/// - **Path Parameters:**
+///   - `id`: The unique ID of the exchange rate in the database.
+///
+/// - **Responses:**
+///   - `500`: Internal error, likely related to the database.
+///   - `404`: The exchange rate doesn't exist.
+///   - `200`: The exchange rate has been successfully deleted.
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tag = EXCHANGE_RATE_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "exchange rate database id to delete exchange rate for"),
+    ),
+    responses(
+        (status = 500, description = "An internal error occured, probably databse related"), 
+        (status = 404, description = "The exchange rate doesn't exist"), 
+        (status = 200, description = "The exchange rate is deleted")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn delete_exchange_rate(
+    admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+) -> Result<impl IntoResponse, AppError> {
+    let result = service::Query::find_exchange_rate_by_id(&conn, id).await?;
+
+    match result {
+        Some(exchange_rate) => {
+            service::Mutation::delete_exchange_rate(&conn, id).await?;
+
+            log::info!(
+                "{admin} just deleted the exchange rate \"{}\" - {:?}",
+                id,
+                exchange_rate
+            );
+
+            Ok((StatusCode::OK, ""))
+        }
+        None => Err(AppError::NotFound(format!(
+            "The exchange rate with id: {id} doesn't exist"
+        ))),
+    }
+}
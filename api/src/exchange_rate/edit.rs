@@ -0,0 +1,67 @@
+//! Route for editing an existing exchange rate.
+
+use crate::utils::openapi::EXCHANGE_RATE_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::exchange_rate::ActiveModel,
+    request::exchange_rate::EditExchangeRateRequest,
+};
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Edit an existing exchange rate by ID.
+///
+/// The admin can change the rate and the date from which it applies, the currencies can't be changed.
+///
+/// Returns an error if the exchange rate doesn't exist, if there is a validation issue, or if a database operation fails.
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tag = EXCHANGE_RATE_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "exchange rate database id to edit exchange rate for"),
+    ),
+    request_body(content = EditExchangeRateRequest, content_type = "application/json"), 
+    responses(
+       (status = 500, description = "An internal error occured, probably database related"), 
+       (status = 400, description = "Your request is not correctly formatted", body = ErrorResponse), 
+       (status = 200, description = "The exchange rate is correctly edited")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn edit_exchange_rate(
+    admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+    Json(edit_exchange_rate): Json<EditExchangeRateRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let result = service::Query::find_exchange_rate_by_id(&conn, id).await?;
+
+    match result {
+        Some(_existing_exchange_rate) => {
+            let edit_exchange_rate: ActiveModel = edit_exchange_rate.try_into()?;
+
+            let result =
+                service::Mutation::update_exchange_rate(&conn, id, edit_exchange_rate).await?;
+
+            log::info!(
+                "{admin} successfully edited exchange rate \"{}\" - {:?}",
+                id,
+                result
+            );
+
+            Ok((StatusCode::OK, ""))
+        }
+        None => Err(AppError::NotFound(format!(
+            "The exchange rate with id: {id} doesn't exist"
+        ))),
+    }
+}
@@ -0,0 +1,116 @@
+//! This module contains the route handler for retrieving exchange rate information.
+
+use crate::utils::openapi::EXCHANGE_RATE_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use axum_extra::extract::Query;
+use entity::{
+    error::AppError,
+    models::exchange_rate::{Exchange_rateFilterQuery, Exchange_rateSortQuery},
+    response::exchange_rate::{
+        ExchangeRateListResponse, ExchangeRateResponse, ExchangeRateResponseError,
+    },
+};
+use extractor::query::Pagination;
+use service::Connection;
+
+/// Handles the request to fetch an exchange rate by its unique identifier.
+///
+/// - **Path Parameters**:  
+///   `id` (UUID): The database ID of the exchange rate to retrieve.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: The exchange rate was successfully retrieved.
+///   - `404 Not Found`: The exchange rate doesn't exist.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+#[utoipa::path(
+    get,
+    path = "/{id}", 
+    tag = EXCHANGE_RATE_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the exchange rate to retrieve."),
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."), 
+        (status = 404, description = "The exchange rate doesn't exist."), 
+        (status = 200, description = "The exchange rate was successfully retrieved.", body = ExchangeRateResponse)
+    ),
+    security(
+        (),
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_exchange_rate(
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+) -> Result<Json<ExchangeRateResponse>, AppError> {
+    let result = service::Query::find_exchange_rate_by_id(&conn, id).await?;
+
+    match result {
+        Some(exchange_rate) => Ok(Json(exchange_rate.try_into()?)),
+        None => Err(AppError::NotFound(format!(
+            "The exchange rate with id: {id} doesn't exist"
+        ))),
+    }
+}
+
+/// Handles the request to retrieve a paginated list of exchange rates.
+///
+/// - **Query Parameters**:  
+///   - `page` (Optional, u64): The page index, default is 0.
+///   - `per_page` (Optional, u64): The number of exchange rate per page, default is 20.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: Successfully retrieved a list of exchange rates.
+///   - `400 Bad Request`: The request is improperly formatted.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = EXCHANGE_RATE_TAG,
+    params(
+        Pagination,
+        Exchange_rateFilterQuery,
+        Exchange_rateSortQuery,
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."), 
+        (status = 200, description = "Successfully retrieved a list of exchange rates.", body = ExchangeRateListResponse)
+    ),
+    security(
+        (),
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_all_exchange_rates(
+    Query(pagination): Query<Pagination>,
+    Query(filter): Query<Exchange_rateFilterQuery>,
+    Query(sort): Query<Exchange_rateSortQuery>,
+    State(conn): State<Connection>,
+) -> Result<Json<ExchangeRateListResponse>, AppError> {
+    let page = pagination.page.unwrap_or(0);
+    let per_page = pagination.per_page.unwrap_or(20);
+
+    let result = service::Query::list_exchange_rates_with_condition(
+        &conn,
+        filter.clone(),
+        sort,
+        page,
+        per_page,
+    )
+    .await?;
+
+    let total_exchange_rates =
+        service::Query::count_exchange_rates_with_condition(&conn, filter).await?;
+    let total_page = ((total_exchange_rates.max(1) - 1) / per_page) + 1;
+
+    let exchange_rates: Result<Vec<_>, ExchangeRateResponseError> =
+        result.into_iter().map(TryInto::try_into).collect();
+    Ok(Json(ExchangeRateListResponse {
+        current_page: page,
+        total_page,
+        exchange_rates: exchange_rates?,
+    }))
+}
@@ -0,0 +1,10 @@
+//! Exchange rate module for managing the rates between currencies.
+//!
+//! This module provides handlers for the endpoints related to creating,
+//! retrieving, updating, and deleting exchange rates. These rates are used
+//! to price a product or credit a refill in the currency of the user balance.
+
+pub mod delete;
+pub mod edit;
+pub mod get;
+pub mod new;
@@ -0,0 +1,68 @@
+//! This module contains the route handler for creating a new exchange rate.
+//!
+//! The handler will be accessible via a POST request to the `/exchange_rate` endpoint.
+//! Admin privileges are required to access this route.
+
+use crate::{currency::check_currency, utils::openapi::EXCHANGE_RATE_TAG};
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::exchange_rate::ActiveModel,
+    request::exchange_rate::NewExchangeRateRequest,
+};
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Handler for creating a new exchange rate.
+///
+/// The rate applies from its `effective_from` date, or right away if none is given,
+/// until a newer rate of the same currency pair takes effect.
+///
+/// - **Admin privileges** are required to access this route.
+/// - Returns a `201 Created` status upon successful creation along with the exchange rate's ID.
+///
+/// Path: `/exchange_rate`
+///
+/// - **Request Body:** Expects a `NewExchangeRateRequest` JSON object.
+/// - **Responses:**
+///     - 500: Internal server error (likely database related).
+///     - 400: Bad request (invalid input data).
+///     - 201: Successfully created a new exchange rate, returns its ID as a string.
+#[utoipa::path(
+    post,
+    path = "", 
+    tag = EXCHANGE_RATE_TAG,
+    request_body(content = NewExchangeRateRequest, content_type = "application/json"), 
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."), 
+        (status = 400, description = "The request is improperly formatted.", body = ErrorResponse), 
+        (status = 201, description = "Successfully created a new exchange rate, returns its ID as a string.", body = uuid::Uuid)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn post_new_exchange_rate(
+    admin: Admin,
+    State(conn): State<Connection>,
+    Json(exchange_rate): Json<NewExchangeRateRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    check_currency(&conn, &exchange_rate.from_currency).await?;
+    check_currency(&conn, &exchange_rate.to_currency).await?;
+
+    let exchange_rate: ActiveModel = exchange_rate.try_into()?;
+
+    let result = service::Mutation::create_exchange_rate(&conn, exchange_rate).await?;
+
+    let id = result.id;
+    log::info!(
+        "{admin} added a new exchange rate \"{}\" - ({:?} -> {:?} at {} from {})",
+        id,
+        result.from_currency,
+        result.to_currency,
+        result.rate,
+        result.effective_from
+    );
+
+    Ok((StatusCode::CREATED, id.to_string()).into_response())
+}
@@ -12,10 +12,21 @@
 //! - This file exports submodules that define individual route handlers.
 //! - Each submodule is responsible for a specific section of the API
 
+pub mod admin;
+pub mod category;
+pub mod currency;
+pub mod exchange_rate;
 pub mod location;
+pub mod order;
+pub mod payment;
 pub mod product;
+pub mod purchase_order;
 pub mod recipe;
 pub mod refill;
+pub mod report;
+pub mod stocktake;
+pub mod supplier;
+pub mod transaction;
 pub mod user;
 pub mod utils;
 pub mod warehouse;
@@ -65,3 +65,59 @@ pub async fn delete_location(
         ))),
     }
 }
+
+/// Unlinks a warehouse from a location.
+///
+/// Once a location isn't linked to any warehouse anymore, its stock is taken from every warehouse.
+/// Only an admin can perform this action.
+///
+/// - **Path Parameters:**
+///   - `location_id`: The unique ID of the location in the database.
+///   - `warehouse_id`: The unique ID of the linked warehouse in the database.
+///
+/// - **Responses:**
+///   - `500`: Internal error, likely related to the database.
+///   - `404`: The warehouse isn't linked to the location.
+///   - `400`: The request format is invalid.
+///   - `200`: The warehouse has been successfully unlinked.
+#[utoipa::path(
+    delete,
+    path = "/{location_id}/warehouse/{warehouse_id}",
+    tag = LOCATION_TAG,
+    params(
+        ("location_id" = uuid::Uuid, Path, description = "The database ID of the location."),
+        ("warehouse_id" = uuid::Uuid, Path, description = "The database ID of the linked warehouse."),
+    ),
+    responses(
+        (status = 500, description = "An internal error occured, probably databse related"),
+        (status = 404, description = "The warehouse isn't linked to the location"),
+        (status = 400, description = "Your request is not correctly formatted"),
+        (status = 200, description = "The warehouse is unlinked from the location")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn delete_location_warehouse(
+    admin: Admin,
+    Path((location_id, warehouse_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+    State(conn): State<Connection>,
+) -> Result<impl IntoResponse, AppError> {
+    let result =
+        service::Query::find_location_warehouse_by_id(&conn, location_id, warehouse_id).await?;
+
+    match result {
+        Some(location_warehouse) => {
+            service::Mutation::delete_location_warehouse(&conn, location_id, warehouse_id).await?;
+
+            log::info!(
+                "{admin} just unlinked the location ({location_id}) warehouse ({warehouse_id}) - {location_warehouse:?}"
+            );
+
+            Ok((StatusCode::OK, ""))
+        }
+        None => Err(AppError::NotFound(format!(
+            "Could not find location \"{location_id}\" / warehouse \"{warehouse_id}\" combination"
+        ))),
+    }
+}
@@ -9,8 +9,10 @@ use axum::{
 };
 use entity::{
     error::{AppError, ErrorResponse},
-    models::location::ActiveModel,
-    request::location::EditLocationRequest,
+    models::{location::ActiveModel, location_warehouse},
+    request::location::{
+        EditLocationRequest, EditLocationWarehouseRequest, LocationWarehouseRequestError,
+    },
 };
 use extractor::profile::admin::Admin;
 use service::Connection;
@@ -63,3 +65,74 @@ pub async fn edit_location(
         ))),
     }
 }
+
+/// Edit the link between a location and one of its warehouses.
+///
+/// The admin can change the priority of the warehouse for the location, which must stay unique
+/// within the location.
+///
+/// Returns an error if the link doesn't exist, if the priority is already used, or if a database operation fails.
+#[utoipa::path(
+    put,
+    path = "/{location_id}/warehouse/{warehouse_id}",
+    tag = LOCATION_TAG,
+    params(
+        ("location_id" = uuid::Uuid, Path, description = "The database ID of the location."),
+        ("warehouse_id" = uuid::Uuid, Path, description = "The database ID of the linked warehouse."),
+    ),
+    request_body(content = EditLocationWarehouseRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error occured, probably database related"),
+        (status = 404, description = "The warehouse isn't linked to the location"),
+        (status = 400, description = "Your request is not correctly formatted", body = ErrorResponse),
+        (status = 200, description = "The location warehouse is correctly edited")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn edit_location_warehouse(
+    admin: Admin,
+    Path((location_id, warehouse_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+    State(conn): State<Connection>,
+    Json(edit_location_warehouse): Json<EditLocationWarehouseRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if service::Query::find_location_warehouse_by_id(&conn, location_id, warehouse_id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "Could not find location \"{location_id}\" / warehouse \"{warehouse_id}\" combination"
+        )));
+    }
+
+    if let Some(priority) = edit_location_warehouse.priority {
+        if let Some(existing) =
+            service::Query::find_location_warehouse_by_priority(&conn, location_id, priority)
+                .await?
+        {
+            if existing.warehouse_id != warehouse_id {
+                return Err(LocationWarehouseRequestError::PriorityAlreadyUsed(
+                    priority,
+                    existing.warehouse_id,
+                ))?;
+            }
+        }
+    }
+
+    let edit_location_warehouse_model: location_warehouse::ActiveModel =
+        edit_location_warehouse.into();
+    let result = service::Mutation::update_location_warehouse(
+        &conn,
+        location_id,
+        warehouse_id,
+        edit_location_warehouse_model,
+    )
+    .await?;
+
+    log::info!(
+        "{admin} successfully edited location ({location_id}) warehouse ({warehouse_id}) - {result:?}"
+    );
+
+    Ok((StatusCode::OK, ""))
+}
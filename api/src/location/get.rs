@@ -9,7 +9,7 @@ use axum_extra::extract::Query;
 use entity::{
     error::AppError,
     models::location::{LocationFilterQuery, LocationSortQuery},
-    response::location::{LocationListResponse, LocationResponse},
+    response::location::{LocationListResponse, LocationResponse, LocationWarehouseListResponse},
 };
 use extractor::{profile::admin::Admin, query::Pagination};
 use service::Connection;
@@ -127,3 +127,56 @@ pub async fn get_all_locations(
         locations,
     }))
 }
+
+/// Handles the request to list the warehouses serving a location.
+///
+/// - **Path Parameters**:  
+///   `id` (UUID): The database ID of the location.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: Successfully retrieved the warehouses, from the highest priority to the lowest.
+///   - `404 Not Found`: The location doesn't exist.
+///   - `400 Bad Request`: The request is improperly formatted.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+///
+/// - **Permissions**:  
+///   Only an admin can list the warehouses of a location.
+#[utoipa::path(
+    get,
+    path = "/{id}/warehouse",
+    tag = LOCATION_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the location."),
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The location doesn't exist."),
+        (status = 400, description = "The request is improperly formatted."),
+        (status = 200, description = "Successfully retrieved the warehouses of the location.", body = LocationWarehouseListResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_location_warehouses(
+    _admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+) -> Result<Json<LocationWarehouseListResponse>, AppError> {
+    if service::Query::find_location_by_id(&conn, id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "The location with id: {id} doesn't exist"
+        )));
+    }
+
+    let warehouses = service::Query::list_location_warehouses(&conn, id)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    Ok(Json(LocationWarehouseListResponse { warehouses }))
+}
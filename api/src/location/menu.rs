@@ -0,0 +1,79 @@
+//! This module contains the route handler for listing the products that can be bought at a location.
+
+use crate::utils::openapi::LOCATION_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use entity::{
+    error::AppError,
+    response::{
+        location::LocationMenuResponse,
+        product::{ProductResponse, ProductResponseError},
+    },
+};
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Handles the request to list the products that can be bought at a location right now.
+///
+/// The products are the purchasable ones with a sell price, available in the warehouses
+/// linked to the location, or in any warehouse when it isn't linked to one. Each product
+/// comes with the quantity available, counting what its recipes can make.
+///
+/// - **Path Parameters**:  
+///   `id` (UUID): The database ID of the location.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: Successfully retrieved the menu of the location.
+///   - `404 Not Found`: The location doesn't exist, or is hidden and the requester is not an admin.
+///   - `400 Bad Request`: The request is improperly formatted.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+///
+/// - **Permissions**:  
+///   If the location is hidden, only an admin can retrieve its menu.
+#[utoipa::path(
+    get,
+    path = "/{id}/menu",
+    tag = LOCATION_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the location."),
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The location doesn't exist, or is hidden and the requester is not an admin."),
+        (status = 400, description = "The request is improperly formatted."),
+        (status = 200, description = "Successfully retrieved the menu of the location.", body = LocationMenuResponse)
+    ),
+    security(
+        (),
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_location_menu(
+    admin: Option<Admin>,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+) -> Result<Json<LocationMenuResponse>, AppError> {
+    match service::Query::find_location_by_id(&conn, id).await? {
+        Some(location) if !location.hidden || admin.is_some() => {}
+        _ => {
+            return Err(AppError::NotFound(format!(
+                "The location with id: {id} doesn't exist"
+            )));
+        }
+    }
+
+    let products = service::Query::find_location_menu(&conn, id)
+        .await?
+        .into_iter()
+        .map(|(product, available_quantity)| {
+            Ok(ProductResponse::try_from(product)?.with_available_quantity(available_quantity))
+        })
+        .collect::<Result<_, ProductResponseError>>()?;
+
+    Ok(Json(LocationMenuResponse {
+        location: id,
+        products,
+    }))
+}
@@ -3,9 +3,12 @@
 //! This module contains handlers, request structures, and responses for
 //! managing location-related resources in the API. It is responsible for
 //! providing endpoints for creating, retrieving, updating, and deleting
-//! locations, as well as any other location-specific functionality.
+//! locations, linking them to the warehouses serving them and listing what can be
+//! bought there, as well as any other location-specific functionality.
 
 pub mod delete;
 pub mod edit;
 pub mod get;
+pub mod menu;
 pub mod new;
+pub mod orders;
@@ -5,11 +5,18 @@
 //! Admin privileges are required to access this route.
 
 use crate::utils::openapi::LOCATION_TAG;
-use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
 use entity::{
     error::{AppError, ErrorResponse},
-    models::location::ActiveModel,
-    request::location::NewLocationRequest,
+    models::{location::ActiveModel, location_warehouse},
+    request::location::{
+        LocationWarehouseRequestError, NewLocationRequest, NewLocationWarehouseRequest,
+    },
 };
 use extractor::profile::admin::Admin;
 use service::Connection;
@@ -63,3 +70,93 @@ pub async fn post_new_location(
 
     Ok((StatusCode::CREATED, id.to_string()).into_response())
 }
+
+/// Handles the link of a warehouse to a location.
+///
+/// This endpoint allows an administrator to take the stock of a location from a warehouse.
+/// Once a location is linked to a warehouse, its purchases and its menu only use the
+/// warehouses linked to it, from the highest priority to the lowest. The location and the
+/// warehouse must exist, and no other warehouse of the location may use the same priority.
+/// The function returns a `201 Created` response upon success, or an appropriate error
+/// response if the request is invalid or an internal error occurs.
+#[utoipa::path(
+    post,
+    path = "/{location_id}/warehouse/{warehouse_id}",
+    tag = LOCATION_TAG,
+    params(
+        ("location_id" = uuid::Uuid, Path, description = "The database ID of the location."),
+        ("warehouse_id" = uuid::Uuid, Path, description = "The database ID of the warehouse to link."),
+    ),
+    request_body(content = NewLocationWarehouseRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The location doesn't exist."),
+        (status = 400, description = "The request is improperly formatted.", body = ErrorResponse),
+        (status = 201, description = "Successfully linked the warehouse to the location")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn post_new_location_warehouse(
+    admin: Admin,
+    Path((location_id, warehouse_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+    State(conn): State<Connection>,
+    Json(location_warehouse): Json<NewLocationWarehouseRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if service::Query::find_location_by_id(&conn, location_id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "The location with id: {location_id} doesn't exist"
+        )));
+    }
+
+    if service::Query::find_warehouse_by_id(&conn, warehouse_id)
+        .await?
+        .is_none()
+    {
+        return Err(LocationWarehouseRequestError::WarehouseDoesntExist(
+            warehouse_id,
+        ))?;
+    }
+
+    if service::Query::find_location_warehouse_by_id(&conn, location_id, warehouse_id)
+        .await?
+        .is_some()
+    {
+        return Err(
+            LocationWarehouseRequestError::LocationWarehouseAlreadyExist(location_id, warehouse_id),
+        )?;
+    }
+
+    if let Some(existing) = service::Query::find_location_warehouse_by_priority(
+        &conn,
+        location_id,
+        location_warehouse.priority,
+    )
+    .await?
+    {
+        return Err(LocationWarehouseRequestError::PriorityAlreadyUsed(
+            existing.priority,
+            existing.warehouse_id,
+        ))?;
+    }
+
+    let location_warehouse_model: location_warehouse::ActiveModel = location_warehouse.into();
+
+    let result = service::Mutation::create_location_warehouse(
+        &conn,
+        location_id,
+        warehouse_id,
+        location_warehouse_model,
+    )
+    .await?;
+
+    log::info!(
+        "{admin} added a new location ({location_id}) warehouse ({warehouse_id}) - {result:?}",
+    );
+
+    Ok((StatusCode::CREATED, ""))
+}
@@ -0,0 +1,116 @@
+//! This module contains the route handler for listing the orders to deliver to a location.
+
+use crate::utils::openapi::LOCATION_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use axum_extra::extract::Query;
+use entity::{
+    error::AppError,
+    response::order::{OrderFulfilmentListResponse, OrderFulfilmentResponse},
+};
+use extractor::{profile::admin::Admin, query::Pagination};
+use service::Connection;
+use std::collections::HashMap;
+
+/// Handles the request to list the orders still to deliver to a location.
+///
+/// Only completed orders not delivered yet are listed, the oldest first, so runners
+/// know what to bring to each room or dispenser.
+///
+/// - **Path Parameters**:  
+///   `id` (UUID): The database ID of the location.
+///
+/// - **Query Parameters**:  
+///   - `page` (Optional, u64): The page index, default is 0.
+///   - `per_page` (Optional, u64): The number of orders per page, default is 20.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: Successfully retrieved the orders to deliver.
+///   - `404 Not Found`: The location doesn't exist.
+///   - `400 Bad Request`: The request is improperly formatted.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+///
+/// - **Permissions**:  
+///   Only an admin can list the orders of a location.
+#[utoipa::path(
+    get,
+    path = "/{id}/orders",
+    tag = LOCATION_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the location."),
+        Pagination,
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The location doesn't exist."),
+        (status = 400, description = "The request is improperly formatted."),
+        (status = 200, description = "Successfully retrieved the orders to deliver.", body = OrderFulfilmentListResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_location_orders(
+    _admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    Query(pagination): Query<Pagination>,
+    State(conn): State<Connection>,
+) -> Result<Json<OrderFulfilmentListResponse>, AppError> {
+    let page = pagination.page.unwrap_or(0);
+    let per_page = pagination.per_page.unwrap_or(20);
+
+    if service::Query::find_location_by_id(&conn, id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "The location with id: {id} doesn't exist"
+        )));
+    }
+
+    let result = service::Query::list_open_orders_by_location(&conn, id, page, per_page).await?;
+
+    let total_orders = service::Query::count_open_orders_by_location(&conn, id).await?;
+    let total_page = ((total_orders.max(1) - 1) / per_page) + 1;
+
+    let products = service::Query::list_transaction_products_by_transaction_ids(
+        &conn,
+        result
+            .iter()
+            .map(|(transaction, _)| transaction.id)
+            .collect(),
+    )
+    .await?;
+
+    let mut products_by_order = HashMap::<uuid::Uuid, Vec<_>>::new();
+    for line in products {
+        products_by_order
+            .entry(line.transaction_id)
+            .or_default()
+            .push(line.into());
+    }
+
+    let orders = result
+        .into_iter()
+        .filter_map(|(transaction, purchase)| {
+            Some(OrderFulfilmentResponse {
+                id: transaction.id,
+                user_id: transaction.user_id,
+                location_id: purchase.location_id?,
+                status: purchase.fulfilment_status?.into(),
+                created_at: transaction.created_at.into(),
+                products: products_by_order
+                    .remove(&transaction.id)
+                    .unwrap_or_default(),
+            })
+        })
+        .collect();
+
+    Ok(Json(OrderFulfilmentListResponse {
+        current_page: page,
+        total_page,
+        orders,
+    }))
+}
@@ -0,0 +1,115 @@
+//! This module contains the route handler for canceling an order.
+//!
+//! The handler will be accessible via a POST request to the `/order/{id}/cancel` endpoint.
+//! It allows the buyer, or an admin, to cancel an order and get its balance back.
+
+use crate::utils::openapi::ORDER_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::sea_orm_active_enums::{TransactionStatus, TransactionType},
+    request::order::{CancelOrderRequest, CancelOrderRequestError, OrderCancellation},
+};
+use extractor::{profile::user::User, utils::OrderCancelGracePeriod};
+use service::{Connection, stock::CancelRejection};
+
+/// Handler for canceling an order paid with the user balance.
+///
+/// The order moves to `Canceled`, its total is credited back to the balance and every
+/// product taken from a warehouse is put back into it. The refund is recorded along with
+/// the user who canceled the order and the reason.
+///
+/// The buyer can only cancel its order during the grace period following it, an admin can
+/// cancel any order anytime. An order can only be canceled (and refunded) once.
+///
+/// Path: `/order/{id}/cancel`
+///
+/// - **Request Body:** Expects a `CancelOrderRequest` JSON object.
+/// - **Responses:**
+///     - 500: Internal server error (likely database related).
+///     - 404: The order doesn't exist, or isn't yours.
+///     - 400: Bad request (invalid reason, order already canceled, grace period over or balance
+///       in another currency).
+///     - 200: Successfully canceled the order.
+#[utoipa::path(
+    post,
+    path = "/{id}/cancel",
+    tag = ORDER_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "Order database id (the transaction's ID)"),
+    ),
+    request_body(content = CancelOrderRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The order doesn't exist, or isn't yours."),
+        (status = 400, description = "The request is improperly formatted, or the order cannot be canceled.", body = ErrorResponse),
+        (status = 200, description = "Successfully canceled the order.")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn post_cancel_order(
+    user: User,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+    State(grace_period): State<OrderCancelGracePeriod>,
+    Json(cancel): Json<CancelOrderRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let cancellation: OrderCancellation = cancel.try_into()?;
+
+    let transaction = match service::Query::find_transaction_by_id(&conn, id).await? {
+        Some(transaction) if transaction.user_id == user.id || user.is_admin => transaction,
+        _ => {
+            return Err(AppError::NotFound(format!(
+                "The order with id: {id} doesn't exist"
+            )));
+        }
+    };
+
+    if transaction.transaction_type != TransactionType::PurchaseWithBalance {
+        return Err(CancelOrderRequestError::TransactionIsNotAnOrder(id).into());
+    }
+
+    if transaction.status != TransactionStatus::Completed {
+        return Err(CancelOrderRequestError::OrderIsNotCompleted(id).into());
+    }
+
+    if !user.is_admin {
+        let ordered_at: chrono::DateTime<chrono::Utc> = transaction
+            .completed_at
+            .unwrap_or(transaction.created_at)
+            .into();
+        let deadline = chrono::Duration::from_std(grace_period.0)
+            .ok()
+            .and_then(|grace_period| ordered_at.checked_add_signed(grace_period))
+            .unwrap_or(chrono::DateTime::<chrono::Utc>::MAX_UTC);
+        if chrono::Utc::now() > deadline {
+            return Err(CancelOrderRequestError::GracePeriodIsOver(id, deadline).into());
+        }
+    }
+
+    let reason = cancellation.reason.clone();
+
+    let result =
+        service::Mutation::cancel_purchase_with_balance(&conn, id, user.id, cancellation).await?;
+
+    match result {
+        Ok(_) => {}
+        Err(CancelRejection::NotCompleted) => {
+            return Err(CancelOrderRequestError::OrderIsNotCompleted(id).into());
+        }
+        Err(CancelRejection::BalanceCurrencyDoesntMatch) => {
+            return Err(CancelOrderRequestError::BalanceCurrencyDoesntMatch(id).into());
+        }
+    }
+
+    log::info!("{user} canceled the order \"{id}\": {reason}");
+
+    Ok((StatusCode::OK, ""))
+}
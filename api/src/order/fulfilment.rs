@@ -0,0 +1,101 @@
+//! This module contains the route handler for following the delivery of an order.
+//!
+//! The handler will be accessible via a PUT request to the `/order/{id}/fulfilment` endpoint.
+//! It allows the staff to move an order to deliver forward, from `queued` to `delivered`.
+
+use crate::utils::openapi::ORDER_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::sea_orm_active_enums::{FulfilmentStatus, TransactionStatus, TransactionType},
+    request::order::{EditOrderFulfilmentRequest, OrderFulfilmentRequestError},
+};
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Handler for moving an order to deliver forward.
+///
+/// An order goes from `queued` to `preparing`, `ready` and finally `delivered`,
+/// steps can be skipped but the status never goes back.
+///
+/// Path: `/order/{id}/fulfilment`
+///
+/// - **Request Body:** Expects an `EditOrderFulfilmentRequest` JSON object.
+/// - **Responses:**
+///     - 500: Internal server error (likely database related).
+///     - 404: The order doesn't exist.
+///     - 400: Bad request (order without location, canceled, or status going back).
+///     - 200: Successfully updated the fulfilment of the order.
+#[utoipa::path(
+    put,
+    path = "/{id}/fulfilment",
+    tag = ORDER_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "Order database id (the transaction's ID)"),
+    ),
+    request_body(content = EditOrderFulfilmentRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The order doesn't exist."),
+        (status = 400, description = "The request is improperly formatted, or the order cannot move to this status.", body = ErrorResponse),
+        (status = 200, description = "Successfully updated the fulfilment of the order.")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn edit_order_fulfilment(
+    admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+    Json(edit): Json<EditOrderFulfilmentRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let status: FulfilmentStatus = edit.status.into();
+
+    let Some(transaction) = service::Query::find_transaction_by_id(&conn, id).await? else {
+        return Err(AppError::NotFound(format!(
+            "The order with id: {id} doesn't exist"
+        )));
+    };
+
+    if transaction.transaction_type != TransactionType::PurchaseWithBalance {
+        return Err(OrderFulfilmentRequestError::TransactionIsNotAnOrder(id).into());
+    }
+
+    if transaction.status != TransactionStatus::Completed {
+        return Err(OrderFulfilmentRequestError::OrderIsNotCompleted(id).into());
+    }
+
+    let current = service::Query::find_transaction_purchase_with_balance_by_id(&conn, id)
+        .await?
+        .and_then(|purchase| purchase.fulfilment_status)
+        .ok_or(OrderFulfilmentRequestError::OrderHasNoLocation(id))?;
+
+    if status.step() < current.step() {
+        return Err(OrderFulfilmentRequestError::StatusCannotGoBack(
+            id,
+            current.into(),
+            status.into(),
+        )
+        .into());
+    }
+
+    if status == current {
+        return Ok((StatusCode::OK, ""));
+    }
+
+    let result =
+        service::Mutation::update_order_fulfilment(&conn, id, current, status.clone()).await?;
+    if result.is_none() {
+        return Err(OrderFulfilmentRequestError::StatusWasChanged(id).into());
+    }
+
+    log::info!("{admin} moved the order \"{id}\" to {status:?}");
+
+    Ok((StatusCode::OK, ""))
+}
@@ -0,0 +1,9 @@
+//! Order module for buying products through the API.
+//!
+//! This module provides the handlers for placing an order, paid with the
+//! balance of the user, for canceling it, and for following its delivery.
+//! Every order is recorded in the transaction ledger.
+
+pub mod cancel;
+pub mod fulfilment;
+pub mod new;
@@ -0,0 +1,137 @@
+//! This module contains the route handler for placing a new order.
+//!
+//! The handler will be accessible via a POST request to the `/order` endpoint.
+//! It allows a user to buy a list of products with its balance.
+
+use crate::utils::openapi::ORDER_TAG;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use entity::{
+    error::{AppError, ErrorResponse},
+    request::order::{NewOrderRequest, OrderLine, OrderRequestError, merge_order_products},
+};
+use extractor::profile::user::User;
+use service::{Connection, exchange::Rounding, stock::PurchaseRejection};
+
+/// Handler for placing a new order paid with the user balance.
+///
+/// Every line is validated against its product (purchasable, not disabled, below
+/// `max_quantity_per_command`). A product sold in another currency than the balance is
+/// priced with the exchange rate effective now, its unit price being rounded up. The balance
+/// is then debited, every line taken from the stock of a warehouse and the purchase recorded
+/// in a single database transaction.
+///
+/// An order with a `location` is queued to be delivered there.
+///
+/// - Returns a `201 Created` status upon success along with the transaction's ID.
+///
+/// Path: `/order`
+///
+/// - **Request Body:** Expects a `NewOrderRequest` JSON object.
+/// - **Responses:**
+///     - 500: Internal server error (likely database related).
+///     - 400: Bad request (invalid input data or insufficient balance).
+///     - 201: Successfully placed the order, returns the transaction's ID as a string.
+#[utoipa::path(
+    post,
+    path = "",
+    tag = ORDER_TAG,
+    request_body(content = NewOrderRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 400, description = "The request is improperly formatted, or the balance is insufficient.", body = ErrorResponse),
+        (status = 201, description = "Successfully placed the order, returns the transaction's ID as a string.", body = uuid::Uuid)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn post_new_order(
+    user: User,
+    State(conn): State<Connection>,
+    Json(order): Json<NewOrderRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let products = merge_order_products(order.products);
+    if products.is_empty() {
+        return Err(OrderRequestError::OrderCannotBeEmpty.into());
+    }
+
+    let Some(user_model) = service::Query::find_user_by_id(&conn, user.id).await? else {
+        return Err(AppError::NotFound(format!(
+            "The user with id: {} doesn't exist",
+            user.id
+        )));
+    };
+    let currency = user_model.balance_currency;
+
+    if let Some(location_id) = order.location {
+        match service::Query::find_location_by_id(&conn, location_id).await? {
+            Some(location) if location.disabled => {
+                return Err(OrderRequestError::LocationIsDisabled(location_id).into());
+            }
+            Some(_) => {}
+            None => return Err(OrderRequestError::LocationDoesntExist(location_id).into()),
+        }
+    }
+
+    let mut lines = Vec::with_capacity(products.len());
+    for line in products {
+        let Some(product) = service::Query::find_product_by_id(&conn, line.product).await? else {
+            return Err(OrderRequestError::ProductDoesntExist(line.product).into());
+        };
+        let mut line = OrderLine::try_new(product, line.quantity)?;
+
+        if line.currency != currency {
+            let price = service::Query::convert_amount(
+                &conn,
+                line.price,
+                line.currency.clone(),
+                currency.clone(),
+                Rounding::Up,
+            )
+            .await?;
+
+            let Some(price) = price else {
+                return Err(OrderRequestError::ProductCurrencyDoesntMatch(
+                    line.product.id,
+                    line.currency.into(),
+                    currency.into(),
+                )
+                .into());
+            };
+
+            line.price = price;
+            line.currency = currency.clone();
+        }
+
+        lines.push(line);
+    }
+
+    let total = lines
+        .iter()
+        .map(OrderLine::total)
+        .sum::<rust_decimal::Decimal>();
+
+    let result = service::Mutation::create_purchase_with_balance(
+        &conn,
+        user.id,
+        currency,
+        lines,
+        order.location,
+    )
+    .await?;
+
+    let transaction = match result {
+        Ok(transaction) => transaction,
+        Err(PurchaseRejection::InsufficientBalance) => {
+            return Err(OrderRequestError::InsufficientBalance(total, user_model.balance).into());
+        }
+        Err(PurchaseRejection::InsufficientStock(product)) => {
+            return Err(OrderRequestError::InsufficientStock(product).into());
+        }
+    };
+
+    let id = transaction.id;
+    log::info!("{user} placed a new order \"{id}\" - ({total})");
+
+    Ok((StatusCode::CREATED, id.to_string()).into_response())
+}
@@ -0,0 +1,123 @@
+//! This module contains the checkout routes of the fake payment provider.
+//!
+//! They stand in for the checkout page of a real payment provider: visiting them confirms
+//! or cancels the payment of a refill, then sends the user back to the frontend.
+//! These routes are only registered when the fake payment provider is configured.
+
+use super::{cancel_url, success_url};
+use crate::utils::openapi::PAYMENT_TAG;
+use axum::{
+    extract::{Path, State},
+    response::{IntoResponse, Redirect},
+};
+use entity::{
+    error::AppError,
+    models::sea_orm_active_enums::{TransactionStatus, TransactionType},
+};
+use extractor::{profile::user::User, utils::FrontendUrl};
+use service::Connection;
+
+/// Checks that `id` is an open refill transaction of `user`.
+async fn ensure_open_refill_transaction(
+    conn: &Connection,
+    user: &User,
+    id: uuid::Uuid,
+) -> Result<(), AppError> {
+    match service::Query::find_transaction_by_id(conn, id).await? {
+        Some(transaction)
+            if transaction.user_id == user.id
+                && transaction.transaction_type == TransactionType::RefillBalance
+                && matches!(
+                    transaction.status,
+                    TransactionStatus::Created | TransactionStatus::Pending
+                ) =>
+        {
+            Ok(())
+        }
+        _ => Err(AppError::NotFound(format!(
+            "The transaction with id: {id} is not waiting for a payment"
+        ))),
+    }
+}
+
+/// Confirms the payment of a refill transaction, crediting the user balance.
+///
+/// - **Response Codes**:
+///   - `303 See Other`: The payment was confirmed, the user is sent back to the frontend.
+///   - `404 Not Found`: The transaction doesn't exist, or is not waiting for a payment.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+#[utoipa::path(
+    get,
+    path = "/fake/{id}",
+    tag = PAYMENT_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the transaction to pay."),
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The transaction doesn't exist, or is not waiting for a payment."),
+        (status = 303, description = "The payment was confirmed, go back to the frontend.")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_fake_payment_confirm(
+    user: User,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+    State(frontend_url): State<FrontendUrl>,
+) -> Result<impl IntoResponse, AppError> {
+    ensure_open_refill_transaction(&conn, &user, id).await?;
+
+    if service::Mutation::complete_refill_balance_transaction(&conn, id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "The transaction with id: {id} is not waiting for a payment"
+        )));
+    }
+
+    log::info!("{user} paid transaction \"{id}\" with the fake payment provider");
+
+    Ok(Redirect::to(&success_url(&frontend_url, id)))
+}
+
+/// Cancels the payment of a refill transaction.
+///
+/// - **Response Codes**:
+///   - `303 See Other`: The payment was canceled, the user is sent back to the frontend.
+///   - `404 Not Found`: The transaction doesn't exist, or is not waiting for a payment.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+#[utoipa::path(
+    get,
+    path = "/fake/{id}/cancel",
+    tag = PAYMENT_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the transaction to cancel."),
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The transaction doesn't exist, or is not waiting for a payment."),
+        (status = 303, description = "The payment was canceled, go back to the frontend.")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_fake_payment_cancel(
+    user: User,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+    State(frontend_url): State<FrontendUrl>,
+) -> Result<impl IntoResponse, AppError> {
+    ensure_open_refill_transaction(&conn, &user, id).await?;
+
+    service::Mutation::update_open_transaction_status(&conn, id, TransactionStatus::Canceled)
+        .await?;
+
+    log::info!("{user} canceled transaction \"{id}\" with the fake payment provider");
+
+    Ok(Redirect::to(&cancel_url(&frontend_url, id)))
+}
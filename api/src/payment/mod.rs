@@ -0,0 +1,22 @@
+//! Payment module for the routes called back by payment providers.
+//!
+//! This module provides the handlers receiving the outcome of a payment (webhooks,
+//! checkout pages of the fake provider), and the helpers building the urls a user
+//! is sent back to once a payment is done.
+
+pub mod fake;
+pub mod webhook;
+
+use extractor::utils::FrontendUrl;
+
+/// Url of the frontend page the user is sent to once a payment succeeded.
+pub fn success_url(frontend_url: &FrontendUrl, transaction_id: uuid::Uuid) -> String {
+    let frontend_url = frontend_url.0.trim_end_matches('/');
+    format!("{frontend_url}/payment/success?transaction={transaction_id}")
+}
+
+/// Url of the frontend page the user is sent to once a payment was abandoned.
+pub fn cancel_url(frontend_url: &FrontendUrl, transaction_id: uuid::Uuid) -> String {
+    let frontend_url = frontend_url.0.trim_end_matches('/');
+    format!("{frontend_url}/payment/cancel?transaction={transaction_id}")
+}
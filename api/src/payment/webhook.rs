@@ -0,0 +1,109 @@
+//! This module contains the route handler receiving the webhooks of the payment provider.
+//!
+//! The handler will be accessible via a POST request to the `/webhook/payment` endpoint.
+//! Payloads are only trusted once their signature has been verified by the provider.
+
+use crate::utils::openapi::PAYMENT_TAG;
+use axum::{body::Bytes, extract::State, http::HeaderMap, http::StatusCode};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::sea_orm_active_enums::TransactionStatus,
+    request::payment::PaymentWebhookError,
+};
+use service::{
+    Connection,
+    payment::{PaymentError, PaymentGateway},
+};
+
+/// Handler for the webhooks sent by the payment provider.
+///
+/// The signature of the payload is verified, then the refill transaction it is about is
+/// moved to `Pending`, `Completed` (crediting the user balance), `Failed` or `Canceled`.
+/// Receiving the same event twice is harmless, a closed transaction is never updated again.
+///
+/// Path: `/webhook/payment`
+///
+/// - **Responses:**
+///     - 500: Internal server error (likely database related).
+///     - 404: No provider sending webhooks is configured, or the transaction doesn't exist.
+///     - 400: The signature or the payload is invalid.
+///     - 200: The event was processed.
+#[utoipa::path(
+    post,
+    path = "/payment",
+    tag = PAYMENT_TAG,
+    request_body(content = String, description = "The raw payload signed by the provider."),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "No provider sending webhooks is configured, or the transaction doesn't exist."),
+        (status = 400, description = "The signature or the payload is invalid.", body = ErrorResponse),
+        (status = 200, description = "The event was processed.")
+    )
+)]
+pub async fn post_payment_webhook(
+    State(conn): State<Connection>,
+    State(payment): State<Option<PaymentGateway>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<StatusCode, AppError> {
+    let Some((provider, header)) = payment.as_ref().and_then(|payment| {
+        payment
+            .provider
+            .webhook_signature_header()
+            .map(|header| (&payment.provider, header))
+    }) else {
+        return Err(AppError::NotFound(
+            "No payment provider is waiting for webhooks".to_string(),
+        ));
+    };
+
+    let Some(signature) = headers.get(header).and_then(|x| x.to_str().ok()) else {
+        return Err(PaymentWebhookError::MissingSignature(header.to_string()).into());
+    };
+
+    let event = match provider.parse_webhook(signature, &body) {
+        Ok(Some(event)) => event,
+        Ok(None) => return Ok(StatusCode::OK),
+        Err(PaymentError::InvalidSignature(err)) => {
+            log::warn!("Rejected a {} webhook: {err}", provider.name());
+            return Err(PaymentWebhookError::InvalidSignature(err).into());
+        }
+        Err(err) => return Err(PaymentWebhookError::InvalidPayload(err.to_string()).into()),
+    };
+
+    let id = event.transaction_id;
+    let Some(refill) = service::Query::find_transaction_refill_balance_by_id(&conn, id).await?
+    else {
+        return Err(AppError::NotFound(format!(
+            "The transaction with id: {id} doesn't exist"
+        )));
+    };
+
+    if refill
+        .stripe_transaction_id
+        .is_some_and(|session_id| session_id != event.session_id)
+    {
+        return Err(PaymentWebhookError::SessionDoesntMatch(id, event.session_id).into());
+    }
+
+    let result = match event.status {
+        TransactionStatus::Completed => {
+            service::Mutation::complete_refill_balance_transaction(&conn, id).await?
+        }
+        status => service::Mutation::update_open_transaction_status(&conn, id, status).await?,
+    };
+
+    match result {
+        Some(transaction) => log::info!(
+            "{} moved transaction \"{id}\" to {:?}",
+            provider.name(),
+            transaction.status
+        ),
+        None => log::info!(
+            "{} notified transaction \"{id}\" which is already closed",
+            provider.name()
+        ),
+    }
+
+    Ok(StatusCode::OK)
+}
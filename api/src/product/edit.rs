@@ -1,6 +1,6 @@
 //! Route for editing an existing product in the store.
 
-use crate::utils::openapi::PRODUCT_TAG;
+use crate::{category::check_category, currency::check_currency, utils::openapi::PRODUCT_TAG};
 use axum::{
     Json,
     extract::{Path, State},
@@ -45,6 +45,16 @@ pub async fn edit_product(
 
     match result {
         Some(existing_product) => {
+            if let Some(currency) = &edit_product.sell_price_currency {
+                check_currency(&conn, currency).await?;
+            }
+            if let Some(Some(currency)) = &edit_product.cost_price_currency {
+                check_currency(&conn, currency).await?;
+            }
+            if let Some(Some(category)) = edit_product.category {
+                check_category(&conn, category).await?;
+            }
+
             let edit_product: ActiveModel = edit_product.try_into()?;
 
             let mut check_image = None;
@@ -8,10 +8,12 @@ use axum::{
 use axum_extra::extract::Query;
 use entity::{
     error::AppError,
-    models::product::{ProductFilterQuery, ProductSortQuery},
+    models::product::{self, ProductFilterQuery, ProductSortQuery},
+    request::product::ProductCategoryQuery,
     response::product::{ProductListResponse, ProductResponse, ProductResponseError},
 };
 use extractor::{profile::admin::Admin, query::Pagination};
+use sea_orm::{ColumnTrait, Condition, sea_query::IntoCondition};
 use service::Connection;
 
 /// Handles the request to fetch a product by its unique identifier.
@@ -58,7 +60,17 @@ pub async fn get_product(
                 )));
             };
 
-            Ok(Json(product.try_into()?))
+            let available = service::Query::available_quantities(&conn, None, &[id]).await?;
+            let available_quantity = available.get(&id).copied().unwrap_or_default();
+
+            let cost = (product.cost_price, product.cost_price_currency.clone());
+            let mut response =
+                ProductResponse::try_from(product)?.with_available_quantity(available_quantity);
+            if admin.is_some() {
+                response = response.with_cost_price(cost.0, cost.1)?;
+            }
+
+            Ok(Json(response))
         }
         None => Err(AppError::NotFound(format!(
             "The product with id: {id} doesn't exist"
@@ -71,6 +83,8 @@ pub async fn get_product(
 /// - **Query Parameters**:  
 ///   - `page` (Optional, u64): The page index, default is 0.
 ///   - `per_page` (Optional, u64): The number of products per page, default is 20.
+///   - `category_id_eq` (Optional, UUID): Only lists the products of this category, or of a
+///     category nested inside it.
 ///
 /// - **Response Codes**:  
 ///   - `200 OK`: Successfully retrieved a list of products.
@@ -86,6 +100,7 @@ pub async fn get_product(
     params(
         Pagination,
         ProductFilterQuery,
+        ProductCategoryQuery,
         ProductSortQuery
     ),
     responses(
@@ -98,6 +113,7 @@ pub async fn get_all_products(
     admin: Option<Admin>,
     Query(pagination): Query<Pagination>,
     Query(mut filter): Query<ProductFilterQuery>,
+    Query(category): Query<ProductCategoryQuery>,
     Query(sort): Query<ProductSortQuery>,
     State(conn): State<Connection>,
 ) -> Result<Json<ProductListResponse>, AppError> {
@@ -113,16 +129,42 @@ pub async fn get_all_products(
         filter.hidden_neq = None;
     }
 
-    let result =
-        service::Query::list_products_with_condition(&conn, filter.clone(), sort, page, per_page)
-            .await?;
+    let mut condition = Condition::all().add(filter.into_condition());
+    if !category.category_id_eq.is_empty() {
+        let mut categories = Vec::new();
+        for id in category.category_id_eq {
+            categories.extend(service::Query::category_descendants(&conn, id).await?);
+        }
+        condition = condition.add(product::Column::CategoryId.is_in(categories));
+    }
 
-    let total_products = service::Query::count_products_with_condition(&conn, filter).await?;
+    let result = service::Query::list_products_with_condition(
+        &conn,
+        condition.clone(),
+        sort,
+        page,
+        per_page,
+    )
+    .await?;
+
+    let total_products = service::Query::count_products_with_condition(&conn, condition).await?;
     let total_page = ((total_products.max(1) - 1) / per_page) + 1;
 
+    let product_ids = result.iter().map(|product| product.id).collect::<Vec<_>>();
+    let available = service::Query::available_quantities(&conn, None, &product_ids).await?;
+
     let products = result
         .into_iter()
-        .map(|x| x.try_into())
+        .map(|product| {
+            let available_quantity = available.get(&product.id).copied().unwrap_or_default();
+            let cost = (product.cost_price, product.cost_price_currency.clone());
+            let response =
+                ProductResponse::try_from(product)?.with_available_quantity(available_quantity);
+            match admin {
+                Some(_) => response.with_cost_price(cost.0, cost.1),
+                None => Ok(response),
+            }
+        })
         .collect::<Result<_, ProductResponseError>>()?;
     Ok(Json(ProductListResponse {
         current_page: page,
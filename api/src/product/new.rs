@@ -4,7 +4,7 @@
 //! It allows for the creation of new product entries in the database.
 //! Admin privileges are required to access this route.
 
-use crate::utils::openapi::PRODUCT_TAG;
+use crate::{category::check_category, currency::check_currency, utils::openapi::PRODUCT_TAG};
 use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
 use entity::{
     error::{AppError, ErrorResponse},
@@ -66,6 +66,16 @@ pub async fn post_new_product(
             })?;
     }
 
+    if let Some(currency) = &product.sell_price_currency {
+        check_currency(&conn, currency).await?;
+    }
+    if let Some(currency) = &product.cost_price_currency {
+        check_currency(&conn, currency).await?;
+    }
+    if let Some(category) = product.category {
+        check_category(&conn, category).await?;
+    }
+
     let product_model: ActiveModel = product.try_into()?;
     let result = service::Mutation::create_product(&conn, product_model).await?;
 
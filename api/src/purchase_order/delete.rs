@@ -0,0 +1,68 @@
+//! This module defines the API endpoint to delete a draft purchase order by its ID.
+//!
+//! Only an admin can delete a purchase order.
+
+use crate::utils::openapi::PURCHASE_ORDER_TAG;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{
+    error::{AppError, ErrorResponse},
+    request::purchase_order::PurchaseOrderRequestError,
+};
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Deletes a draft purchase order by its database ID, with its lines.
+///
+/// A purchase order which was sent is kept. Only an admin can perform this action.
+///
+/// - **Path Parameters:**
+///   - `id`: The unique ID of the purchase order in the database.
+///
+/// - **Responses:**
+///   - `500`: Internal error, likely related to the database.
+///   - `404`: The purchase order doesn't exist.
+///   - `400`: The purchase order isn't a draft.
+///   - `200`: The purchase order has been successfully deleted.
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tag = PURCHASE_ORDER_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "purchase order database id to delete"),
+    ),
+    responses(
+        (status = 500, description = "An internal error occured, probably databse related"),
+        (status = 404, description = "The purchase order doesn't exist"),
+        (status = 400, description = "The purchase order isn't a draft", body = ErrorResponse),
+        (status = 200, description = "The purchase order is deleted")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn delete_purchase_order(
+    admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+) -> Result<impl IntoResponse, AppError> {
+    let Some(purchase_order) = service::Query::find_purchase_order_by_id(&conn, id).await? else {
+        return Err(AppError::NotFound(format!(
+            "The purchase order with id: {id} doesn't exist"
+        )));
+    };
+
+    if service::Mutation::delete_purchase_order(&conn, id)
+        .await?
+        .is_none()
+    {
+        return Err(PurchaseOrderRequestError::PurchaseOrderIsNotDraft(id))?;
+    }
+
+    log::info!("{admin} just deleted the purchase order \"{id}\" - {purchase_order:?}");
+
+    Ok((StatusCode::OK, ""))
+}
@@ -0,0 +1,207 @@
+//! This module contains the route handlers for editing a draft purchase order, sending it to its
+//! supplier, and receiving the ordered products.
+//!
+//! Admin privileges are required to access these routes.
+
+use super::new::{check_lines, check_supplier, check_warehouse};
+use crate::{currency::check_currency, utils::openapi::PURCHASE_ORDER_TAG};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::{purchase_order, purchase_order_line},
+    request::purchase_order::{
+        EditPurchaseOrderRequest, PurchaseOrderRequestError, ReceivePurchaseOrderRequest,
+    },
+    response::purchase_order::PurchaseOrderResponse,
+};
+use extractor::profile::admin::Admin;
+use service::{Connection, stock::ReceiptRejection};
+
+/// Checks the purchase order `id` exists.
+async fn check_purchase_order(conn: &Connection, id: uuid::Uuid) -> Result<(), AppError> {
+    match service::Query::find_purchase_order_by_id(conn, id).await? {
+        Some(_) => Ok(()),
+        None => Err(AppError::NotFound(format!(
+            "The purchase order with id: {id} doesn't exist"
+        ))),
+    }
+}
+
+/// Edits a draft purchase order.
+///
+/// When lines are given, they replace every previous line of the purchase order.
+///
+/// Returns an error if the purchase order doesn't exist or isn't a draft, if a supplier,
+/// warehouse, currency or product is unknown, if there is a validation issue, or if a database
+/// operation fails.
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tag = PURCHASE_ORDER_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the purchase order to edit."),
+    ),
+    request_body(content = EditPurchaseOrderRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error occured, probably database related"),
+        (status = 404, description = "The purchase order doesn't exist"),
+        (status = 400, description = "Your request is not correctly formatted", body = ErrorResponse),
+        (status = 200, description = "The purchase order is correctly edited")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn edit_purchase_order(
+    admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+    Json(edit_purchase_order): Json<EditPurchaseOrderRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    edit_purchase_order.validate()?;
+
+    check_purchase_order(&conn, id).await?;
+
+    if let Some(supplier) = edit_purchase_order.supplier {
+        check_supplier(&conn, supplier).await?;
+    }
+    if let Some(warehouse) = edit_purchase_order.warehouse {
+        check_warehouse(&conn, warehouse).await?;
+    }
+    if let Some(currency) = &edit_purchase_order.currency {
+        check_currency(&conn, currency).await?;
+    }
+    if let Some(lines) = &edit_purchase_order.lines {
+        check_lines(&conn, lines).await?;
+    }
+
+    let lines: Option<Vec<purchase_order_line::ActiveModel>> = edit_purchase_order
+        .lines
+        .clone()
+        .map(|lines| lines.into_iter().map(Into::into).collect());
+    let edit_purchase_order: purchase_order::ActiveModel = edit_purchase_order.into();
+    let Some(result) =
+        service::Mutation::update_purchase_order(&conn, id, edit_purchase_order, lines).await?
+    else {
+        return Err(PurchaseOrderRequestError::PurchaseOrderIsNotDraft(id))?;
+    };
+
+    log::info!("{admin} successfully edited purchase order \"{id}\" - {result:?}");
+
+    Ok((StatusCode::OK, ""))
+}
+
+/// Sends a draft purchase order to its supplier, its lines can't change anymore.
+///
+/// Returns an error if the purchase order doesn't exist, isn't a draft, or if a database
+/// operation fails.
+#[utoipa::path(
+    post,
+    path = "/{id}/send",
+    tag = PURCHASE_ORDER_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the purchase order to send."),
+    ),
+    responses(
+        (status = 500, description = "An internal error occured, probably database related"),
+        (status = 404, description = "The purchase order doesn't exist"),
+        (status = 400, description = "The purchase order isn't a draft", body = ErrorResponse),
+        (status = 200, description = "The purchase order is sent")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn send_purchase_order(
+    admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+) -> Result<impl IntoResponse, AppError> {
+    check_purchase_order(&conn, id).await?;
+
+    let Some(result) = service::Mutation::send_purchase_order(&conn, id).await? else {
+        return Err(PurchaseOrderRequestError::PurchaseOrderIsNotDraft(id))?;
+    };
+
+    log::info!("{admin} sent the purchase order \"{id}\" - {result:?}");
+
+    Ok((StatusCode::OK, ""))
+}
+
+/// Receives products of a sent purchase order into its warehouse.
+///
+/// The stock of each received product grows by its quantity, recorded as a restock in the stock
+/// journal, and the purchase order becomes received once every ordered quantity was received.
+/// When `update_cost_price` is set, the unit cost of each received product becomes its cost
+/// price.
+///
+/// Returns the purchase order with its lines, or an error if it doesn't exist, isn't waiting for
+/// products, if a product isn't ordered or more of it is received than what remains, or if a
+/// database operation fails.
+#[utoipa::path(
+    post,
+    path = "/{id}/receive",
+    tag = PURCHASE_ORDER_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the purchase order to receive."),
+    ),
+    request_body(content = ReceivePurchaseOrderRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error occured, probably database related"),
+        (status = 404, description = "The purchase order doesn't exist"),
+        (status = 400, description = "Your request is not correctly formatted", body = ErrorResponse),
+        (status = 200, description = "The products are received", body = PurchaseOrderResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn receive_purchase_order(
+    admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+    Json(receipt): Json<ReceivePurchaseOrderRequest>,
+) -> Result<Json<PurchaseOrderResponse>, AppError> {
+    receipt.validate()?;
+
+    check_purchase_order(&conn, id).await?;
+
+    let lines: Vec<_> = receipt
+        .products
+        .into_iter()
+        .map(|line| (line.product, line.quantity, line.expires_at.map(Into::into)))
+        .collect();
+    let result = service::Mutation::receive_purchase_order(
+        &conn,
+        id,
+        lines.clone(),
+        receipt.update_cost_price,
+        admin.id,
+    )
+    .await?;
+
+    let purchase_order = match result {
+        Ok(purchase_order) => purchase_order,
+        Err(ReceiptRejection::NotReceivable) => {
+            return Err(PurchaseOrderRequestError::PurchaseOrderCannotBeReceived(id))?;
+        }
+        Err(ReceiptRejection::NotOrdered(product)) => {
+            return Err(PurchaseOrderRequestError::ProductNotOrdered(product))?;
+        }
+        Err(ReceiptRejection::ExceedsOrdered(product, remaining)) => {
+            return Err(PurchaseOrderRequestError::QuantityExceedsOrdered(
+                product, remaining,
+            ))?;
+        }
+    };
+
+    log::info!("{admin} received products of the purchase order \"{id}\" - {lines:?}");
+
+    let purchase_order_lines = service::Query::list_purchase_order_lines(&conn, id).await?;
+    Ok(Json((purchase_order, purchase_order_lines).into()))
+}
@@ -0,0 +1,112 @@
+//! This module contains the route handlers for retrieving purchase orders.
+
+use crate::utils::openapi::PURCHASE_ORDER_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use axum_extra::extract::Query;
+use entity::{
+    error::AppError,
+    models::purchase_order::{Purchase_orderFilterQuery, Purchase_orderSortQuery},
+    response::purchase_order::{PurchaseOrderListResponse, PurchaseOrderResponse},
+};
+use extractor::{profile::admin::Admin, query::Pagination};
+use service::Connection;
+
+/// Handles the request to fetch a purchase order with its lines.
+///
+/// - **Path Parameters**:  
+///   `id` (UUID): The database ID of the purchase order to retrieve.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: The purchase order was successfully retrieved.
+///   - `404 Not Found`: The purchase order doesn't exist.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tag = PURCHASE_ORDER_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the purchase order to retrieve."),
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The purchase order doesn't exist."),
+        (status = 200, description = "The purchase order was successfully retrieved.", body = PurchaseOrderResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_purchase_order(
+    _admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+) -> Result<Json<PurchaseOrderResponse>, AppError> {
+    let Some(purchase_order) = service::Query::find_purchase_order_by_id(&conn, id).await? else {
+        return Err(AppError::NotFound(format!(
+            "The purchase order with id: {id} doesn't exist"
+        )));
+    };
+
+    let lines = service::Query::list_purchase_order_lines(&conn, id).await?;
+    Ok(Json((purchase_order, lines).into()))
+}
+
+/// Handles the request to retrieve a paginated list of purchase orders, without their lines.
+///
+/// - **Query Parameters**:  
+///   - `page` (Optional, u64): The page index, default is 0.
+///   - `per_page` (Optional, u64): The number of purchase orders per page, default is 20.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: Successfully retrieved a list of purchase orders.
+///   - `400 Bad Request`: The request is improperly formatted.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = PURCHASE_ORDER_TAG,
+    params(
+        Pagination,
+        Purchase_orderFilterQuery,
+        Purchase_orderSortQuery,
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 200, description = "Successfully retrieved a list of purchase orders.", body = PurchaseOrderListResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_all_purchase_orders(
+    _admin: Admin,
+    Query(pagination): Query<Pagination>,
+    Query(filter): Query<Purchase_orderFilterQuery>,
+    Query(sort): Query<Purchase_orderSortQuery>,
+    State(conn): State<Connection>,
+) -> Result<Json<PurchaseOrderListResponse>, AppError> {
+    let page = pagination.page.unwrap_or(0);
+    let per_page = pagination.per_page.unwrap_or(20);
+
+    let result = service::Query::list_purchase_orders_with_condition(
+        &conn,
+        filter.clone(),
+        sort,
+        page,
+        per_page,
+    )
+    .await?;
+
+    let total_purchase_orders =
+        service::Query::count_purchase_orders_with_condition(&conn, filter).await?;
+    let total_page = ((total_purchase_orders.max(1) - 1) / per_page) + 1;
+
+    Ok(Json(PurchaseOrderListResponse {
+        current_page: page,
+        total_page,
+        purchase_orders: result.into_iter().map(Into::into).collect(),
+    }))
+}
@@ -0,0 +1,10 @@
+//! Purchase order module for restocking the warehouses from suppliers.
+//!
+//! This module provides handlers for the endpoints related to drafting a
+//! purchase order to a supplier, sending it, and receiving the ordered
+//! products into its warehouse, possibly over several deliveries.
+
+pub mod delete;
+pub mod edit;
+pub mod get;
+pub mod new;
@@ -0,0 +1,108 @@
+//! This module contains the route handler for drafting a purchase order.
+//!
+//! The handler will be accessible via a POST request to the `/purchase_order` endpoint.
+//! Admin privileges are required to access this route.
+
+use crate::{currency::check_currency, utils::openapi::PURCHASE_ORDER_TAG};
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::{purchase_order, purchase_order_line},
+    request::purchase_order::{
+        NewPurchaseOrderRequest, PurchaseOrderLineRequest, PurchaseOrderRequestError,
+    },
+};
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Checks the supplier exists and is enabled.
+pub(crate) async fn check_supplier(conn: &Connection, id: uuid::Uuid) -> Result<(), AppError> {
+    match service::Query::find_supplier_by_id(conn, id).await? {
+        Some(supplier) if supplier.disabled => {
+            Err(PurchaseOrderRequestError::SupplierIsDisabled(id))?
+        }
+        Some(_) => Ok(()),
+        None => Err(PurchaseOrderRequestError::SupplierDoesntExist(id))?,
+    }
+}
+
+/// Checks the warehouse exists.
+pub(crate) async fn check_warehouse(conn: &Connection, id: uuid::Uuid) -> Result<(), AppError> {
+    match service::Query::find_warehouse_by_id(conn, id).await? {
+        Some(_) => Ok(()),
+        None => Err(PurchaseOrderRequestError::WarehouseDoesntExist(id))?,
+    }
+}
+
+/// Checks every ordered product exists.
+pub(crate) async fn check_lines(
+    conn: &Connection,
+    lines: &[PurchaseOrderLineRequest],
+) -> Result<(), AppError> {
+    for line in lines {
+        if service::Query::find_product_by_id(conn, line.product)
+            .await?
+            .is_none()
+        {
+            return Err(PurchaseOrderRequestError::ProductDoesntExist(line.product))?;
+        }
+    }
+    Ok(())
+}
+
+/// Handler for drafting a purchase order to a supplier.
+///
+/// The purchase order stays a draft, whose lines can still change, until it is sent.
+///
+/// - **Admin privileges** are required to access this route.
+/// - Returns a `201 Created` status upon successful creation along with the purchase order's ID.
+///
+/// Path: `/purchase_order`
+///
+/// - **Request Body:** Expects a `NewPurchaseOrderRequest` JSON object.
+/// - **Responses:**
+///     - 500: Internal server error (likely database related).
+///     - 400: Bad request (invalid lines, unknown or disabled supplier, unknown warehouse,
+///       currency or product).
+///     - 201: Successfully drafted the purchase order, returns its ID as a string.
+#[utoipa::path(
+    post,
+    path = "",
+    tag = PURCHASE_ORDER_TAG,
+    request_body(content = NewPurchaseOrderRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 400, description = "The request is improperly formatted.", body = ErrorResponse),
+        (status = 201, description = "Successfully drafted the purchase order, returns its ID as a string.", body = uuid::Uuid)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn post_new_purchase_order(
+    admin: Admin,
+    State(conn): State<Connection>,
+    Json(purchase_order): Json<NewPurchaseOrderRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    purchase_order.validate()?;
+
+    check_supplier(&conn, purchase_order.supplier).await?;
+    check_warehouse(&conn, purchase_order.warehouse).await?;
+    check_currency(&conn, &purchase_order.currency).await?;
+    check_lines(&conn, &purchase_order.lines).await?;
+
+    let lines: Vec<purchase_order_line::ActiveModel> = purchase_order
+        .lines
+        .iter()
+        .cloned()
+        .map(Into::into)
+        .collect();
+    let purchase_order: purchase_order::ActiveModel = purchase_order.into();
+    let result =
+        service::Mutation::create_purchase_order(&conn, purchase_order, lines, admin.id).await?;
+
+    let id = result.id;
+    log::info!("{admin} drafted the purchase order \"{id}\" - {result:?}");
+
+    Ok((StatusCode::CREATED, id.to_string()).into_response())
+}
@@ -1,6 +1,6 @@
 //! Route for editing an existing recipe in the store.
 
-use crate::utils::openapi::RECIPE_TAG;
+use crate::{recipe::new::convert_ingredient, utils::openapi::RECIPE_TAG};
 use axum::{
     Json,
     extract::{Path, State},
@@ -21,7 +21,8 @@ use service::Connection;
 /// The admin can change attributes such as the name, price, quantity, or image of the recipe.
 /// If the recipe image is changed, the old image will be deleted from S3 storage.
 ///
-/// Returns an error if the recipe doesn't exist, if there is a validation issue, or if a database or S3 operation fails.
+/// Returns an error if the recipe doesn't exist, if there is a validation issue, if an ingredient
+/// would require the resulting product through other recipes, or if a database or S3 operation fails.
 #[utoipa::path(
     put,
     path = "/{id}",
@@ -60,20 +61,34 @@ pub async fn edit_recipe(
                 }
             }
 
-            for ingredient in edit_recipe.ingredients.clone() {
+            let mut ingredients = Vec::with_capacity(edit_recipe.ingredients.len());
+            for ingredient in edit_recipe.ingredients {
                 TryInto::<recipe_ingredients::ActiveModel>::try_into(ingredient.clone())?;
 
-                service::Query::find_product_by_id(&conn, ingredient.product)
-                    .await?
-                    .ok_or(RecipeRequestError::IngredientCannotBeFound(
-                        ingredient.product,
-                    ))?;
+                ingredients.push(convert_ingredient(&conn, ingredient).await?);
+            }
+
+            // The edited recipe replaces the existing one in the graph of recipes
+            let result_product_id = match &edit_recipe_model.result_product_id {
+                Set(product) => *product,
+                _ => existing_recipe.0.result_product_id,
+            };
+            let ingredient_ids: Vec<_> = ingredients.iter().map(|x| x.product).collect();
+            if let Some(cycle) = service::Query::find_recipe_cycle(
+                &conn,
+                result_product_id,
+                &ingredient_ids,
+                Some(id),
+            )
+            .await?
+            {
+                return Err(RecipeRequestError::IngredientCreatesCycle(cycle).into());
             }
             //
 
             let result = service::Mutation::update_recipe(&conn, id, edit_recipe_model).await?;
 
-            let edit_ingredients = merge_ingredients(edit_recipe.ingredients);
+            let edit_ingredients = merge_ingredients(ingredients);
 
             let new_ingredients_id: std::collections::HashSet<_> =
                 edit_ingredients.iter().map(|x| x.product).collect();
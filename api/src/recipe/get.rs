@@ -9,10 +9,31 @@ use axum_extra::extract::Query;
 use entity::{
     error::AppError,
     models::recipe::{RecipeFilterQuery, RecipeSortQuery},
-    response::recipe::{RecipeListResponse, RecipeResponse, RecipeResponseError},
+    response::{
+        r#enum::UnitResponse,
+        recipe::{
+            RecipeListResponse, RecipeRawProductResponse, RecipeResponse, RecipeResponseError,
+            RecipeTreeNodeResponse, RecipeTreeResponse, RecipeUnitTotalResponse,
+        },
+    },
 };
 use extractor::{profile::admin::Admin, query::Pagination};
+use rust_decimal::Decimal;
 use service::Connection;
+use service::bom::{Bom, BomNode};
+use service::costing::RecipeCost;
+use std::collections::HashMap;
+
+/// Adds its cost to a recipe response, when it could be computed.
+fn with_cost(
+    response: RecipeResponse,
+    cost: Option<RecipeCost>,
+) -> Result<RecipeResponse, RecipeResponseError> {
+    Ok(match cost {
+        Some(cost) => response.with_cost((cost.currency, cost.cost, cost.sell_price).try_into()?),
+        None => response,
+    })
+}
 
 /// Handles the request to fetch a recipe by its unique identifier.
 ///
@@ -51,7 +72,10 @@ pub async fn get_recipe(
     let result = service::Query::find_recipe_by_id(&conn, id).await?;
 
     match result {
-        Some(recipe) => Ok(Json(recipe.try_into()?)),
+        Some(recipe) => {
+            let mut costs = service::Query::find_recipe_costs(&conn, &[id]).await?;
+            Ok(Json(with_cost(recipe.try_into()?, costs.remove(&id))?))
+        }
         None => Err(AppError::NotFound(format!(
             "The recipe with id: {id} doesn't exist"
         ))),
@@ -103,9 +127,15 @@ pub async fn get_all_recipes(
     let total_recipes = service::Query::count_recipes_with_condition(&conn, filter).await?;
     let total_page = ((total_recipes.max(1) - 1) / per_page) + 1;
 
+    let ids: Vec<_> = result.iter().map(|(recipe, _)| recipe.id).collect();
+    let mut costs: HashMap<_, _> = service::Query::find_recipe_costs(&conn, &ids).await?;
+
     let recipes = result
         .into_iter()
-        .map(|x| x.try_into())
+        .map(|x| {
+            let cost = costs.remove(&x.0.id);
+            with_cost(x.try_into()?, cost)
+        })
         .collect::<Result<_, RecipeResponseError>>()?;
     Ok(Json(RecipeListResponse {
         current_page: page,
@@ -113,3 +143,111 @@ pub async fn get_all_recipes(
         recipes,
     }))
 }
+
+/// Converts a quantity of a bill of materials for a response.
+fn tree_quantity(quantity: Decimal) -> Result<f64, RecipeResponseError> {
+    quantity
+        .try_into()
+        .map_err(|err| RecipeResponseError::QuantityCannotBeConverted(quantity, err))
+}
+
+/// Finds the unit of a product of a bill of materials.
+fn tree_unit(bom: &Bom, product_id: uuid::Uuid) -> Result<UnitResponse, AppError> {
+    bom.products
+        .get(&product_id)
+        .map(|product| product.unit.clone().into())
+        .ok_or(AppError::InternalError(format!(
+            "Product \"{product_id}\" of the recipe tree cannot be found"
+        )))
+}
+
+/// Converts an ingredient of a bill of materials, and its own ingredients, for a response.
+fn tree_node(bom: &Bom, node: &BomNode) -> Result<RecipeTreeNodeResponse, AppError> {
+    Ok(RecipeTreeNodeResponse {
+        product: node.product_id,
+        unit: tree_unit(bom, node.product_id)?,
+        quantity: tree_quantity(node.quantity)?,
+        recipe: node.recipe_id,
+        ingredients: node
+            .ingredients
+            .iter()
+            .map(|ingredient| tree_node(bom, ingredient))
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// Handles the request to expand a recipe into its bill of materials.
+///
+/// Every ingredient made by a recipe is expanded in turn, down to the raw products, which are
+/// totaled per product and per unit. Only enabled recipes and ingredients are used, and a
+/// product made by several recipes is expanded with the oldest one.
+///
+/// - **Path Parameters**:  
+///   `id` (UUID): The database ID of the recipe to expand.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: The bill of materials was successfully computed.
+///   - `404 Not Found`: The recipe doesn't exist.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+#[utoipa::path(get, path = "/{id}/tree",
+    tag = RECIPE_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the recipe to expand."),
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The recipe doesn't exist."),
+        (status = 200, description = "The bill of materials was successfully computed.", body = RecipeTreeResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_recipe_tree(
+    _admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+) -> Result<Json<RecipeTreeResponse>, AppError> {
+    let Some(bom) = service::Query::find_recipe_tree(&conn, id).await? else {
+        return Err(AppError::NotFound(format!(
+            "The recipe with id: {id} doesn't exist"
+        )));
+    };
+
+    let ingredients = bom
+        .ingredients
+        .iter()
+        .map(|node| tree_node(&bom, node))
+        .collect::<Result<_, _>>()?;
+
+    let raw_products = bom
+        .raw_products
+        .iter()
+        .map(|(product_id, quantity)| {
+            Ok(RecipeRawProductResponse {
+                product: *product_id,
+                unit: tree_unit(&bom, *product_id)?,
+                quantity: tree_quantity(*quantity)?,
+            })
+        })
+        .collect::<Result<_, AppError>>()?;
+
+    let units = bom
+        .units
+        .iter()
+        .map(|(unit, quantity)| {
+            Ok(RecipeUnitTotalResponse {
+                unit: unit.clone().into(),
+                quantity: tree_quantity(*quantity)?,
+            })
+        })
+        .collect::<Result<_, AppError>>()?;
+
+    Ok(Json(RecipeTreeResponse {
+        recipe: bom.recipe.id,
+        product: bom.recipe.result_product_id,
+        ingredients,
+        raw_products,
+        units,
+    }))
+}
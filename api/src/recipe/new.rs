@@ -12,12 +12,14 @@ use entity::{
     request::recipe::{NewRecipeRequest, RecipeIngredientRequest, RecipeRequestError},
 };
 use extractor::profile::admin::Admin;
+use rust_decimal::Decimal;
 use service::Connection;
 
 /// Handler for creating a new recipe.
 ///
 /// This function allows an admin to create a new recipe by sending a POST request to the `/recipe` endpoint.
 /// The new recipe is validated and stored in the database. The image associated with the recipe is checked in S3 storage.
+/// An ingredient can't require the resulting product, even through the recipes of other products.
 ///
 /// - **Admin privileges** are required to access this route.
 /// - Returns a `201 Created` status upon successful creation along with the recipe's ID.
@@ -48,19 +50,12 @@ pub async fn post_new_recipe(
     let recipe_model: ActiveModel = recipe.clone().try_into()?;
 
     // Verifiy that every product exist before mutating anything
-    let result_product = service::Query::find_product_by_id(&conn, recipe.product)
+    service::Query::find_product_by_id(&conn, recipe.product)
         .await?
         .ok_or(RecipeRequestError::ProductCannotBeFound(recipe.product))?;
 
-    if result_product.unit != entity::models::sea_orm_active_enums::Unit::Unit {
-        return Err(RecipeRequestError::ResultingProductIsNotUnit(
-            result_product.id,
-            result_product.unit.into(),
-        )
-        .into());
-    }
-
-    for ingredient in recipe.ingredients.clone() {
+    let mut ingredients = Vec::with_capacity(recipe.ingredients.len());
+    for ingredient in recipe.ingredients {
         TryInto::<recipe_ingredients::ActiveModel>::try_into(ingredient.clone())?;
         if ingredient.product == recipe.product {
             return Err(
@@ -68,18 +63,21 @@ pub async fn post_new_recipe(
             );
         }
 
-        service::Query::find_product_by_id(&conn, ingredient.product)
-            .await?
-            .ok_or(RecipeRequestError::IngredientCannotBeFound(
-                ingredient.product,
-            ))?;
+        ingredients.push(convert_ingredient(&conn, ingredient).await?);
+    }
+
+    let ingredient_ids: Vec<_> = ingredients.iter().map(|x| x.product).collect();
+    if let Some(cycle) =
+        service::Query::find_recipe_cycle(&conn, recipe.product, &ingredient_ids, None).await?
+    {
+        return Err(RecipeRequestError::IngredientCreatesCycle(cycle).into());
     }
     //
 
     let result = service::Mutation::create_recipe(&conn, recipe_model).await?;
     let id = result.id;
 
-    for ingredient in merge_ingredients(recipe.ingredients) {
+    for ingredient in merge_ingredients(ingredients) {
         let ingredient_model: recipe_ingredients::ActiveModel = ingredient.clone().try_into()?;
 
         service::Mutation::add_recipe_ingredient(&conn, id, ingredient.product, ingredient_model)
@@ -96,6 +94,40 @@ pub async fn post_new_recipe(
     Ok((StatusCode::CREATED, id.to_string()).into_response())
 }
 
+/// Checks the product of an ingredient exists, and converts its quantity into the unit of the
+/// product when it is expressed in another unit.
+pub(crate) async fn convert_ingredient(
+    conn: &Connection,
+    mut ingredient: RecipeIngredientRequest,
+) -> Result<RecipeIngredientRequest, AppError> {
+    let product = service::Query::find_product_by_id(conn, ingredient.product)
+        .await?
+        .ok_or(RecipeRequestError::IngredientCannotBeFound(
+            ingredient.product,
+        ))?;
+
+    let Some(unit) = ingredient.unit.take() else {
+        return Ok(ingredient);
+    };
+
+    let quantity = ingredient.quantity.to_string();
+    let quantity = Decimal::from_str_exact(&quantity)
+        .map_err(|err| RecipeRequestError::QuantityCannotBeConvertedInDecimal(quantity, err))?;
+    let (unit, quantity) = unit.to_base(quantity);
+    let quantity = service::unit::convert(&product, &unit, quantity).ok_or(
+        RecipeRequestError::IngredientUnitDoesntMatch(
+            product.id,
+            product.unit.clone().into(),
+            unit.into(),
+        ),
+    )?;
+    ingredient.quantity = quantity.try_into().map_err(|err| {
+        RecipeRequestError::QuantityCannotBeConvertedInDecimal(quantity.to_string(), err)
+    })?;
+
+    Ok(ingredient)
+}
+
 /// Merges a list of `RecipeIngredientRequest` items by combining their quantities
 /// and handling their `disabled` states. Ingredients with the same `product` are merged.
 fn merge_ingredients<I: IntoIterator<Item = RecipeIngredientRequest>>(
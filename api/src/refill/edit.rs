@@ -1,6 +1,6 @@
 //! Route for editing an existing refill in the store.
 
-use crate::utils::openapi::REFILL_TAG;
+use crate::{currency::check_currency, utils::openapi::REFILL_TAG};
 use axum::{
     Json,
     extract::{Path, State},
@@ -47,6 +47,13 @@ pub async fn edit_refill(
 
     match result {
         Some(_existing_refill) => {
+            if let Some(currency) = &edit_refill.price_currency {
+                check_currency(&conn, currency).await?;
+            }
+            if let Some(currency) = &edit_refill.credit_currency {
+                check_currency(&conn, currency).await?;
+            }
+
             let edit_refill: ActiveModel = edit_refill.try_into()?;
 
             let result = service::Mutation::update_refill(&conn, id, edit_refill).await?;
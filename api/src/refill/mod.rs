@@ -9,3 +9,4 @@ pub mod delete;
 pub mod edit;
 pub mod get;
 pub mod new;
+pub mod purchase;
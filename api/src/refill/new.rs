@@ -4,7 +4,7 @@
 //! It allows for the creation of new refill entries in the database.
 //! Admin privileges are required to access this route.
 
-use crate::utils::openapi::REFILL_TAG;
+use crate::{currency::check_currency, utils::openapi::REFILL_TAG};
 use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
 use entity::{
     error::{AppError, ErrorResponse},
@@ -48,6 +48,9 @@ pub async fn post_new_refill(
     State(conn): State<Connection>,
     Json(refill): Json<NewRefillRequest>,
 ) -> Result<impl IntoResponse, AppError> {
+    check_currency(&conn, &refill.price_currency).await?;
+    check_currency(&conn, &refill.credit_currency).await?;
+
     let refill: ActiveModel = refill.try_into()?;
 
     let result = service::Mutation::create_refill(&conn, refill).await?;
@@ -0,0 +1,194 @@
+//! This module contains the route handler for buying a refill.
+//!
+//! The handler will be accessible via a POST request to the `/refill/{id}/purchase` endpoint.
+//! It records the purchase in the transaction ledger and hands off the payment to the
+//! configured payment provider.
+
+use crate::{
+    payment::{cancel_url, success_url},
+    utils::openapi::REFILL_TAG,
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::{sea_orm_active_enums::TransactionStatus, transaction_refill_balance},
+    request::refill::RefillPurchaseError,
+    response::refill::RefillPurchaseResponse,
+};
+use extractor::{profile::user::User, utils::FrontendUrl};
+use sea_orm::ActiveValue::Set;
+use service::{
+    Connection,
+    exchange::Rounding,
+    payment::{Checkout, PaymentGateway},
+};
+
+/// Handler for buying a refill.
+///
+/// A `RefillBalance` transaction is created, then a checkout session is opened on the
+/// payment provider. The user balance is only credited once the provider confirms the payment.
+///
+/// A credit in another currency than the balance is converted with the exchange rate effective
+/// now, rounded down. The converted credit is the one recorded, so a later change of the rate
+/// doesn't change what the user gets.
+///
+/// - Returns a `201 Created` status along with the transaction's ID and the checkout url.
+///
+/// Path: `/refill/{id}/purchase`
+///
+/// - **Responses:**
+///     - 500: Internal server error (database or payment provider related).
+///     - 404: The refill doesn't exist.
+///     - 400: Bad request (disabled refill or no exchange rate to the balance currency).
+///     - 201: Successfully started the purchase.
+#[utoipa::path(
+    post,
+    path = "/{id}/purchase",
+    tag = REFILL_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the refill to buy."),
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database or the payment provider, occurred."),
+        (status = 404, description = "The refill doesn't exist."),
+        (status = 400, description = "The refill cannot be bought.", body = ErrorResponse),
+        (status = 201, description = "Successfully started the purchase, the user must now go to the checkout url.", body = RefillPurchaseResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn post_purchase_refill(
+    user: User,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+    State(payment): State<Option<PaymentGateway>>,
+    State(frontend_url): State<FrontendUrl>,
+) -> Result<impl IntoResponse, AppError> {
+    let Some(payment) = payment else {
+        return Err(AppError::InternalError(
+            "No payment provider is configured".to_string(),
+        ));
+    };
+
+    let refill = match service::Query::find_refill_by_id(&conn, id).await? {
+        Some(refill) if !refill.hidden || user.is_admin => refill,
+        _ => {
+            return Err(AppError::NotFound(format!(
+                "The refill with id: {id} doesn't exist"
+            )));
+        }
+    };
+
+    if refill.disabled {
+        return Err(RefillPurchaseError::RefillIsDisabled(id).into());
+    }
+
+    let Some(user_model) = service::Query::find_user_by_id(&conn, user.id).await? else {
+        return Err(AppError::NotFound(format!(
+            "The user with id: {} doesn't exist",
+            user.id
+        )));
+    };
+
+    let credit = service::Query::convert_amount(
+        &conn,
+        refill.credit,
+        refill.credit_currency.clone(),
+        user_model.balance_currency.clone(),
+        Rounding::Down,
+    )
+    .await?;
+
+    let Some(credit) = credit else {
+        return Err(RefillPurchaseError::CreditCurrencyDoesntMatch(
+            id,
+            refill.credit_currency.into(),
+            user_model.balance_currency.into(),
+        )
+        .into());
+    };
+
+    // Nothing is recorded until everything the checkout needs is known
+    let Some(currency) =
+        service::Query::find_currency_by_code(&conn, &refill.price_currency.0).await?
+    else {
+        return Err(AppError::InternalError(format!(
+            "The currency {} of refill \"{id}\" isn't registered",
+            refill.price_currency
+        )));
+    };
+
+    let transaction = service::Mutation::create_refill_balance_transaction(
+        &conn,
+        user.id,
+        &refill,
+        credit,
+        user_model.balance_currency,
+    )
+    .await?;
+    let transaction_id = transaction.id;
+
+    let checkout = Checkout {
+        transaction_id,
+        name: refill.name.clone().unwrap_or(format!("Refill {id}")),
+        price: refill.price,
+        currency,
+        success_url: success_url(&frontend_url, transaction_id),
+        cancel_url: cancel_url(&frontend_url, transaction_id),
+    };
+
+    let session = match payment.provider.create_checkout(&checkout).await {
+        Ok(session) => session,
+        Err(err) => {
+            service::Mutation::update_open_transaction_status(
+                &conn,
+                transaction_id,
+                TransactionStatus::Failed,
+            )
+            .await?;
+            log::error!(
+                "{user} couldn't buy refill \"{id}\" with {}: {err}",
+                payment.provider.name()
+            );
+            return Err(AppError::InternalError(err.to_string()));
+        }
+    };
+
+    service::Mutation::update_transaction_refill_balance(
+        &conn,
+        transaction_id,
+        transaction_refill_balance::ActiveModel {
+            stripe_transaction_id: Set(Some(session.id)),
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    service::Mutation::update_open_transaction_status(
+        &conn,
+        transaction_id,
+        TransactionStatus::Pending,
+    )
+    .await?;
+
+    log::info!(
+        "{user} started buying refill \"{id}\" - transaction \"{transaction_id}\" ({} {:?})",
+        refill.price,
+        refill.price_currency
+    );
+
+    Ok((
+        StatusCode::CREATED,
+        Json(RefillPurchaseResponse {
+            transaction_id,
+            checkout_url: session.url,
+        }),
+    )
+        .into_response())
+}
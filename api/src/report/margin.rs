@@ -0,0 +1,58 @@
+//! Routes for reporting the margin made on the product of each recipe
+
+use crate::utils::openapi::REPORT_TAG;
+use axum::{Json, extract::State};
+use entity::{
+    error::AppError,
+    response::{
+        recipe::RecipeResponseError,
+        report::{MarginReportResponse, RecipeMarginResponse},
+    },
+};
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Report the cost, sell price and margin of every enabled recipe.
+///
+/// The recipes are ordered from the lowest to the highest margin rate, the ones
+/// whose cost or sell price is unknown come last.
+#[utoipa::path(
+    get,
+    path = "/margins",
+    tag = REPORT_TAG,
+    responses(
+        (status = 500, description = "An internal error occured, probably database related"),
+        (status = 403, description = "You are not an admin"),
+        (status = 200, description = "The margins are computed", body = MarginReportResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_margin_report(
+    _admin: Admin,
+    State(conn): State<Connection>,
+) -> Result<Json<MarginReportResponse>, AppError> {
+    let costs = service::Query::list_recipe_costs(&conn).await?;
+
+    let mut recipes = costs
+        .into_iter()
+        .map(|cost| {
+            Ok(RecipeMarginResponse {
+                recipe: cost.recipe.id,
+                name: cost.recipe.name,
+                product: cost.product.id,
+                product_name: cost.product.name,
+                cost: (cost.currency, cost.cost, cost.sell_price).try_into()?,
+            })
+        })
+        .collect::<Result<Vec<_>, RecipeResponseError>>()?;
+    recipes.sort_by(|a, b| match (a.cost.margin_rate, b.cost.margin_rate) {
+        (Some(a), Some(b)) => a.total_cmp(&b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+
+    Ok(Json(MarginReportResponse { recipes }))
+}
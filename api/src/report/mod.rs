@@ -0,0 +1,6 @@
+//! Report module for the figures computed over the whole shop.
+//!
+//! This module provides the handlers for the reports reserved to admins, such as
+//! the margin made on the product of each recipe.
+
+pub mod margin;
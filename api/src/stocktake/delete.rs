@@ -0,0 +1,69 @@
+//! This module defines the API endpoint to cancel a stocktake by its ID.
+//!
+//! Only an admin can cancel a stocktake.
+
+use crate::utils::openapi::STOCKTAKE_TAG;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{
+    error::{AppError, ErrorResponse},
+    request::stocktake::StocktakeRequestError,
+};
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Cancels an open stocktake by its database ID.
+///
+/// The counts are kept but never applied, the stock is left untouched.
+/// Only an admin can perform this action.
+///
+/// - **Path Parameters:**
+///   - `id`: The unique ID of the stocktake in the database.
+///
+/// - **Responses:**
+///   - `500`: Internal error, likely related to the database.
+///   - `404`: The stocktake doesn't exist.
+///   - `400`: The stocktake isn't open.
+///   - `200`: The stocktake has been successfully canceled.
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tag = STOCKTAKE_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "stocktake database id to cancel"),
+    ),
+    responses(
+        (status = 500, description = "An internal error occured, probably databse related"),
+        (status = 404, description = "The stocktake doesn't exist"),
+        (status = 400, description = "The stocktake isn't open", body = ErrorResponse),
+        (status = 200, description = "The stocktake is canceled")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn delete_stocktake(
+    admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+) -> Result<impl IntoResponse, AppError> {
+    if service::Query::find_stocktake_by_id(&conn, id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "The stocktake with id: {id} doesn't exist"
+        )));
+    }
+
+    let Some(stocktake) = service::Mutation::cancel_stocktake(&conn, id, admin.id).await? else {
+        return Err(StocktakeRequestError::StocktakeIsNotOpen(id))?;
+    };
+
+    log::info!("{admin} just canceled the stocktake \"{id}\" - {stocktake:?}");
+
+    Ok((StatusCode::OK, ""))
+}
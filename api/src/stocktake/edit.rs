@@ -0,0 +1,135 @@
+//! This module contains the route handlers for counting the products of a stocktake and for
+//! closing it.
+//!
+//! Admin privileges are required to access these routes.
+
+use super::get::stocktake_report;
+use crate::utils::openapi::STOCKTAKE_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::sea_orm_active_enums::StocktakeStatus,
+    request::stocktake::{StocktakeCountRequest, StocktakeRequestError},
+    response::stocktake::StocktakeResponse,
+};
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Checks the stocktake `id` exists and is still open.
+async fn check_open_stocktake(conn: &Connection, id: uuid::Uuid) -> Result<(), AppError> {
+    match service::Query::find_stocktake_by_id(conn, id).await? {
+        Some(stocktake) if stocktake.status == StocktakeStatus::Open => Ok(()),
+        Some(_) => Err(StocktakeRequestError::StocktakeIsNotOpen(id))?,
+        None => Err(AppError::NotFound(format!(
+            "The stocktake with id: {id} doesn't exist"
+        ))),
+    }
+}
+
+/// Submits counted quantities to an open stocktake.
+///
+/// Counting a product again replaces its previous count, whoever made it, so several devices
+/// can count the same warehouse at once. The stock is left untouched until the stocktake is
+/// closed.
+///
+/// Returns an error if the stocktake doesn't exist or isn't open, if a product doesn't exist,
+/// if there is a validation issue, or if a database operation fails.
+#[utoipa::path(
+    put,
+    path = "/{id}/count",
+    tag = STOCKTAKE_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the stocktake."),
+    ),
+    request_body(content = StocktakeCountRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error occured, probably database related"),
+        (status = 404, description = "The stocktake doesn't exist"),
+        (status = 400, description = "Your request is not correctly formatted", body = ErrorResponse),
+        (status = 200, description = "The counted quantities are recorded")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn put_stocktake_count(
+    admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+    Json(count): Json<StocktakeCountRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    count.validate()?;
+
+    check_open_stocktake(&conn, id).await?;
+
+    for line in &count.products {
+        if service::Query::find_product_by_id(&conn, line.product)
+            .await?
+            .is_none()
+        {
+            return Err(StocktakeRequestError::ProductDoesntExist(line.product))?;
+        }
+    }
+
+    let lines: Vec<_> = count
+        .products
+        .into_iter()
+        .map(|line| (line.product, line.quantity))
+        .collect();
+    let Some(_) = service::Mutation::count_stocktake(&conn, id, lines.clone(), admin.id).await?
+    else {
+        return Err(StocktakeRequestError::StocktakeIsNotOpen(id))?;
+    };
+
+    log::info!("{admin} counted products of the stocktake \"{id}\" - {lines:?}");
+
+    Ok((StatusCode::OK, ""))
+}
+
+/// Closes an open stocktake, applying the counted quantities to the stock of its warehouse.
+///
+/// The stock of every counted product is set to its counted quantity, each difference being
+/// recorded as a stocktake movement in the stock journal. Products which weren't counted are
+/// left untouched.
+///
+/// Returns the variance report of the stocktake, or an error if it doesn't exist, isn't open,
+/// or if a database operation fails.
+#[utoipa::path(
+    post,
+    path = "/{id}/close",
+    tag = STOCKTAKE_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the stocktake to close."),
+    ),
+    responses(
+        (status = 500, description = "An internal error occured, probably database related"),
+        (status = 404, description = "The stocktake doesn't exist"),
+        (status = 400, description = "The stocktake isn't open", body = ErrorResponse),
+        (status = 200, description = "The stocktake is closed", body = StocktakeResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn close_stocktake(
+    admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+) -> Result<Json<StocktakeResponse>, AppError> {
+    check_open_stocktake(&conn, id).await?;
+
+    let Some(stocktake) = service::Mutation::close_stocktake(&conn, id, admin.id).await? else {
+        return Err(StocktakeRequestError::StocktakeIsNotOpen(id))?;
+    };
+
+    let report = stocktake_report(&conn, stocktake).await?;
+
+    log::info!("{admin} closed the stocktake \"{id}\" - {report:?}");
+
+    Ok(Json(report))
+}
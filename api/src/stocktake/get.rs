@@ -0,0 +1,119 @@
+//! This module contains the route handlers for retrieving stocktakes and their variance report.
+
+use crate::utils::openapi::STOCKTAKE_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use axum_extra::extract::Query;
+use entity::{
+    error::AppError,
+    models::stocktake::{self, StocktakeFilterQuery, StocktakeSortQuery},
+    response::stocktake::{StocktakeListResponse, StocktakeResponse},
+};
+use extractor::{profile::admin::Admin, query::Pagination};
+use service::Connection;
+
+/// Builds the variance report of a stocktake: every counted product with the quantity the
+/// warehouse held when it was closed, or holds right now while it is open.
+pub(crate) async fn stocktake_report(
+    conn: &Connection,
+    stocktake: stocktake::Model,
+) -> Result<StocktakeResponse, AppError> {
+    let counts = service::Query::list_stocktake_counts(conn, &stocktake).await?;
+
+    let mut response: StocktakeResponse = stocktake.into();
+    response.products = Some(counts.into_iter().map(Into::into).collect());
+    Ok(response)
+}
+
+/// Handles the request to fetch a stocktake and its variance report.
+///
+/// - **Path Parameters**:  
+///   `id` (UUID): The database ID of the stocktake to retrieve.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: The stocktake was successfully retrieved.
+///   - `404 Not Found`: The stocktake doesn't exist.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tag = STOCKTAKE_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the stocktake to retrieve."),
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The stocktake doesn't exist."),
+        (status = 200, description = "The stocktake was successfully retrieved.", body = StocktakeResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_stocktake(
+    _admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+) -> Result<Json<StocktakeResponse>, AppError> {
+    let result = service::Query::find_stocktake_by_id(&conn, id).await?;
+
+    match result {
+        Some(stocktake) => Ok(Json(stocktake_report(&conn, stocktake).await?)),
+        None => Err(AppError::NotFound(format!(
+            "The stocktake with id: {id} doesn't exist"
+        ))),
+    }
+}
+
+/// Handles the request to retrieve a paginated list of stocktakes, without their counts.
+///
+/// - **Query Parameters**:  
+///   - `page` (Optional, u64): The page index, default is 0.
+///   - `per_page` (Optional, u64): The number of stocktakes per page, default is 20.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: Successfully retrieved a list of stocktakes.
+///   - `400 Bad Request`: The request is improperly formatted.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = STOCKTAKE_TAG,
+    params(
+        Pagination,
+        StocktakeFilterQuery,
+        StocktakeSortQuery,
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 200, description = "Successfully retrieved a list of stocktakes.", body = StocktakeListResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_all_stocktakes(
+    _admin: Admin,
+    Query(pagination): Query<Pagination>,
+    Query(filter): Query<StocktakeFilterQuery>,
+    Query(sort): Query<StocktakeSortQuery>,
+    State(conn): State<Connection>,
+) -> Result<Json<StocktakeListResponse>, AppError> {
+    let page = pagination.page.unwrap_or(0);
+    let per_page = pagination.per_page.unwrap_or(20);
+
+    let result =
+        service::Query::list_stocktakes_with_condition(&conn, filter.clone(), sort, page, per_page)
+            .await?;
+
+    let total_stocktakes = service::Query::count_stocktakes_with_condition(&conn, filter).await?;
+    let total_page = ((total_stocktakes.max(1) - 1) / per_page) + 1;
+
+    Ok(Json(StocktakeListResponse {
+        current_page: page,
+        total_page,
+        stocktakes: result.into_iter().map(Into::into).collect(),
+    }))
+}
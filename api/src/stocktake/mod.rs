@@ -0,0 +1,11 @@
+//! Stocktake module for counting the stock of a warehouse.
+//!
+//! This module provides handlers for the endpoints related to opening a
+//! stocktake on a warehouse, submitting the counted quantities, possibly
+//! from several devices, and closing it to apply them to the stock with a
+//! variance report, or canceling it.
+
+pub mod delete;
+pub mod edit;
+pub mod get;
+pub mod new;
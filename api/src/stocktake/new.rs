@@ -0,0 +1,69 @@
+//! This module contains the route handler for opening a stocktake.
+//!
+//! The handler will be accessible via a POST request to the `/stocktake` endpoint.
+//! Admin privileges are required to access this route.
+
+use crate::utils::openapi::STOCKTAKE_TAG;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::stocktake::ActiveModel,
+    request::stocktake::{NewStocktakeRequest, StocktakeRequestError},
+};
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Handler for opening a stocktake on a warehouse.
+///
+/// Only one stocktake can be open per warehouse. The stock is left untouched until the
+/// stocktake is closed.
+///
+/// - **Admin privileges** are required to access this route.
+/// - Returns a `201 Created` status upon successful creation along with the stocktake's ID.
+///
+/// Path: `/stocktake`
+///
+/// - **Request Body:** Expects a `NewStocktakeRequest` JSON object.
+/// - **Responses:**
+///     - 500: Internal server error (likely database related).
+///     - 400: Bad request (unknown warehouse, or a stocktake is already open on it).
+///     - 201: Successfully opened the stocktake, returns its ID as a string.
+#[utoipa::path(
+    post,
+    path = "",
+    tag = STOCKTAKE_TAG,
+    request_body(content = NewStocktakeRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 400, description = "The request is improperly formatted.", body = ErrorResponse),
+        (status = 201, description = "Successfully opened the stocktake, returns its ID as a string.", body = uuid::Uuid)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn post_new_stocktake(
+    admin: Admin,
+    State(conn): State<Connection>,
+    Json(stocktake): Json<NewStocktakeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let warehouse_id = stocktake.warehouse;
+    if service::Query::find_warehouse_by_id(&conn, warehouse_id)
+        .await?
+        .is_none()
+    {
+        return Err(StocktakeRequestError::WarehouseDoesntExist(warehouse_id))?;
+    }
+
+    if let Some(open) = service::Query::find_open_stocktake(&conn, warehouse_id).await? {
+        return Err(StocktakeRequestError::StocktakeAlreadyOpen(open.id))?;
+    }
+
+    let stocktake: ActiveModel = stocktake.into();
+    let result = service::Mutation::create_stocktake(&conn, stocktake, admin.id).await?;
+
+    let id = result.id;
+    log::info!("{admin} opened the stocktake \"{id}\" of warehouse ({warehouse_id})");
+
+    Ok((StatusCode::CREATED, id.to_string()).into_response())
+}
@@ -0,0 +1,62 @@
+//! This module defines the API endpoint to delete a supplier by its ID.
+//!
+//! Only an admin can delete a supplier.
+
+use crate::utils::openapi::SUPPLIER_TAG;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::error::AppError;
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Deletes a supplier by its database ID.
+///
+/// The supplier is not removed but marked as disabled, so its purchase orders are kept and no
+/// new one can be made to it. Only an admin can perform this action.
+///
+/// - **Path Parameters:**
+///   - `id`: The unique ID of the supplier in the database.
+///
+/// - **Responses:**
+///   - `500`: Internal error, likely related to the database.
+///   - `404`: The supplier doesn't exist.
+///   - `200`: The supplier has been successfully disabled.
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tag = SUPPLIER_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "supplier database id to delete supplier for"),
+    ),
+    responses(
+        (status = 500, description = "An internal error occured, probably databse related"),
+        (status = 404, description = "The supplier doesn't exist"),
+        (status = 200, description = "The supplier is disabled")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn delete_supplier(
+    admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+) -> Result<impl IntoResponse, AppError> {
+    if service::Query::find_supplier_by_id(&conn, id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "The supplier with id: {id} doesn't exist"
+        )));
+    }
+
+    let supplier = service::Mutation::delete_supplier(&conn, id).await?;
+
+    log::info!("{admin} just disabled the supplier \"{id}\" - {supplier:?}");
+
+    Ok((StatusCode::OK, ""))
+}
@@ -0,0 +1,60 @@
+//! Route for editing an existing supplier.
+
+use crate::utils::openapi::SUPPLIER_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::supplier::ActiveModel,
+    request::supplier::EditSupplierRequest,
+};
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Edit an existing supplier by ID.
+///
+/// Returns an error if the supplier doesn't exist, if there is a validation issue, or if a database operation fails.
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tag = SUPPLIER_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "supplier database id to edit supplier for"),
+    ),
+    request_body(content = EditSupplierRequest, content_type = "application/json"),
+    responses(
+       (status = 500, description = "An internal error occured, probably database related"),
+       (status = 404, description = "The supplier doesn't exist"),
+       (status = 400, description = "Your request is not correctly formatted", body = ErrorResponse),
+       (status = 200, description = "The supplier is correctly edited")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn edit_supplier(
+    admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+    Json(edit_supplier): Json<EditSupplierRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if service::Query::find_supplier_by_id(&conn, id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "The supplier with id: {id} doesn't exist"
+        )));
+    }
+
+    let edit_supplier: ActiveModel = edit_supplier.try_into()?;
+    let result = service::Mutation::update_supplier(&conn, id, edit_supplier).await?;
+
+    log::info!("{admin} successfully edited supplier \"{id}\" - {result:?}");
+
+    Ok((StatusCode::OK, ""))
+}
@@ -0,0 +1,106 @@
+//! This module contains the route handlers for retrieving supplier information.
+
+use crate::utils::openapi::SUPPLIER_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use axum_extra::extract::Query;
+use entity::{
+    error::AppError,
+    models::supplier::{SupplierFilterQuery, SupplierSortQuery},
+    response::supplier::{SupplierListResponse, SupplierResponse},
+};
+use extractor::{profile::admin::Admin, query::Pagination};
+use service::Connection;
+
+/// Handles the request to fetch a supplier by its unique identifier.
+///
+/// - **Path Parameters**:  
+///   `id` (UUID): The database ID of the supplier to retrieve.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: The supplier was successfully retrieved.
+///   - `404 Not Found`: The supplier doesn't exist.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tag = SUPPLIER_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the supplier to retrieve."),
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The supplier doesn't exist."),
+        (status = 200, description = "The supplier was successfully retrieved.", body = SupplierResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_supplier(
+    _admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+) -> Result<Json<SupplierResponse>, AppError> {
+    let result = service::Query::find_supplier_by_id(&conn, id).await?;
+
+    match result {
+        Some(supplier) => Ok(Json(supplier.into())),
+        None => Err(AppError::NotFound(format!(
+            "The supplier with id: {id} doesn't exist"
+        ))),
+    }
+}
+
+/// Handles the request to retrieve a paginated list of suppliers.
+///
+/// - **Query Parameters**:  
+///   - `page` (Optional, u64): The page index, default is 0.
+///   - `per_page` (Optional, u64): The number of suppliers per page, default is 20.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: Successfully retrieved a list of suppliers.
+///   - `400 Bad Request`: The request is improperly formatted.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = SUPPLIER_TAG,
+    params(
+        Pagination,
+        SupplierFilterQuery,
+        SupplierSortQuery,
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 200, description = "Successfully retrieved a list of suppliers.", body = SupplierListResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_all_suppliers(
+    _admin: Admin,
+    Query(pagination): Query<Pagination>,
+    Query(filter): Query<SupplierFilterQuery>,
+    Query(sort): Query<SupplierSortQuery>,
+    State(conn): State<Connection>,
+) -> Result<Json<SupplierListResponse>, AppError> {
+    let page = pagination.page.unwrap_or(0);
+    let per_page = pagination.per_page.unwrap_or(20);
+
+    let result =
+        service::Query::list_suppliers_with_condition(&conn, filter.clone(), sort, page, per_page)
+            .await?;
+
+    let total_suppliers = service::Query::count_suppliers_with_condition(&conn, filter).await?;
+    let total_page = ((total_suppliers.max(1) - 1) / per_page) + 1;
+
+    Ok(Json(SupplierListResponse {
+        current_page: page,
+        total_page,
+        suppliers: result.into_iter().map(Into::into).collect(),
+    }))
+}
@@ -0,0 +1,10 @@
+//! Supplier module for managing the suppliers the products are bought from.
+//!
+//! This module provides handlers for the endpoints related to registering,
+//! retrieving, updating, and disabling suppliers, which purchase orders are
+//! made to.
+
+pub mod delete;
+pub mod edit;
+pub mod get;
+pub mod new;
@@ -0,0 +1,55 @@
+//! This module contains the route handler for registering a new supplier.
+//!
+//! The handler will be accessible via a POST request to the `/supplier` endpoint.
+//! Admin privileges are required to access this route.
+
+use crate::utils::openapi::SUPPLIER_TAG;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::supplier::ActiveModel,
+    request::supplier::NewSupplierRequest,
+};
+use extractor::profile::admin::Admin;
+use service::Connection;
+
+/// Handler for registering a new supplier.
+///
+/// - **Admin privileges** are required to access this route.
+/// - Returns a `201 Created` status upon successful creation along with the supplier's ID.
+///
+/// Path: `/supplier`
+///
+/// - **Request Body:** Expects a `NewSupplierRequest` JSON object.
+/// - **Responses:**
+///     - 500: Internal server error (likely database related).
+///     - 400: Bad request (invalid name or email).
+///     - 201: Successfully registered the supplier, returns its ID as a string.
+#[utoipa::path(
+    post,
+    path = "",
+    tag = SUPPLIER_TAG,
+    request_body(content = NewSupplierRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 400, description = "The request is improperly formatted.", body = ErrorResponse),
+        (status = 201, description = "Successfully registered the supplier, returns its ID as a string.", body = uuid::Uuid)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn post_new_supplier(
+    admin: Admin,
+    State(conn): State<Connection>,
+    Json(supplier): Json<NewSupplierRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let supplier: ActiveModel = supplier.try_into()?;
+
+    let result = service::Mutation::create_supplier(&conn, supplier).await?;
+
+    let id = result.id;
+    log::info!("{admin} added a new supplier \"{id}\" - {result:?}");
+
+    Ok((StatusCode::CREATED, id.to_string()).into_response())
+}
@@ -0,0 +1,128 @@
+//! This module contains the route handler for retrieving transaction information.
+
+use crate::utils::openapi::TRANSACTION_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use axum_extra::extract::Query;
+use entity::{
+    error::AppError,
+    models::transaction::{TransactionFilterQuery, TransactionSortQuery},
+    response::transaction::{TransactionListResponse, TransactionResponse},
+};
+use extractor::{profile::user::User, query::Pagination};
+use service::Connection;
+
+/// Handles the request to fetch a transaction by its unique identifier.
+///
+/// - **Path Parameters**:  
+///   `id` (UUID): The database ID of the transaction to retrieve.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: The transaction was successfully retrieved.
+///   - `404 Not Found`: The transaction doesn't exist.
+///   - `400 Bad Request`: The request is improperly formatted.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+///
+/// - **Permissions**:  
+///   A user can only retrieve its own transactions, an admin can retrieve every transaction.
+#[utoipa::path(
+    get,
+    path = "/{id}", 
+    tag = TRANSACTION_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "The database ID of the transaction to retrieve."),
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."), 
+        (status = 404, description = "The transaction doesn't exist, or doesn't belong to the requester."), 
+        (status = 200, description = "The transaction was successfully retrieved.", body = TransactionResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_transaction(
+    user: User,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+) -> Result<Json<TransactionResponse>, AppError> {
+    let result = service::Query::find_transaction_by_id(&conn, id).await?;
+
+    match result {
+        Some(transaction) if transaction.user_id == user.id || user.is_admin => {
+            Ok(Json(transaction.into()))
+        }
+        _ => Err(AppError::NotFound(format!(
+            "The transaction with id: {id} doesn't exist"
+        ))),
+    }
+}
+
+/// Handles the request to retrieve a paginated list of transactions.
+///
+/// - **Query Parameters**:  
+///   - `page` (Optional, u64): The page index, default is 0.
+///   - `per_page` (Optional, u64): The number of transaction per page, default is 20.
+///
+/// - **Response Codes**:  
+///   - `200 OK`: Successfully retrieved a list of transactions.
+///   - `400 Bad Request`: The request is improperly formatted.
+///   - `500 Internal Server Error`: An internal error, most likely related to the database, occurred.
+///
+/// - **Permissions**:  
+///   A user only sees its own transactions, an admin sees every transaction.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = TRANSACTION_TAG,
+    params(
+        Pagination,
+        TransactionFilterQuery,
+        TransactionSortQuery,
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."), 
+        (status = 400, description = "The request is improperly formatted."), 
+        (status = 200, description = "Successfully retrieved a list of transactions.", body = TransactionListResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_all_transactions(
+    user: User,
+    Query(pagination): Query<Pagination>,
+    Query(mut filter): Query<TransactionFilterQuery>,
+    Query(sort): Query<TransactionSortQuery>,
+    State(conn): State<Connection>,
+) -> Result<Json<TransactionListResponse>, AppError> {
+    let page = pagination.page.unwrap_or(0);
+    let per_page = pagination.per_page.unwrap_or(20);
+
+    if !user.is_admin {
+        filter.user_id_eq = vec![user.id];
+        filter.user_id_neq = vec![];
+    }
+
+    let result = service::Query::list_transactions_with_condition(
+        &conn,
+        filter.clone(),
+        sort,
+        page,
+        per_page,
+    )
+    .await?;
+
+    let total_transactions =
+        service::Query::count_transactions_with_condition(&conn, filter).await?;
+    let total_page = ((total_transactions.max(1) - 1) / per_page) + 1;
+
+    let transactions = result.into_iter().map(Into::into).collect();
+    Ok(Json(TransactionListResponse {
+        current_page: page,
+        total_page,
+        transactions,
+    }))
+}
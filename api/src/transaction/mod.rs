@@ -0,0 +1,7 @@
+//! Transaction module for browsing the ledger through the API.
+//!
+//! This module provides handlers for retrieving transactions. Transactions are
+//! never created directly through these endpoints, they are recorded by the
+//! flows affecting a user balance (purchases, refills, balance edits, ...).
+
+pub mod get;
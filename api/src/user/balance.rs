@@ -0,0 +1,81 @@
+//! Route for manually editing the balance of a user
+
+use crate::utils::openapi::USER_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use entity::{
+    error::{AppError, ErrorResponse},
+    request::user::{BalanceEdit, EditUserBalanceRequest, EditUserBalanceRequestError},
+};
+use extractor::profile::{admin::Admin, user::User};
+use service::Connection;
+
+/// Edit the balance of an existing user by ID.
+///
+/// The signed delta is added to the balance and recorded as a `BalanceEdit` transaction
+/// along with the admin and the reason, so every change can be traced back.
+///
+/// Returns an error if the user doesn't exist, if the currency isn't the balance currency,
+/// or if the balance would become negative.
+#[utoipa::path(
+    post,
+    path = "/{id}/balance",
+    tag = USER_TAG,
+    params(
+        ("id" = uuid::Uuid, Path, description = "user database id to edit the balance for"),
+    ),
+    request_body(content = EditUserBalanceRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error occured, probably database related"),
+        (status = 404, description = "The user doesn't exist"),
+        (status = 400, description = "Your request is not correctly formatted", body = ErrorResponse),
+        (status = 201, description = "The balance is edited, returns the transaction's ID as a string.", body = uuid::Uuid)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn edit_user_balance(
+    admin: Admin,
+    Path(id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+    Json(edit_balance): Json<EditUserBalanceRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let edit: BalanceEdit = edit_balance.try_into()?;
+
+    let Some(existing_user) = service::Query::find_user_by_id(&conn, id).await? else {
+        return Err(AppError::NotFound(format!(
+            "The user with id: {id} doesn't exist"
+        )));
+    };
+
+    if edit.currency != existing_user.balance_currency {
+        return Err(EditUserBalanceRequestError::CurrencyDoesntMatch(
+            edit.currency.into(),
+            existing_user.balance_currency.into(),
+        )
+        .into());
+    }
+
+    let delta = edit.delta;
+    let balance = existing_user.balance;
+    let reason = edit.reason.clone();
+
+    let Some(transaction) =
+        service::Mutation::create_balance_edit(&conn, id, admin.id, edit).await?
+    else {
+        return Err(EditUserBalanceRequestError::BalanceCannotBeNegative(delta, balance).into());
+    };
+
+    log::info!(
+        "{admin} edited the balance of {} by {delta} - transaction \"{}\": {reason}",
+        Into::<User>::into(existing_user),
+        transaction.id
+    );
+
+    Ok((StatusCode::CREATED, transaction.id.to_string()).into_response())
+}
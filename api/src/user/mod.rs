@@ -4,6 +4,7 @@
 //! in the `scrounch_backend` application. These routes typically handle operations
 //! such as retrieving user information, updating user profiles, and other user-centric
 //! tasks.
+pub mod balance;
 pub mod edit;
 pub mod get;
 pub mod me;
@@ -11,7 +11,7 @@ use axum::{extract::State, response::IntoResponse};
 use entity::{
     error::AppError,
     models::{
-        sea_orm_active_enums::Currency,
+        currency::EPICOIN,
         user::{self},
     },
 };
@@ -62,7 +62,7 @@ pub async fn get_login(
                 name: Set(user.name),
                 email: Set(user.email),
                 is_admin: Set(is_admin),
-                balance_currency: Set(Currency::Epicoin),
+                balance_currency: Set(EPICOIN.into()),
                 ..Default::default()
             },
         )
@@ -48,12 +48,21 @@ impl utoipa::Modify for AxumOidcSecurity {
     components(
         schemas(FileType),
         schemas(entity::models::product::ProductSortEnum),
+        schemas(entity::models::category::CategorySortEnum),
         schemas(entity::models::refill::RefillSortEnum),
         schemas(entity::models::location::LocationSortEnum),
         schemas(entity::models::user::UserSortEnum),
         schemas(entity::models::recipe::RecipeSortEnum),
         schemas(entity::models::warehouse::WarehouseSortEnum),
         schemas(entity::models::warehouse_product::Warehouse_productSortEnum),
+        schemas(entity::models::warehouse_recipe::Warehouse_recipeSortEnum),
+        schemas(entity::models::stock_movement::Stock_movementSortEnum),
+        schemas(entity::models::stocktake::StocktakeSortEnum),
+        schemas(entity::models::supplier::SupplierSortEnum),
+        schemas(entity::models::purchase_order::Purchase_orderSortEnum),
+        schemas(entity::models::transaction::TransactionSortEnum),
+        schemas(entity::models::exchange_rate::Exchange_rateSortEnum),
+        schemas(entity::models::currency::CurrencySortEnum),
     ),
 )]
 pub struct ApiDoc;
@@ -61,6 +70,9 @@ pub struct ApiDoc;
 /// Tag used to categorize API endpoints related to user management and user-specific actions.
 pub const USER_TAG: &str = "user";
 
+/// Tag used to categorize API endpoints focused on the categories the products are grouped in.
+pub const CATEGORY_TAG: &str = "category";
+
 /// Tag used to categorize API endpoints for managing and accessing location-based data.
 pub const LOCATION_TAG: &str = "location";
 
@@ -76,6 +88,36 @@ pub const RECIPE_TAG: &str = "recipe";
 /// Tag used to categorize API endpoints focused on warehouse and related operations.
 pub const WAREHOUSE_TAG: &str = "warehouse";
 
+/// Tag used to categorize API endpoints focused on counting the stock of warehouses.
+pub const STOCKTAKE_TAG: &str = "stocktake";
+
+/// Tag used to categorize API endpoints focused on the suppliers the products are bought from.
+pub const SUPPLIER_TAG: &str = "supplier";
+
+/// Tag used to categorize API endpoints focused on restocking the warehouses from suppliers.
+pub const PURCHASE_ORDER_TAG: &str = "purchase_order";
+
+/// Tag used to categorize API endpoints reporting on the activity of the shop.
+pub const REPORT_TAG: &str = "report";
+
+/// Tag used to categorize API endpoints focused on the transaction ledger.
+pub const TRANSACTION_TAG: &str = "transaction";
+
+/// Tag used to categorize API endpoints focused on orders paid with the user balance.
+pub const ORDER_TAG: &str = "order";
+
+/// Tag used to categorize API endpoints focused on the currency registry.
+pub const CURRENCY_TAG: &str = "currency";
+
+/// Tag used to categorize API endpoints focused on the exchange rates between currencies.
+pub const EXCHANGE_RATE_TAG: &str = "exchange_rate";
+
+/// Tag used to categorize API endpoints called back by payment providers.
+pub const PAYMENT_TAG: &str = "payment";
+
+/// Tag used to categorize API endpoints reserved to the maintenance of the application.
+pub const ADMIN_TAG: &str = "admin";
+
 /// Tag used to categorize miscellaneous API endpoints that do not fit into other categories.
 pub const MISC_TAG: &str = "misc";
 
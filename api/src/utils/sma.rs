@@ -1,6 +1,7 @@
 //! This module provides the functionality to interact with the Sma API for importing
 //! existing products into the system. It handles communication with the Sma API,
-//! processing the data, and updating the local database with imported products.
+//! processing the data, and updating the local database with imported products and
+//! the categories they belong to.
 
 use super::openapi::MISC_TAG;
 use axum::{
@@ -9,16 +10,27 @@ use axum::{
 };
 use entity::{
     error::AppError,
-    models::product::{self, Model as Product},
+    models::{
+        category,
+        product::{self, Model as Product},
+        sea_orm_active_enums::Unit,
+    },
+    request::r#enum::{QuantityRequest, QuantityUnitRequest},
     response::{
-        product::{EditedProductResponse, ProductResponse, ProductResponseError},
+        product::{
+            EditedProductResponse, ProductContentResponse, ProductResponse, ProductResponseError,
+        },
         sma::SmaResponse,
     },
 };
 use extractor::{profile::admin::Admin, utils::SmaParams};
 use futures::future::join_all;
-use sea_orm::ActiveValue::Set;
+use sea_orm::{
+    ActiveModelTrait,
+    ActiveValue::{NotSet, Set},
+};
 use service::{Connection, s3::FileType};
+use std::collections::HashMap;
 
 /// Enum representing changes in SMA products.
 ///
@@ -145,6 +157,16 @@ pub struct SmCategory {
     pub description: String,
 }
 
+impl SmCategory {
+    /// The Sma id of the parent category, Sma using `0` for a top level category.
+    fn parent(&self) -> Option<&str> {
+        match self.parent_id.trim() {
+            "" | "0" => None,
+            parent => Some(parent),
+        }
+    }
+}
+
 /// Struct representing the tax rate associated with an SMA product.
 ///
 /// `SmaTaxRate` includes tax attributes such as rate, type, and unique identifiers.
@@ -195,10 +217,52 @@ pub struct SmaUnit {
     pub operation_value: Option<String>,
 }
 
+impl SmaUnit {
+    /// What a single piece of a product sold in this unit holds, in grams, liters or meters.
+    ///
+    /// A unit defined from a known base unit holds `operation_value` of it, multiplied or
+    /// divided according to `operator`, such as a "Can" of `* 33` `cl`. Otherwise a unit which is
+    /// itself a weight, a volume or a length, such as `kg`, holds one of it. Anything else,
+    /// pieces included, holds no known content.
+    fn content(&self) -> Option<(sea_orm::prelude::Decimal, Unit)> {
+        let base = self
+            .base_unit
+            .as_deref()
+            .and_then(|base| base.parse::<QuantityUnitRequest>().ok());
+
+        let content = match base {
+            Some(unit) => {
+                let value = self.operation_value.as_deref()?.trim();
+                let value = sea_orm::prelude::Decimal::from_str_exact(value).ok()?;
+                let quantity = match self.operator.as_deref().map(str::trim) {
+                    Some("*") => value,
+                    Some("/") if !value.is_zero() => sea_orm::prelude::Decimal::ONE / value,
+                    _ => return None,
+                };
+                QuantityRequest { quantity, unit }
+            }
+            None => QuantityRequest {
+                quantity: sea_orm::prelude::Decimal::ONE,
+                unit: self.code.parse().ok()?,
+            },
+        };
+
+        match content.to_base() {
+            (Unit::Unit, _) => None,
+            (_, quantity) if quantity <= sea_orm::prelude::Decimal::ZERO => None,
+            (unit, quantity) => Some((quantity, unit)),
+        }
+    }
+}
+
 /// Updates the local product database by importing products from the Sma API.
 /// This function retrieves the latest products from Sma, processes the data,
 /// and updates the local products accordingly.
 ///
+/// The Sma category of each product is created or renamed locally, then assigned to the
+/// products created, and to the existing products without a category. Once every product is
+/// imported, the categories are nested as in Sma.
+///
 /// # Errors
 /// - Returns a 500 status code if there is an internal error, such as a failure to fetch or process Sma data.
 /// - Returns a 400 status code if the request to Sma is not correctly formatted.
@@ -248,6 +312,7 @@ pub async fn post_update_from_sma(
                 })?;
 
             let mut products: Vec<SmaChange> = Vec::new();
+            let mut categories: HashMap<String, (category::Model, SmCategory)> = HashMap::new();
 
             let mut start = 0;
             let limit = 20;
@@ -274,21 +339,30 @@ pub async fn post_update_from_sma(
 
                 let total = sma_result.total;
 
-                let result = join_all(
-                    sma_result
-                        .data
-                        .into_iter()
-                        .filter(|x| {
-                            if let Some(categories) = arguments.categories.clone() {
-                                categories.contains(&x.category.code)
-                            } else {
-                                true
-                            }
-                        })
-                        .map(|x| async {
-                            create_or_update_sma_product(&conn, &s3, x, params).await
-                        }),
-                )
+                let data = sma_result
+                    .data
+                    .into_iter()
+                    .filter(|x| {
+                        if let Some(categories) = arguments.categories.clone() {
+                            categories.contains(&x.category.code)
+                        } else {
+                            true
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                // Categories are shared by products, so they are created one at a time
+                for x in &data {
+                    if !categories.contains_key(&x.category.id) {
+                        let model = create_or_update_sma_category(&conn, &x.category).await?;
+                        categories.insert(x.category.id.clone(), (model, x.category.clone()));
+                    }
+                }
+
+                let result = join_all(data.into_iter().map(|x| {
+                    let category = categories.get(&x.category.id).map(|(model, _)| model.id);
+                    create_or_update_sma_product(&conn, &s3, x, category, params)
+                }))
                 .await
                 .into_iter()
                 .collect::<Result<Vec<_>, AppError>>()?;
@@ -302,6 +376,8 @@ pub async fn post_update_from_sma(
                 // This is just the rust idiomatic way of a do_while
             }
 
+            link_sma_categories(&conn, &categories).await?;
+
             let iter = products.into_iter();
             Ok(Json(SmaResponse {
                 unchanged: iter
@@ -335,6 +411,118 @@ pub async fn post_update_from_sma(
     }
 }
 
+/// Creates the category from the SMA system in the database, or renames the one already
+/// imported if its name or code changed in Sma.
+async fn create_or_update_sma_category(
+    conn: &Connection,
+    category: &SmCategory,
+) -> Result<category::Model, AppError> {
+    let code = Some(category.code.clone()).filter(|code| !code.is_empty());
+    let result = service::Query::find_category_by_sma_id(conn, category.id.clone()).await?;
+
+    match result {
+        Some(existing_category) => {
+            if existing_category.name == category.name && existing_category.code == code {
+                return Ok(existing_category);
+            }
+
+            let result = service::Mutation::update_category(
+                conn,
+                existing_category.id,
+                category::ActiveModel {
+                    name: Set(category.name.clone()),
+                    code: Set(code),
+                    ..Default::default()
+                },
+            )
+            .await?;
+            log::info!(
+                "Updating category from SMA: {} \"{}\" - {result:?}",
+                result.name,
+                result.id
+            );
+
+            Ok(result)
+        }
+        None => {
+            let form_data = category::ActiveModel {
+                id: Set(uuid::Uuid::new_v4()),
+                name: Set(category.name.clone()),
+                code: Set(code),
+                parent_id: Set(None),
+                created_at: NotSet,
+                sma_id: Set(Some(category.id.clone())),
+            };
+            let result = service::Mutation::create_category(conn, form_data).await?;
+            log::info!(
+                "Adding new category from SMA: {} \"{}\" - {result:?}",
+                result.name,
+                result.id
+            );
+
+            Ok(result)
+        }
+    }
+}
+
+/// Nests the categories imported from the SMA system as they are in Sma, once every one of them
+/// exists in the database. A parent which wasn't imported is looked for among the categories
+/// imported before, and left out if it still can't be found.
+///
+/// A parent set by hand, one which wasn't imported from SMA, is kept, and a category is never
+/// nested inside one of its subcategories.
+async fn link_sma_categories(
+    conn: &Connection,
+    categories: &HashMap<String, (category::Model, SmCategory)>,
+) -> Result<(), AppError> {
+    for (model, category) in categories.values() {
+        let parent = match category.parent() {
+            Some(parent) => match categories.get(parent) {
+                Some((parent, _)) => Some(parent.id),
+                None => service::Query::find_category_by_sma_id(conn, parent.to_string())
+                    .await?
+                    .map(|parent| parent.id),
+            },
+            None => None,
+        };
+
+        let Some(parent) = parent else {
+            continue;
+        };
+        if model.parent_id == Some(parent) {
+            continue;
+        }
+
+        if let Some(current) = model.parent_id {
+            let current = service::Query::find_category_by_id(conn, current).await?;
+            if current.is_some_and(|current| current.sma_id.is_none()) {
+                continue;
+            }
+        }
+
+        let descendants = service::Query::category_descendants(conn, model.id).await?;
+        if descendants.contains(&parent) {
+            log::warn!(
+                "SMA category \"{}\" can't be nested inside one of its subcategories \"{parent}\"",
+                model.id
+            );
+            continue;
+        }
+
+        service::Mutation::update_category(
+            conn,
+            model.id,
+            category::ActiveModel {
+                parent_id: Set(Some(parent)),
+                ..Default::default()
+            },
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
 /// Creates or updates a product from the SMA system in the database, depending on whether
 /// the product already exists or not. If the product exists, it will be updated based on
 /// the changes indicated by the `overwrite_matrix`; otherwise, a new product will be cre
@@ -342,6 +530,7 @@ async fn create_or_update_sma_product(
     conn: &Connection,
     s3: &entity::s3::S3FileStorage,
     product: SmaProduct,
+    category: Option<uuid::Uuid>,
     overwrite_matrix: SmaChangeTypeMatrix,
 ) -> Result<SmaChange, AppError> {
     let result = service::Query::find_product_by_sma_code(conn, product.code.clone()).await?;
@@ -358,6 +547,26 @@ async fn create_or_update_sma_product(
                 edited_product.name = product.name;
             }
 
+            // A category set by hand is kept
+            if existing_product.category_id.is_none() && category.is_some() {
+                is_change = true;
+                changes.category = category;
+                edited_product.category_id = category;
+            }
+
+            // A content set by hand is kept as well
+            if existing_product.content_quantity.is_none() {
+                if let Some((quantity, unit)) = product.unit.content() {
+                    is_change = true;
+                    changes.content = Some(ProductContentResponse {
+                        quantity,
+                        unit: unit.clone().into(),
+                    });
+                    edited_product.content_quantity = Some(quantity);
+                    edited_product.content_unit = Some(unit);
+                }
+            }
+
             if overwrite_matrix.price {
                 let price =
                     sea_orm::prelude::Decimal::from_str_exact(&product.price).map_err(|err| {
@@ -383,6 +592,8 @@ async fn create_or_update_sma_product(
 
             match is_change {
                 true => {
+                    // A model converts into unchanged values, which would never be saved
+                    let edited_product = product::ActiveModel::from(edited_product).reset_all();
                     service::Mutation::update_product(conn, existing_product.id, edited_product)
                         .await?;
                     Ok(SmaChange::Edited(changes))
@@ -429,11 +640,14 @@ async fn create_or_update_sma_product(
                 log::info!("Adding new product Image from SMA: {name}");
             }
 
+            let content = product.unit.content();
             let form_data = product::ActiveModel {
                 id: Set(uuid::Uuid::new_v4()),
 
                 name: Set(product.name),
 
+                category_id: Set(category),
+
                 image: Set(filename),
 
                 sell_price: Set(Some(
@@ -445,11 +659,12 @@ async fn create_or_update_sma_product(
                     })?,
                 )),
 
-                sell_price_currency: Set(Some(
-                    entity::models::sea_orm_active_enums::Currency::Euro,
-                )),
+                sell_price_currency: Set(Some(entity::models::currency::EURO.into())),
+
+                unit: Set(Unit::Unit),
 
-                unit: Set(entity::models::sea_orm_active_enums::Unit::Unit),
+                content_quantity: Set(content.as_ref().map(|(quantity, _)| *quantity)),
+                content_unit: Set(content.map(|(_, unit)| unit)),
 
                 sma_code: Set(Some(product.code)),
                 ..Default::default()
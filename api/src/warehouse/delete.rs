@@ -64,3 +64,115 @@ pub async fn delete_warehouse(
         ))),
     }
 }
+
+/// Removes a product from a warehouse, its remaining quantity being recorded as a correction
+/// in the stock journal.
+///
+/// Only an admin can perform this action.
+///
+/// - **Path Parameters:**
+///   - `warehouse_id`: The unique ID of the warehouse in the database.
+///   - `product_id`: The unique ID of the stocked product in the database.
+///
+/// - **Responses:**
+///   - `500`: Internal error, likely related to the database.
+///   - `404`: The product isn't stocked in the warehouse.
+///   - `400`: The request format is invalid.
+///   - `200`: The product has been successfully removed from the warehouse.
+#[utoipa::path(
+    delete,
+    path = "/{warehouse_id}/product/{product_id}",
+    tag = WAREHOUSE_TAG,
+    params(
+        ("warehouse_id" = uuid::Uuid, Path, description = "The database ID of the warehouse."),
+        ("product_id" = uuid::Uuid, Path, description = "The database ID of the stocked product."),
+    ),
+    responses(
+        (status = 500, description = "An internal error occured, probably databse related"),
+        (status = 404, description = "The product isn't stocked in the warehouse"),
+        (status = 400, description = "Your request is not correctly formatted"),
+        (status = 200, description = "The product is removed from the warehouse")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn delete_warehouse_product(
+    admin: Admin,
+    Path((warehouse_id, product_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+    State(conn): State<Connection>,
+) -> Result<impl IntoResponse, AppError> {
+    let result =
+        service::Query::find_warehouse_product_by_id(&conn, warehouse_id, product_id).await?;
+
+    match result {
+        Some(warehouse_product) => {
+            service::Mutation::delete_warehouse_product(&conn, warehouse_id, product_id, admin.id)
+                .await?;
+
+            log::info!(
+                "{admin} just removed the warehouse ({warehouse_id}) product ({product_id}) - {warehouse_product:?}"
+            );
+
+            Ok((StatusCode::OK, ""))
+        }
+        None => Err(AppError::NotFound(format!(
+            "Could not find warehouse \"{warehouse_id}\" / product \"{product_id}\" combination"
+        ))),
+    }
+}
+
+/// Unlinks a recipe from a warehouse, which stops making its product from it.
+///
+/// Only an admin can perform this action.
+///
+/// - **Path Parameters:**
+///   - `warehouse_id`: The unique ID of the warehouse in the database.
+///   - `recipe_id`: The unique ID of the linked recipe in the database.
+///
+/// - **Responses:**
+///   - `500`: Internal error, likely related to the database.
+///   - `404`: The recipe isn't linked to the warehouse.
+///   - `400`: The request format is invalid.
+///   - `200`: The recipe has been successfully unlinked.
+#[utoipa::path(
+    delete,
+    path = "/{warehouse_id}/recipe/{recipe_id}",
+    tag = WAREHOUSE_TAG,
+    params(
+        ("warehouse_id" = uuid::Uuid, Path, description = "The database ID of the warehouse."),
+        ("recipe_id" = uuid::Uuid, Path, description = "The database ID of the linked recipe."),
+    ),
+    responses(
+        (status = 500, description = "An internal error occured, probably databse related"),
+        (status = 404, description = "The recipe isn't linked to the warehouse"),
+        (status = 400, description = "Your request is not correctly formatted"),
+        (status = 200, description = "The recipe is unlinked from the warehouse")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn delete_warehouse_recipe(
+    admin: Admin,
+    Path((warehouse_id, recipe_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+    State(conn): State<Connection>,
+) -> Result<impl IntoResponse, AppError> {
+    let result =
+        service::Query::find_warehouse_recipe_by_id(&conn, warehouse_id, recipe_id).await?;
+
+    match result {
+        Some(warehouse_recipe) => {
+            service::Mutation::delete_warehouse_recipe(&conn, warehouse_id, recipe_id).await?;
+
+            log::info!(
+                "{admin} just unlinked the warehouse ({warehouse_id}) recipe ({recipe_id}) - {warehouse_recipe:?}"
+            );
+
+            Ok((StatusCode::OK, ""))
+        }
+        None => Err(AppError::NotFound(format!(
+            "Could not find warehouse \"{warehouse_id}\" / recipe \"{recipe_id}\" combination"
+        ))),
+    }
+}
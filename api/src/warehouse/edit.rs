@@ -7,7 +7,14 @@ use axum::{
     http::StatusCode,
     response::IntoResponse,
 };
-use entity::{error::AppError, models::warehouse, request::warehouse::EditWarehouseRequest};
+use entity::{
+    error::{AppError, ErrorResponse},
+    models::{warehouse, warehouse_product, warehouse_recipe},
+    request::warehouse::{
+        BulkWarehouseProductRequest, EditWarehouseProductRequest, EditWarehouseRecipeRequest,
+        EditWarehouseRequest, WarehouseProductRequestError, WarehouseRecipeRequestError,
+    },
+};
 use extractor::profile::admin::Admin;
 use service::Connection;
 
@@ -62,3 +69,195 @@ pub async fn edit_warehouse(
         ))),
     }
 }
+
+/// Edit the stock of a product in a warehouse.
+///
+/// The admin can change the quantity and the low-stock thresholds of the product. A change of
+/// quantity is recorded as a correction in the stock journal.
+///
+/// Returns an error if the product isn't stocked in the warehouse, if there is a validation issue, or if a database operation fails.
+#[utoipa::path(
+    put,
+    path = "/{warehouse_id}/product/{product_id}",
+    tag = WAREHOUSE_TAG,
+    params(
+        ("warehouse_id" = uuid::Uuid, Path, description = "The database ID of the warehouse."),
+        ("product_id" = uuid::Uuid, Path, description = "The database ID of the stocked product."),
+    ),
+    request_body(content = EditWarehouseProductRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error occured, probably database related"),
+        (status = 404, description = "The product isn't stocked in the warehouse"),
+        (status = 400, description = "Your request is not correctly formatted", body = ErrorResponse),
+        (status = 200, description = "The warehouse product is correctly edited")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn edit_warehouse_product(
+    admin: Admin,
+    Path((warehouse_id, product_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+    State(conn): State<Connection>,
+    Json(edit_warehouse_product): Json<EditWarehouseProductRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if service::Query::find_warehouse_product_by_id(&conn, warehouse_id, product_id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "Could not find warehouse \"{warehouse_id}\" / product \"{product_id}\" combination"
+        )));
+    }
+
+    let edit_warehouse_product_model: warehouse_product::ActiveModel =
+        edit_warehouse_product.try_into()?;
+    let result = service::Mutation::update_warehouse_product(
+        &conn,
+        warehouse_id,
+        product_id,
+        edit_warehouse_product_model,
+        admin.id,
+    )
+    .await?;
+
+    log::info!(
+        "{admin} successfully edited warehouse ({warehouse_id}) product ({product_id}) - {result:?}"
+    );
+
+    Ok((StatusCode::OK, ""))
+}
+
+/// Sets the quantity of many products of a warehouse at once, after a physical stock count.
+///
+/// Every product must exist, it is stocked in the warehouse if it wasn't yet. All the quantities
+/// are set at once, each change being recorded as a correction in the stock journal.
+///
+/// Returns an error if the warehouse or a product doesn't exist, if there is a validation issue, or if a database operation fails.
+#[utoipa::path(
+    put,
+    path = "/{warehouse_id}/product",
+    tag = WAREHOUSE_TAG,
+    params(
+        ("warehouse_id" = uuid::Uuid, Path, description = "The database ID of the warehouse."),
+    ),
+    request_body(content = BulkWarehouseProductRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error occured, probably database related"),
+        (status = 400, description = "Your request is not correctly formatted", body = ErrorResponse),
+        (status = 200, description = "The quantities are correctly set")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn edit_warehouse_products(
+    admin: Admin,
+    Path(warehouse_id): Path<uuid::Uuid>,
+    State(conn): State<Connection>,
+    Json(bulk): Json<BulkWarehouseProductRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    bulk.validate()?;
+
+    if service::Query::find_warehouse_by_id(&conn, warehouse_id)
+        .await?
+        .is_none()
+    {
+        return Err(WarehouseProductRequestError::WarehouseDoesntExist(
+            warehouse_id,
+        ))?;
+    }
+
+    for line in &bulk.products {
+        if service::Query::find_product_by_id(&conn, line.product)
+            .await?
+            .is_none()
+        {
+            return Err(WarehouseProductRequestError::ProductDoesntExist(
+                line.product,
+            ))?;
+        }
+    }
+
+    let lines = bulk
+        .products
+        .into_iter()
+        .map(|line| (line.product, line.quantity))
+        .collect();
+    let result =
+        service::Mutation::set_warehouse_product_quantities(&conn, warehouse_id, lines, admin.id)
+            .await?;
+
+    log::info!("{admin} set the quantities of warehouse ({warehouse_id}) - {result:?}");
+
+    Ok((StatusCode::OK, ""))
+}
+
+/// Edit the link between a warehouse and one of its recipes.
+///
+/// The admin can change the priority of the recipe in the warehouse, which must stay unique
+/// within the warehouse.
+///
+/// Returns an error if the link doesn't exist, if the priority is already used, or if a database operation fails.
+#[utoipa::path(
+    put,
+    path = "/{warehouse_id}/recipe/{recipe_id}",
+    tag = WAREHOUSE_TAG,
+    params(
+        ("warehouse_id" = uuid::Uuid, Path, description = "The database ID of the warehouse."),
+        ("recipe_id" = uuid::Uuid, Path, description = "The database ID of the linked recipe."),
+    ),
+    request_body(content = EditWarehouseRecipeRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error occured, probably database related"),
+        (status = 404, description = "The recipe isn't linked to the warehouse"),
+        (status = 400, description = "Your request is not correctly formatted", body = ErrorResponse),
+        (status = 200, description = "The warehouse recipe is correctly edited")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn edit_warehouse_recipe(
+    admin: Admin,
+    Path((warehouse_id, recipe_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+    State(conn): State<Connection>,
+    Json(edit_warehouse_recipe): Json<EditWarehouseRecipeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if service::Query::find_warehouse_recipe_by_id(&conn, warehouse_id, recipe_id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "Could not find warehouse \"{warehouse_id}\" / recipe \"{recipe_id}\" combination"
+        )));
+    }
+
+    if let Some(priority) = edit_warehouse_recipe.priority {
+        if let Some(existing) =
+            service::Query::find_warehouse_recipe_by_priority(&conn, warehouse_id, priority).await?
+        {
+            if existing.recipe_id != recipe_id {
+                return Err(WarehouseRecipeRequestError::PriorityAlreadyUsed(
+                    priority,
+                    existing.recipe_id,
+                ))?;
+            }
+        }
+    }
+
+    let edit_warehouse_recipe_model: warehouse_recipe::ActiveModel = edit_warehouse_recipe.into();
+    let result = service::Mutation::update_warehouse_recipe(
+        &conn,
+        warehouse_id,
+        recipe_id,
+        edit_warehouse_recipe_model,
+    )
+    .await?;
+
+    log::info!(
+        "{admin} successfully edited warehouse ({warehouse_id}) recipe ({recipe_id}) - {result:?}"
+    );
+
+    Ok((StatusCode::OK, ""))
+}
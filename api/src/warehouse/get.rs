@@ -7,14 +7,20 @@ use axum::{
 };
 use axum_extra::extract::Query;
 use entity::{
-    error::AppError,
+    error::{AppError, ErrorResponse},
     models::{
+        stock_movement::{Stock_movementFilterQuery, Stock_movementSortQuery},
         warehouse::{WarehouseFilterQuery, WarehouseSortQuery},
         warehouse_product::{Warehouse_productFilterQuery, Warehouse_productSortQuery},
+        warehouse_product_batch::Warehouse_product_batchFilterQuery,
+        warehouse_recipe::{Warehouse_recipeFilterQuery, Warehouse_recipeSortQuery},
     },
+    request::warehouse::ExpiringQuery,
     response::warehouse::{
-        WarehouseListResponse, WarehouseProductResponse, WarehouseProductResponseError,
-        WarehouseProductsListResponse, WarehouseResponse,
+        ExpiringBatchListResponse, StockMovementListResponse, WarehouseListResponse,
+        WarehouseProductBatchesResponse, WarehouseProductResponse, WarehouseProductResponseError,
+        WarehouseProductsListResponse, WarehouseRecipeResponse, WarehouseRecipeResponseError,
+        WarehouseRecipesListResponse, WarehouseResponse,
     },
 };
 use extractor::{profile::admin::Admin, query::Pagination};
@@ -149,7 +155,15 @@ pub async fn get_warehouse_product(
         service::Query::find_warehouse_product_by_id(&conn, warehouse_id, product_id).await?;
 
     match result {
-        Some(warehouse_product) => Ok(Json(warehouse_product.try_into()?)),
+        Some((warehouse_product, product)) => {
+            let available =
+                service::Query::available_quantities(&conn, Some(warehouse_id), &[product_id])
+                    .await?;
+            let available_quantity = available.get(&product_id).copied().unwrap_or_default();
+            Ok(Json(
+                (warehouse_product, product, available_quantity).try_into()?,
+            ))
+        }
         None => Err(AppError::NotFound(format!(
             "Could not find warehouse \"{warehouse_id}\" / product \"{product_id}\" combination"
         ))),
@@ -213,9 +227,208 @@ pub async fn get_all_warehouse_products(
         service::Query::count_warehouse_products(&conn, warehouse_id, filter).await?;
     let total_page = ((total_warehouses.max(1) - 1) / per_page) + 1;
 
+    let product_ids = result
+        .iter()
+        .map(|(_, product)| product.id)
+        .collect::<Vec<_>>();
+    let available =
+        service::Query::available_quantities(&conn, Some(warehouse_id), &product_ids).await?;
+
     let products = result
+        .into_iter()
+        .map(|(warehouse_product, product)| {
+            let available_quantity = available.get(&product.id).copied().unwrap_or_default();
+            (warehouse_product, product, available_quantity).try_into()
+        })
+        .collect::<Result<_, WarehouseProductResponseError>>()?;
+    Ok(Json(WarehouseProductsListResponse {
+        current_page: page,
+        total_page,
+        products,
+    }))
+}
+
+/// Retrieves a recipe linked to a warehouse, along with its priority.
+#[utoipa::path(get, path = "/{warehouse_id}/recipe/{recipe_id}",
+    tag = WAREHOUSE_TAG,
+    params(
+        ("warehouse_id" = uuid::Uuid, Path, description = "The database ID of the warehouse to retrieve."),
+        ("recipe_id" = uuid::Uuid, Path, description = "The database ID of the recipe to retrieve."),
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The recipe isn't linked to the warehouse."),
+        (status = 400, description = "The request is improperly formatted."),
+        (status = 200, description = "The warehouse recipe was successfully retrieved.", body = WarehouseRecipeResponse)
+    ),
+    security(
+        (),
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_warehouse_recipe(
+    _admin: Admin,
+    Path((warehouse_id, recipe_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+    State(conn): State<Connection>,
+) -> Result<Json<WarehouseRecipeResponse>, AppError> {
+    let result =
+        service::Query::find_warehouse_recipe_by_id(&conn, warehouse_id, recipe_id).await?;
+
+    match result {
+        Some(warehouse_recipe) => Ok(Json(warehouse_recipe.try_into()?)),
+        None => Err(AppError::NotFound(format!(
+            "Could not find warehouse \"{warehouse_id}\" / recipe \"{recipe_id}\" combination"
+        ))),
+    }
+}
+
+/// Fetches the recipes linked to a warehouse with pagination, filtering, and sorting options.
+///
+/// # Returns
+/// - `200 OK`: A paginated list of recipes for the warehouse, including the current page and total pages.
+/// - `404 Not Found`: The warehouse doesn't exist.
+/// - `500 Internal Server Error`: An internal error occurs, possibly related to the database.
+/// - `400 Bad Request`: Invalid query parameters (pagination, filtering, or sorting).
+#[utoipa::path(get, path = "/{warehouse_id}/recipe",
+    tag = WAREHOUSE_TAG,
+    params(
+        ("warehouse_id" = uuid::Uuid, Path, description = "The database ID of the warehouse to retrieve."),
+        Pagination,
+        Warehouse_recipeFilterQuery,
+        Warehouse_recipeSortQuery,
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The warehouse doesn't exist."),
+        (status = 400, description = "The request is improperly formatted."),
+        (status = 200, description = "The warehouse recipes were successfully retrieved.", body = WarehouseRecipesListResponse)
+    ),
+    security(
+        (),
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_all_warehouse_recipes(
+    _admin: Admin,
+    Path(warehouse_id): Path<uuid::Uuid>,
+    Query(pagination): Query<Pagination>,
+    Query(filter): Query<Warehouse_recipeFilterQuery>,
+    Query(sort): Query<Warehouse_recipeSortQuery>,
+    State(conn): State<Connection>,
+) -> Result<Json<WarehouseRecipesListResponse>, AppError> {
+    if service::Query::find_warehouse_by_id(&conn, warehouse_id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "The warehouse with id: {warehouse_id} doesn't exist"
+        )));
+    }
+
+    let page = pagination.page.unwrap_or(0);
+    let per_page = pagination.per_page.unwrap_or(20);
+
+    let result = service::Query::find_warehouse_recipes_by_id(
+        &conn,
+        warehouse_id,
+        filter.clone(),
+        sort,
+        page,
+        per_page,
+    )
+    .await?;
+
+    let total_recipes =
+        service::Query::count_warehouse_recipes_with_condition(&conn, warehouse_id, filter).await?;
+    let total_page = ((total_recipes.max(1) - 1) / per_page) + 1;
+
+    let recipes = result
         .into_iter()
         .map(TryInto::try_into)
+        .collect::<Result<_, WarehouseRecipeResponseError>>()?;
+    Ok(Json(WarehouseRecipesListResponse {
+        current_page: page,
+        total_page,
+        recipes,
+    }))
+}
+
+/// Fetches the products of a warehouse whose stock is at or below their `min_quantity`, with
+/// pagination, filtering, and sorting options.
+///
+/// Products without a `min_quantity` are never listed.
+///
+/// # Returns
+/// - `200 OK`: A paginated list of the products running low, including the current page and total pages.
+/// - `404 Not Found`: The warehouse doesn't exist.
+/// - `500 Internal Server Error`: An internal error occurs, possibly related to the database.
+/// - `400 Bad Request`: Invalid query parameters (pagination, filtering, or sorting).
+#[utoipa::path(get, path = "/{warehouse_id}/low-stock",
+    tag = WAREHOUSE_TAG,
+    params(
+        ("warehouse_id" = uuid::Uuid, Path, description = "The database ID of the warehouse to retrieve."),
+        Pagination,
+        Warehouse_productFilterQuery,
+        Warehouse_productSortQuery,
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The warehouse doesn't exist."),
+        (status = 400, description = "The request is improperly formatted."),
+        (status = 200, description = "The products running low were successfully retrieved.", body = WarehouseProductsListResponse)
+    ),
+    security(
+        (),
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_warehouse_low_stock(
+    _admin: Admin,
+    Path(warehouse_id): Path<uuid::Uuid>,
+    Query(pagination): Query<Pagination>,
+    Query(filter): Query<Warehouse_productFilterQuery>,
+    Query(sort): Query<Warehouse_productSortQuery>,
+    State(conn): State<Connection>,
+) -> Result<Json<WarehouseProductsListResponse>, AppError> {
+    if service::Query::find_warehouse_by_id(&conn, warehouse_id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "The warehouse with id: {warehouse_id} doesn't exist"
+        )));
+    }
+
+    let page = pagination.page.unwrap_or(0);
+    let per_page = pagination.per_page.unwrap_or(20);
+
+    let result = service::Query::list_low_stock_products(
+        &conn,
+        warehouse_id,
+        filter.clone(),
+        sort,
+        page,
+        per_page,
+    )
+    .await?;
+
+    let total_products =
+        service::Query::count_low_stock_products(&conn, warehouse_id, filter).await?;
+    let total_page = ((total_products.max(1) - 1) / per_page) + 1;
+
+    let product_ids = result
+        .iter()
+        .map(|(_, product)| product.id)
+        .collect::<Vec<_>>();
+    let available =
+        service::Query::available_quantities(&conn, Some(warehouse_id), &product_ids).await?;
+
+    let products = result
+        .into_iter()
+        .map(|(warehouse_product, product)| {
+            let available_quantity = available.get(&product.id).copied().unwrap_or_default();
+            (warehouse_product, product, available_quantity).try_into()
+        })
         .collect::<Result<_, WarehouseProductResponseError>>()?;
     Ok(Json(WarehouseProductsListResponse {
         current_page: page,
@@ -223,3 +436,195 @@ pub async fn get_all_warehouse_products(
         products,
     }))
 }
+
+/// Fetches the movements of the stock of a warehouse with pagination, filtering, and sorting options.
+///
+/// Every change of a stock is recorded with its signed delta, its reason (purchase, restock,
+/// correction, recipe consumption or transfer), its author and its timestamp, so the current
+/// stock can be explained and shrinkage tracked down.
+///
+/// # Returns
+/// - `200 OK`: A paginated list of movements for the warehouse, including the current page and total pages.
+/// - `404 Not Found`: The warehouse doesn't exist.
+/// - `500 Internal Server Error`: An internal error occurs, possibly related to the database.
+/// - `400 Bad Request`: Invalid query parameters (pagination, filtering, or sorting).
+#[utoipa::path(get, path = "/{warehouse_id}/movements",
+    tag = WAREHOUSE_TAG,
+    params(
+        ("warehouse_id" = uuid::Uuid, Path, description = "The database ID of the warehouse to retrieve."),
+        Pagination,
+        Stock_movementFilterQuery,
+        Stock_movementSortQuery,
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The warehouse doesn't exist."),
+        (status = 400, description = "The request is improperly formatted."),
+        (status = 200, description = "The movements were successfully retrieved.", body = StockMovementListResponse)
+    ),
+    security(
+        (),
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_warehouse_movements(
+    _admin: Admin,
+    Path(warehouse_id): Path<uuid::Uuid>,
+    Query(pagination): Query<Pagination>,
+    Query(filter): Query<Stock_movementFilterQuery>,
+    Query(sort): Query<Stock_movementSortQuery>,
+    State(conn): State<Connection>,
+) -> Result<Json<StockMovementListResponse>, AppError> {
+    if service::Query::find_warehouse_by_id(&conn, warehouse_id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "The warehouse with id: {warehouse_id} doesn't exist"
+        )));
+    }
+
+    let page = pagination.page.unwrap_or(0);
+    let per_page = pagination.per_page.unwrap_or(20);
+
+    let result = service::Query::list_stock_movements(
+        &conn,
+        warehouse_id,
+        filter.clone(),
+        sort,
+        page,
+        per_page,
+    )
+    .await?;
+
+    let total_movements =
+        service::Query::count_stock_movements(&conn, warehouse_id, filter).await?;
+    let total_page = ((total_movements.max(1) - 1) / per_page) + 1;
+
+    Ok(Json(StockMovementListResponse {
+        current_page: page,
+        total_page,
+        movements: result.into_iter().map(Into::into).collect(),
+    }))
+}
+
+/// Fetches the batches of a product in a warehouse, in the order they are consumed: the oldest
+/// received first.
+///
+/// The batches only hold the part of the stock received as batches, the rest isn't tracked.
+///
+/// # Returns
+/// - `200 OK`: The batches of the product, an empty list when it isn't tracked in batches.
+/// - `404 Not Found`: The product isn't stocked in the warehouse.
+/// - `500 Internal Server Error`: An internal error occurs, possibly related to the database.
+#[utoipa::path(get, path = "/{warehouse_id}/product/{product_id}/batch",
+    tag = WAREHOUSE_TAG,
+    params(
+        ("warehouse_id" = uuid::Uuid, Path, description = "The database ID of the warehouse to retrieve."),
+        ("product_id" = uuid::Uuid, Path, description = "The database ID of the product to retrieve."),
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The product isn't stocked in the warehouse."),
+        (status = 400, description = "The request is improperly formatted."),
+        (status = 200, description = "The batches were successfully retrieved.", body = WarehouseProductBatchesResponse)
+    ),
+    security(
+        (),
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_warehouse_product_batches(
+    _admin: Admin,
+    Path((warehouse_id, product_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+    State(conn): State<Connection>,
+) -> Result<Json<WarehouseProductBatchesResponse>, AppError> {
+    if service::Query::find_warehouse_product_by_id(&conn, warehouse_id, product_id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "Could not find warehouse \"{warehouse_id}\" / product \"{product_id}\" combination"
+        )));
+    }
+
+    let result =
+        service::Query::list_warehouse_product_batches(&conn, warehouse_id, product_id).await?;
+
+    Ok(Json(WarehouseProductBatchesResponse {
+        batches: result.into_iter().map(Into::into).collect(),
+    }))
+}
+
+/// Fetches the batches of a warehouse expiring within a window, such as `?within=7d`, with
+/// pagination and filtering options.
+///
+/// Batches already expired are listed too, the first to expire first. The window is parsed by
+/// `humantime`, it is 7 days when not provided.
+///
+/// # Returns
+/// - `200 OK`: A paginated list of the batches about to expire, including the current page and total pages.
+/// - `404 Not Found`: The warehouse doesn't exist.
+/// - `500 Internal Server Error`: An internal error occurs, possibly related to the database.
+/// - `400 Bad Request`: Invalid query parameters (window, pagination or filtering).
+#[utoipa::path(get, path = "/{warehouse_id}/expiring",
+    tag = WAREHOUSE_TAG,
+    params(
+        ("warehouse_id" = uuid::Uuid, Path, description = "The database ID of the warehouse to retrieve."),
+        ExpiringQuery,
+        Pagination,
+        Warehouse_product_batchFilterQuery,
+    ),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 404, description = "The warehouse doesn't exist."),
+        (status = 400, description = "The request is improperly formatted.", body = ErrorResponse),
+        (status = 200, description = "The expiring batches were successfully retrieved.", body = ExpiringBatchListResponse)
+    ),
+    security(
+        (),
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn get_warehouse_expiring(
+    _admin: Admin,
+    Path(warehouse_id): Path<uuid::Uuid>,
+    Query(expiring): Query<ExpiringQuery>,
+    Query(pagination): Query<Pagination>,
+    Query(filter): Query<Warehouse_product_batchFilterQuery>,
+    State(conn): State<Connection>,
+) -> Result<Json<ExpiringBatchListResponse>, AppError> {
+    let before = expiring.before()?;
+
+    if service::Query::find_warehouse_by_id(&conn, warehouse_id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound(format!(
+            "The warehouse with id: {warehouse_id} doesn't exist"
+        )));
+    }
+
+    let page = pagination.page.unwrap_or(0);
+    let per_page = pagination.per_page.unwrap_or(20);
+
+    let result = service::Query::list_expiring_batches(
+        &conn,
+        warehouse_id,
+        before,
+        filter.clone(),
+        page,
+        per_page,
+    )
+    .await?;
+
+    let total_batches =
+        service::Query::count_expiring_batches(&conn, warehouse_id, before, filter).await?;
+    let total_page = ((total_batches.max(1) - 1) / per_page) + 1;
+
+    Ok(Json(ExpiringBatchListResponse {
+        current_page: page,
+        total_page,
+        batches: result.into_iter().map(Into::into).collect(),
+    }))
+}
@@ -13,9 +13,11 @@ use axum::{
 };
 use entity::{
     error::{AppError, ErrorResponse},
-    models::{warehouse, warehouse_product},
+    models::{warehouse, warehouse_product, warehouse_recipe},
     request::warehouse::{
-        NewWarehouseProductRequest, NewWarehouseRequest, WarehouseProductRequestError,
+        NewWarehouseProductBatchRequest, NewWarehouseProductRequest, NewWarehouseRecipeRequest,
+        NewWarehouseRequest, WarehouseProductBatchRequestError, WarehouseProductRequestError,
+        WarehouseRecipeRequestError,
     },
 };
 use extractor::profile::admin::Admin;
@@ -118,6 +120,7 @@ pub async fn post_new_warehouse_product(
                 warehouse_id,
                 product_id,
                 warehouse_product_model,
+                admin.id,
             )
             .await?;
 
@@ -145,3 +148,157 @@ pub async fn post_new_warehouse_product(
         }
     }
 }
+
+/// Handles the receipt of a batch of a product in a warehouse.
+///
+/// This endpoint allows an administrator to add stock of a product to a warehouse as a batch,
+/// with the date it expires at if it does. The product is stocked in the warehouse if it wasn't
+/// yet, and the increase is recorded as a restock. Whatever decreases the stock then consumes
+/// the oldest batch first. The function returns a `201 Created` response with the id of the
+/// batch upon success, or an appropriate error response if the request is invalid or an
+/// internal error occurs.
+#[utoipa::path(
+    post,
+    path = "/{warehouse_id}/product/{product_id}/batch",
+    tag = WAREHOUSE_TAG,
+    params(
+        ("warehouse_id" = uuid::Uuid, Path, description = "The database ID of the warehouse."),
+        ("product_id" = uuid::Uuid, Path, description = "The database ID of the product received."),
+    ),
+    request_body(content = NewWarehouseProductBatchRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 400, description = "The request is improperly formatted.", body = ErrorResponse),
+        (status = 201, description = "Successfully received the batch", body = String)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn post_new_warehouse_product_batch(
+    admin: Admin,
+    Path((warehouse_id, product_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+    State(conn): State<Connection>,
+    Json(batch): Json<NewWarehouseProductBatchRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    batch.validate()?;
+
+    if service::Query::find_warehouse_by_id(&conn, warehouse_id)
+        .await?
+        .is_none()
+    {
+        return Err(WarehouseProductBatchRequestError::WarehouseDoesntExist(
+            warehouse_id,
+        ))?;
+    }
+
+    if service::Query::find_product_by_id(&conn, product_id)
+        .await?
+        .is_none()
+    {
+        return Err(WarehouseProductBatchRequestError::ProductDoesntExist(
+            product_id,
+        ))?;
+    }
+
+    let result = service::Mutation::create_warehouse_product_batch(
+        &conn,
+        warehouse_id,
+        product_id,
+        batch.quantity,
+        batch.expires_at.map(Into::into),
+        admin.id,
+    )
+    .await?;
+
+    log::info!(
+        "{admin} received a batch in warehouse ({warehouse_id}) of product ({product_id}) - {result:?}",
+    );
+
+    Ok((StatusCode::CREATED, result.id.to_string()))
+}
+
+/// Handles the link of a recipe to a warehouse.
+///
+/// This endpoint allows an administrator to let a warehouse make a product from one of its
+/// recipes. The warehouse must exist, the recipe must exist and be enabled, and no other recipe
+/// of the warehouse may use the same priority. The function returns a `201 Created` response
+/// upon success, or an appropriate error response if the request is invalid or an internal error occurs.
+#[utoipa::path(
+    post,
+    path = "/{warehouse_id}/recipe/{recipe_id}",
+    tag = WAREHOUSE_TAG,
+    params(
+        ("warehouse_id" = uuid::Uuid, Path, description = "The database ID of the warehouse."),
+        ("recipe_id" = uuid::Uuid, Path, description = "The database ID of the recipe to link."),
+    ),
+    request_body(content = NewWarehouseRecipeRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 400, description = "The request is improperly formatted.", body = ErrorResponse),
+        (status = 201, description = "Successfully linked the recipe to the warehouse")
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn post_new_warehouse_recipe(
+    admin: Admin,
+    Path((warehouse_id, recipe_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+    State(conn): State<Connection>,
+    Json(warehouse_recipe): Json<NewWarehouseRecipeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if service::Query::find_warehouse_by_id(&conn, warehouse_id)
+        .await?
+        .is_none()
+    {
+        return Err(WarehouseRecipeRequestError::WarehouseDoesntExist(
+            warehouse_id,
+        ))?;
+    }
+
+    match service::Query::find_recipe_by_id(&conn, recipe_id).await? {
+        None => return Err(WarehouseRecipeRequestError::RecipeDoesntExist(recipe_id))?,
+        Some((recipe, _)) if recipe.disabled => {
+            return Err(WarehouseRecipeRequestError::RecipeIsDisabled(recipe_id))?;
+        }
+        Some(_) => {}
+    }
+
+    if service::Query::find_warehouse_recipe_by_id(&conn, warehouse_id, recipe_id)
+        .await?
+        .is_some()
+    {
+        return Err(WarehouseRecipeRequestError::WarehouseRecipeAlreadyExist(
+            warehouse_id,
+            recipe_id,
+        ))?;
+    }
+
+    if let Some(existing) = service::Query::find_warehouse_recipe_by_priority(
+        &conn,
+        warehouse_id,
+        warehouse_recipe.priority,
+    )
+    .await?
+    {
+        return Err(WarehouseRecipeRequestError::PriorityAlreadyUsed(
+            existing.priority,
+            existing.recipe_id,
+        ))?;
+    }
+
+    let warehouse_recipe_model: warehouse_recipe::ActiveModel = warehouse_recipe.into();
+
+    let result = service::Mutation::create_warehouse_recipe(
+        &conn,
+        warehouse_id,
+        recipe_id,
+        warehouse_recipe_model,
+    )
+    .await?;
+
+    log::info!("{admin} added a new warehouse ({warehouse_id}) recipe ({recipe_id}) - {result:?}",);
+
+    Ok((StatusCode::CREATED, ""))
+}
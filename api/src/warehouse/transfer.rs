@@ -0,0 +1,110 @@
+//! This module contains the route handler for transferring stock between warehouses.
+//!
+//! The handler will be accessible via a POST request to the `/warehouse/{from}/transfer/{to}`
+//! endpoint. It allows an admin to record crates physically moved from a warehouse to another.
+
+use crate::utils::openapi::WAREHOUSE_TAG;
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use entity::{
+    error::{AppError, ErrorResponse},
+    request::warehouse::{TransferRequest, TransferRequestError},
+    response::warehouse::TransferResponse,
+};
+use extractor::profile::admin::Admin;
+use service::{Connection, stock::InsufficientStock};
+
+/// Handler for moving products from a warehouse to another.
+///
+/// Every quantity is converted into the unit of its product, from a multiple of it such as
+/// centiliters for a product counted in liters, or through the content of a single unit of the
+/// product such as 33 centiliters for a can.
+///
+/// The products are taken from the source, which must hold enough of each of them, and stocked
+/// in the destination in a single database transaction: either everything moves or nothing
+/// does. Both sides are recorded as transfers in the stock journal.
+///
+/// Path: `/warehouse/{from}/transfer/{to}`
+///
+/// - **Request Body:** Expects a `TransferRequest` JSON object.
+/// - **Responses:**
+///     - 500: Internal server error (likely database related).
+///     - 400: Bad request (unknown warehouse or product, wrong unit, insufficient stock).
+///     - 200: Successfully moved the products, along with their resulting stock.
+#[utoipa::path(
+    post,
+    path = "/{from}/transfer/{to}",
+    tag = WAREHOUSE_TAG,
+    params(
+        ("from" = uuid::Uuid, Path, description = "The database ID of the warehouse to take the products from."),
+        ("to" = uuid::Uuid, Path, description = "The database ID of the warehouse to move the products to."),
+    ),
+    request_body(content = TransferRequest, content_type = "application/json"),
+    responses(
+        (status = 500, description = "An internal error, most likely related to the database, occurred."),
+        (status = 400, description = "The request is improperly formatted, or the source doesn't hold enough stock.", body = ErrorResponse),
+        (status = 200, description = "Successfully moved the products.", body = TransferResponse)
+    ),
+    security(
+        ("axum-oidc" = [])
+    )
+)]
+pub async fn post_warehouse_transfer(
+    admin: Admin,
+    Path((from, to)): Path<(uuid::Uuid, uuid::Uuid)>,
+    State(conn): State<Connection>,
+    Json(transfer): Json<TransferRequest>,
+) -> Result<Json<TransferResponse>, AppError> {
+    transfer.validate()?;
+
+    if from == to {
+        return Err(TransferRequestError::WarehousesCannotBeTheSame(from).into());
+    }
+
+    for warehouse_id in [from, to] {
+        if service::Query::find_warehouse_by_id(&conn, warehouse_id)
+            .await?
+            .is_none()
+        {
+            return Err(TransferRequestError::WarehouseDoesntExist(warehouse_id).into());
+        }
+    }
+
+    let mut lines = Vec::with_capacity(transfer.products.len());
+    for line in transfer.products {
+        let Some(product) = service::Query::find_product_by_id(&conn, line.product).await? else {
+            return Err(TransferRequestError::ProductDoesntExist(line.product).into());
+        };
+
+        let (unit, quantity) = line.unit.to_base(line.quantity);
+        let Some(quantity) = service::unit::convert(&product, &unit, quantity) else {
+            return Err(TransferRequestError::UnitDoesntMatch(
+                line.product,
+                product.unit.into(),
+                unit.into(),
+            )
+            .into());
+        };
+
+        lines.push((line.product, quantity));
+    }
+
+    let result = service::Mutation::transfer_stock(&conn, from, to, lines, admin.id).await?;
+
+    let products = match result {
+        Ok(products) => products,
+        Err(InsufficientStock(product)) => {
+            return Err(TransferRequestError::InsufficientStock(product).into());
+        }
+    };
+
+    log::info!("{admin} transferred stock from warehouse {from} to warehouse {to} - {products:?}");
+
+    Ok(Json(TransferResponse {
+        from,
+        to,
+        products: products.into_iter().map(Into::into).collect(),
+    }))
+}
@@ -0,0 +1,69 @@
+//! `SeaORM` entity representing the `category` table: a group of products,
+//! which can itself be nested inside a parent category.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Represents the `category` entity in the database.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "category")]
+pub struct Model {
+    /// Unique identifier for the category. Primary key, non-auto-incrementing.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+
+    /// Name of the category.
+    pub name: String,
+
+    /// Optional unique short code of the category.
+    #[sea_orm(unique)]
+    pub code: Option<String>,
+
+    /// The category this one is nested in, `None` for a top level category.
+    pub parent_id: Option<Uuid>,
+
+    /// Timestamp for when the category was created.
+    #[sea_orm(filter_override = "chrono::DateTime<chrono::Utc>", filter_plus_order)]
+    pub created_at: DateTimeWithTimeZone,
+
+    /// Optional unique id of the category in the Sma system.
+    #[sea_orm(unique)]
+    pub sma_id: Option<String>,
+}
+
+/// Enum representing relationships for the `category` entity.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// The category this one is nested in.
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::ParentId",
+        to = "Column::Id",
+        on_update = "Cascade",
+        on_delete = "Restrict"
+    )]
+    Parent,
+    /// The products of this category.
+    #[sea_orm(has_many = "super::product::Entity")]
+    Products,
+}
+
+impl Related<super::product::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Products.def()
+    }
+}
+
+/// Custom behavior for the `ActiveModel` of the `category` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,88 @@
+//! `SeaORM` entity representing the `currency` table, the registry of every
+//! currency an amount can be expressed in. Amounts refer to their currency by
+//! its code, see `CurrencyCode`.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Code of the Euro, registered by the migrations.
+pub const EURO: &str = "euro";
+
+/// Code of the Epicoin, the internal currency registered by the migrations.
+pub const EPICOIN: &str = "epicoin";
+
+/// Represents the `currency` entity in the database.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "currency")]
+pub struct Model {
+    /// Unique code of the currency (e.g. `euro`). Primary key.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub code: String,
+
+    /// Symbol displayed next to an amount in this currency.
+    pub symbol: String,
+
+    /// Number of decimal places an amount in this currency is rounded to.
+    #[sea_orm(filter_plus_order)]
+    pub decimal_places: i16,
+
+    /// Indicates if the currency only exists inside the application, and
+    /// cannot be collected by a payment provider.
+    #[sea_orm(filter_single)]
+    pub is_internal: bool,
+
+    /// Timestamp indicating when the currency was registered.
+    #[sea_orm(filter_override = "chrono::DateTime<chrono::Utc>", filter_plus_order)]
+    pub created_at: DateTimeWithTimeZone,
+}
+
+/// Enum representing relationships for the `currency` entity.
+/// Currencies are referred to by their code, no relationship is defined from here.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// Custom behavior for the `ActiveModel` of the `currency` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
+
+/// Code of a currency of the `currency` table, as stored in every column
+/// holding the currency of an amount.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, DeriveValueType,
+)]
+#[serde(transparent)]
+pub struct CurrencyCode(pub String);
+
+impl sea_orm::sea_query::Nullable for CurrencyCode {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+impl std::fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for CurrencyCode {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<Model> for CurrencyCode {
+    fn from(value: Model) -> Self {
+        Self(value.code)
+    }
+}
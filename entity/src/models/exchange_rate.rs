@@ -0,0 +1,57 @@
+//! `SeaORM` entity representing the `exchange_rate` table. Each row gives the
+//! rate to convert an amount from a currency to another, starting at a given date.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::currency::CurrencyCode;
+
+/// Represents the `exchange_rate` entity in the database. A rate applies from
+/// its `effective_from` date until the next rate of the same currency pair.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "exchange_rate")]
+pub struct Model {
+    /// Unique identifier for the exchange rate. Primary key, non-auto-incrementing.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+
+    /// Currency the amounts are converted from.
+    #[sea_orm(filter_override = "crate::request::r#enum::CurrencyRequest")]
+    pub from_currency: CurrencyCode,
+
+    /// Currency the amounts are converted to.
+    #[sea_orm(filter_override = "crate::request::r#enum::CurrencyRequest")]
+    pub to_currency: CurrencyCode,
+
+    /// Amount of `to_currency` given for one `from_currency`, stored as a
+    /// decimal with up to 16 digits and 6 decimal places.
+    #[sea_orm(column_type = "Decimal(Some((16, 6)))", filter_plus_order)]
+    pub rate: Decimal,
+
+    /// Timestamp from which the rate applies.
+    #[sea_orm(filter_override = "chrono::DateTime<chrono::Utc>", filter_plus_order)]
+    pub effective_from: DateTimeWithTimeZone,
+
+    /// Timestamp indicating when the exchange rate was created.
+    #[sea_orm(filter_override = "chrono::DateTime<chrono::Utc>", filter_plus_order)]
+    pub created_at: DateTimeWithTimeZone,
+}
+
+/// Enum representing relationships for the `exchange_rate` entity.
+/// Currently, there are no relationships defined for this entity.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// Custom behavior for the `ActiveModel` of the `exchange_rate` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
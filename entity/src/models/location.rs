@@ -59,10 +59,28 @@ pub struct Model {
 
 /// Enum representing the relations of the `Location` entity.
 ///
-/// Currently, there are no defined relations for the `Location` entity,
-/// but this enum can be expanded in the future if needed.
+/// A location is linked to the warehouses its stock is taken from.
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
-pub enum Relation {}
+pub enum Relation {
+    /// Represent the warehouses serving this location
+    #[sea_orm(has_many = "super::location_warehouse::Entity")]
+    LocationWarehouses,
+}
+
+impl Related<super::location_warehouse::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LocationWarehouses.def()
+    }
+}
+
+impl Related<super::warehouse::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::location_warehouse::Relation::Warehouse.def()
+    }
+    fn via() -> Option<RelationDef> {
+        Some(super::location_warehouse::Relation::Location.def().rev())
+    }
+}
 
 impl ActiveModelBehavior for ActiveModel {
     fn before_save<'life0, 'async_trait, C>(
@@ -2,13 +2,31 @@
 
 pub mod prelude;
 
+pub mod category;
+pub mod currency;
+pub mod exchange_rate;
 pub mod location;
+pub mod location_warehouse;
 pub mod product;
+pub mod purchase_order;
+pub mod purchase_order_line;
 pub mod recipe;
 pub mod recipe_ingredients;
 pub mod refill;
 pub mod sea_orm_active_enums;
+pub mod stock_movement;
+pub mod stocktake;
+pub mod stocktake_count;
+pub mod supplier;
+pub mod transaction;
+pub mod transaction_balance_edit;
+pub mod transaction_product;
+pub mod transaction_product_stock;
+pub mod transaction_purchase_with_balance;
+pub mod transaction_refill_balance;
+pub mod transaction_refund;
 pub mod user;
 pub mod warehouse;
 pub mod warehouse_product;
+pub mod warehouse_product_batch;
 pub mod warehouse_recipe;
@@ -1,11 +1,29 @@
 //! `SeaORM` Entity. Generated by sea-orm-codegen 0.12.15
 
+pub use super::category::Entity as Category;
+pub use super::currency::Entity as Currency;
+pub use super::exchange_rate::Entity as ExchangeRate;
 pub use super::location::Entity as Location;
+pub use super::location_warehouse::Entity as LocationWarehouse;
 pub use super::product::Entity as Product;
+pub use super::purchase_order::Entity as PurchaseOrder;
+pub use super::purchase_order_line::Entity as PurchaseOrderLine;
 pub use super::recipe::Entity as Recipe;
 pub use super::recipe_ingredients::Entity as RecipeIngredients;
 pub use super::refill::Entity as Refill;
+pub use super::stock_movement::Entity as StockMovement;
+pub use super::stocktake::Entity as Stocktake;
+pub use super::stocktake_count::Entity as StocktakeCount;
+pub use super::supplier::Entity as Supplier;
+pub use super::transaction::Entity as Transaction;
+pub use super::transaction_balance_edit::Entity as TransactionBalanceEdit;
+pub use super::transaction_product::Entity as TransactionProduct;
+pub use super::transaction_product_stock::Entity as TransactionProductStock;
+pub use super::transaction_purchase_with_balance::Entity as TransactionPurchaseWithBalance;
+pub use super::transaction_refill_balance::Entity as TransactionRefillBalance;
+pub use super::transaction_refund::Entity as TransactionRefund;
 pub use super::user::Entity as User;
 pub use super::warehouse::Entity as Warehouse;
 pub use super::warehouse_product::Entity as WarehouseProduct;
+pub use super::warehouse_product_batch::Entity as WarehouseProductBatch;
 pub use super::warehouse_recipe::Entity as WarehouseRecipe;
@@ -4,7 +4,7 @@
 //! columns in the `product` table.
 //! `SeaORM` Entity. Generated by sea-orm-codegen 0.12.15.
 
-use super::sea_orm_active_enums::{Currency, Unit};
+use super::{currency::CurrencyCode, sea_orm_active_enums::Unit};
 use sea_orm::{ActiveValue::Set, entity::prelude::*};
 use serde::{Deserialize, Serialize};
 
@@ -33,6 +33,10 @@ pub struct Model {
     /// Name of the product, required for identification.
     pub name: String,
 
+    /// The category the product belongs to, if any.
+    #[sea_orm(filter_skip)]
+    pub category_id: Option<Uuid>,
+
     /// Display Order of the product inside of lists, 0 is last + default
     #[sea_orm(filter_plus_order)]
     pub display_order: i32,
@@ -44,12 +48,31 @@ pub struct Model {
 
     /// Selling Price Currency for the product,
     #[sea_orm(filter_override = "crate::request::r#enum::CurrencyRequest")]
-    pub sell_price_currency: Option<Currency>,
+    pub sell_price_currency: Option<CurrencyCode>,
+
+    /// Price paid to buy the product, per unit, liter or meter the product is counted in, or
+    /// per kilogram for a product counted in grams. Stored as a decimal with up to 10 digits and 2 decimal places.
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))", nullable, filter_skip)]
+    pub cost_price: Option<Decimal>,
+
+    /// Currency of the cost price
+    #[sea_orm(filter_skip)]
+    pub cost_price_currency: Option<CurrencyCode>,
 
     /// Represent the unit type of Product, if it's a liquid -> Liter, etc..., the default is Unit
     #[sea_orm(filter_override = "crate::request::r#enum::UnitRequest")]
     pub unit: Unit,
 
+    /// What a single unit of the product holds, such as 0.33 liter for a can, letting its
+    /// quantities be expressed in units as well as in grams, liters or meters. Stored as a
+    /// decimal with up to 10 digits and 3 decimal places.
+    #[sea_orm(column_type = "Decimal(Some((10, 3)))", nullable, filter_skip)]
+    pub content_quantity: Option<Decimal>,
+
+    /// Unit of the content, never `Unit`
+    #[sea_orm(filter_skip)]
+    pub content_unit: Option<Unit>,
+
     /// Optional maximum quantity that can be ordered in a single command.
     pub max_quantity_per_command: Option<i16>,
 
@@ -79,11 +102,35 @@ pub struct Model {
 }
 
 /// Enum representing the relations of the `Product` entity.
-///
-/// Currently, there are no defined relations for the `Product` entity,
-/// but this enum can be expanded in the future if needed.
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    /// For the Currency of the selling price
+    #[sea_orm(
+        belongs_to = "super::currency::Entity",
+        from = "Column::SellPriceCurrency",
+        to = "super::currency::Column::Code",
+        on_update = "Cascade",
+        on_delete = "Restrict"
+    )]
+    Currency,
+    /// For the Currency of the cost price
+    #[sea_orm(
+        belongs_to = "super::currency::Entity",
+        from = "Column::CostPriceCurrency",
+        to = "super::currency::Column::Code",
+        on_update = "Cascade",
+        on_delete = "Restrict"
+    )]
+    CostCurrency,
+    /// For the Category
+    #[sea_orm(
+        belongs_to = "super::category::Entity",
+        from = "Column::CategoryId",
+        to = "super::category::Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    Category,
     /// For the Recipe
     #[sea_orm(has_many = "super::recipe::Entity")]
     Recipe,
@@ -95,6 +142,18 @@ pub enum Relation {
     WarehouseProduct,
 }
 
+impl Related<super::currency::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Currency.def()
+    }
+}
+
+impl Related<super::category::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Category.def()
+    }
+}
+
 impl Related<super::recipe_ingredients::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::RecipeIngredients.def()
@@ -0,0 +1,123 @@
+//! `SeaORM` entity representing the `purchase_order` table. A purchase order
+//! lists the products bought from a supplier to restock a warehouse, which
+//! are added to its stock as they are received.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{currency::CurrencyCode, sea_orm_active_enums::PurchaseOrderStatus};
+
+/// Represents the `purchase_order` entity in the database, with who drafted
+/// it and when it was sent and fully received.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "purchase_order")]
+pub struct Model {
+    /// Unique identifier for the purchase order. Primary key, non-auto-incrementing.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+
+    /// The supplier the products are bought from.
+    pub supplier_id: Uuid,
+
+    /// The warehouse the products are delivered to.
+    pub warehouse_id: Uuid,
+
+    /// How far the purchase order went, from its draft to its full receipt.
+    #[sea_orm(filter_override = "crate::request::r#enum::PurchaseOrderStatusRequest")]
+    pub status: PurchaseOrderStatus,
+
+    /// Currency of the unit costs of the lines.
+    #[sea_orm(filter_override = "crate::request::r#enum::CurrencyRequest")]
+    pub currency: CurrencyCode,
+
+    /// The user who drafted the purchase order, if it still exists.
+    pub author_id: Option<Uuid>,
+
+    /// Timestamp for when the purchase order was drafted.
+    #[sea_orm(filter_override = "chrono::DateTime<chrono::Utc>", filter_plus_order)]
+    pub created_at: DateTimeWithTimeZone,
+
+    /// Timestamp for when the purchase order was sent to the supplier, if it was.
+    #[sea_orm(filter_skip)]
+    pub sent_at: Option<DateTimeWithTimeZone>,
+
+    /// Timestamp for when the last ordered product was received, if it was.
+    #[sea_orm(filter_skip)]
+    pub received_at: Option<DateTimeWithTimeZone>,
+}
+
+/// Enum representing relationships for the `purchase_order` entity.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// The supplier the products are bought from.
+    #[sea_orm(
+        belongs_to = "super::supplier::Entity",
+        from = "Column::SupplierId",
+        to = "super::supplier::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Restrict"
+    )]
+    Supplier,
+
+    /// The warehouse the products are delivered to.
+    #[sea_orm(
+        belongs_to = "super::warehouse::Entity",
+        from = "Column::WarehouseId",
+        to = "super::warehouse::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Warehouse,
+
+    /// The currency of the unit costs.
+    #[sea_orm(
+        belongs_to = "super::currency::Entity",
+        from = "Column::Currency",
+        to = "super::currency::Column::Code",
+        on_update = "Cascade",
+        on_delete = "Restrict"
+    )]
+    Currency,
+
+    /// The ordered products of this purchase order.
+    #[sea_orm(has_many = "super::purchase_order_line::Entity")]
+    PurchaseOrderLines,
+}
+
+impl Related<super::supplier::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Supplier.def()
+    }
+}
+
+impl Related<super::warehouse::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Warehouse.def()
+    }
+}
+
+impl Related<super::currency::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Currency.def()
+    }
+}
+
+impl Related<super::purchase_order_line::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PurchaseOrderLines.def()
+    }
+}
+
+/// Custom behavior for the `ActiveModel` of the `purchase_order` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
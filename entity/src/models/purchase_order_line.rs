@@ -0,0 +1,84 @@
+//! `SeaORM` entity representing the `purchase_order_line` table: a product
+//! ordered by a purchase order, with how much of it was received so far.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Represents the `purchase_order_line` entity in the database. A product
+/// appears once per purchase order.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "purchase_order_line")]
+pub struct Model {
+    /// The purchase order this line belongs to.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub purchase_order_id: Uuid,
+    /// The ordered product.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub product_id: Uuid,
+
+    /// The ordered quantity
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))", filter_plus_order)]
+    pub quantity: Decimal,
+    /// The quantity received so far, never above the ordered one
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))", filter_plus_order)]
+    pub received_quantity: Decimal,
+    /// Price of the product, per unit, liter or meter the product is counted in,
+    /// or per kilogram for a product counted in grams, in the currency of the
+    /// purchase order
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))", filter_plus_order)]
+    pub unit_cost: Decimal,
+
+    /// Timestamp for when the product is expected to be delivered, if known.
+    #[sea_orm(filter_skip)]
+    pub expected_at: Option<DateTimeWithTimeZone>,
+}
+
+/// Enum representing relationships for the `purchase_order_line` entity.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// The purchase order this line belongs to.
+    #[sea_orm(
+        belongs_to = "super::purchase_order::Entity",
+        from = "Column::PurchaseOrderId",
+        to = "super::purchase_order::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    PurchaseOrder,
+
+    /// The ordered product.
+    #[sea_orm(
+        belongs_to = "super::product::Entity",
+        from = "Column::ProductId",
+        to = "super::product::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Product,
+}
+
+impl Related<super::purchase_order::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PurchaseOrder.def()
+    }
+}
+
+impl Related<super::product::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Product.def()
+    }
+}
+
+/// Custom behavior for the `ActiveModel` of the `purchase_order_line` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
@@ -5,7 +5,7 @@
 use sea_orm::{ActiveValue::Set, entity::prelude::*};
 use serde::{Deserialize, Serialize};
 
-use super::sea_orm_active_enums::Currency;
+use super::currency::CurrencyCode;
 
 /// Represents the `refill` entity in the database, detailing refill transactions
 /// including pricing, credit amount, and currency information.
@@ -40,7 +40,7 @@ pub struct Model {
 
     /// Currency type for the refill transaction price.
     #[sea_orm(filter_override = "crate::request::r#enum::CurrencyRequest")]
-    pub price_currency: Currency,
+    pub price_currency: CurrencyCode,
 
     /// Credit amount awarded in this refill transaction, stored as a decimal
     /// with up to 10 digits and 2 decimal places.
@@ -49,7 +49,7 @@ pub struct Model {
 
     /// Currency type for the refill transaction credit.
     #[sea_orm(filter_override = "crate::request::r#enum::CurrencyRequest")]
-    pub credit_currency: Currency,
+    pub credit_currency: CurrencyCode,
 
     /// Indicates if the refill transaction is hidden.
     #[sea_orm(filter_single)]
@@ -61,9 +61,28 @@ pub struct Model {
 }
 
 /// Enum representing relationships for the `refill` entity.
-/// Currently, there are no relationships defined for this entity.
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
-pub enum Relation {}
+pub enum Relation {
+    /// The currency of the price.
+    #[sea_orm(
+        belongs_to = "super::currency::Entity",
+        from = "Column::PriceCurrency",
+        to = "super::currency::Column::Code",
+        on_update = "Cascade",
+        on_delete = "Restrict"
+    )]
+    PriceCurrency,
+
+    /// The currency of the credit.
+    #[sea_orm(
+        belongs_to = "super::currency::Entity",
+        from = "Column::CreditCurrency",
+        to = "super::currency::Column::Code",
+        on_update = "Cascade",
+        on_delete = "Restrict"
+    )]
+    CreditCurrency,
+}
 
 /// Custom behavior for the `ActiveModel` of the `refill` entity.
 /// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
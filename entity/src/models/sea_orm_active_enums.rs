@@ -8,21 +8,6 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
-/// Represents a currency type used in the application.
-/// This enum is serialized and deserialized for compatibility with external
-/// systems and stored in the database as an enumerated type.
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
-#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "currency")]
-pub enum Currency {
-    /// The custom currency "epicoin" used within the system.
-    #[sea_orm(string_value = "epicoin")]
-    Epicoin,
-
-    /// The standard currency "euro."
-    #[sea_orm(string_value = "euro")]
-    Euro,
-}
-
 /// Represents a unit of measurement used in the application.
 ///
 /// This enum defines various standard units, such as grams, liters, meters, and generic units
@@ -61,3 +46,167 @@ pub enum LocationCategory {
     #[sea_orm(string_value = "room")]
     Room,
 }
+
+/// Represents the kind of a transaction recorded in the ledger.
+///
+/// Each variant has a matching detail table (`transaction_refill_balance`,
+/// `transaction_purchase_with_balance`, ...) sharing the transaction id.
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "transaction_type")]
+pub enum TransactionType {
+    /// A refill pack bought to credit the user balance.
+    #[sea_orm(string_value = "refill_balance")]
+    RefillBalance,
+
+    /// A manual edit of the user balance made by an admin.
+    #[sea_orm(string_value = "balance_edit")]
+    BalanceEdit,
+
+    /// A purchase paid with the user balance.
+    #[sea_orm(string_value = "purchase_with_balance")]
+    PurchaseWithBalance,
+
+    /// A purchase paid directly, without going through the balance.
+    #[sea_orm(string_value = "purchase_direct")]
+    PurchaseDirect,
+}
+
+/// Represents the lifecycle state of a transaction.
+///
+/// A transaction starts as `Created`, may wait for an external confirmation
+/// as `Pending`, and ends up either `Completed`, `Failed` or `Canceled`.
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "transaction_status")]
+pub enum TransactionStatus {
+    /// The transaction has been recorded but nothing happened yet.
+    #[sea_orm(string_value = "created")]
+    Created,
+
+    /// The transaction is waiting for an external confirmation.
+    #[sea_orm(string_value = "pending")]
+    Pending,
+
+    /// The transaction went through and its effects are applied.
+    #[sea_orm(string_value = "completed")]
+    Completed,
+
+    /// The transaction couldn't be processed.
+    #[sea_orm(string_value = "failed")]
+    Failed,
+
+    /// The transaction has been canceled.
+    #[sea_orm(string_value = "canceled")]
+    Canceled,
+}
+
+/// Represents where an order to deliver stands.
+///
+/// An order to deliver to a location starts `Queued`, then goes through
+/// `Preparing` and `Ready` until it's `Delivered`, never going back.
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "fulfilment_status")]
+pub enum FulfilmentStatus {
+    /// The order is waiting for someone to take care of it.
+    #[sea_orm(string_value = "queued")]
+    Queued,
+
+    /// The order is being prepared.
+    #[sea_orm(string_value = "preparing")]
+    Preparing,
+
+    /// The order is ready to be delivered.
+    #[sea_orm(string_value = "ready")]
+    Ready,
+
+    /// The order has been delivered to its location.
+    #[sea_orm(string_value = "delivered")]
+    Delivered,
+}
+
+impl FulfilmentStatus {
+    /// Position of the status in the lifecycle of an order, from `Queued` to `Delivered`.
+    pub fn step(&self) -> u8 {
+        match self {
+            Self::Queued => 0,
+            Self::Preparing => 1,
+            Self::Ready => 2,
+            Self::Delivered => 3,
+        }
+    }
+}
+
+/// Represents why the stock of a product in a warehouse changed.
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "stock_movement_reason"
+)]
+pub enum StockMovementReason {
+    /// The product was sold, or put back when its sale was canceled.
+    #[sea_orm(string_value = "purchase")]
+    Purchase,
+
+    /// New products were brought into the warehouse.
+    #[sea_orm(string_value = "restock")]
+    Restock,
+
+    /// The stock was set by hand, after a count or a mistake.
+    #[sea_orm(string_value = "correction")]
+    Correction,
+
+    /// The product was used as an ingredient of a recipe, or put back when its sale was canceled.
+    #[sea_orm(string_value = "recipe_consumption")]
+    RecipeConsumption,
+
+    /// The product was moved from or to another warehouse.
+    #[sea_orm(string_value = "transfer")]
+    Transfer,
+
+    /// The stock was set to the quantity counted during a stocktake.
+    #[sea_orm(string_value = "stocktake")]
+    Stocktake,
+}
+
+/// Represents the lifecycle of a stocktake, a physical count of the stock of a warehouse.
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "stocktake_status")]
+pub enum StocktakeStatus {
+    /// The products are being counted.
+    #[sea_orm(string_value = "open")]
+    Open,
+
+    /// The counted quantities were applied to the stock.
+    #[sea_orm(string_value = "closed")]
+    Closed,
+
+    /// The stocktake was abandoned, the stock is left untouched.
+    #[sea_orm(string_value = "canceled")]
+    Canceled,
+}
+
+/// Represents the lifecycle of a purchase order, from its draft to the receipt of every
+/// ordered product.
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "purchase_order_status"
+)]
+pub enum PurchaseOrderStatus {
+    /// The purchase order is being prepared, its lines can still change.
+    #[sea_orm(string_value = "draft")]
+    Draft,
+
+    /// The purchase order was sent to the supplier, nothing was received yet.
+    #[sea_orm(string_value = "sent")]
+    Sent,
+
+    /// Some of the ordered products were received.
+    #[sea_orm(string_value = "partially_received")]
+    PartiallyReceived,
+
+    /// Every ordered product was received.
+    #[sea_orm(string_value = "received")]
+    Received,
+}
@@ -0,0 +1,100 @@
+//! `SeaORM` entity representing the `stock_movement` table. Every change of
+//! the stock of a product in a warehouse is recorded here, so the stock can
+//! be explained from its history.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::sea_orm_active_enums::StockMovementReason;
+
+/// Represents the `stock_movement` entity in the database: a signed change of
+/// the stock of a product in a warehouse, with who made it and why.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "stock_movement")]
+pub struct Model {
+    /// Unique identifier for the movement. Primary key, non-auto-incrementing.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+
+    /// The warehouse whose stock changed.
+    pub warehouse_id: Uuid,
+
+    /// The product whose stock changed.
+    pub product_id: Uuid,
+
+    /// Signed quantity added to the stock, stored as a decimal with up to
+    /// 10 digits and 2 decimal places.
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))", filter_plus_order)]
+    pub delta: Decimal,
+
+    /// Why the stock changed.
+    #[sea_orm(filter_override = "crate::request::r#enum::StockMovementReasonRequest")]
+    pub reason: StockMovementReason,
+
+    /// The user who made the change, if it still exists.
+    pub author_id: Option<Uuid>,
+
+    /// Timestamp for when the stock changed.
+    #[sea_orm(filter_override = "chrono::DateTime<chrono::Utc>", filter_plus_order)]
+    pub created_at: DateTimeWithTimeZone,
+}
+
+/// Enum representing relationships for the `stock_movement` entity.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// The warehouse whose stock changed.
+    #[sea_orm(
+        belongs_to = "super::warehouse::Entity",
+        from = "Column::WarehouseId",
+        to = "super::warehouse::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Warehouse,
+
+    /// The product whose stock changed.
+    #[sea_orm(
+        belongs_to = "super::product::Entity",
+        from = "Column::ProductId",
+        to = "super::product::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Product,
+
+    /// The user who made the change.
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::AuthorId",
+        to = "super::user::Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    User,
+}
+
+impl Related<super::warehouse::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Warehouse.def()
+    }
+}
+
+impl Related<super::product::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Product.def()
+    }
+}
+
+/// Custom behavior for the `ActiveModel` of the `stock_movement` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
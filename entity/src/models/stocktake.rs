@@ -0,0 +1,83 @@
+//! `SeaORM` entity representing the `stocktake` table. A stocktake is a
+//! physical count of the products of a warehouse, whose counted quantities
+//! replace the stock once it is closed.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::sea_orm_active_enums::StocktakeStatus;
+
+/// Represents the `stocktake` entity in the database: a count session of a
+/// warehouse, with who opened and closed it.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "stocktake")]
+pub struct Model {
+    /// Unique identifier for the stocktake. Primary key, non-auto-incrementing.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+
+    /// The warehouse being counted.
+    pub warehouse_id: Uuid,
+
+    /// Whether the stocktake is still being counted, applied or abandoned.
+    #[sea_orm(filter_override = "crate::request::r#enum::StocktakeStatusRequest")]
+    pub status: StocktakeStatus,
+
+    /// The user who opened the stocktake, if it still exists.
+    pub author_id: Option<Uuid>,
+
+    /// The user who closed or canceled the stocktake, if any.
+    pub closed_by_id: Option<Uuid>,
+
+    /// Timestamp for when the stocktake was opened.
+    #[sea_orm(filter_override = "chrono::DateTime<chrono::Utc>", filter_plus_order)]
+    pub created_at: DateTimeWithTimeZone,
+
+    /// Timestamp for when the stocktake was closed or canceled, if it was.
+    #[sea_orm(filter_skip)]
+    pub closed_at: Option<DateTimeWithTimeZone>,
+}
+
+/// Enum representing relationships for the `stocktake` entity.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// The warehouse being counted.
+    #[sea_orm(
+        belongs_to = "super::warehouse::Entity",
+        from = "Column::WarehouseId",
+        to = "super::warehouse::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Warehouse,
+
+    /// The counted quantities of this stocktake.
+    #[sea_orm(has_many = "super::stocktake_count::Entity")]
+    StocktakeCounts,
+}
+
+impl Related<super::warehouse::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Warehouse.def()
+    }
+}
+
+impl Related<super::stocktake_count::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StocktakeCounts.def()
+    }
+}
+
+/// Custom behavior for the `ActiveModel` of the `stocktake` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,83 @@
+//! `SeaORM` entity representing the `stocktake_count` table: the quantity of
+//! a product counted during a stocktake.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Represents the `stocktake_count` entity in the database. Counting the same
+/// product again replaces the previous count.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "stocktake_count")]
+pub struct Model {
+    /// The stocktake this count belongs to.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub stocktake_id: Uuid,
+    /// The counted product.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub product_id: Uuid,
+
+    /// The counted quantity
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))", filter_plus_order)]
+    pub quantity: Decimal,
+    /// The quantity the warehouse held when the stocktake was closed, unset
+    /// while it is open
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))", nullable, filter_skip)]
+    pub expected_quantity: Option<Decimal>,
+
+    /// The user who last counted the product, if it still exists.
+    pub author_id: Option<Uuid>,
+
+    /// Timestamp for when the product was last counted.
+    #[sea_orm(filter_override = "chrono::DateTime<chrono::Utc>", filter_plus_order)]
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+/// Enum representing relationships for the `stocktake_count` entity.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// The stocktake this count belongs to.
+    #[sea_orm(
+        belongs_to = "super::stocktake::Entity",
+        from = "Column::StocktakeId",
+        to = "super::stocktake::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Stocktake,
+
+    /// The counted product.
+    #[sea_orm(
+        belongs_to = "super::product::Entity",
+        from = "Column::ProductId",
+        to = "super::product::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Product,
+}
+
+impl Related<super::stocktake::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Stocktake.def()
+    }
+}
+
+impl Related<super::product::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Product.def()
+    }
+}
+
+/// Custom behavior for the `ActiveModel` of the `stocktake_count` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
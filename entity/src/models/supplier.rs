@@ -0,0 +1,56 @@
+//! `SeaORM` entity representing the `supplier` table: a business the
+//! products are bought from to restock the warehouses.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Represents the `supplier` entity in the database.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "supplier")]
+pub struct Model {
+    /// Unique identifier for the supplier. Primary key, non-auto-incrementing.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+
+    /// Name of the supplier.
+    pub name: String,
+
+    /// Address the purchase orders are sent to, if known.
+    pub email: Option<String>,
+
+    /// Indicates whether the supplier is disabled, no purchase order can be made to it.
+    #[sea_orm(filter_single)]
+    pub disabled: bool,
+
+    /// Timestamp for when the supplier was created.
+    #[sea_orm(filter_override = "chrono::DateTime<chrono::Utc>", filter_plus_order)]
+    pub created_at: DateTimeWithTimeZone,
+}
+
+/// Enum representing relationships for the `supplier` entity.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// The purchase orders made to this supplier.
+    #[sea_orm(has_many = "super::purchase_order::Entity")]
+    PurchaseOrders,
+}
+
+impl Related<super::purchase_order::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PurchaseOrders.def()
+    }
+}
+
+/// Custom behavior for the `ActiveModel` of the `supplier` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,114 @@
+//! `SeaORM` entity representing the `transaction` table. Every movement on a
+//! user balance is recorded here as a ledger entry, the details depending on
+//! its type being stored in the matching `transaction_*` table.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::sea_orm_active_enums::{TransactionStatus, TransactionType};
+
+/// Represents the `transaction` entity in the database, the common part of
+/// every ledger entry: its owner, its type, its status and its timestamps.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "transaction")]
+pub struct Model {
+    /// Unique identifier for the transaction. Primary key, non-auto-incrementing.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+
+    /// The user whose balance is affected by this transaction.
+    pub user_id: Uuid,
+
+    /// Timestamp indicating when the transaction was created.
+    #[sea_orm(filter_override = "chrono::DateTime<chrono::Utc>", filter_plus_order)]
+    pub created_at: DateTimeWithTimeZone,
+
+    /// Timestamp indicating when the transaction reached a final state.
+    #[sea_orm(filter_skip)]
+    pub completed_at: Option<DateTimeWithTimeZone>,
+
+    /// Current state of the transaction.
+    #[sea_orm(filter_override = "crate::request::r#enum::TransactionStatusRequest")]
+    pub status: TransactionStatus,
+
+    /// Kind of the transaction, telling which detail table holds the rest.
+    #[sea_orm(
+        column_name = "type",
+        filter_override = "crate::request::r#enum::TransactionTypeRequest"
+    )]
+    pub transaction_type: TransactionType,
+}
+
+/// Enum representing relationships for the `transaction` entity.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// The user owning this transaction.
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::UserId",
+        to = "super::user::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    User,
+
+    /// Details of a `RefillBalance` transaction.
+    #[sea_orm(has_one = "super::transaction_refill_balance::Entity")]
+    TransactionRefillBalance,
+
+    /// Details of a `PurchaseWithBalance` transaction.
+    #[sea_orm(has_one = "super::transaction_purchase_with_balance::Entity")]
+    TransactionPurchaseWithBalance,
+
+    /// Details of a `BalanceEdit` transaction.
+    #[sea_orm(has_one = "super::transaction_balance_edit::Entity")]
+    TransactionBalanceEdit,
+
+    /// Refund of a canceled purchase.
+    #[sea_orm(has_one = "super::transaction_refund::Entity")]
+    TransactionRefund,
+}
+
+impl Related<super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl Related<super::transaction_refill_balance::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionRefillBalance.def()
+    }
+}
+
+impl Related<super::transaction_purchase_with_balance::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionPurchaseWithBalance.def()
+    }
+}
+
+impl Related<super::transaction_balance_edit::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionBalanceEdit.def()
+    }
+}
+
+impl Related<super::transaction_refund::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionRefund.def()
+    }
+}
+
+/// Custom behavior for the `ActiveModel` of the `transaction` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,77 @@
+//! `SeaORM` entity representing the `transaction_balance_edit` table. It
+//! holds the details of a `BalanceEdit` transaction: the admin who edited
+//! the balance, the signed amount applied and the reason of the edit.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::currency::CurrencyCode;
+
+/// Represents the `transaction_balance_edit` entity in the database, sharing
+/// its primary key with the `transaction` it details.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "transaction_balance_edit")]
+pub struct Model {
+    /// Identifier of the detailed transaction. Primary key, non-auto-incrementing.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+
+    /// The admin who edited the balance, if it still exists.
+    pub author_id: Option<Uuid>,
+
+    /// Signed amount added to the user balance, stored as a decimal with up to
+    /// 10 digits and 2 decimal places.
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))", filter_plus_order)]
+    pub delta: Decimal,
+
+    /// Currency type for the amount added.
+    #[sea_orm(filter_override = "crate::request::r#enum::CurrencyRequest")]
+    pub delta_currency: CurrencyCode,
+
+    /// Why the balance was edited.
+    pub reason: String,
+}
+
+/// Enum representing relationships for the `transaction_balance_edit` entity.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// The transaction detailed by this entry.
+    #[sea_orm(
+        belongs_to = "super::transaction::Entity",
+        from = "Column::Id",
+        to = "super::transaction::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Transaction,
+
+    /// The admin who edited the balance.
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::AuthorId",
+        to = "super::user::Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    User,
+}
+
+impl Related<super::transaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Transaction.def()
+    }
+}
+
+/// Custom behavior for the `ActiveModel` of the `transaction_balance_edit` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
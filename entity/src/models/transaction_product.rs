@@ -0,0 +1,129 @@
+//! `SeaORM` entity representing the `transaction_product` table. Each row is a
+//! line of a purchase, keeping a snapshot of the product name and price at
+//! the time it was bought.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::currency::CurrencyCode;
+
+/// Represents the `transaction_product` entity in the database, a single line
+/// of a `PurchaseWithBalance` transaction.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "transaction_product")]
+pub struct Model {
+    /// Unique identifier for the line. Primary key, non-auto-incrementing.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+
+    /// The purchase this line belongs to.
+    pub transaction_id: Uuid,
+
+    /// The product bought, if it still exists.
+    pub product_id: Option<Uuid>,
+
+    /// Name of the product when it was bought.
+    pub name: String,
+
+    /// Number of products bought on this line.
+    #[sea_orm(filter_plus_order)]
+    pub quantity: i32,
+
+    /// Unit price of the product when it was bought, stored as a decimal with
+    /// up to 10 digits and 2 decimal places.
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))", filter_plus_order)]
+    pub price: Decimal,
+
+    /// Currency type for the unit price.
+    #[sea_orm(filter_override = "crate::request::r#enum::CurrencyRequest")]
+    pub price_currency: CurrencyCode,
+
+    /// The warehouse the products were taken from, if any.
+    pub warehouse_id: Option<Uuid>,
+
+    /// The recipe used to make the products in the warehouse, if they weren't
+    /// taken from its stock but made from its ingredients.
+    pub recipe_id: Option<Uuid>,
+}
+
+/// Enum representing relationships for the `transaction_product` entity.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// The purchase this line belongs to.
+    #[sea_orm(
+        belongs_to = "super::transaction_purchase_with_balance::Entity",
+        from = "Column::TransactionId",
+        to = "super::transaction_purchase_with_balance::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    TransactionPurchaseWithBalance,
+
+    /// The product bought.
+    #[sea_orm(
+        belongs_to = "super::product::Entity",
+        from = "Column::ProductId",
+        to = "super::product::Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    Product,
+
+    /// The warehouse the products were taken from.
+    #[sea_orm(
+        belongs_to = "super::warehouse::Entity",
+        from = "Column::WarehouseId",
+        to = "super::warehouse::Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    Warehouse,
+
+    /// The recipe used to make the products.
+    #[sea_orm(
+        belongs_to = "super::recipe::Entity",
+        from = "Column::RecipeId",
+        to = "super::recipe::Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    Recipe,
+}
+
+impl Related<super::transaction_purchase_with_balance::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionPurchaseWithBalance.def()
+    }
+}
+
+impl Related<super::product::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Product.def()
+    }
+}
+
+impl Related<super::warehouse::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Warehouse.def()
+    }
+}
+
+impl Related<super::recipe::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Recipe.def()
+    }
+}
+
+/// Custom behavior for the `ActiveModel` of the `transaction_product` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
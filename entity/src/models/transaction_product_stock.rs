@@ -0,0 +1,89 @@
+//! `SeaORM` entity representing the `transaction_product_stock` table. Each
+//! row is a quantity of a product taken from the stock for a line of a
+//! purchase, from a batch or not, so a canceled purchase puts back exactly
+//! what it took.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Represents the `transaction_product_stock` entity in the database: how much
+/// of a product was taken from the warehouse of a purchase line, the product
+/// itself or one of the ingredients it was made from.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "transaction_product_stock")]
+pub struct Model {
+    /// Unique identifier for the row. Primary key, non-auto-incrementing.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+
+    /// The purchase line the stock was taken for.
+    pub transaction_product_id: Uuid,
+
+    /// The product taken from the stock.
+    pub product_id: Uuid,
+
+    /// Quantity taken from the stock, stored as a decimal with up to 10
+    /// digits and 2 decimal places.
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
+    pub quantity: Decimal,
+
+    /// Timestamp after which the batch the quantity was taken from can't be
+    /// used anymore, if it expires.
+    #[sea_orm(filter_skip)]
+    pub expires_at: Option<DateTimeWithTimeZone>,
+
+    /// Timestamp for when the batch the quantity was taken from was received,
+    /// `None` when it wasn't taken from a batch.
+    #[sea_orm(filter_skip)]
+    pub received_at: Option<DateTimeWithTimeZone>,
+}
+
+/// Enum representing relationships for the `transaction_product_stock` entity.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// The purchase line the stock was taken for.
+    #[sea_orm(
+        belongs_to = "super::transaction_product::Entity",
+        from = "Column::TransactionProductId",
+        to = "super::transaction_product::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    TransactionProduct,
+
+    /// The product taken from the stock.
+    #[sea_orm(
+        belongs_to = "super::product::Entity",
+        from = "Column::ProductId",
+        to = "super::product::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Product,
+}
+
+impl Related<super::transaction_product::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionProduct.def()
+    }
+}
+
+impl Related<super::product::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Product.def()
+    }
+}
+
+/// Custom behavior for the `ActiveModel` of the `transaction_product_stock` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,93 @@
+//! `SeaORM` entity representing the `transaction_purchase_with_balance` table.
+//! It holds the details of a `PurchaseWithBalance` transaction.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{currency::CurrencyCode, sea_orm_active_enums::FulfilmentStatus};
+
+/// Represents the `transaction_purchase_with_balance` entity in the database,
+/// sharing its primary key with the `transaction` it details.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "transaction_purchase_with_balance")]
+pub struct Model {
+    /// Identifier of the detailed transaction. Primary key, non-auto-incrementing.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+
+    /// Total price paid for the purchase, stored as a decimal with up to 10
+    /// digits and 2 decimal places.
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))", filter_plus_order)]
+    pub price_total: Decimal,
+
+    /// Currency type for the total price paid.
+    #[sea_orm(filter_override = "crate::request::r#enum::CurrencyRequest")]
+    pub price_total_currency: CurrencyCode,
+
+    /// The location the purchase must be delivered to, if any.
+    pub location_id: Option<Uuid>,
+
+    /// Where the delivery stands, only set when the purchase has a location.
+    #[sea_orm(filter_override = "crate::request::r#enum::FulfilmentStatusRequest")]
+    pub fulfilment_status: Option<FulfilmentStatus>,
+}
+
+/// Enum representing relationships for the `transaction_purchase_with_balance` entity.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// The transaction detailed by this entry.
+    #[sea_orm(
+        belongs_to = "super::transaction::Entity",
+        from = "Column::Id",
+        to = "super::transaction::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Transaction,
+
+    /// The products bought with this purchase.
+    #[sea_orm(has_many = "super::transaction_product::Entity")]
+    TransactionProduct,
+
+    /// The location the purchase must be delivered to.
+    #[sea_orm(
+        belongs_to = "super::location::Entity",
+        from = "Column::LocationId",
+        to = "super::location::Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    Location,
+}
+
+impl Related<super::transaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Transaction.def()
+    }
+}
+
+impl Related<super::transaction_product::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TransactionProduct.def()
+    }
+}
+
+impl Related<super::location::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Location.def()
+    }
+}
+
+/// Custom behavior for the `ActiveModel` of the `transaction_purchase_with_balance` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,92 @@
+//! `SeaORM` entity representing the `transaction_refill_balance` table. It
+//! holds the details of a `RefillBalance` transaction: the refill bought and
+//! a snapshot of its price and credit at the time of the purchase.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::currency::CurrencyCode;
+
+/// Represents the `transaction_refill_balance` entity in the database, sharing
+/// its primary key with the `transaction` it details.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "transaction_refill_balance")]
+pub struct Model {
+    /// Identifier of the detailed transaction. Primary key, non-auto-incrementing.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+
+    /// The refill bought, if it still exists.
+    pub refill_id: Option<Uuid>,
+
+    /// Price paid for the refill, stored as a decimal with up to 10 digits
+    /// and 2 decimal places.
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))", filter_plus_order)]
+    pub price: Decimal,
+
+    /// Currency type for the price paid.
+    #[sea_orm(filter_override = "crate::request::r#enum::CurrencyRequest")]
+    pub price_currency: CurrencyCode,
+
+    /// Credit added to the user balance, stored as a decimal with up to 10
+    /// digits and 2 decimal places.
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))", filter_plus_order)]
+    pub credit: Decimal,
+
+    /// Currency type for the credit added.
+    #[sea_orm(filter_override = "crate::request::r#enum::CurrencyRequest")]
+    pub credit_currency: CurrencyCode,
+
+    /// Identifier of the payment on the Stripe side, if any.
+    pub stripe_transaction_id: Option<String>,
+}
+
+/// Enum representing relationships for the `transaction_refill_balance` entity.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// The transaction detailed by this entry.
+    #[sea_orm(
+        belongs_to = "super::transaction::Entity",
+        from = "Column::Id",
+        to = "super::transaction::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Transaction,
+
+    /// The refill bought with this transaction.
+    #[sea_orm(
+        belongs_to = "super::refill::Entity",
+        from = "Column::RefillId",
+        to = "super::refill::Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    Refill,
+}
+
+impl Related<super::transaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Transaction.def()
+    }
+}
+
+impl Related<super::refill::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Refill.def()
+    }
+}
+
+/// Custom behavior for the `ActiveModel` of the `transaction_refill_balance` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
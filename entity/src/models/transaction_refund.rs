@@ -0,0 +1,81 @@
+//! `SeaORM` entity representing the `transaction_refund` table. It records
+//! the cancellation of a purchase: who canceled it, the amount credited back
+//! to the balance and why.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::currency::CurrencyCode;
+
+/// Represents the `transaction_refund` entity in the database, sharing its
+/// primary key with the canceled `transaction`, which can only be refunded once.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "transaction_refund")]
+pub struct Model {
+    /// Identifier of the canceled transaction. Primary key, non-auto-incrementing.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+
+    /// The user who canceled the purchase, if it still exists.
+    pub author_id: Option<Uuid>,
+
+    /// Amount credited back to the user balance, stored as a decimal with up
+    /// to 10 digits and 2 decimal places.
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))", filter_plus_order)]
+    pub amount: Decimal,
+
+    /// Currency type for the amount credited back.
+    #[sea_orm(filter_override = "crate::request::r#enum::CurrencyRequest")]
+    pub amount_currency: CurrencyCode,
+
+    /// Why the purchase was canceled.
+    pub reason: String,
+
+    /// Timestamp for when the purchase was canceled.
+    #[sea_orm(filter_override = "chrono::DateTime<chrono::Utc>", filter_plus_order)]
+    pub created_at: DateTimeWithTimeZone,
+}
+
+/// Enum representing relationships for the `transaction_refund` entity.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// The canceled transaction.
+    #[sea_orm(
+        belongs_to = "super::transaction::Entity",
+        from = "Column::Id",
+        to = "super::transaction::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Transaction,
+
+    /// The user who canceled the purchase.
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::AuthorId",
+        to = "super::user::Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    User,
+}
+
+impl Related<super::transaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Transaction.def()
+    }
+}
+
+/// Custom behavior for the `ActiveModel` of the `transaction_refund` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
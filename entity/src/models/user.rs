@@ -6,7 +6,7 @@
 //!
 //! `SeaORM` Entity. Generated by sea-orm-codegen 0.12.15
 
-use super::sea_orm_active_enums::Currency;
+use super::currency::CurrencyCode;
 use sea_orm::{ActiveValue::Set, entity::prelude::*};
 use serde::{Deserialize, Serialize};
 
@@ -45,7 +45,7 @@ pub struct Model {
 
     /// Currency type of the user's balance.
     #[sea_orm(filter_override = "crate::request::r#enum::CurrencyRequest")]
-    pub balance_currency: Currency,
+    pub balance_currency: CurrencyCode,
 
     /// Indicates if the user has administrative privileges.
     #[sea_orm(filter_single)]
@@ -65,11 +65,24 @@ pub struct Model {
 }
 
 /// Enum defining the relationships associated with the `User` entity.
-///
-/// This enum is currently empty but can be extended to define relationships with
-/// other entities, such as orders or profiles.
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
-pub enum Relation {}
+pub enum Relation {
+    /// The currency of the balance.
+    #[sea_orm(
+        belongs_to = "super::currency::Entity",
+        from = "Column::BalanceCurrency",
+        to = "super::currency::Column::Code",
+        on_update = "Cascade",
+        on_delete = "Restrict"
+    )]
+    Currency,
+}
+
+impl Related<super::currency::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Currency.def()
+    }
+}
 
 /// Custom behavior for the `User` ActiveModel.
 ///
@@ -43,6 +43,10 @@ pub enum Relation {
     /// Represent the recipes of this warehouse
     #[sea_orm(has_many = "super::warehouse_recipe::Entity")]
     WarehouseRecipes,
+
+    /// Represent the locations served by this warehouse
+    #[sea_orm(has_many = "super::location_warehouse::Entity")]
+    LocationWarehouses,
 }
 
 impl Related<super::warehouse_product::Entity> for Entity {
@@ -57,6 +61,12 @@ impl Related<super::warehouse_recipe::Entity> for Entity {
     }
 }
 
+impl Related<super::location_warehouse::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LocationWarehouses.def()
+    }
+}
+
 impl Related<super::product::Entity> for Entity {
     fn to() -> RelationDef {
         super::warehouse_product::Relation::Product.def()
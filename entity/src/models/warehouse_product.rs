@@ -28,6 +28,12 @@ pub struct Model {
     /// The quantity of this product in this warehouse
     #[sea_orm(column_type = "Decimal(Some((10, 2)))")]
     pub quantity: Decimal,
+    /// The quantity at or below which the product is running low, if any
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))", nullable, filter_skip)]
+    pub min_quantity: Option<Decimal>,
+    /// The quantity to bring back when the product is running low, if any
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))", nullable, filter_skip)]
+    pub reorder_quantity: Option<Decimal>,
 
     /// Timestamp for when the link was created.
     #[sea_orm(filter_override = "chrono::DateTime<chrono::Utc>", filter_plus_order)]
@@ -57,6 +63,10 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Warehouse,
+
+    /// Represent the batches the stock is tracked in
+    #[sea_orm(has_many = "super::warehouse_product_batch::Entity")]
+    WarehouseProductBatches,
 }
 
 impl Related<super::product::Entity> for Entity {
@@ -71,4 +81,10 @@ impl Related<super::warehouse::Entity> for Entity {
     }
 }
 
+impl Related<super::warehouse_product_batch::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WarehouseProductBatches.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,68 @@
+//! `SeaORM` entity representing the `warehouse_product_batch` table: a part of
+//! the stock of a product in a warehouse, received at once and expiring at
+//! the same date.
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Represents the `warehouse_product_batch` entity in the database. The
+/// batches of a product never hold more than its stock, the rest of the stock
+/// isn't tracked in batches.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    Eq,
+    Serialize,
+    Deserialize,
+    proc::DeriveToFilterQuery,
+    proc::DeriveToSortQuery,
+)]
+#[sea_orm(table_name = "warehouse_product_batch")]
+pub struct Model {
+    /// Unique identifier for the batch. Primary key, non-auto-incrementing.
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+
+    /// The warehouse holding the batch.
+    pub warehouse_id: Uuid,
+    /// The product of the batch.
+    pub product_id: Uuid,
+
+    /// The quantity left in the batch
+    #[sea_orm(column_type = "Decimal(Some((10, 2)))", filter_plus_order)]
+    pub quantity: Decimal,
+
+    /// Timestamp after which the batch can't be used anymore, if it expires.
+    #[sea_orm(filter_skip)]
+    pub expires_at: Option<DateTimeWithTimeZone>,
+
+    /// Timestamp for when the batch was received, the oldest batch is consumed first.
+    #[sea_orm(filter_override = "chrono::DateTime<chrono::Utc>", filter_plus_order)]
+    pub received_at: DateTimeWithTimeZone,
+}
+
+/// Enum representing relationships for the `warehouse_product_batch` entity.
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// The stock this batch is part of.
+    #[sea_orm(
+        belongs_to = "super::warehouse_product::Entity",
+        from = "(Column::WarehouseId, Column::ProductId)",
+        to = "(super::warehouse_product::Column::WarehouseId, super::warehouse_product::Column::ProductId)",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    WarehouseProduct,
+}
+
+impl Related<super::warehouse_product::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WarehouseProduct.def()
+    }
+}
+
+/// Custom behavior for the `ActiveModel` of the `warehouse_product_batch` entity.
+/// By default, SeaORM provides basic behavior, so no custom behavior is defined here.
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,140 @@
+//! # Category Request Models and Error Handling
+//! This module defines the structures used to create the categories the products are grouped
+//! in, to nest them inside each other, and to edit them.
+
+use crate::{error::impl_bad_request_app_error, models::category};
+use sea_orm::ActiveValue::{NotSet, Set};
+
+/// The maximum allowed length for a category name.
+pub const CATEGORY_NAME_MAX_LENGTH: usize = 64;
+
+/// Enum representing potential errors in the category request validation process.
+#[derive(Debug, PartialEq, Clone, strum_macros::IntoStaticStr)]
+pub enum CategoryRequestError {
+    /// Error when the category name is empty.
+    NameCannotBeEmpty,
+    /// Error when the category name exceeds the allowed maximum length.
+    NameCannotBeLongerThan(String, usize),
+    /// Error when the parent category doesn't exist.
+    ParentDoesntExist(uuid::Uuid),
+    /// Error when the parent category is the category itself or one of its descendants.
+    ParentCreatesCycle(uuid::Uuid),
+    /// Error when a category still containing other categories is deleted.
+    CategoryHasChildren(uuid::Uuid),
+}
+
+impl std::error::Error for CategoryRequestError {}
+
+/// Formats error messages for the `CategoryRequestError` enum.
+impl std::fmt::Display for CategoryRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NameCannotBeEmpty => write!(f, "Name cannot be empty"),
+            Self::NameCannotBeLongerThan(name, max) => {
+                write!(f, "Name \"{name}\" is longer than {max} characters")
+            }
+            Self::ParentDoesntExist(id) => {
+                write!(f, "Parent category with id \"{id}\" doesn't exist")
+            }
+            Self::ParentCreatesCycle(id) => write!(
+                f,
+                "Category \"{id}\" cannot be nested inside itself or one of its subcategories"
+            ),
+            Self::CategoryHasChildren(id) => {
+                write!(f, "Category \"{id}\" still contains subcategories")
+            }
+        }
+    }
+}
+impl_bad_request_app_error!(CategoryRequestError);
+
+/// Checks a category name is neither empty nor too long.
+fn validate_name(name: String) -> Result<String, CategoryRequestError> {
+    if name.is_empty() {
+        return Err(CategoryRequestError::NameCannotBeEmpty);
+    }
+    if name.len() > CATEGORY_NAME_MAX_LENGTH {
+        return Err(CategoryRequestError::NameCannotBeLongerThan(
+            name,
+            CATEGORY_NAME_MAX_LENGTH,
+        ));
+    }
+    Ok(name)
+}
+
+/// Request structure for creating a new category.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "name": "Sodas",
+    "code": "SODA",
+    "parent": "5c3e1a2b-7d4f-4e8a-9b1c-2d3e4f5a6b7c",
+}))]
+pub struct NewCategoryRequest {
+    /// Name of the category, required and validated for length.
+    pub name: String,
+
+    /// Optional unique short code of the category.
+    pub code: Option<String>,
+
+    /// Id of the category this one is nested in, top level if not provided.
+    pub parent: Option<uuid::Uuid>,
+}
+
+/// Converts `NewCategoryRequest` into `ActiveModel` with validation.
+/// The parent is checked by the handler, as it needs the database.
+impl TryFrom<NewCategoryRequest> for category::ActiveModel {
+    type Error = CategoryRequestError;
+
+    fn try_from(value: NewCategoryRequest) -> Result<Self, Self::Error> {
+        Ok(category::ActiveModel {
+            id: Set(uuid::Uuid::new_v4()),
+            name: Set(validate_name(value.name)?),
+            code: Set(value.code),
+            parent_id: Set(value.parent),
+            created_at: Set(chrono::offset::Local::now().into()),
+            sma_id: Set(None),
+        })
+    }
+}
+
+/// Structure representing a request to edit an existing category.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "name": "Soft drinks",
+    "parent": "5c3e1a2b-7d4f-4e8a-9b1c-2d3e4f5a6b7c",
+}))]
+pub struct EditCategoryRequest {
+    /// New name for the category.
+    pub name: Option<String>,
+
+    /// New code of the category, can be `None` to remove it.
+    pub code: Option<Option<String>>,
+
+    /// New parent of the category, can be `None` to move it to the top level.
+    pub parent: Option<Option<uuid::Uuid>>,
+}
+
+/// Converts `EditCategoryRequest` into `ActiveModel` with validation.
+/// Only fields present in the request are updated.
+impl TryFrom<EditCategoryRequest> for category::ActiveModel {
+    type Error = CategoryRequestError;
+
+    fn try_from(value: EditCategoryRequest) -> Result<Self, Self::Error> {
+        Ok(category::ActiveModel {
+            id: NotSet,
+            name: match value.name {
+                Some(name) => Set(validate_name(name)?),
+                None => NotSet,
+            },
+            code: match value.code {
+                Some(code) => Set(code),
+                None => NotSet,
+            },
+            parent_id: match value.parent {
+                Some(parent) => Set(parent),
+                None => NotSet,
+            },
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,198 @@
+//! # Currency Request Models and Error Handling
+//! This module defines the structures and associated logic for handling the requests
+//! managing the currency registry, and the error returned when a request refers to a
+//! currency which isn't registered.
+
+use crate::{error::impl_bad_request_app_error, models::currency::ActiveModel};
+use sea_orm::ActiveValue::{NotSet, Set};
+
+/// The maximum allowed length for a currency code, as stored in the database.
+pub const CURRENCY_CODE_MAX_LENGTH: usize = 16;
+
+/// The maximum allowed length for a currency symbol.
+pub const CURRENCY_SYMBOL_MAX_LENGTH: usize = 8;
+
+/// The maximum number of decimal places of a currency, every amount being
+/// stored with 2 decimal places.
+pub const CURRENCY_DECIMAL_PLACES_MAX: u8 = 2;
+
+/// Enum representing potential errors in the currency request validation process.
+#[derive(Debug, PartialEq, Clone, strum_macros::IntoStaticStr)]
+pub enum CurrencyRequestError {
+    /// Error when the code is empty.
+    CodeCannotBeEmpty,
+    /// Error when the code exceeds the allowed maximum length.
+    CodeCannotBeLongerThan(String, usize),
+    /// Error when the code isn't only made of lowercase letters, digits and underscores.
+    CodeIsInvalid(String),
+    /// Error when the symbol is empty.
+    SymbolCannotBeEmpty,
+    /// Error when the symbol exceeds the allowed maximum length.
+    SymbolCannotBeLongerThan(String, usize),
+    /// Error when the number of decimal places is above the maximum.
+    DecimalPlacesCannotBeAbove(u8, u8),
+    /// Error when a currency with this code is already registered.
+    CurrencyAlreadyExist(String),
+    /// Error when the currency isn't registered.
+    CurrencyDoesntExist(String),
+    /// Error when the currency is still used and cannot be deleted.
+    CurrencyIsInUse(String),
+}
+
+impl std::error::Error for CurrencyRequestError {}
+
+/// Formats error messages for the `CurrencyRequestError` enum.
+impl std::fmt::Display for CurrencyRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CodeCannotBeEmpty => write!(f, "Code cannot be empty"),
+            Self::CodeCannotBeLongerThan(code, max) => {
+                write!(f, "Code \"{code}\" is longer than {max} characters")
+            }
+            Self::CodeIsInvalid(code) => write!(
+                f,
+                "Code \"{code}\" can only contain lowercase letters, digits and underscores"
+            ),
+            Self::SymbolCannotBeEmpty => write!(f, "Symbol cannot be empty"),
+            Self::SymbolCannotBeLongerThan(symbol, max) => {
+                write!(f, "Symbol \"{symbol}\" is longer than {max} characters")
+            }
+            Self::DecimalPlacesCannotBeAbove(decimal_places, max) => {
+                write!(
+                    f,
+                    "Decimal places \"{decimal_places}\" cannot be above {max}"
+                )
+            }
+            Self::CurrencyAlreadyExist(code) => {
+                write!(f, "Currency \"{code}\" already exist")
+            }
+            Self::CurrencyDoesntExist(code) => write!(f, "Currency \"{code}\" doesn't exist"),
+            Self::CurrencyIsInUse(code) => {
+                write!(f, "Currency \"{code}\" is still in use")
+            }
+        }
+    }
+}
+impl_bad_request_app_error!(CurrencyRequestError);
+
+/// Validates a currency symbol.
+fn validate_symbol(symbol: String) -> Result<String, CurrencyRequestError> {
+    let symbol = symbol.trim().to_string();
+    if symbol.is_empty() {
+        return Err(CurrencyRequestError::SymbolCannotBeEmpty);
+    }
+    if symbol.chars().count() > CURRENCY_SYMBOL_MAX_LENGTH {
+        return Err(CurrencyRequestError::SymbolCannotBeLongerThan(
+            symbol,
+            CURRENCY_SYMBOL_MAX_LENGTH,
+        ));
+    }
+    Ok(symbol)
+}
+
+/// Validates a number of decimal places.
+fn validate_decimal_places(decimal_places: u8) -> Result<i16, CurrencyRequestError> {
+    if decimal_places > CURRENCY_DECIMAL_PLACES_MAX {
+        return Err(CurrencyRequestError::DecimalPlacesCannotBeAbove(
+            decimal_places,
+            CURRENCY_DECIMAL_PLACES_MAX,
+        ));
+    }
+    Ok(decimal_places.into())
+}
+
+/// Request structure for registering a new currency.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "code": "euro",
+    "symbol": "€",
+    "decimal_places": 2,
+    "is_internal": false
+}))]
+pub struct NewCurrencyRequest {
+    /// Unique code of the currency, used to refer to it everywhere else.
+    pub code: String,
+
+    /// Symbol displayed next to an amount in this currency.
+    pub symbol: String,
+
+    /// Number of decimal places of an amount in this currency, 2 if not provided.
+    pub decimal_places: Option<u8>,
+
+    /// If the currency only exists inside the application, false if not provided.
+    pub is_internal: Option<bool>,
+}
+
+/// Converts `NewCurrencyRequest` into `ActiveModel` with validation.
+/// Errors are returned if validation fails.
+impl TryFrom<NewCurrencyRequest> for ActiveModel {
+    type Error = CurrencyRequestError;
+
+    fn try_from(value: NewCurrencyRequest) -> Result<Self, Self::Error> {
+        let code = value.code;
+        if code.is_empty() {
+            return Err(Self::Error::CodeCannotBeEmpty);
+        }
+        if code.len() > CURRENCY_CODE_MAX_LENGTH {
+            return Err(Self::Error::CodeCannotBeLongerThan(
+                code,
+                CURRENCY_CODE_MAX_LENGTH,
+            ));
+        }
+        if !code
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err(Self::Error::CodeIsInvalid(code));
+        }
+
+        Ok(ActiveModel {
+            code: Set(code),
+            symbol: Set(validate_symbol(value.symbol)?),
+            decimal_places: Set(validate_decimal_places(
+                value.decimal_places.unwrap_or(CURRENCY_DECIMAL_PLACES_MAX),
+            )?),
+            is_internal: Set(value.is_internal.unwrap_or(false)),
+            created_at: Set(chrono::offset::Local::now().into()),
+        })
+    }
+}
+
+/// Structure representing a request to edit a registered currency.
+/// Its code cannot be changed.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+pub struct EditCurrencyRequest {
+    /// Optional new symbol.
+    pub symbol: Option<String>,
+
+    /// Optional new number of decimal places.
+    pub decimal_places: Option<u8>,
+
+    /// Optional field to make the currency internal or not.
+    pub is_internal: Option<bool>,
+}
+
+/// Converts `EditCurrencyRequest` into `ActiveModel` with validation.
+/// Only fields present in the request are updated.
+impl TryFrom<EditCurrencyRequest> for ActiveModel {
+    type Error = CurrencyRequestError;
+
+    fn try_from(value: EditCurrencyRequest) -> Result<Self, Self::Error> {
+        Ok(ActiveModel {
+            code: NotSet,
+            symbol: match value.symbol {
+                Some(symbol) => Set(validate_symbol(symbol)?),
+                None => NotSet,
+            },
+            decimal_places: match value.decimal_places {
+                Some(decimal_places) => Set(validate_decimal_places(decimal_places)?),
+                None => NotSet,
+            },
+            is_internal: match value.is_internal {
+                Some(is_internal) => Set(is_internal),
+                None => NotSet,
+            },
+            created_at: NotSet,
+        })
+    }
+}
@@ -1,30 +1,37 @@
 //! This module defines the request format for currency types in the API,
 //! facilitating serialization and deserialization to/from lowercase strings.
 
-use crate::models::sea_orm_active_enums::{Currency, Unit};
+use crate::models::{
+    currency::CurrencyCode,
+    sea_orm_active_enums::{
+        FulfilmentStatus, PurchaseOrderStatus, StockMovementReason, StocktakeStatus,
+        TransactionStatus, TransactionType, Unit,
+    },
+};
 
-/// Represents the request format for currency types in the API,
-/// enabling serialization and deserialization to/from lowercase strings.
-#[derive(Debug, PartialEq, Clone, Copy, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
-#[serde(rename_all = "lowercase")]
-pub enum CurrencyRequest {
-    /// Represents the Euro currency.
-    Euro,
+/// Represents the request format for currencies in the API: the code of a
+/// currency of the registry (e.g. `euro`, `epicoin`).
+///
+/// The code is only checked against the registry once the request reaches
+/// its handler, see `CurrencyRequestError::CurrencyDoesntExist`.
+#[derive(Debug, PartialEq, Eq, Clone, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+#[serde(transparent)]
+#[schema(value_type = String, example = "euro")]
+pub struct CurrencyRequest(pub String);
 
-    /// Represents the Epicoin currency.
-    Epicoin,
+impl std::fmt::Display for CurrencyRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
 }
 
-impl From<CurrencyRequest> for Currency {
+impl From<CurrencyRequest> for CurrencyCode {
     fn from(value: CurrencyRequest) -> Self {
-        match value {
-            CurrencyRequest::Euro => Self::Euro,
-            CurrencyRequest::Epicoin => Self::Epicoin,
-        }
+        Self(value.0)
     }
 }
 
-impl From<CurrencyRequest> for Option<Currency> {
+impl From<CurrencyRequest> for Option<CurrencyCode> {
     fn from(value: CurrencyRequest) -> Self {
         Some(value.into())
     }
@@ -54,3 +61,246 @@ impl From<UnitRequest> for Unit {
         }
     }
 }
+
+/// Represents the unit a quantity is expressed in, the units a product can be counted in along
+/// with their usual multiples. Each one can also be written with its symbol, such as `cl`.
+#[derive(
+    Debug, PartialEq, Eq, Clone, Copy, serde::Deserialize, serde::Serialize, utoipa::ToSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum QuantityUnitRequest {
+    /// Represents a single unit or piece (e.g., an item).
+    #[serde(alias = "pc")]
+    Unit,
+    /// Represents weight in grams.
+    #[serde(alias = "g")]
+    Gram,
+    /// Represents weight in kilograms, a thousand grams.
+    #[serde(alias = "kg")]
+    Kilogram,
+    /// Represents volume in milliliters, a thousandth of a liter.
+    #[serde(alias = "ml")]
+    Milliliter,
+    /// Represents volume in centiliters, a hundredth of a liter.
+    #[serde(alias = "cl")]
+    Centiliter,
+    /// Represents volume in liters.
+    #[serde(alias = "l")]
+    Liter,
+    /// Represents length in centimeters, a hundredth of a meter.
+    #[serde(alias = "cm")]
+    Centimeter,
+    /// Represents length in meters.
+    #[serde(alias = "m")]
+    Meter,
+}
+
+impl QuantityUnitRequest {
+    /// Expresses `quantity` in the unit products are counted in for its dimension: grams,
+    /// liters, meters or units.
+    pub fn to_base(self, quantity: rust_decimal::Decimal) -> (Unit, rust_decimal::Decimal) {
+        let (unit, scale) = match self {
+            Self::Unit => (Unit::Unit, rust_decimal::Decimal::ONE),
+            Self::Gram => (Unit::Gram, rust_decimal::Decimal::ONE),
+            Self::Kilogram => (Unit::Gram, rust_decimal::Decimal::ONE_THOUSAND),
+            Self::Milliliter => (Unit::Liter, rust_decimal::Decimal::new(1, 3)),
+            Self::Centiliter => (Unit::Liter, rust_decimal::Decimal::new(1, 2)),
+            Self::Liter => (Unit::Liter, rust_decimal::Decimal::ONE),
+            Self::Centimeter => (Unit::Meter, rust_decimal::Decimal::new(1, 2)),
+            Self::Meter => (Unit::Meter, rust_decimal::Decimal::ONE),
+        };
+        (unit, quantity * scale)
+    }
+}
+
+/// Parses a unit from its name or its symbol, such as `kilogram` or `kg`, whatever its case.
+impl std::str::FromStr for QuantityUnitRequest {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_value(serde_json::Value::String(s.trim().to_lowercase()))
+    }
+}
+
+/// Represents a quantity along with the unit it is expressed in, such as `33` `cl`.
+#[derive(
+    Debug, PartialEq, Eq, Clone, Copy, serde::Deserialize, serde::Serialize, utoipa::ToSchema,
+)]
+#[schema(example = json!({ "quantity": 33, "unit": "cl" }))]
+pub struct QuantityRequest {
+    /// The quantity, in `unit`.
+    pub quantity: rust_decimal::Decimal,
+
+    /// The unit of the quantity.
+    pub unit: QuantityUnitRequest,
+}
+
+impl QuantityRequest {
+    /// Expresses the quantity in the unit products are counted in for its dimension, see
+    /// [`QuantityUnitRequest::to_base`].
+    pub fn to_base(self) -> (Unit, rust_decimal::Decimal) {
+        self.unit.to_base(self.quantity)
+    }
+}
+
+/// Represents the request format for transaction types in the API
+#[derive(Debug, PartialEq, Clone, Copy, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionTypeRequest {
+    /// A refill pack bought to credit the user balance.
+    RefillBalance,
+    /// A manual edit of the user balance made by an admin.
+    BalanceEdit,
+    /// A purchase paid with the user balance.
+    PurchaseWithBalance,
+    /// A purchase paid directly, without going through the balance.
+    PurchaseDirect,
+}
+
+impl From<TransactionTypeRequest> for TransactionType {
+    fn from(value: TransactionTypeRequest) -> Self {
+        match value {
+            TransactionTypeRequest::RefillBalance => Self::RefillBalance,
+            TransactionTypeRequest::BalanceEdit => Self::BalanceEdit,
+            TransactionTypeRequest::PurchaseWithBalance => Self::PurchaseWithBalance,
+            TransactionTypeRequest::PurchaseDirect => Self::PurchaseDirect,
+        }
+    }
+}
+
+/// Represents the request format for transaction status in the API
+#[derive(Debug, PartialEq, Clone, Copy, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionStatusRequest {
+    /// The transaction has been recorded but nothing happened yet.
+    Created,
+    /// The transaction is waiting for an external confirmation.
+    Pending,
+    /// The transaction went through and its effects are applied.
+    Completed,
+    /// The transaction couldn't be processed.
+    Failed,
+    /// The transaction has been canceled.
+    Canceled,
+}
+
+impl From<TransactionStatusRequest> for TransactionStatus {
+    fn from(value: TransactionStatusRequest) -> Self {
+        match value {
+            TransactionStatusRequest::Created => Self::Created,
+            TransactionStatusRequest::Pending => Self::Pending,
+            TransactionStatusRequest::Completed => Self::Completed,
+            TransactionStatusRequest::Failed => Self::Failed,
+            TransactionStatusRequest::Canceled => Self::Canceled,
+        }
+    }
+}
+
+/// Represents the request format for the fulfilment status of an order in the API
+#[derive(Debug, PartialEq, Clone, Copy, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum FulfilmentStatusRequest {
+    /// The order is waiting for someone to take care of it.
+    Queued,
+    /// The order is being prepared.
+    Preparing,
+    /// The order is ready to be delivered.
+    Ready,
+    /// The order has been delivered to its location.
+    Delivered,
+}
+
+impl From<FulfilmentStatusRequest> for FulfilmentStatus {
+    fn from(value: FulfilmentStatusRequest) -> Self {
+        match value {
+            FulfilmentStatusRequest::Queued => Self::Queued,
+            FulfilmentStatusRequest::Preparing => Self::Preparing,
+            FulfilmentStatusRequest::Ready => Self::Ready,
+            FulfilmentStatusRequest::Delivered => Self::Delivered,
+        }
+    }
+}
+
+impl From<FulfilmentStatusRequest> for Option<FulfilmentStatus> {
+    fn from(value: FulfilmentStatusRequest) -> Self {
+        Some(value.into())
+    }
+}
+
+/// Represents the request format for the reason of a stock movement in the API
+#[derive(Debug, PartialEq, Clone, Copy, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StockMovementReasonRequest {
+    /// The product was sold, or put back when its sale was canceled.
+    Purchase,
+    /// New products were brought into the warehouse.
+    Restock,
+    /// The stock was set by hand, after a count or a mistake.
+    Correction,
+    /// The product was used as an ingredient of a recipe, or put back when its sale was canceled.
+    RecipeConsumption,
+    /// The product was moved from or to another warehouse.
+    Transfer,
+    /// The stock was set to the quantity counted during a stocktake.
+    Stocktake,
+}
+
+impl From<StockMovementReasonRequest> for StockMovementReason {
+    fn from(value: StockMovementReasonRequest) -> Self {
+        match value {
+            StockMovementReasonRequest::Purchase => Self::Purchase,
+            StockMovementReasonRequest::Restock => Self::Restock,
+            StockMovementReasonRequest::Correction => Self::Correction,
+            StockMovementReasonRequest::RecipeConsumption => Self::RecipeConsumption,
+            StockMovementReasonRequest::Transfer => Self::Transfer,
+            StockMovementReasonRequest::Stocktake => Self::Stocktake,
+        }
+    }
+}
+
+/// Represents the request format for the status of a stocktake in the API
+#[derive(Debug, PartialEq, Clone, Copy, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StocktakeStatusRequest {
+    /// The products are being counted.
+    Open,
+    /// The counted quantities were applied to the stock.
+    Closed,
+    /// The stocktake was abandoned, the stock is left untouched.
+    Canceled,
+}
+
+impl From<StocktakeStatusRequest> for StocktakeStatus {
+    fn from(value: StocktakeStatusRequest) -> Self {
+        match value {
+            StocktakeStatusRequest::Open => Self::Open,
+            StocktakeStatusRequest::Closed => Self::Closed,
+            StocktakeStatusRequest::Canceled => Self::Canceled,
+        }
+    }
+}
+
+/// Represents the request format for the status of a purchase order in the API
+#[derive(Debug, PartialEq, Clone, Copy, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PurchaseOrderStatusRequest {
+    /// The purchase order is being prepared.
+    Draft,
+    /// The purchase order was sent to the supplier.
+    Sent,
+    /// Some of the ordered products were received.
+    PartiallyReceived,
+    /// Every ordered product was received.
+    Received,
+}
+
+impl From<PurchaseOrderStatusRequest> for PurchaseOrderStatus {
+    fn from(value: PurchaseOrderStatusRequest) -> Self {
+        match value {
+            PurchaseOrderStatusRequest::Draft => Self::Draft,
+            PurchaseOrderStatusRequest::Sent => Self::Sent,
+            PurchaseOrderStatusRequest::PartiallyReceived => Self::PartiallyReceived,
+            PurchaseOrderStatusRequest::Received => Self::Received,
+        }
+    }
+}
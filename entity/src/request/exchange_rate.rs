@@ -0,0 +1,131 @@
+//! # Exchange Rate Request Models and Error Handling
+//! This module defines the structures and associated logic for handling exchange rate requests,
+//! including both the creation and editing of exchange rates.
+
+use crate::{error::impl_bad_request_app_error, models::exchange_rate::ActiveModel};
+use rust_decimal::{Decimal, Error as DecimalError};
+use sea_orm::ActiveValue::{NotSet, Set};
+
+use super::r#enum::CurrencyRequest;
+use crate::response::r#enum::CurrencyResponse;
+
+/// Enum representing potential errors in the exchange rate request validation process.
+#[derive(Debug, PartialEq, Clone, strum_macros::IntoStaticStr)]
+pub enum ExchangeRateRequestError {
+    /// Error when the rate is zero or negative.
+    RateCannotBeNullOrNegative(f64),
+    /// Error when the rate cannot be converted to a `Decimal` type.
+    RateCannotBeConvertedInDecimal(String, DecimalError),
+    /// Error when both currencies are the same.
+    CurrenciesCannotBeTheSame(CurrencyResponse),
+}
+
+impl std::error::Error for ExchangeRateRequestError {}
+
+/// Formats error messages for the `ExchangeRateRequestError` enum.
+impl std::fmt::Display for ExchangeRateRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RateCannotBeNullOrNegative(rate) => {
+                write!(f, "Rate \"{rate}\" cannot be null or negative")
+            }
+            Self::RateCannotBeConvertedInDecimal(rate, err) => {
+                write!(f, "Rate \"{rate}\" cannot be converted in Decimal: {err}")
+            }
+            Self::CurrenciesCannotBeTheSame(currency) => {
+                write!(f, "Cannot exchange {currency} with itself")
+            }
+        }
+    }
+}
+impl_bad_request_app_error!(ExchangeRateRequestError);
+
+/// Validates a rate and converts it to a `Decimal`.
+fn rate_to_decimal(rate: f64) -> Result<Decimal, ExchangeRateRequestError> {
+    if rate <= 0.0 {
+        return Err(ExchangeRateRequestError::RateCannotBeNullOrNegative(rate));
+    }
+    let rate = rate.to_string();
+    Decimal::from_str_exact(&rate)
+        .map_err(|err| ExchangeRateRequestError::RateCannotBeConvertedInDecimal(rate, err))
+}
+
+/// Request structure for creating a new exchange rate.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "from_currency": "euro",
+    "to_currency": "epicoin",
+    "rate": 1.0,
+    "effective_from": "2024-10-09T17:55:30.795279Z"
+}))]
+pub struct NewExchangeRateRequest {
+    /// Currency the amounts are converted from.
+    pub from_currency: CurrencyRequest,
+
+    /// Currency the amounts are converted to.
+    pub to_currency: CurrencyRequest,
+
+    /// Amount of `to_currency` given for one `from_currency`.
+    pub rate: f64,
+
+    /// When the rate starts to apply, now if not provided.
+    pub effective_from: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Converts `NewExchangeRateRequest` into `ActiveModel` with validation.
+/// Errors are returned if validation fails.
+impl TryFrom<NewExchangeRateRequest> for ActiveModel {
+    type Error = ExchangeRateRequestError;
+
+    fn try_from(value: NewExchangeRateRequest) -> Result<Self, Self::Error> {
+        if value.from_currency == value.to_currency {
+            return Err(Self::Error::CurrenciesCannotBeTheSame(CurrencyResponse(
+                value.from_currency.0,
+            )));
+        }
+
+        let now = chrono::offset::Local::now();
+        Ok(ActiveModel {
+            id: Set(uuid::Uuid::new_v4()),
+            from_currency: Set(value.from_currency.into()),
+            to_currency: Set(value.to_currency.into()),
+            rate: Set(rate_to_decimal(value.rate)?),
+            effective_from: Set(match value.effective_from {
+                Some(effective_from) => effective_from.into(),
+                None => now.into(),
+            }),
+            created_at: Set(now.into()),
+        })
+    }
+}
+
+/// Structure representing a request to edit an existing exchange rate.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+pub struct EditExchangeRateRequest {
+    /// Optional new rate.
+    pub rate: Option<f64>,
+
+    /// Optional new date from which the rate applies.
+    pub effective_from: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Converts `EditExchangeRateRequest` into `ActiveModel` with validation.
+/// Only fields present in the request are updated.
+impl TryFrom<EditExchangeRateRequest> for ActiveModel {
+    type Error = ExchangeRateRequestError;
+
+    fn try_from(value: EditExchangeRateRequest) -> Result<Self, Self::Error> {
+        Ok(ActiveModel {
+            id: NotSet,
+            rate: match value.rate {
+                Some(rate) => Set(rate_to_decimal(rate)?),
+                None => NotSet,
+            },
+            effective_from: match value.effective_from {
+                Some(effective_from) => Set(effective_from.into()),
+                None => NotSet,
+            },
+            ..Default::default()
+        })
+    }
+}
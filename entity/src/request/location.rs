@@ -5,7 +5,8 @@
 use crate::{
     error::impl_bad_request_app_error,
     models::{
-        location::ActiveModel, sea_orm_active_enums::LocationCategory as ModelLocationCategory,
+        location::ActiveModel, location_warehouse,
+        sea_orm_active_enums::LocationCategory as ModelLocationCategory,
     },
 };
 use sea_orm::ActiveValue::{NotSet, Set};
@@ -165,3 +166,88 @@ impl TryFrom<EditLocationRequest> for ActiveModel {
         })
     }
 }
+
+/// Enum representing potential errors in the location warehouse request validation process.
+#[derive(Debug, PartialEq, Clone, strum_macros::IntoStaticStr)]
+pub enum LocationWarehouseRequestError {
+    /// Error when you attempt to add an existing link
+    LocationWarehouseAlreadyExist(uuid::Uuid, uuid::Uuid),
+
+    /// Error when the warehouse doesn't exist
+    WarehouseDoesntExist(uuid::Uuid),
+
+    /// Error when another warehouse of the location already has this priority
+    PriorityAlreadyUsed(i32, uuid::Uuid),
+}
+
+impl std::error::Error for LocationWarehouseRequestError {}
+
+/// Formats error messages for the `LocationWarehouseRequestError` enum.
+impl std::fmt::Display for LocationWarehouseRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LocationWarehouseAlreadyExist(location_id, warehouse_id) => {
+                write!(
+                    f,
+                    "Link between Location \"{location_id}\" and Warehouse \"{warehouse_id}\" already exist."
+                )
+            }
+            Self::WarehouseDoesntExist(id) => {
+                write!(f, "Warehouse with id \"{id}\" doesn't exist.")
+            }
+            Self::PriorityAlreadyUsed(priority, id) => {
+                write!(
+                    f,
+                    "Priority {priority} is already used by Warehouse \"{id}\" for this Location."
+                )
+            }
+        }
+    }
+}
+impl_bad_request_app_error!(LocationWarehouseRequestError);
+
+/// Request structure for linking a warehouse to a location.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "priority": 10,
+}))]
+pub struct NewLocationWarehouseRequest {
+    /// Priority of the warehouse for the location, the highest is used first. Unique per location.
+    pub priority: i32,
+}
+
+/// Converts `NewLocationWarehouseRequest` into `ActiveModel`.
+impl From<NewLocationWarehouseRequest> for location_warehouse::ActiveModel {
+    fn from(value: NewLocationWarehouseRequest) -> Self {
+        location_warehouse::ActiveModel {
+            location_id: NotSet,
+            warehouse_id: NotSet,
+            priority: Set(value.priority),
+            created_at: Set(chrono::offset::Local::now().into()),
+        }
+    }
+}
+
+/// Request structure for editing the link between a warehouse and a location.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "priority": 5,
+}))]
+pub struct EditLocationWarehouseRequest {
+    /// New priority of the warehouse for the location. Unique per location.
+    pub priority: Option<i32>,
+}
+
+/// Converts `EditLocationWarehouseRequest` into `ActiveModel`.
+/// Only fields present in the request are updated.
+impl From<EditLocationWarehouseRequest> for location_warehouse::ActiveModel {
+    fn from(value: EditLocationWarehouseRequest) -> Self {
+        location_warehouse::ActiveModel {
+            priority: match value.priority {
+                Some(priority) => Set(priority),
+                None => NotSet,
+            },
+            ..Default::default()
+        }
+    }
+}
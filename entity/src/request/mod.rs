@@ -9,10 +9,18 @@
 //! records related to different entities in the system, like products, users,
 //! or locations, depending on the application's functionality.
 
+pub mod category;
+pub mod currency;
 pub mod r#enum;
+pub mod exchange_rate;
 pub mod location;
+pub mod order;
+pub mod payment;
 pub mod product;
+pub mod purchase_order;
 pub mod recipe;
 pub mod refill;
+pub mod stocktake;
+pub mod supplier;
 pub mod user;
 pub mod warehouse;
@@ -0,0 +1,339 @@
+//! # Order Request Models and Error Handling
+//! This module defines the structures and associated logic for handling order requests,
+//! an order being a list of products paid with the user balance.
+
+use crate::{
+    error::impl_bad_request_app_error,
+    models::{currency::CurrencyCode, product},
+    request::r#enum::FulfilmentStatusRequest,
+    response::r#enum::{CurrencyResponse, FulfilmentStatusResponse},
+};
+use rust_decimal::Decimal;
+
+/// Enum representing potential errors in the order request validation process.
+#[derive(Debug, PartialEq, Clone, strum_macros::IntoStaticStr)]
+pub enum OrderRequestError {
+    /// Error when the order doesn't contain any product.
+    OrderCannotBeEmpty,
+    /// Error when a product quantity is zero.
+    QuantityCannotBeNull(uuid::Uuid),
+    /// Error when a product quantity is above what can be stored.
+    QuantityCannotBeConverted(uuid::Uuid, u64),
+    /// Error when a product quantity is above its `max_quantity_per_command`.
+    QuantityCannotBeAboveMaximum(uuid::Uuid, u64, u64),
+    /// Error when the product doesn't exist.
+    ProductDoesntExist(uuid::Uuid),
+    /// Error when the product is disabled or not purchasable.
+    ProductIsNotPurchasable(uuid::Uuid),
+    /// Error when the product price cannot be converted in the currency of the user balance.
+    ProductCurrencyDoesntMatch(uuid::Uuid, CurrencyResponse, CurrencyResponse),
+    /// Error when the user balance cannot pay for the whole order.
+    InsufficientBalance(Decimal, Decimal),
+    /// Error when no warehouse has enough of the product, nor of the ingredients of its recipes.
+    InsufficientStock(uuid::Uuid),
+    /// Error when the delivery location doesn't exist.
+    LocationDoesntExist(uuid::Uuid),
+    /// Error when the delivery location is disabled.
+    LocationIsDisabled(uuid::Uuid),
+}
+
+impl std::error::Error for OrderRequestError {}
+
+/// Formats error messages for the `OrderRequestError` enum.
+impl std::fmt::Display for OrderRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OrderCannotBeEmpty => write!(f, "Order cannot be empty"),
+            Self::QuantityCannotBeNull(product) => {
+                write!(f, "Quantity of product \"{product}\" cannot be null")
+            }
+            Self::QuantityCannotBeConverted(product, quantity) => {
+                write!(
+                    f,
+                    "Quantity \"{quantity}\" of product \"{product}\" cannot be converted"
+                )
+            }
+            Self::QuantityCannotBeAboveMaximum(product, quantity, max) => {
+                write!(
+                    f,
+                    "Quantity \"{quantity}\" of product \"{product}\" is above the maximum of {max} per command"
+                )
+            }
+            Self::ProductDoesntExist(product) => {
+                write!(f, "Product \"{product}\" doesn't exist")
+            }
+            Self::ProductIsNotPurchasable(product) => {
+                write!(f, "Product \"{product}\" is not purchasable")
+            }
+            Self::ProductCurrencyDoesntMatch(product, product_currency, balance_currency) => {
+                write!(
+                    f,
+                    "Product \"{product}\" is sold in {product_currency} and there is no rate to the balance currency {balance_currency}"
+                )
+            }
+            Self::InsufficientBalance(total, balance) => {
+                write!(f, "Order total \"{total}\" is above balance \"{balance}\"")
+            }
+            Self::InsufficientStock(product) => {
+                write!(f, "Product \"{product}\" is out of stock")
+            }
+            Self::LocationDoesntExist(location) => {
+                write!(f, "Location \"{location}\" doesn't exist")
+            }
+            Self::LocationIsDisabled(location) => {
+                write!(f, "Location \"{location}\" is disabled")
+            }
+        }
+    }
+}
+impl_bad_request_app_error!(OrderRequestError);
+
+/// Request structure for a new order paid with the user balance.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "products": [
+        { "product": "afd0dac6-70b2-4752-a66f-d79437c53f01", "quantity": 2 },
+        { "product": "f317ccf1-e196-4bd2-8fb0-106aa05aa899", "quantity": 1 },
+    ],
+    "location": "a2b1c3d4-5e6f-4a7b-8c9d-0e1f2a3b4c5d"
+}))]
+pub struct NewOrderRequest {
+    /// List of products to buy
+    pub products: Vec<OrderProductRequest>,
+
+    /// Location the order must be delivered to, if any
+    pub location: Option<uuid::Uuid>,
+}
+
+/// Request structure for a single line of an order.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+pub struct OrderProductRequest {
+    /// Id of the product to buy
+    pub product: uuid::Uuid,
+
+    /// Number of this product to buy
+    pub quantity: u64,
+}
+
+/// A validated line of an order, holding the product as it was when ordered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderLine {
+    /// The product bought
+    pub product: product::Model,
+
+    /// Number of this product bought
+    pub quantity: i32,
+
+    /// Unit price of the product
+    pub price: Decimal,
+
+    /// Currency of the unit price
+    pub currency: CurrencyCode,
+}
+
+impl OrderLine {
+    /// Validates that `quantity` of `product` can be bought, at its sell price.
+    pub fn try_new(product: product::Model, quantity: u64) -> Result<Self, OrderRequestError> {
+        if quantity == 0 {
+            return Err(OrderRequestError::QuantityCannotBeNull(product.id));
+        }
+
+        if !product.purchasable || product.disabled {
+            return Err(OrderRequestError::ProductIsNotPurchasable(product.id));
+        }
+
+        if let Some(max) = product.max_quantity_per_command {
+            let max = u64::try_from(max).unwrap_or(0);
+            if quantity > max {
+                return Err(OrderRequestError::QuantityCannotBeAboveMaximum(
+                    product.id, quantity, max,
+                ));
+            }
+        }
+
+        let (price, currency) = match (product.sell_price, &product.sell_price_currency) {
+            (Some(price), Some(currency)) => (price, currency.clone()),
+            _ => return Err(OrderRequestError::ProductIsNotPurchasable(product.id)),
+        };
+
+        let quantity = i32::try_from(quantity)
+            .map_err(|_| OrderRequestError::QuantityCannotBeConverted(product.id, quantity))?;
+
+        Ok(Self {
+            product,
+            quantity,
+            price,
+            currency,
+        })
+    }
+
+    /// Total price of this line.
+    pub fn total(&self) -> Decimal {
+        self.price * Decimal::from(self.quantity)
+    }
+}
+
+/// Merges the lines of an order by combining the quantities of the same product.
+/// The order of the first appearance of each product is kept.
+pub fn merge_order_products<I: IntoIterator<Item = OrderProductRequest>>(
+    products: I,
+) -> Vec<OrderProductRequest> {
+    products
+        .into_iter()
+        .fold(Vec::new(), |mut acc: Vec<OrderProductRequest>, line| {
+            match acc.iter_mut().find(|x| x.product == line.product) {
+                Some(existing) => {
+                    existing.quantity = existing.quantity.saturating_add(line.quantity)
+                }
+                None => acc.push(line),
+            }
+            acc
+        })
+}
+
+/// The maximum allowed length for the reason of an order cancellation.
+pub const ORDER_CANCEL_REASON_MAX_LENGTH: usize = 256;
+
+/// Enum representing potential errors when canceling an order.
+#[derive(Debug, PartialEq, Clone, strum_macros::IntoStaticStr)]
+pub enum CancelOrderRequestError {
+    /// Error when the reason is empty.
+    ReasonCannotBeEmpty,
+    /// Error when the reason exceeds the allowed maximum length.
+    ReasonCannotBeLongerThan(String, usize),
+    /// Error when the transaction is not an order paid with the balance.
+    TransactionIsNotAnOrder(uuid::Uuid),
+    /// Error when the order is not completed, e.g. already canceled and refunded.
+    OrderIsNotCompleted(uuid::Uuid),
+    /// Error when a user tries to cancel its order after the grace period.
+    GracePeriodIsOver(uuid::Uuid, chrono::DateTime<chrono::Utc>),
+    /// Error when the balance of the buyer isn't in the currency the order was paid in anymore.
+    BalanceCurrencyDoesntMatch(uuid::Uuid),
+}
+
+impl std::error::Error for CancelOrderRequestError {}
+
+/// Formats error messages for the `CancelOrderRequestError` enum.
+impl std::fmt::Display for CancelOrderRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ReasonCannotBeEmpty => write!(f, "Reason cannot be empty"),
+            Self::ReasonCannotBeLongerThan(reason, max) => {
+                write!(f, "Reason \"{reason}\" is longer than {max} characters")
+            }
+            Self::TransactionIsNotAnOrder(id) => {
+                write!(f, "Transaction \"{id}\" is not an order")
+            }
+            Self::OrderIsNotCompleted(id) => {
+                write!(f, "Order \"{id}\" is not completed, it cannot be canceled")
+            }
+            Self::GracePeriodIsOver(id, deadline) => {
+                write!(f, "Order \"{id}\" could only be canceled until {deadline}")
+            }
+            Self::BalanceCurrencyDoesntMatch(id) => {
+                write!(
+                    f,
+                    "Order \"{id}\" wasn't paid in the currency of the balance, it cannot be refunded"
+                )
+            }
+        }
+    }
+}
+impl_bad_request_app_error!(CancelOrderRequestError);
+
+/// Request structure to cancel an order and refund its balance.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "reason": "Product was out of stock"
+}))]
+pub struct CancelOrderRequest {
+    /// Why the order is canceled
+    pub reason: String,
+}
+
+/// A validated order cancellation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderCancellation {
+    /// Why the order is canceled, trimmed
+    pub reason: String,
+}
+
+impl TryFrom<CancelOrderRequest> for OrderCancellation {
+    type Error = CancelOrderRequestError;
+
+    fn try_from(value: CancelOrderRequest) -> Result<Self, Self::Error> {
+        let reason = value.reason.trim().to_string();
+        if reason.is_empty() {
+            return Err(Self::Error::ReasonCannotBeEmpty);
+        }
+        if reason.len() > ORDER_CANCEL_REASON_MAX_LENGTH {
+            return Err(Self::Error::ReasonCannotBeLongerThan(
+                reason,
+                ORDER_CANCEL_REASON_MAX_LENGTH,
+            ));
+        }
+
+        Ok(Self { reason })
+    }
+}
+
+/// Enum representing potential errors when updating the fulfilment of an order.
+#[derive(Debug, PartialEq, Clone, strum_macros::IntoStaticStr)]
+pub enum OrderFulfilmentRequestError {
+    /// Error when the transaction is not an order paid with the balance.
+    TransactionIsNotAnOrder(uuid::Uuid),
+    /// Error when the order has no location to be delivered to.
+    OrderHasNoLocation(uuid::Uuid),
+    /// Error when the order is not completed, e.g. canceled.
+    OrderIsNotCompleted(uuid::Uuid),
+    /// Error when the new status is before the current one.
+    StatusCannotGoBack(
+        uuid::Uuid,
+        FulfilmentStatusResponse,
+        FulfilmentStatusResponse,
+    ),
+    /// Error when the status was changed by someone else in the meantime.
+    StatusWasChanged(uuid::Uuid),
+}
+
+impl std::error::Error for OrderFulfilmentRequestError {}
+
+/// Formats error messages for the `OrderFulfilmentRequestError` enum.
+impl std::fmt::Display for OrderFulfilmentRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TransactionIsNotAnOrder(id) => {
+                write!(f, "Transaction \"{id}\" is not an order")
+            }
+            Self::OrderHasNoLocation(id) => {
+                write!(f, "Order \"{id}\" has no location to be delivered to")
+            }
+            Self::OrderIsNotCompleted(id) => {
+                write!(f, "Order \"{id}\" is not completed, it cannot be delivered")
+            }
+            Self::StatusCannotGoBack(id, current, status) => {
+                write!(
+                    f,
+                    "Order \"{id}\" is {current:?}, it cannot go back to {status:?}"
+                )
+            }
+            Self::StatusWasChanged(id) => {
+                write!(
+                    f,
+                    "Fulfilment of order \"{id}\" was changed in the meantime"
+                )
+            }
+        }
+    }
+}
+impl_bad_request_app_error!(OrderFulfilmentRequestError);
+
+/// Request structure to move an order to deliver forward.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "status": "preparing"
+}))]
+pub struct EditOrderFulfilmentRequest {
+    /// New fulfilment status of the order
+    pub status: FulfilmentStatusRequest,
+}
@@ -0,0 +1,37 @@
+//! # Payment Webhook Error Handling
+//! This module defines the errors returned when a webhook sent by a payment provider is rejected.
+
+use crate::error::impl_bad_request_app_error;
+
+/// Enum representing potential errors when receiving a payment webhook.
+#[derive(Debug, PartialEq, Clone, strum_macros::IntoStaticStr)]
+pub enum PaymentWebhookError {
+    /// Error when the signature header is missing.
+    MissingSignature(String),
+    /// Error when the signature doesn't match the payload.
+    InvalidSignature(String),
+    /// Error when the payload cannot be understood.
+    InvalidPayload(String),
+    /// Error when the checkout session doesn't match the one of the transaction.
+    SessionDoesntMatch(uuid::Uuid, String),
+}
+
+impl std::error::Error for PaymentWebhookError {}
+
+/// Formats error messages for the `PaymentWebhookError` enum.
+impl std::fmt::Display for PaymentWebhookError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingSignature(header) => write!(f, "Header \"{header}\" is missing"),
+            Self::InvalidSignature(err) => write!(f, "Signature is invalid: {err}"),
+            Self::InvalidPayload(err) => write!(f, "Payload is invalid: {err}"),
+            Self::SessionDoesntMatch(transaction, session) => {
+                write!(
+                    f,
+                    "Session \"{session}\" doesn't belong to transaction \"{transaction}\""
+                )
+            }
+        }
+    }
+}
+impl_bad_request_app_error!(PaymentWebhookError);
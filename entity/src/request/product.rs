@@ -11,7 +11,8 @@ use rust_decimal::{Decimal, Error as DecimalError};
 use sea_orm::ActiveValue::{NotSet, Set};
 use std::num::TryFromIntError;
 
-use super::r#enum::{CurrencyRequest, UnitRequest};
+use super::r#enum::{CurrencyRequest, QuantityRequest, UnitRequest};
+use crate::models::sea_orm_active_enums::Unit;
 
 /// The maximum allowed length for a product name.
 /// This constraint ensures that names remain concise and standardized in the database.
@@ -40,6 +41,18 @@ pub enum ProductRequestError {
     ImageDoesNotExist(String),
     /// Display order cannot be converted to i32
     DisplayOrderCannotBeConvertedToI32(u64, TryFromIntError),
+    /// Error when the cost price is negative.
+    CostPriceCannotBeNegative(f64),
+    /// Error when the cost price is given without its currency.
+    CostPriceRequiresCurrency,
+    /// Error when the currency of the cost price is given without the cost price.
+    CostPriceCurrencyRequiresPrice,
+    /// Error when the content of a unit of the product isn't above zero.
+    ContentMustBePositive(Decimal),
+    /// Error when the content of a unit of the product is itself expressed in units.
+    ContentCannotBeUnit,
+    /// Error when the category doesn't exist.
+    CategoryDoesntExist(uuid::Uuid),
 }
 impl std::error::Error for ProductRequestError {}
 
@@ -83,6 +96,27 @@ impl std::fmt::Display for ProductRequestError {
                     "Display Order \"{display_order}\" cannot be converted to i32 {err}"
                 )
             }
+            ProductRequestError::CostPriceCannotBeNegative(price) => {
+                write!(f, "Cost Price \"{price}\" cannot be negative")
+            }
+            ProductRequestError::CostPriceRequiresCurrency => {
+                write!(f, "Cost Price cannot be set without its currency")
+            }
+            ProductRequestError::CostPriceCurrencyRequiresPrice => {
+                write!(
+                    f,
+                    "Cost Price Currency cannot be set without the cost price"
+                )
+            }
+            ProductRequestError::ContentMustBePositive(quantity) => {
+                write!(f, "Content \"{quantity}\" must be positive")
+            }
+            ProductRequestError::ContentCannotBeUnit => {
+                write!(f, "Content must be a weight, a volume or a length")
+            }
+            ProductRequestError::CategoryDoesntExist(id) => {
+                write!(f, "Category with id \"{id}\" doesn't exist")
+            }
         }
     }
 }
@@ -101,6 +135,41 @@ impl From<ProductRequestError> for crate::error::ErrorResponse {
     }
 }
 
+/// Converts a cost price into a `Decimal`, a product can be free to buy but not cost less.
+fn cost_price(price: f64) -> Result<Decimal, ProductRequestError> {
+    if price < 0.0 {
+        return Err(ProductRequestError::CostPriceCannotBeNegative(price));
+    }
+
+    let price = price.to_string();
+    Decimal::from_str_exact(&price)
+        .map_err(|err| ProductRequestError::PriceCannotBeConvertedInDecimal(price, err))
+}
+
+/// Checks the cost price and its currency are set, or removed, together.
+fn cost_price_pair<P, C>(
+    price: &Option<P>,
+    currency: &Option<C>,
+) -> Result<(), ProductRequestError> {
+    match (price, currency) {
+        (Some(_), None) => Err(ProductRequestError::CostPriceRequiresCurrency),
+        (None, Some(_)) => Err(ProductRequestError::CostPriceCurrencyRequiresPrice),
+        _ => Ok(()),
+    }
+}
+
+/// Converts the content of a unit of the product into grams, liters or meters.
+fn content(content: QuantityRequest) -> Result<(Decimal, Unit), ProductRequestError> {
+    if content.quantity <= Decimal::ZERO {
+        return Err(ProductRequestError::ContentMustBePositive(content.quantity));
+    }
+
+    match content.to_base() {
+        (Unit::Unit, _) => Err(ProductRequestError::ContentCannotBeUnit),
+        (unit, quantity) => Ok((quantity, unit)),
+    }
+}
+
 /// Request structure for creating a new product, including validation rules.
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
 pub struct NewProductRequest {
@@ -108,10 +177,17 @@ pub struct NewProductRequest {
     pub image: Option<String>,
     /// Name of the product, required and validated for length.
     pub name: String,
+    /// Optional id of the category the product belongs to.
+    pub category: Option<uuid::Uuid>,
     /// Price of the product, required and must be positive.
     pub sell_price: Option<f64>,
     /// Currency of the product.
     pub sell_price_currency: Option<CurrencyRequest>,
+    /// Price paid to buy the product, per unit, liter or meter the product is counted in, or
+    /// per kilogram for a product counted in grams.
+    pub cost_price: Option<f64>,
+    /// Currency of the cost price, required along with it.
+    pub cost_price_currency: Option<CurrencyRequest>,
     /// If the product is purchasable or if it's just an ingredients, if it's not it's
     /// automatically hidden
     pub purchasable: Option<bool>,
@@ -121,6 +197,8 @@ pub struct NewProductRequest {
     pub disabled: Option<bool>,
     /// Represent the unit type of Product, if it's a liquid -> Liter, etc..., the default is Unit
     pub unit: Option<UnitRequest>,
+    /// What a single unit of the product holds, such as `33` `cl` for a can.
+    pub content: Option<QuantityRequest>,
     /// Optional maximum quantity per command, limited to a certain maximum.
     pub max_quantity_per_command: Option<u64>,
     /// Optional SMA code for product identification.
@@ -132,6 +210,8 @@ pub struct NewProductRequest {
 impl TryFrom<NewProductRequest> for ActiveModel {
     type Error = ProductRequestError;
     fn try_from(mut value: NewProductRequest) -> Result<Self, Self::Error> {
+        cost_price_pair(&value.cost_price, &value.cost_price_currency)?;
+        let content = value.content.map(content).transpose()?;
         Ok(ActiveModel {
             id: Set(uuid::Uuid::new_v4()),
             image: Set(value.image),
@@ -148,6 +228,7 @@ impl TryFrom<NewProductRequest> for ActiveModel {
                 }
                 Set(name)
             },
+            category_id: Set(value.category),
             display_order: Set(0),
             sell_price: match value.sell_price {
                 Some(price) => {
@@ -179,7 +260,11 @@ impl TryFrom<NewProductRequest> for ActiveModel {
                     Set(None)
                 }
             },
+            cost_price: Set(value.cost_price.map(cost_price).transpose()?),
+            cost_price_currency: Set(value.cost_price_currency.map(Into::into)),
             unit: Set(value.unit.unwrap_or(UnitRequest::Unit).into()),
+            content_quantity: Set(content.as_ref().map(|(quantity, _)| *quantity)),
+            content_unit: Set(content.map(|(_, unit)| unit)),
             max_quantity_per_command: match value.max_quantity_per_command {
                 Some(max) => {
                     if max > PRODUCT_MAX_QUANTITY_PER_COMMAND {
@@ -235,16 +320,26 @@ pub struct EditProductRequest {
     pub image: Option<Option<String>>,
     /// Optional name of the product with length validation.
     pub name: Option<String>,
+    /// Optional id of the category the product belongs to, can be `None` to remove it.
+    pub category: Option<Option<uuid::Uuid>>,
     /// Display Order of the product inside of lists, 0 is last + default
     pub display_order: Option<u64>,
     /// Optional price of the product, required to be positive if present.
     pub sell_price: Option<Option<f64>>,
     /// Optional price of the product, required to be positive if present.
     pub sell_price_currency: Option<CurrencyRequest>,
+    /// Optional cost price of the product, can be `None` if specified, always along with its
+    /// currency. Per unit, liter or meter the product is counted in, or per kilogram for a
+    /// product counted in grams.
+    pub cost_price: Option<Option<f64>>,
+    /// Optional currency of the cost price, can be `None` if specified.
+    pub cost_price_currency: Option<Option<CurrencyRequest>>,
     /// If the product is purchasable or if it's just an ingredients
     pub purchasable: Option<bool>,
     /// Represent the unit type of Product, if it's a liquid -> Liter, etc..., the default is Unit
     pub unit: Option<UnitRequest>,
+    /// What a single unit of the product holds, can be `None` if specified.
+    pub content: Option<Option<QuantityRequest>>,
     /// Optional maximum quantity per command with conversion and size limits.
     pub max_quantity_per_command: Option<Option<u64>>,
     /// Optional SMA code for product identification, can be `None` if specified.
@@ -260,6 +355,15 @@ pub struct EditProductRequest {
 impl TryFrom<EditProductRequest> for ActiveModel {
     type Error = ProductRequestError;
     fn try_from(mut value: EditProductRequest) -> Result<Self, Self::Error> {
+        cost_price_pair(&value.cost_price, &value.cost_price_currency)?;
+        cost_price_pair(
+            &value.cost_price.flatten(),
+            &value.cost_price_currency.clone().flatten(),
+        )?;
+        let content = value
+            .content
+            .map(|content| content.map(self::content).transpose())
+            .transpose()?;
         Ok(ActiveModel {
             id: NotSet,
             image: match value.image {
@@ -284,6 +388,10 @@ impl TryFrom<EditProductRequest> for ActiveModel {
                 }
                 None => NotSet,
             },
+            category_id: match value.category {
+                Some(category) => Set(category),
+                None => NotSet,
+            },
             display_order: {
                 let display_order = value.display_order.unwrap_or(0);
                 let display_order_i32 = display_order.try_into().map_err(|err| {
@@ -321,6 +429,14 @@ impl TryFrom<EditProductRequest> for ActiveModel {
                 Some(currency) => Set(currency.into()),
                 None => NotSet,
             },
+            cost_price: match value.cost_price {
+                Some(price) => Set(price.map(cost_price).transpose()?),
+                None => NotSet,
+            },
+            cost_price_currency: match value.cost_price_currency {
+                Some(currency) => Set(currency.map(Into::into)),
+                None => NotSet,
+            },
             max_quantity_per_command: match value.max_quantity_per_command {
                 Some(max_opt) => match max_opt {
                     Some(max) => {
@@ -349,6 +465,14 @@ impl TryFrom<EditProductRequest> for ActiveModel {
                 Some(unit) => Set(unit.into()),
                 None => NotSet,
             },
+            content_quantity: match &content {
+                Some(content) => Set(content.as_ref().map(|(quantity, _)| *quantity)),
+                None => NotSet,
+            },
+            content_unit: match content {
+                Some(content) => Set(content.map(|(_, unit)| unit)),
+                None => NotSet,
+            },
             sma_code: match value.sma_code {
                 Some(sma_opt) => match sma_opt {
                     Some(sma_code) => Set(Some(sma_code)),
@@ -389,3 +513,13 @@ impl TryFrom<EditProductRequest> for ActiveModel {
         })
     }
 }
+
+/// Query parameters selecting the products of some categories.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ProductCategoryQuery {
+    /// Ids of the categories to list the products of, including the products of every category
+    /// nested inside them.
+    #[serde(default)]
+    pub category_id_eq: Vec<uuid::Uuid>,
+}
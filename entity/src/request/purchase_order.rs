@@ -0,0 +1,329 @@
+//! # Purchase Order Request Models and Error Handling
+//! This module defines the structures used to draft a purchase order to a supplier, to edit it
+//! while it is a draft, and to receive the ordered products into its warehouse.
+
+use crate::{
+    error::impl_bad_request_app_error,
+    models::{purchase_order, purchase_order_line, sea_orm_active_enums::PurchaseOrderStatus},
+    request::r#enum::CurrencyRequest,
+};
+use sea_orm::ActiveValue::{NotSet, Set};
+
+/// Enum representing potential errors in the purchase order request validation process.
+#[derive(Debug, PartialEq, Clone, strum_macros::IntoStaticStr)]
+pub enum PurchaseOrderRequestError {
+    /// Error when the supplier doesn't exist
+    SupplierDoesntExist(uuid::Uuid),
+
+    /// Error when the supplier is disabled
+    SupplierIsDisabled(uuid::Uuid),
+
+    /// Error when the warehouse doesn't exist
+    WarehouseDoesntExist(uuid::Uuid),
+
+    /// Error when the product doesn't exist
+    ProductDoesntExist(uuid::Uuid),
+
+    /// Error when a purchase order doesn't order any product
+    LinesCannotBeEmpty,
+
+    /// Error when an ordered or received quantity isn't above zero
+    QuantityMustBePositive(uuid::Uuid),
+
+    /// Error when a unit cost is negative
+    UnitCostCannotBeNegative(uuid::Uuid),
+
+    /// Error when the same product appears twice
+    ProductCannotBeRepeated(uuid::Uuid),
+
+    /// Error when the purchase order can't be changed anymore, as it was sent
+    PurchaseOrderIsNotDraft(uuid::Uuid),
+
+    /// Error when the purchase order isn't waiting for any product
+    PurchaseOrderCannotBeReceived(uuid::Uuid),
+
+    /// Error when a received product isn't ordered by the purchase order
+    ProductNotOrdered(uuid::Uuid),
+
+    /// Error when more of a product is received than what remains to receive
+    QuantityExceedsOrdered(uuid::Uuid, rust_decimal::Decimal),
+}
+
+impl std::error::Error for PurchaseOrderRequestError {}
+
+/// Formats error messages for the `PurchaseOrderRequestError` enum.
+impl std::fmt::Display for PurchaseOrderRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SupplierDoesntExist(id) => {
+                write!(f, "Supplier with id \"{id}\" doesn't exist.")
+            }
+            Self::SupplierIsDisabled(id) => write!(f, "Supplier with id \"{id}\" is disabled."),
+            Self::WarehouseDoesntExist(id) => {
+                write!(f, "Warehouse with id \"{id}\" doesn't exist.")
+            }
+            Self::ProductDoesntExist(id) => {
+                write!(f, "Product with id \"{id}\" doesn't exist.")
+            }
+            Self::LinesCannotBeEmpty => write!(f, "Lines cannot be empty."),
+            Self::QuantityMustBePositive(id) => {
+                write!(f, "Quantity of product \"{id}\" must be positive.")
+            }
+            Self::UnitCostCannotBeNegative(id) => {
+                write!(f, "Unit cost of product \"{id}\" cannot be negative.")
+            }
+            Self::ProductCannotBeRepeated(id) => {
+                write!(f, "Product with id \"{id}\" cannot be repeated.")
+            }
+            Self::PurchaseOrderIsNotDraft(id) => {
+                write!(f, "Purchase order \"{id}\" is not a draft.")
+            }
+            Self::PurchaseOrderCannotBeReceived(id) => {
+                write!(f, "Purchase order \"{id}\" is not waiting for any product.")
+            }
+            Self::ProductNotOrdered(id) => {
+                write!(f, "Product with id \"{id}\" is not ordered.")
+            }
+            Self::QuantityExceedsOrdered(id, remaining) => {
+                write!(
+                    f,
+                    "Only {remaining} of product \"{id}\" remain to be received."
+                )
+            }
+        }
+    }
+}
+impl_bad_request_app_error!(PurchaseOrderRequestError);
+
+/// Request structure for drafting a purchase order to a supplier.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "supplier": "3f0c7b8e-2d4a-4c1e-9b6f-5a7d8e9f0a1b",
+    "warehouse": "1a731f58-18f1-4c95-8de5-611bde07f4f1",
+    "currency": "euro",
+    "lines": [
+        {
+            "product": "afd0dac6-70b2-4752-a66f-d79437c53f01",
+            "quantity": 24,
+            "unit_cost": 0.45,
+            "expected_at": "2024-03-01T09:00:00Z",
+        },
+    ],
+}))]
+pub struct NewPurchaseOrderRequest {
+    /// Id of the supplier to buy from, it must be enabled.
+    pub supplier: uuid::Uuid,
+
+    /// Id of the warehouse the products are delivered to.
+    pub warehouse: uuid::Uuid,
+
+    /// Currency of the unit costs.
+    pub currency: CurrencyRequest,
+
+    /// Ordered products.
+    pub lines: Vec<PurchaseOrderLineRequest>,
+}
+
+/// A product to order.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+pub struct PurchaseOrderLineRequest {
+    /// Id of the product
+    pub product: uuid::Uuid,
+
+    /// Quantity to order, must be above zero.
+    pub quantity: rust_decimal::Decimal,
+
+    /// Price of the product, per unit, liter or meter the product is counted in, or per
+    /// kilogram for a product counted in grams.
+    pub unit_cost: rust_decimal::Decimal,
+
+    /// When the product is expected to be delivered.
+    pub expected_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Checks a purchase order orders at least one product, each product once, with a quantity
+/// above zero and a unit cost which isn't negative.
+fn validate_lines(lines: &[PurchaseOrderLineRequest]) -> Result<(), PurchaseOrderRequestError> {
+    if lines.is_empty() {
+        return Err(PurchaseOrderRequestError::LinesCannotBeEmpty);
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    for line in lines {
+        if line.quantity <= rust_decimal::Decimal::ZERO {
+            return Err(PurchaseOrderRequestError::QuantityMustBePositive(
+                line.product,
+            ));
+        }
+        if line.unit_cost < rust_decimal::Decimal::ZERO {
+            return Err(PurchaseOrderRequestError::UnitCostCannotBeNegative(
+                line.product,
+            ));
+        }
+        if !seen.insert(line.product) {
+            return Err(PurchaseOrderRequestError::ProductCannotBeRepeated(
+                line.product,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+impl NewPurchaseOrderRequest {
+    /// Checks the lines of the purchase order.
+    ///
+    /// The supplier, warehouse, currency and products are checked by the handler, as they need
+    /// the database.
+    pub fn validate(&self) -> Result<(), PurchaseOrderRequestError> {
+        validate_lines(&self.lines)
+    }
+}
+
+/// Converts `NewPurchaseOrderRequest` into a draft purchase order `ActiveModel`, its lines are
+/// converted on their own.
+impl From<NewPurchaseOrderRequest> for purchase_order::ActiveModel {
+    fn from(value: NewPurchaseOrderRequest) -> Self {
+        purchase_order::ActiveModel {
+            id: Set(uuid::Uuid::new_v4()),
+            supplier_id: Set(value.supplier),
+            warehouse_id: Set(value.warehouse),
+            status: Set(PurchaseOrderStatus::Draft),
+            currency: Set(value.currency.into()),
+            author_id: NotSet,
+            created_at: Set(chrono::offset::Local::now().into()),
+            sent_at: Set(None),
+            received_at: Set(None),
+        }
+    }
+}
+
+/// Converts `PurchaseOrderLineRequest` into a line `ActiveModel`, nothing being received yet.
+/// The purchase order is set when the line is inserted.
+impl From<PurchaseOrderLineRequest> for purchase_order_line::ActiveModel {
+    fn from(value: PurchaseOrderLineRequest) -> Self {
+        purchase_order_line::ActiveModel {
+            purchase_order_id: NotSet,
+            product_id: Set(value.product),
+            quantity: Set(value.quantity),
+            received_quantity: Set(rust_decimal::Decimal::ZERO),
+            unit_cost: Set(value.unit_cost),
+            expected_at: Set(value.expected_at.map(Into::into)),
+        }
+    }
+}
+
+/// Request structure for editing a draft purchase order.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "lines": [
+        { "product": "afd0dac6-70b2-4752-a66f-d79437c53f01", "quantity": 48, "unit_cost": 0.42 },
+    ],
+}))]
+pub struct EditPurchaseOrderRequest {
+    /// New supplier to buy from, it must be enabled.
+    pub supplier: Option<uuid::Uuid>,
+
+    /// New warehouse the products are delivered to.
+    pub warehouse: Option<uuid::Uuid>,
+
+    /// New currency of the unit costs.
+    pub currency: Option<CurrencyRequest>,
+
+    /// New ordered products, replacing every previous line.
+    pub lines: Option<Vec<PurchaseOrderLineRequest>>,
+}
+
+impl EditPurchaseOrderRequest {
+    /// Checks the new lines of the purchase order, if any.
+    pub fn validate(&self) -> Result<(), PurchaseOrderRequestError> {
+        match &self.lines {
+            Some(lines) => validate_lines(lines),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Converts `EditPurchaseOrderRequest` into `ActiveModel`, its lines are converted on their own.
+/// Only fields present in the request are updated.
+impl From<EditPurchaseOrderRequest> for purchase_order::ActiveModel {
+    fn from(value: EditPurchaseOrderRequest) -> Self {
+        purchase_order::ActiveModel {
+            supplier_id: match value.supplier {
+                Some(supplier) => Set(supplier),
+                None => NotSet,
+            },
+            warehouse_id: match value.warehouse {
+                Some(warehouse) => Set(warehouse),
+                None => NotSet,
+            },
+            currency: match value.currency {
+                Some(currency) => Set(currency.into()),
+                None => NotSet,
+            },
+            ..Default::default()
+        }
+    }
+}
+
+/// Request structure for receiving products of a purchase order into its warehouse.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "products": [
+        {
+            "product": "afd0dac6-70b2-4752-a66f-d79437c53f01",
+            "quantity": 12,
+            "expires_at": "2024-06-30T00:00:00Z",
+        },
+    ],
+    "update_cost_price": true,
+}))]
+pub struct ReceivePurchaseOrderRequest {
+    /// Received quantity of each product, at most what remains to receive of it.
+    pub products: Vec<ReceivePurchaseOrderLineRequest>,
+
+    /// Whether the unit cost of the received products becomes their cost price, `false` if not
+    /// provided.
+    #[serde(default)]
+    pub update_cost_price: bool,
+}
+
+/// Received quantity of a product.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+pub struct ReceivePurchaseOrderLineRequest {
+    /// Id of the product
+    pub product: uuid::Uuid,
+
+    /// Quantity delivered, must be above zero.
+    pub quantity: rust_decimal::Decimal,
+
+    /// When the delivered products expire, if they do.
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl ReceivePurchaseOrderRequest {
+    /// Checks the receipt contains at least one product, each product once and above zero.
+    ///
+    /// The quantities are checked against the purchase order by the handler.
+    pub fn validate(&self) -> Result<(), PurchaseOrderRequestError> {
+        if self.products.is_empty() {
+            return Err(PurchaseOrderRequestError::LinesCannotBeEmpty);
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        for line in &self.products {
+            if line.quantity <= rust_decimal::Decimal::ZERO {
+                return Err(PurchaseOrderRequestError::QuantityMustBePositive(
+                    line.product,
+                ));
+            }
+            if !seen.insert(line.product) {
+                return Err(PurchaseOrderRequestError::ProductCannotBeRepeated(
+                    line.product,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
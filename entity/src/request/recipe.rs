@@ -9,6 +9,7 @@
 use crate::{
     error::impl_bad_request_app_error,
     models::{recipe, recipe_ingredients},
+    request::r#enum::QuantityUnitRequest,
 };
 use rust_decimal::{Decimal, Error as DecimalError};
 use sea_orm::ActiveValue::{NotSet, Set};
@@ -34,10 +35,16 @@ pub enum RecipeRequestError {
     IngredientCannotBeFound(uuid::Uuid),
     /// Error if the ingredient product is the same as the resulting product.
     IngredientCannotBeResultingProduct(uuid::Uuid),
-    /// Error if the resulting Product unit is not an Unit.
-    ResultingProductIsNotUnit(uuid::Uuid, crate::response::r#enum::UnitResponse),
     /// Error if the recipe can't be found in the database.
     RecipeCannotBeFound(uuid::Uuid),
+    /// Error if an ingredient requires, through other recipes, the resulting product.
+    IngredientCreatesCycle(Vec<uuid::Uuid>),
+    /// Error if the unit of an ingredient can't be converted into the unit of its product.
+    IngredientUnitDoesntMatch(
+        uuid::Uuid,
+        crate::response::r#enum::UnitResponse,
+        crate::response::r#enum::UnitResponse,
+    ),
 }
 impl std::error::Error for RecipeRequestError {}
 
@@ -69,12 +76,22 @@ impl std::fmt::Display for RecipeRequestError {
                     "Ingredient \"{product}\" cannot be the same as the resulting product"
                 )
             }
-            RecipeRequestError::ResultingProductIsNotUnit(product, unit) => {
-                write!(f, "Product \"{product}\" unit cannot be {unit:?}")
-            }
             RecipeRequestError::RecipeCannotBeFound(recipe) => {
                 write!(f, "Recipe \"{recipe}\" cannot be found")
             }
+            RecipeRequestError::IngredientCreatesCycle(path) => {
+                let path: Vec<_> = path
+                    .iter()
+                    .map(|product| format!("\"{product}\""))
+                    .collect();
+                write!(f, "Ingredients create a cycle: {}", path.join(" -> "))
+            }
+            RecipeRequestError::IngredientUnitDoesntMatch(product, product_unit, unit) => {
+                write!(
+                    f,
+                    "Ingredient \"{product}\" is counted in {product_unit:?}, it can't be counted in {unit:?}"
+                )
+            }
         }
     }
 }
@@ -142,6 +159,10 @@ pub struct RecipeIngredientRequest {
     /// Quantity of this product for the recipe
     pub quantity: f64,
 
+    /// Unit of the quantity, converted into the unit of the product, the unit of the product
+    /// if not provided.
+    pub unit: Option<QuantityUnitRequest>,
+
     /// Optional field to disable or enable the recipe.
     pub disabled: Option<bool>,
 }
@@ -7,6 +7,7 @@ use rust_decimal::{Decimal, Error as DecimalError};
 use sea_orm::ActiveValue::{NotSet, Set};
 
 use super::r#enum::CurrencyRequest;
+use crate::response::r#enum::CurrencyResponse;
 
 /// The maximum allowed length for a refill name.
 /// This constraint ensures that names remain concise and standardized in the database.
@@ -59,6 +60,33 @@ impl std::fmt::Display for RefillRequestError {
 }
 impl_bad_request_app_error!(RefillRequestError);
 
+/// Enum representing potential errors when a user buys a refill.
+#[derive(Debug, PartialEq, Clone, strum_macros::IntoStaticStr)]
+pub enum RefillPurchaseError {
+    /// Error when the refill is disabled.
+    RefillIsDisabled(uuid::Uuid),
+    /// Error when the refill credit cannot be converted in the currency of the user balance.
+    CreditCurrencyDoesntMatch(uuid::Uuid, CurrencyResponse, CurrencyResponse),
+}
+
+impl std::error::Error for RefillPurchaseError {}
+
+/// Formats error messages for the `RefillPurchaseError` enum.
+impl std::fmt::Display for RefillPurchaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RefillIsDisabled(id) => write!(f, "Refill \"{id}\" is disabled"),
+            Self::CreditCurrencyDoesntMatch(id, credit_currency, balance_currency) => {
+                write!(
+                    f,
+                    "Refill \"{id}\" credits {credit_currency} and there is no rate to the balance currency {balance_currency}"
+                )
+            }
+        }
+    }
+}
+impl_bad_request_app_error!(RefillPurchaseError);
+
 /// Request structure for creating a new refill, including validation rules.
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
 pub struct NewRefillRequest {
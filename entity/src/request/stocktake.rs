@@ -0,0 +1,131 @@
+//! # Stocktake Request Models and Error Handling
+//! This module defines the structures used to open a stocktake on a warehouse and to submit the
+//! counted quantities of its products.
+
+use crate::{error::impl_bad_request_app_error, models::stocktake};
+use sea_orm::ActiveValue::{NotSet, Set};
+
+/// Enum representing potential errors in the stocktake request validation process.
+#[derive(Debug, PartialEq, Clone, strum_macros::IntoStaticStr)]
+pub enum StocktakeRequestError {
+    /// Error when the warehouse doesn't exist
+    WarehouseDoesntExist(uuid::Uuid),
+
+    /// Error when the warehouse is already being counted
+    StocktakeAlreadyOpen(uuid::Uuid),
+
+    /// Error when the stocktake was already closed or canceled
+    StocktakeIsNotOpen(uuid::Uuid),
+
+    /// Error when the product doesn't exist
+    ProductDoesntExist(uuid::Uuid),
+
+    /// Error when a count doesn't contain any product
+    CountCannotBeEmpty,
+
+    /// Error when a counted quantity is negative
+    QuantityCannotBeNegative(uuid::Uuid),
+
+    /// Error when a count contains the same product twice
+    ProductCannotBeRepeated(uuid::Uuid),
+}
+
+impl std::error::Error for StocktakeRequestError {}
+
+/// Formats error messages for the `StocktakeRequestError` enum.
+impl std::fmt::Display for StocktakeRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WarehouseDoesntExist(id) => {
+                write!(f, "Warehouse with id \"{id}\" doesn't exist.")
+            }
+            Self::StocktakeAlreadyOpen(id) => {
+                write!(f, "Stocktake \"{id}\" is already open on this warehouse.")
+            }
+            Self::StocktakeIsNotOpen(id) => write!(f, "Stocktake \"{id}\" is not open."),
+            Self::ProductDoesntExist(id) => {
+                write!(f, "Product with id \"{id}\" doesn't exist.")
+            }
+            Self::CountCannotBeEmpty => write!(f, "Count cannot be empty."),
+            Self::QuantityCannotBeNegative(id) => {
+                write!(f, "Quantity of product \"{id}\" cannot be negative.")
+            }
+            Self::ProductCannotBeRepeated(id) => {
+                write!(f, "Product with id \"{id}\" cannot be repeated.")
+            }
+        }
+    }
+}
+impl_bad_request_app_error!(StocktakeRequestError);
+
+/// Request structure for opening a stocktake on a warehouse.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "warehouse": "5d1a4b3e-0b4c-4f5e-9a43-6f3f0b1c2d7e",
+}))]
+pub struct NewStocktakeRequest {
+    /// Id of the warehouse to count, only one stocktake can be open per warehouse.
+    pub warehouse: uuid::Uuid,
+}
+
+/// Converts `NewStocktakeRequest` into an open stocktake `ActiveModel`.
+impl From<NewStocktakeRequest> for stocktake::ActiveModel {
+    fn from(value: NewStocktakeRequest) -> Self {
+        stocktake::ActiveModel {
+            id: Set(uuid::Uuid::new_v4()),
+            warehouse_id: Set(value.warehouse),
+            status: Set(crate::models::sea_orm_active_enums::StocktakeStatus::Open),
+            author_id: NotSet,
+            closed_by_id: Set(None),
+            created_at: Set(chrono::offset::Local::now().into()),
+            closed_at: Set(None),
+        }
+    }
+}
+
+/// Request structure for submitting counted quantities to an open stocktake. Counting a product
+/// again replaces its previous count, so several devices can count the same warehouse.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "products": [
+        { "product": "afd0dac6-70b2-4752-a66f-d79437c53f01", "quantity": 12 },
+        { "product": "f317ccf1-e196-4bd2-8fb0-106aa05aa899", "quantity": 0 },
+    ],
+}))]
+pub struct StocktakeCountRequest {
+    /// Counted quantity of each product.
+    pub products: Vec<StocktakeCountLineRequest>,
+}
+
+/// Counted quantity of a product.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+pub struct StocktakeCountLineRequest {
+    /// Id of the product
+    pub product: uuid::Uuid,
+
+    /// Quantity found in the warehouse.
+    pub quantity: rust_decimal::Decimal,
+}
+
+impl StocktakeCountRequest {
+    /// Checks the count contains at least one product, each product once and never below zero.
+    pub fn validate(&self) -> Result<(), StocktakeRequestError> {
+        if self.products.is_empty() {
+            return Err(StocktakeRequestError::CountCannotBeEmpty);
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        for line in &self.products {
+            if line.quantity < rust_decimal::Decimal::ZERO {
+                return Err(StocktakeRequestError::QuantityCannotBeNegative(
+                    line.product,
+                ));
+            }
+            if !seen.insert(line.product) {
+                return Err(StocktakeRequestError::ProductCannotBeRepeated(line.product));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+//! # Supplier Request Models and Error Handling
+//! This module defines the structures used to register the suppliers the products are bought
+//! from, and to edit them.
+
+use crate::{error::impl_bad_request_app_error, models::supplier};
+use sea_orm::ActiveValue::{NotSet, Set};
+
+/// The maximum allowed length for a supplier name.
+pub const SUPPLIER_NAME_MAX_LENGTH: usize = 64;
+
+/// Enum representing potential errors in the supplier request validation process.
+#[derive(Debug, PartialEq, Clone, strum_macros::IntoStaticStr)]
+pub enum SupplierRequestError {
+    /// Error when the supplier name is empty.
+    NameCannotBeEmpty,
+    /// Error when the supplier name exceeds the allowed maximum length.
+    NameCannotBeLongerThan(String, usize),
+    /// Error when the email address isn't one.
+    EmailIsInvalid(String),
+}
+
+impl std::error::Error for SupplierRequestError {}
+
+/// Formats error messages for the `SupplierRequestError` enum.
+impl std::fmt::Display for SupplierRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NameCannotBeEmpty => write!(f, "Name cannot be empty"),
+            Self::NameCannotBeLongerThan(name, max) => {
+                write!(f, "Name \"{name}\" is longer than {max} characters")
+            }
+            Self::EmailIsInvalid(email) => write!(f, "Email \"{email}\" is not valid"),
+        }
+    }
+}
+impl_bad_request_app_error!(SupplierRequestError);
+
+/// Checks a supplier name is neither empty nor too long.
+fn validate_name(name: String) -> Result<String, SupplierRequestError> {
+    if name.is_empty() {
+        return Err(SupplierRequestError::NameCannotBeEmpty);
+    }
+    if name.len() > SUPPLIER_NAME_MAX_LENGTH {
+        return Err(SupplierRequestError::NameCannotBeLongerThan(
+            name,
+            SUPPLIER_NAME_MAX_LENGTH,
+        ));
+    }
+    Ok(name)
+}
+
+/// Checks an email address has a local part and a domain.
+fn validate_email(email: Option<String>) -> Result<Option<String>, SupplierRequestError> {
+    match email {
+        Some(email) => match email.split_once('@') {
+            Some((local, domain)) if !local.is_empty() && !domain.is_empty() => Ok(Some(email)),
+            _ => Err(SupplierRequestError::EmailIsInvalid(email)),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Request structure for registering a new supplier.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "name": "Metro",
+    "email": "orders@metro.example",
+}))]
+pub struct NewSupplierRequest {
+    /// Name of the supplier, required and validated for length.
+    pub name: String,
+
+    /// Address the purchase orders are sent to.
+    pub email: Option<String>,
+}
+
+/// Converts `NewSupplierRequest` into `ActiveModel` with validation.
+/// Errors are returned if validation fails.
+impl TryFrom<NewSupplierRequest> for supplier::ActiveModel {
+    type Error = SupplierRequestError;
+
+    fn try_from(value: NewSupplierRequest) -> Result<Self, Self::Error> {
+        Ok(supplier::ActiveModel {
+            id: Set(uuid::Uuid::new_v4()),
+            name: Set(validate_name(value.name)?),
+            email: Set(validate_email(value.email)?),
+            disabled: Set(false),
+            created_at: Set(chrono::offset::Local::now().into()),
+        })
+    }
+}
+
+/// Structure representing a request to edit an existing supplier.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "email": "purchasing@metro.example",
+    "disabled": true,
+}))]
+pub struct EditSupplierRequest {
+    /// New name for the supplier.
+    pub name: Option<String>,
+
+    /// New email address of the supplier, can be `None` to remove it.
+    pub email: Option<Option<String>>,
+
+    /// Optional new disabled status for the supplier.
+    pub disabled: Option<bool>,
+}
+
+/// Converts `EditSupplierRequest` into `ActiveModel` with validation.
+/// Only fields present in the request are updated.
+impl TryFrom<EditSupplierRequest> for supplier::ActiveModel {
+    type Error = SupplierRequestError;
+
+    fn try_from(value: EditSupplierRequest) -> Result<Self, Self::Error> {
+        Ok(supplier::ActiveModel {
+            id: NotSet,
+            name: match value.name {
+                Some(name) => Set(validate_name(name)?),
+                None => NotSet,
+            },
+            email: match value.email {
+                Some(email) => Set(validate_email(email)?),
+                None => NotSet,
+            },
+            disabled: match value.disabled {
+                Some(disabled) => Set(disabled),
+                None => NotSet,
+            },
+            ..Default::default()
+        })
+    }
+}
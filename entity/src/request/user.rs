@@ -2,13 +2,24 @@
 //! to update specific user attributes. This struct allows partial updates
 //! to the `User` entity in the database, focusing on attributes such as
 //! `is_admin` and `is_banned`.
+//!
+//! It also defines the `EditUserBalanceRequest` struct, used by admins to
+//! manually correct the balance of a user.
 
+use rust_decimal::{Decimal, Error as DecimalError};
 use sea_orm::{
     ActiveValue::{NotSet, Set},
     IntoActiveModel,
 };
 
-use crate::models::user::ActiveModel;
+use super::r#enum::CurrencyRequest;
+use crate::{
+    error::impl_bad_request_app_error, models::user::ActiveModel,
+    response::r#enum::CurrencyResponse,
+};
+
+/// The maximum allowed length for the reason of a balance edit.
+pub const BALANCE_EDIT_REASON_MAX_LENGTH: usize = 256;
 
 /// Represents an update request for user-specific fields, allowing modification
 /// of key boolean attributes such as `is_admin` and `is_banned`.
@@ -37,3 +48,114 @@ impl IntoActiveModel<ActiveModel> for EditUserRequest {
         }
     }
 }
+
+/// Enum representing potential errors in the balance edit request validation process.
+#[derive(Debug, PartialEq, Clone, strum_macros::IntoStaticStr)]
+pub enum EditUserBalanceRequestError {
+    /// Error when the delta is zero.
+    DeltaCannotBeNull,
+    /// Error when the delta cannot be converted to a decimal.
+    DeltaCannotBeConvertedInDecimal(String, DecimalError),
+    /// Error when the reason is empty.
+    ReasonCannotBeEmpty,
+    /// Error when the reason exceeds the allowed maximum length.
+    ReasonCannotBeLongerThan(String, usize),
+    /// Error when the currency is not the one of the user balance.
+    CurrencyDoesntMatch(CurrencyResponse, CurrencyResponse),
+    /// Error when the edit would make the balance negative.
+    BalanceCannotBeNegative(Decimal, Decimal),
+}
+
+impl std::error::Error for EditUserBalanceRequestError {}
+
+/// Formats error messages for the `EditUserBalanceRequestError` enum.
+impl std::fmt::Display for EditUserBalanceRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DeltaCannotBeNull => write!(f, "Delta cannot be null"),
+            Self::DeltaCannotBeConvertedInDecimal(delta, err) => {
+                write!(f, "Delta \"{delta}\" cannot be converted in Decimal: {err}")
+            }
+            Self::ReasonCannotBeEmpty => write!(f, "Reason cannot be empty"),
+            Self::ReasonCannotBeLongerThan(reason, max) => {
+                write!(f, "Reason \"{reason}\" is longer than {max} characters")
+            }
+            Self::CurrencyDoesntMatch(currency, balance_currency) => {
+                write!(
+                    f,
+                    "Currency {currency} is not the balance currency {balance_currency}"
+                )
+            }
+            Self::BalanceCannotBeNegative(delta, balance) => {
+                write!(
+                    f,
+                    "Delta \"{delta}\" would make balance \"{balance}\" negative"
+                )
+            }
+        }
+    }
+}
+impl_bad_request_app_error!(EditUserBalanceRequestError);
+
+/// Request structure for manually editing the balance of a user, every edit is
+/// recorded as a `BalanceEdit` transaction.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "delta": -2.5,
+    "currency": "epicoin",
+    "reason": "Refund of a product that was out of stock"
+}))]
+pub struct EditUserBalanceRequest {
+    /// Signed amount added to the balance, negative to debit it.
+    pub delta: f64,
+
+    /// Currency of the amount, must be the one of the balance.
+    pub currency: CurrencyRequest,
+
+    /// Why the balance is edited, mandatory.
+    pub reason: String,
+}
+
+/// A validated balance edit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceEdit {
+    /// Signed amount added to the balance
+    pub delta: Decimal,
+
+    /// Currency of the amount
+    pub currency: crate::models::currency::CurrencyCode,
+
+    /// Why the balance is edited
+    pub reason: String,
+}
+
+/// Validates an `EditUserBalanceRequest` into a `BalanceEdit`.
+impl TryFrom<EditUserBalanceRequest> for BalanceEdit {
+    type Error = EditUserBalanceRequestError;
+
+    fn try_from(value: EditUserBalanceRequest) -> Result<Self, Self::Error> {
+        let delta = value.delta.to_string();
+        let delta = Decimal::from_str_exact(&delta)
+            .map_err(|err| Self::Error::DeltaCannotBeConvertedInDecimal(delta, err))?;
+        if delta.is_zero() {
+            return Err(Self::Error::DeltaCannotBeNull);
+        }
+
+        let reason = value.reason.trim().to_string();
+        if reason.is_empty() {
+            return Err(Self::Error::ReasonCannotBeEmpty);
+        }
+        if reason.len() > BALANCE_EDIT_REASON_MAX_LENGTH {
+            return Err(Self::Error::ReasonCannotBeLongerThan(
+                reason,
+                BALANCE_EDIT_REASON_MAX_LENGTH,
+            ));
+        }
+
+        Ok(Self {
+            delta,
+            currency: value.currency.into(),
+            reason,
+        })
+    }
+}
@@ -4,7 +4,9 @@
 
 use crate::{
     error::impl_bad_request_app_error,
-    models::{warehouse, warehouse_product},
+    models::{warehouse, warehouse_product, warehouse_recipe},
+    request::r#enum::QuantityUnitRequest,
+    response::r#enum::UnitResponse,
 };
 use sea_orm::ActiveValue::{NotSet, Set};
 
@@ -118,6 +120,9 @@ pub enum WarehouseProductRequestError {
     /// Error when the quantity cannot be converted
     QuantityCannotBeNegative,
 
+    /// Error when a low-stock threshold is negative
+    ThresholdCannotBeNegative,
+
     /// Error when you attemp to add an existing link
     WarehouseProductAlreadyExist(uuid::Uuid, uuid::Uuid),
 
@@ -126,6 +131,12 @@ pub enum WarehouseProductRequestError {
 
     /// Error when the product doesn't exist
     ProductDoesntExist(uuid::Uuid),
+
+    /// Error when a bulk update doesn't set any quantity
+    ProductsCannotBeEmpty,
+
+    /// Error when a bulk update sets the quantity of a product twice
+    ProductCannotBeRepeated(uuid::Uuid),
 }
 
 impl std::error::Error for WarehouseProductRequestError {}
@@ -138,7 +149,7 @@ impl std::fmt::Display for WarehouseProductRequestError {
                 write!(f, "Warehouse with id \"{id}\" doesn't exist.")
             }
             Self::ProductDoesntExist(id) => {
-                write!(f, "Warehouse with id \"{id}\" doesn't exist.")
+                write!(f, "Product with id \"{id}\" doesn't exist.")
             }
             Self::WarehouseProductAlreadyExist(warehouse_id, product_id) => {
                 write!(
@@ -147,6 +158,11 @@ impl std::fmt::Display for WarehouseProductRequestError {
                 )
             }
             Self::QuantityCannotBeNegative => write!(f, "Quantity cannot be negative."),
+            Self::ThresholdCannotBeNegative => write!(f, "Threshold cannot be negative."),
+            Self::ProductsCannotBeEmpty => write!(f, "Products cannot be empty."),
+            Self::ProductCannotBeRepeated(id) => {
+                write!(f, "Product with id \"{id}\" cannot be repeated.")
+            }
         }
     }
 }
@@ -156,10 +172,30 @@ impl_bad_request_app_error!(WarehouseProductRequestError);
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
 #[schema(example = json!({
     "quantity": 1.4,
+    "min_quantity": 6,
+    "reorder_quantity": 24,
 }))]
 pub struct NewWarehouseProductRequest {
     /// Name of the warehouse, required and validated for length.
     pub quantity: rust_decimal::Decimal,
+
+    /// Quantity at or below which the product is running low, no alert if not provided.
+    pub min_quantity: Option<rust_decimal::Decimal>,
+
+    /// Quantity to bring back when the product is running low.
+    pub reorder_quantity: Option<rust_decimal::Decimal>,
+}
+
+/// Checks a low-stock threshold isn't negative.
+fn validate_threshold(
+    threshold: Option<rust_decimal::Decimal>,
+) -> Result<Option<rust_decimal::Decimal>, WarehouseProductRequestError> {
+    match threshold {
+        Some(threshold) if threshold < rust_decimal::Decimal::ZERO => {
+            Err(WarehouseProductRequestError::ThresholdCannotBeNegative)
+        }
+        threshold => Ok(threshold),
+    }
 }
 
 /// Converts `NewWarehouseProductRequest` into `ActiveModel` with validation.
@@ -177,7 +213,393 @@ impl TryFrom<NewWarehouseProductRequest> for warehouse_product::ActiveModel {
 
                 Set(value.quantity)
             },
+            min_quantity: Set(validate_threshold(value.min_quantity)?),
+            reorder_quantity: Set(validate_threshold(value.reorder_quantity)?),
             created_at: Set(chrono::offset::Local::now().into()),
         })
     }
 }
+
+/// Request structure for editing the stock of a product in a warehouse.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "quantity": 12,
+    "min_quantity": 6,
+}))]
+pub struct EditWarehouseProductRequest {
+    /// New quantity of the product in the warehouse.
+    pub quantity: Option<rust_decimal::Decimal>,
+
+    /// New quantity at or below which the product is running low, can be `None` to stop alerting.
+    pub min_quantity: Option<Option<rust_decimal::Decimal>>,
+
+    /// New quantity to bring back when the product is running low, can be `None`.
+    pub reorder_quantity: Option<Option<rust_decimal::Decimal>>,
+}
+
+/// Converts `EditWarehouseProductRequest` into `ActiveModel` with validation.
+/// Only fields present in the request are updated.
+impl TryFrom<EditWarehouseProductRequest> for warehouse_product::ActiveModel {
+    type Error = WarehouseProductRequestError;
+    fn try_from(value: EditWarehouseProductRequest) -> Result<Self, Self::Error> {
+        Ok(warehouse_product::ActiveModel {
+            quantity: match value.quantity {
+                Some(quantity) if quantity < rust_decimal::Decimal::ZERO => {
+                    return Err(Self::Error::QuantityCannotBeNegative);
+                }
+                Some(quantity) => Set(quantity),
+                None => NotSet,
+            },
+            min_quantity: match value.min_quantity {
+                Some(threshold) => Set(validate_threshold(threshold)?),
+                None => NotSet,
+            },
+            reorder_quantity: match value.reorder_quantity {
+                Some(threshold) => Set(validate_threshold(threshold)?),
+                None => NotSet,
+            },
+            ..Default::default()
+        })
+    }
+}
+
+/// Request structure for setting the quantity of many products of a warehouse at once, after a
+/// physical stock count.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "products": [
+        { "product": "afd0dac6-70b2-4752-a66f-d79437c53f01", "quantity": 12 },
+        { "product": "f317ccf1-e196-4bd2-8fb0-106aa05aa899", "quantity": 0 },
+    ],
+}))]
+pub struct BulkWarehouseProductRequest {
+    /// Counted quantity of each product, a product not stocked yet in the warehouse is added.
+    pub products: Vec<BulkWarehouseProductLineRequest>,
+}
+
+/// Counted quantity of a product.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+pub struct BulkWarehouseProductLineRequest {
+    /// Id of the product
+    pub product: uuid::Uuid,
+
+    /// Quantity of the product in the warehouse.
+    pub quantity: rust_decimal::Decimal,
+}
+
+impl BulkWarehouseProductRequest {
+    /// Checks the request sets at least one quantity, each product once and never below zero.
+    pub fn validate(&self) -> Result<(), WarehouseProductRequestError> {
+        if self.products.is_empty() {
+            return Err(WarehouseProductRequestError::ProductsCannotBeEmpty);
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        for line in &self.products {
+            if line.quantity < rust_decimal::Decimal::ZERO {
+                return Err(WarehouseProductRequestError::QuantityCannotBeNegative);
+            }
+            if !seen.insert(line.product) {
+                return Err(WarehouseProductRequestError::ProductCannotBeRepeated(
+                    line.product,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Enum representing potential errors in the warehouse product batch request validation process.
+#[derive(Debug, PartialEq, Clone, strum_macros::IntoStaticStr)]
+pub enum WarehouseProductBatchRequestError {
+    /// Error when the quantity of the batch isn't above zero
+    QuantityMustBePositive,
+
+    /// Error when the expiry window isn't a duration
+    WindowIsInvalid(String),
+
+    /// Error when the warehouse doesn't exist
+    WarehouseDoesntExist(uuid::Uuid),
+
+    /// Error when the product doesn't exist
+    ProductDoesntExist(uuid::Uuid),
+}
+
+impl std::error::Error for WarehouseProductBatchRequestError {}
+
+/// Formats error messages for the `WarehouseProductBatchRequestError` enum.
+impl std::fmt::Display for WarehouseProductBatchRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::QuantityMustBePositive => write!(f, "Quantity must be positive."),
+            Self::WindowIsInvalid(window) => {
+                write!(f, "Window \"{window}\" is not a duration, such as \"7d\".")
+            }
+            Self::WarehouseDoesntExist(id) => {
+                write!(f, "Warehouse with id \"{id}\" doesn't exist.")
+            }
+            Self::ProductDoesntExist(id) => {
+                write!(f, "Product with id \"{id}\" doesn't exist.")
+            }
+        }
+    }
+}
+impl_bad_request_app_error!(WarehouseProductBatchRequestError);
+
+/// Request structure for adding a batch of a product to the stock of a warehouse.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "quantity": 24,
+    "expires_at": "2024-06-30T00:00:00Z",
+}))]
+pub struct NewWarehouseProductBatchRequest {
+    /// Quantity received, must be above zero.
+    pub quantity: rust_decimal::Decimal,
+
+    /// When the batch expires, if it does.
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl NewWarehouseProductBatchRequest {
+    /// Checks the batch holds something.
+    pub fn validate(&self) -> Result<(), WarehouseProductBatchRequestError> {
+        if self.quantity <= rust_decimal::Decimal::ZERO {
+            return Err(WarehouseProductBatchRequestError::QuantityMustBePositive);
+        }
+        Ok(())
+    }
+}
+
+/// The window used when listing expiring batches without one.
+pub const DEFAULT_EXPIRY_WINDOW: chrono::Duration = chrono::Duration::days(7);
+
+/// Query parameters selecting the batches about to expire.
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExpiringQuery {
+    /// How far ahead to look, such as `12h`, `7d` or `2weeks`, 7 days if not provided.
+    pub within: Option<String>,
+}
+
+impl ExpiringQuery {
+    /// Parses the window, see [`humantime::parse_duration`], and returns the date it ends at
+    /// from now.
+    pub fn before(
+        &self,
+    ) -> Result<chrono::DateTime<chrono::Utc>, WarehouseProductBatchRequestError> {
+        let window = match &self.within {
+            Some(within) => humantime::parse_duration(within)
+                .ok()
+                .and_then(|window| chrono::Duration::from_std(window).ok())
+                .ok_or_else(|| {
+                    WarehouseProductBatchRequestError::WindowIsInvalid(within.clone())
+                })?,
+            None => DEFAULT_EXPIRY_WINDOW,
+        };
+
+        chrono::Utc::now()
+            .checked_add_signed(window)
+            .ok_or_else(|| {
+                WarehouseProductBatchRequestError::WindowIsInvalid(
+                    self.within.clone().unwrap_or_default(),
+                )
+            })
+    }
+}
+
+/// Enum representing potential errors in the warehouse recipe request validation process.
+#[derive(Debug, PartialEq, Clone, strum_macros::IntoStaticStr)]
+pub enum WarehouseRecipeRequestError {
+    /// Error when you attempt to add an existing link
+    WarehouseRecipeAlreadyExist(uuid::Uuid, uuid::Uuid),
+
+    /// Error when the warehouse doesn't exist
+    WarehouseDoesntExist(uuid::Uuid),
+
+    /// Error when the recipe doesn't exist
+    RecipeDoesntExist(uuid::Uuid),
+
+    /// Error when the recipe is disabled
+    RecipeIsDisabled(uuid::Uuid),
+
+    /// Error when another recipe of the warehouse already has this priority
+    PriorityAlreadyUsed(i32, uuid::Uuid),
+}
+
+impl std::error::Error for WarehouseRecipeRequestError {}
+
+/// Formats error messages for the `WarehouseRecipeRequestError` enum.
+impl std::fmt::Display for WarehouseRecipeRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WarehouseRecipeAlreadyExist(warehouse_id, recipe_id) => {
+                write!(
+                    f,
+                    "Link between Warehouse \"{warehouse_id}\" and Recipe \"{recipe_id}\" already exist."
+                )
+            }
+            Self::WarehouseDoesntExist(id) => {
+                write!(f, "Warehouse with id \"{id}\" doesn't exist.")
+            }
+            Self::RecipeDoesntExist(id) => write!(f, "Recipe with id \"{id}\" doesn't exist."),
+            Self::RecipeIsDisabled(id) => write!(f, "Recipe with id \"{id}\" is disabled."),
+            Self::PriorityAlreadyUsed(priority, id) => {
+                write!(
+                    f,
+                    "Priority {priority} is already used by Recipe \"{id}\" in this Warehouse."
+                )
+            }
+        }
+    }
+}
+impl_bad_request_app_error!(WarehouseRecipeRequestError);
+
+/// Request structure for linking a recipe to a warehouse.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "priority": 10,
+}))]
+pub struct NewWarehouseRecipeRequest {
+    /// Priority of the recipe in the warehouse, the highest is tried first. Unique per warehouse.
+    pub priority: i32,
+}
+
+/// Converts `NewWarehouseRecipeRequest` into `ActiveModel`.
+impl From<NewWarehouseRecipeRequest> for warehouse_recipe::ActiveModel {
+    fn from(value: NewWarehouseRecipeRequest) -> Self {
+        warehouse_recipe::ActiveModel {
+            warehouse_id: NotSet,
+            recipe_id: NotSet,
+            priority: Set(value.priority),
+            created_at: Set(chrono::offset::Local::now().into()),
+        }
+    }
+}
+
+/// Request structure for editing the link between a recipe and a warehouse.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "priority": 5,
+}))]
+pub struct EditWarehouseRecipeRequest {
+    /// New priority of the recipe in the warehouse. Unique per warehouse.
+    pub priority: Option<i32>,
+}
+
+/// Converts `EditWarehouseRecipeRequest` into `ActiveModel`.
+/// Only fields present in the request are updated.
+impl From<EditWarehouseRecipeRequest> for warehouse_recipe::ActiveModel {
+    fn from(value: EditWarehouseRecipeRequest) -> Self {
+        warehouse_recipe::ActiveModel {
+            priority: match value.priority {
+                Some(priority) => Set(priority),
+                None => NotSet,
+            },
+            ..Default::default()
+        }
+    }
+}
+
+/// Enum representing potential errors when transferring stock between two warehouses.
+#[derive(Debug, PartialEq, Clone, strum_macros::IntoStaticStr)]
+pub enum TransferRequestError {
+    /// Error when the transfer doesn't move any product.
+    TransferCannotBeEmpty,
+    /// Error when the source and the destination are the same warehouse.
+    WarehousesCannotBeTheSame(uuid::Uuid),
+    /// Error when a quantity to transfer isn't above zero.
+    QuantityMustBePositive(uuid::Uuid),
+    /// Error when a warehouse doesn't exist.
+    WarehouseDoesntExist(uuid::Uuid),
+    /// Error when a product doesn't exist.
+    ProductDoesntExist(uuid::Uuid),
+    /// Error when the unit of a quantity can't be converted into the unit of its product.
+    UnitDoesntMatch(uuid::Uuid, UnitResponse, UnitResponse),
+    /// Error when the source warehouse doesn't hold enough of a product.
+    InsufficientStock(uuid::Uuid),
+}
+
+impl std::error::Error for TransferRequestError {}
+
+/// Formats error messages for the `TransferRequestError` enum.
+impl std::fmt::Display for TransferRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TransferCannotBeEmpty => write!(f, "Transfer cannot be empty"),
+            Self::WarehousesCannotBeTheSame(id) => {
+                write!(f, "Cannot transfer from warehouse \"{id}\" to itself")
+            }
+            Self::QuantityMustBePositive(product) => {
+                write!(f, "Quantity of product \"{product}\" must be positive")
+            }
+            Self::WarehouseDoesntExist(id) => {
+                write!(f, "Warehouse \"{id}\" doesn't exist")
+            }
+            Self::ProductDoesntExist(id) => {
+                write!(f, "Product \"{id}\" doesn't exist")
+            }
+            Self::UnitDoesntMatch(product, product_unit, unit) => {
+                write!(
+                    f,
+                    "Product \"{product}\" is counted in {product_unit:?}, it can't be counted in {unit:?}"
+                )
+            }
+            Self::InsufficientStock(product) => {
+                write!(
+                    f,
+                    "Not enough of product \"{product}\" in the source warehouse"
+                )
+            }
+        }
+    }
+}
+impl_bad_request_app_error!(TransferRequestError);
+
+/// Request structure for moving products from a warehouse to another.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "products": [
+        { "product": "afd0dac6-70b2-4752-a66f-d79437c53f01", "quantity": 24, "unit": "unit" },
+        { "product": "f317ccf1-e196-4bd2-8fb0-106aa05aa899", "quantity": 150, "unit": "cl" },
+    ]
+}))]
+pub struct TransferRequest {
+    /// List of products to move
+    pub products: Vec<TransferProductRequest>,
+}
+
+/// A product to move, with its quantity expressed in any unit convertible into the unit of the
+/// product.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, utoipa::ToSchema)]
+pub struct TransferProductRequest {
+    /// Id of the product
+    pub product: uuid::Uuid,
+
+    /// Quantity to move, must be above zero
+    pub quantity: rust_decimal::Decimal,
+
+    /// Unit of the quantity, converted into the unit of the product
+    pub unit: QuantityUnitRequest,
+}
+
+impl TransferRequest {
+    /// Checks the transfer is not empty and that every quantity is above zero.
+    ///
+    /// The units are checked against the products by the handler, as they need the database.
+    pub fn validate(&self) -> Result<(), TransferRequestError> {
+        if self.products.is_empty() {
+            return Err(TransferRequestError::TransferCannotBeEmpty);
+        }
+
+        if let Some(line) = self
+            .products
+            .iter()
+            .find(|line| line.quantity <= rust_decimal::Decimal::ZERO)
+        {
+            return Err(TransferRequestError::QuantityMustBePositive(line.product));
+        }
+
+        Ok(())
+    }
+}
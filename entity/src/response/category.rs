@@ -0,0 +1,61 @@
+//! This module defines the response structures for category-related API responses.
+
+use serde_with::skip_serializing_none;
+
+use crate::models::category;
+
+/// Response structure for a category.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "id": "8d2f4b6a-1c3e-4a5b-9d7f-0e1a2b3c4d5e",
+    "name": "Sodas",
+    "code": "SODA",
+    "parent": "5c3e1a2b-7d4f-4e8a-9b1c-2d3e4f5a6b7c",
+    "created_at": "2024-02-23T14:00:00Z",
+}))]
+pub struct CategoryResponse {
+    /// Unique identifier for the category.
+    pub id: uuid::Uuid,
+
+    /// Name of the category.
+    pub name: String,
+
+    /// Short code of the category.
+    pub code: Option<String>,
+
+    /// The category this one is nested in, absent for a top level category.
+    pub parent: Option<uuid::Uuid>,
+
+    /// Id of the category in the Sma system, if it was imported from it.
+    pub sma_id: Option<String>,
+
+    /// The timestamp indicating when the category was created.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<category::Model> for CategoryResponse {
+    fn from(value: category::Model) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            code: value.code,
+            parent: value.parent_id,
+            sma_id: value.sma_id,
+            created_at: value.created_at.into(),
+        }
+    }
+}
+
+/// Response structure for a list of categories with pagination details.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct CategoryListResponse {
+    /// Total number of pages available.
+    pub total_page: u64,
+
+    /// Current page number.
+    pub current_page: u64,
+
+    /// List of categories on the current page.
+    pub categories: Vec<CategoryResponse>,
+}
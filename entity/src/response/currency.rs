@@ -0,0 +1,47 @@
+//! This module contains response structures for the currency registry.
+//! It defines the format of data returned to clients regarding currencies.
+
+/// Represents a response containing a currency of the registry returned by the API.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct CurrencyDetailResponse {
+    /// Unique code of the currency.
+    pub code: String,
+
+    /// Symbol displayed next to an amount in this currency.
+    pub symbol: String,
+
+    /// Number of decimal places of an amount in this currency.
+    pub decimal_places: i16,
+
+    /// If the currency only exists inside the application.
+    pub is_internal: bool,
+
+    /// The timestamp indicating when the currency was registered.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Converts a `Model` from the currency module to a `CurrencyDetailResponse`.
+impl From<crate::models::currency::Model> for CurrencyDetailResponse {
+    fn from(value: crate::models::currency::Model) -> Self {
+        Self {
+            code: value.code,
+            symbol: value.symbol,
+            decimal_places: value.decimal_places,
+            is_internal: value.is_internal,
+            created_at: value.created_at.into(),
+        }
+    }
+}
+
+/// Represents a response containing a list of currencies returned by the API.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct CurrencyListResponse {
+    /// The total number of pages available for currency results.
+    pub total_page: u64,
+
+    /// The current page number being viewed.
+    pub current_page: u64,
+
+    /// A list of currency responses.
+    pub currencies: Vec<CurrencyDetailResponse>,
+}
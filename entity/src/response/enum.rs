@@ -1,26 +1,30 @@
 //! This module defines the response format for currency types in the API,
 //! facilitating serialization and deserialization to/from lowercase strings.
 
-use crate::models::sea_orm_active_enums::{Currency, Unit};
+use crate::models::{
+    currency::CurrencyCode,
+    sea_orm_active_enums::{
+        FulfilmentStatus, PurchaseOrderStatus, StockMovementReason, StocktakeStatus,
+        TransactionStatus, TransactionType, Unit,
+    },
+};
 
-/// Represents the response format for currency types in the API,
-/// enabling serialization and deserialization to/from lowercase strings.
-#[derive(Debug, PartialEq, Clone, Copy, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
-#[serde(rename_all = "lowercase")]
-pub enum CurrencyResponse {
-    /// Represents the Euro currency.
-    Euro,
+/// Represents the response format for currencies in the API: the code of a
+/// currency of the registry (e.g. `euro`, `epicoin`).
+#[derive(Debug, PartialEq, Eq, Clone, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+#[serde(transparent)]
+#[schema(value_type = String, example = "euro")]
+pub struct CurrencyResponse(pub String);
 
-    /// Represents the Epicoin currency.
-    Epicoin,
+impl std::fmt::Display for CurrencyResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
 }
 
-impl From<Currency> for CurrencyResponse {
-    fn from(value: Currency) -> Self {
-        match value {
-            Currency::Euro => Self::Euro,
-            Currency::Epicoin => Self::Epicoin,
-        }
+impl From<CurrencyCode> for CurrencyResponse {
+    fn from(value: CurrencyCode) -> Self {
+        Self(value.0)
     }
 }
 
@@ -48,3 +52,159 @@ impl From<Unit> for UnitResponse {
         }
     }
 }
+
+/// Represents the response format for transaction types in the API
+#[derive(Debug, PartialEq, Clone, Copy, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionTypeResponse {
+    /// A refill pack bought to credit the user balance.
+    RefillBalance,
+    /// A manual edit of the user balance made by an admin.
+    BalanceEdit,
+    /// A purchase paid with the user balance.
+    PurchaseWithBalance,
+    /// A purchase paid directly, without going through the balance.
+    PurchaseDirect,
+}
+
+impl From<TransactionType> for TransactionTypeResponse {
+    fn from(value: TransactionType) -> Self {
+        match value {
+            TransactionType::RefillBalance => Self::RefillBalance,
+            TransactionType::BalanceEdit => Self::BalanceEdit,
+            TransactionType::PurchaseWithBalance => Self::PurchaseWithBalance,
+            TransactionType::PurchaseDirect => Self::PurchaseDirect,
+        }
+    }
+}
+
+/// Represents the response format for transaction status in the API
+#[derive(Debug, PartialEq, Clone, Copy, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionStatusResponse {
+    /// The transaction has been recorded but nothing happened yet.
+    Created,
+    /// The transaction is waiting for an external confirmation.
+    Pending,
+    /// The transaction went through and its effects are applied.
+    Completed,
+    /// The transaction couldn't be processed.
+    Failed,
+    /// The transaction has been canceled.
+    Canceled,
+}
+
+impl From<TransactionStatus> for TransactionStatusResponse {
+    fn from(value: TransactionStatus) -> Self {
+        match value {
+            TransactionStatus::Created => Self::Created,
+            TransactionStatus::Pending => Self::Pending,
+            TransactionStatus::Completed => Self::Completed,
+            TransactionStatus::Failed => Self::Failed,
+            TransactionStatus::Canceled => Self::Canceled,
+        }
+    }
+}
+
+/// Represents the response format for the fulfilment status of an order in the API
+#[derive(Debug, PartialEq, Clone, Copy, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum FulfilmentStatusResponse {
+    /// The order is waiting for someone to take care of it.
+    Queued,
+    /// The order is being prepared.
+    Preparing,
+    /// The order is ready to be delivered.
+    Ready,
+    /// The order has been delivered to its location.
+    Delivered,
+}
+
+impl From<FulfilmentStatus> for FulfilmentStatusResponse {
+    fn from(value: FulfilmentStatus) -> Self {
+        match value {
+            FulfilmentStatus::Queued => Self::Queued,
+            FulfilmentStatus::Preparing => Self::Preparing,
+            FulfilmentStatus::Ready => Self::Ready,
+            FulfilmentStatus::Delivered => Self::Delivered,
+        }
+    }
+}
+
+/// Represents the response format for the reason of a stock movement in the API
+#[derive(Debug, PartialEq, Clone, Copy, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StockMovementReasonResponse {
+    /// The product was sold, or put back when its sale was canceled.
+    Purchase,
+    /// New products were brought into the warehouse.
+    Restock,
+    /// The stock was set by hand, after a count or a mistake.
+    Correction,
+    /// The product was used as an ingredient of a recipe, or put back when its sale was canceled.
+    RecipeConsumption,
+    /// The product was moved from or to another warehouse.
+    Transfer,
+    /// The stock was set to the quantity counted during a stocktake.
+    Stocktake,
+}
+
+impl From<StockMovementReason> for StockMovementReasonResponse {
+    fn from(value: StockMovementReason) -> Self {
+        match value {
+            StockMovementReason::Purchase => Self::Purchase,
+            StockMovementReason::Restock => Self::Restock,
+            StockMovementReason::Correction => Self::Correction,
+            StockMovementReason::RecipeConsumption => Self::RecipeConsumption,
+            StockMovementReason::Transfer => Self::Transfer,
+            StockMovementReason::Stocktake => Self::Stocktake,
+        }
+    }
+}
+
+/// Represents the response format for the status of a stocktake in the API
+#[derive(Debug, PartialEq, Clone, Copy, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StocktakeStatusResponse {
+    /// The products are being counted.
+    Open,
+    /// The counted quantities were applied to the stock.
+    Closed,
+    /// The stocktake was abandoned, the stock is left untouched.
+    Canceled,
+}
+
+impl From<StocktakeStatus> for StocktakeStatusResponse {
+    fn from(value: StocktakeStatus) -> Self {
+        match value {
+            StocktakeStatus::Open => Self::Open,
+            StocktakeStatus::Closed => Self::Closed,
+            StocktakeStatus::Canceled => Self::Canceled,
+        }
+    }
+}
+
+/// Represents the response format for the status of a purchase order in the API
+#[derive(Debug, PartialEq, Clone, Copy, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PurchaseOrderStatusResponse {
+    /// The purchase order is being prepared.
+    Draft,
+    /// The purchase order was sent to the supplier.
+    Sent,
+    /// Some of the ordered products were received.
+    PartiallyReceived,
+    /// Every ordered product was received.
+    Received,
+}
+
+impl From<PurchaseOrderStatus> for PurchaseOrderStatusResponse {
+    fn from(value: PurchaseOrderStatus) -> Self {
+        match value {
+            PurchaseOrderStatus::Draft => Self::Draft,
+            PurchaseOrderStatus::Sent => Self::Sent,
+            PurchaseOrderStatus::PartiallyReceived => Self::PartiallyReceived,
+            PurchaseOrderStatus::Received => Self::Received,
+        }
+    }
+}
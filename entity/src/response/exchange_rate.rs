@@ -0,0 +1,84 @@
+//! This module contains response structures for exchange rate-related API responses.
+//! It defines the format of data returned to clients regarding exchange rates.
+
+use rust_decimal::{Decimal, Error as DecimalError};
+
+use crate::error::impl_from_error_to_string;
+
+use super::r#enum::CurrencyResponse;
+
+/// Enum representing errors that can occur during exchange rate response construction.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ExchangeRateResponseError {
+    /// Error when the rate could not be converted to a floating-point representation.
+    RateCannotBeConverted(Decimal, DecimalError),
+}
+
+impl std::error::Error for ExchangeRateResponseError {}
+
+/// Formats error messages for the `ExchangeRateResponseError` enum.
+impl std::fmt::Display for ExchangeRateResponseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RateCannotBeConverted(rate, err) => {
+                write!(f, "Rate \"{rate}\" cannot be converted in: {err}")
+            }
+        }
+    }
+}
+
+impl_from_error_to_string!(ExchangeRateResponseError, InternalError);
+
+/// Represents a response containing exchange rate information returned by the API.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct ExchangeRateResponse {
+    /// Unique identifier for the exchange rate.
+    pub id: uuid::Uuid,
+
+    /// Currency the amounts are converted from.
+    pub from_currency: CurrencyResponse,
+
+    /// Currency the amounts are converted to.
+    pub to_currency: CurrencyResponse,
+
+    /// Amount of `to_currency` given for one `from_currency`.
+    pub rate: f64,
+
+    /// The timestamp from which the rate applies.
+    pub effective_from: chrono::DateTime<chrono::Utc>,
+
+    /// The timestamp indicating when the exchange rate was created.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Converts a `Model` from the exchange_rate module to an `ExchangeRateResponse`.
+impl TryFrom<crate::models::exchange_rate::Model> for ExchangeRateResponse {
+    type Error = ExchangeRateResponseError;
+
+    fn try_from(value: crate::models::exchange_rate::Model) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            from_currency: value.from_currency.into(),
+            to_currency: value.to_currency.into(),
+            rate: match value.rate.try_into() {
+                Ok(rate) => rate,
+                Err(err) => return Err(Self::Error::RateCannotBeConverted(value.rate, err)),
+            },
+            effective_from: value.effective_from.into(),
+            created_at: value.created_at.into(),
+        })
+    }
+}
+
+/// Represents a response containing a list of exchange rates returned by the API.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct ExchangeRateListResponse {
+    /// The total number of pages available for exchange rate results.
+    pub total_page: u64,
+
+    /// The current page number being viewed.
+    pub current_page: u64,
+
+    /// A list of exchange rate responses.
+    pub exchange_rates: Vec<ExchangeRateResponse>,
+}
@@ -5,7 +5,10 @@
 //! location categories, while `LocationResponse` and `LocationListResponse`
 //! represent individual and paginated location responses respectively.
 
-use crate::models::sea_orm_active_enums::LocationCategory as ModelLocationCategory;
+use crate::{
+    models::sea_orm_active_enums::LocationCategory as ModelLocationCategory,
+    response::product::ProductResponse,
+};
 
 /// Enum representing categories of locations, such as dispensers or rooms.
 /// This type is used for serializing request payloads and indicates the
@@ -85,3 +88,64 @@ pub struct LocationListResponse {
     /// A list of locations on the current page, represented by `LocationResponse`.
     pub locations: Vec<LocationResponse>,
 }
+
+/// Response structure representing a warehouse serving a location.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "warehouse": "6f0b5c1e-8f5a-4a8e-9d3e-2b1c0a9f8e7d",
+    "name": "Main stock room",
+    "priority": 10,
+    "created_at": "2024-10-09T17:55:30.795279Z"
+}))]
+pub struct LocationWarehouseResponse {
+    /// Unique identifier of the warehouse.
+    pub warehouse: uuid::Uuid,
+
+    /// The name of the warehouse.
+    pub name: String,
+
+    /// The priority of the warehouse for the location, the highest is used first.
+    pub priority: i32,
+
+    /// The timestamp indicating when the warehouse was linked to the location.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl
+    From<(
+        crate::models::location_warehouse::Model,
+        crate::models::warehouse::Model,
+    )> for LocationWarehouseResponse
+{
+    fn from(
+        (link, warehouse): (
+            crate::models::location_warehouse::Model,
+            crate::models::warehouse::Model,
+        ),
+    ) -> Self {
+        Self {
+            warehouse: warehouse.id,
+            name: warehouse.name,
+            priority: link.priority,
+            created_at: link.created_at.into(),
+        }
+    }
+}
+
+/// Response structure representing the warehouses serving a location, from the highest
+/// priority to the lowest.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct LocationWarehouseListResponse {
+    /// The warehouses serving the location.
+    pub warehouses: Vec<LocationWarehouseResponse>,
+}
+
+/// Response structure representing the products that can be bought at a location.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct LocationMenuResponse {
+    /// Unique identifier of the location.
+    pub location: uuid::Uuid,
+
+    /// The products available at the location, with the quantity available.
+    pub products: Vec<ProductResponse>,
+}
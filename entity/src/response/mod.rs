@@ -1,11 +1,21 @@
 //! This module contains response structures used in the API.
 //! These structures define the format of the data returned to clients
 //! after processing requests.
+pub mod category;
+pub mod currency;
 pub mod r#enum;
+pub mod exchange_rate;
 pub mod location;
+pub mod order;
 pub mod product;
+pub mod purchase_order;
 pub mod recipe;
+pub mod reconcile;
 pub mod refill;
+pub mod report;
 pub mod sma;
+pub mod stocktake;
+pub mod supplier;
+pub mod transaction;
 pub mod user;
 pub mod warehouse;
@@ -0,0 +1,73 @@
+//! This module contains response structures for order-related API responses.
+//! It defines the format of data returned to the staff delivering orders.
+
+use super::r#enum::FulfilmentStatusResponse;
+
+/// Represents a line of an order to deliver.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct OrderFulfilmentProductResponse {
+    /// Unique identifier of the product, if it still exists.
+    pub product_id: Option<uuid::Uuid>,
+
+    /// Name of the product when it was bought.
+    pub name: String,
+
+    /// Number of this product to deliver.
+    pub quantity: i32,
+}
+
+/// Converts a `Model` from the transaction_product module to an `OrderFulfilmentProductResponse`.
+impl From<crate::models::transaction_product::Model> for OrderFulfilmentProductResponse {
+    fn from(value: crate::models::transaction_product::Model) -> Self {
+        Self {
+            product_id: value.product_id,
+            name: value.name,
+            quantity: value.quantity,
+        }
+    }
+}
+
+/// Represents an order to deliver to a location.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "id": "8d2ac1b5-4c9c-4d87-b0c2-02b9a4c5e1f1",
+    "user_id": "3f5c5a0e-8c2e-4b5f-9a1c-0d3b2e6f7a8b",
+    "location_id": "a2b1c3d4-5e6f-4a7b-8c9d-0e1f2a3b4c5d",
+    "status": "queued",
+    "created_at": "2024-10-09T17:55:30.795279Z",
+    "products": [
+        { "product_id": "afd0dac6-70b2-4752-a66f-d79437c53f01", "name": "Coca-Cola", "quantity": 2 }
+    ]
+}))]
+pub struct OrderFulfilmentResponse {
+    /// Unique identifier of the order (the transaction's ID).
+    pub id: uuid::Uuid,
+
+    /// The user who placed the order.
+    pub user_id: uuid::Uuid,
+
+    /// The location the order must be delivered to.
+    pub location_id: uuid::Uuid,
+
+    /// Where the delivery stands.
+    pub status: FulfilmentStatusResponse,
+
+    /// The timestamp indicating when the order was placed.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+
+    /// The products to deliver.
+    pub products: Vec<OrderFulfilmentProductResponse>,
+}
+
+/// Represents a response containing a list of orders to deliver returned by the API.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct OrderFulfilmentListResponse {
+    /// The total number of pages available for order results.
+    pub total_page: u64,
+
+    /// The current page number being viewed.
+    pub current_page: u64,
+
+    /// A list of orders to deliver, the oldest first.
+    pub orders: Vec<OrderFulfilmentResponse>,
+}
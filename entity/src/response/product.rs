@@ -63,6 +63,9 @@ pub struct ProductResponse {
     /// Name of the product.
     name: String,
 
+    /// The category the product belongs to, if any.
+    category: Option<uuid::Uuid>,
+
     /// Display Order of the product.
     display_order: u64,
 
@@ -78,6 +81,9 @@ pub struct ProductResponse {
     /// Represent the unit type of Product, if it's a liquid -> Liter, etc...
     unit: UnitResponse,
 
+    /// What a single unit of the product holds, if known.
+    content: Option<ProductContentResponse>,
+
     /// Optional SMA code associated with the product.
     sma_code: Option<String>,
 
@@ -95,6 +101,51 @@ pub struct ProductResponse {
 
     /// indicating if the product is disabled.
     disabled: bool,
+
+    /// The quantity which can be sold right now, across every warehouse, if computed.
+    available_quantity: Option<rust_decimal::Decimal>,
+
+    /// Price paid to buy the product, per unit, liter or meter the product is counted in, or
+    /// per kilogram for a product counted in grams. Only shown to admins.
+    cost_price: Option<f64>,
+
+    /// Currency of the cost price, only shown to admins.
+    cost_price_currency: Option<CurrencyResponse>,
+}
+
+/// What a single unit of a product holds, such as 0.33 liter for a can.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct ProductContentResponse {
+    /// The quantity held, in `unit`.
+    pub quantity: rust_decimal::Decimal,
+
+    /// Gram, liter or meter.
+    pub unit: UnitResponse,
+}
+
+impl ProductResponse {
+    /// Sets the quantity which can be sold right now, across every warehouse.
+    pub fn with_available_quantity(mut self, available_quantity: rust_decimal::Decimal) -> Self {
+        self.available_quantity = Some(available_quantity);
+        self
+    }
+
+    /// Sets the price paid to buy the product, which only admins may see.
+    pub fn with_cost_price(
+        mut self,
+        cost_price: Option<Decimal>,
+        cost_price_currency: Option<crate::models::currency::CurrencyCode>,
+    ) -> Result<Self, ProductResponseError> {
+        self.cost_price = cost_price
+            .map(|price| {
+                price
+                    .try_into()
+                    .map_err(|err| ProductResponseError::PriceCannotBeConverted(price, err))
+            })
+            .transpose()?;
+        self.cost_price_currency = cost_price_currency.map(Into::into);
+        Ok(self)
+    }
 }
 
 impl TryFrom<crate::models::product::Model> for ProductResponse {
@@ -106,6 +157,7 @@ impl TryFrom<crate::models::product::Model> for ProductResponse {
             image: value.image,
             id: value.id,
             name: value.name,
+            category: value.category_id,
             display_order: value.display_order.try_into().map_err(|err| {
                 Self::Error::DisplayOrderCannotBeConverted(value.display_order, err)
             })?,
@@ -126,6 +178,13 @@ impl TryFrom<crate::models::product::Model> for ProductResponse {
                 None => None,
             },
             unit: value.unit.into(),
+            content: match (value.content_quantity, value.content_unit) {
+                (Some(quantity), Some(unit)) => Some(ProductContentResponse {
+                    quantity,
+                    unit: unit.into(),
+                }),
+                _ => None,
+            },
             sma_code: value.sma_code,
             inventree_code: value.inventree_code,
             created_at: value.created_at.into(),
@@ -138,6 +197,9 @@ impl TryFrom<crate::models::product::Model> for ProductResponse {
                 false => None,
             },
             disabled: value.disabled,
+            available_quantity: None,
+            cost_price: None,
+            cost_price_currency: None,
         })
     }
 }
@@ -164,6 +226,14 @@ pub struct EditedProductResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 
+    /// Optional category assigned to the product.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<uuid::Uuid>,
+
+    /// Optional content of a single unit of the product.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<ProductContentResponse>,
+
     /// Optional price of the product.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub price: Option<f64>,
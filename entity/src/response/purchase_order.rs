@@ -0,0 +1,134 @@
+//! This module defines the response structures for purchase order-related API responses.
+
+use serde_with::skip_serializing_none;
+
+use crate::models::{purchase_order, purchase_order_line};
+
+use super::r#enum::{CurrencyResponse, PurchaseOrderStatusResponse};
+
+/// Response structure for a purchase order, with its lines when requested alone.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "id": "6b0e2c4a-1d3f-4e5a-8b7c-9d0e1f2a3b4c",
+    "supplier": "3f0c7b8e-2d4a-4c1e-9b6f-5a7d8e9f0a1b",
+    "warehouse": "1a731f58-18f1-4c95-8de5-611bde07f4f1",
+    "status": "partially_received",
+    "currency": "euro",
+    "author": "a2b1c3d4-5e6f-4a7b-8c9d-0e1f2a3b4c5d",
+    "created_at": "2024-02-23T14:00:00Z",
+    "sent_at": "2024-02-23T14:30:00Z",
+    "lines": [
+        {
+            "product": "afd0dac6-70b2-4752-a66f-d79437c53f01",
+            "quantity": 24,
+            "received_quantity": 12,
+            "unit_cost": 0.45,
+            "expected_at": "2024-03-01T09:00:00Z",
+        }
+    ],
+}))]
+pub struct PurchaseOrderResponse {
+    /// Unique identifier for the purchase order.
+    pub id: uuid::Uuid,
+
+    /// The supplier the products are bought from.
+    pub supplier: uuid::Uuid,
+
+    /// The warehouse the products are delivered to.
+    pub warehouse: uuid::Uuid,
+
+    /// Whether the purchase order is a draft, sent, or partially or fully received.
+    pub status: PurchaseOrderStatusResponse,
+
+    /// Currency of the unit costs.
+    pub currency: CurrencyResponse,
+
+    /// The user who drafted the purchase order, if it still exists.
+    pub author: Option<uuid::Uuid>,
+
+    /// The timestamp indicating when the purchase order was drafted.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+
+    /// The timestamp indicating when the purchase order was sent.
+    pub sent_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// The timestamp indicating when the last ordered product was received.
+    pub received_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// The ordered products, only when a single purchase order is requested.
+    pub lines: Option<Vec<PurchaseOrderLineResponse>>,
+}
+
+impl From<purchase_order::Model> for PurchaseOrderResponse {
+    fn from(value: purchase_order::Model) -> Self {
+        Self {
+            id: value.id,
+            supplier: value.supplier_id,
+            warehouse: value.warehouse_id,
+            status: value.status.into(),
+            currency: value.currency.into(),
+            author: value.author_id,
+            created_at: value.created_at.into(),
+            sent_at: value.sent_at.map(Into::into),
+            received_at: value.received_at.map(Into::into),
+            lines: None,
+        }
+    }
+}
+
+impl From<(purchase_order::Model, Vec<purchase_order_line::Model>)> for PurchaseOrderResponse {
+    fn from(
+        (purchase_order, lines): (purchase_order::Model, Vec<purchase_order_line::Model>),
+    ) -> Self {
+        let mut response: Self = purchase_order.into();
+        response.lines = Some(lines.into_iter().map(Into::into).collect());
+        response
+    }
+}
+
+/// Represent a product ordered by a purchase order
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct PurchaseOrderLineResponse {
+    /// The ordered product.
+    pub product: uuid::Uuid,
+
+    /// The ordered quantity.
+    pub quantity: rust_decimal::Decimal,
+
+    /// The quantity received so far.
+    pub received_quantity: rust_decimal::Decimal,
+
+    /// Price of the product, per unit, liter or meter the product is counted in, or per
+    /// kilogram for a product counted in grams.
+    pub unit_cost: rust_decimal::Decimal,
+
+    /// When the product is expected to be delivered.
+    pub expected_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl From<purchase_order_line::Model> for PurchaseOrderLineResponse {
+    fn from(value: purchase_order_line::Model) -> Self {
+        Self {
+            product: value.product_id,
+            quantity: value.quantity,
+            received_quantity: value.received_quantity,
+            unit_cost: value.unit_cost,
+            expected_at: value.expected_at.map(Into::into),
+        }
+    }
+}
+
+/// Response structure for a list of purchase orders with pagination details.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct PurchaseOrderListResponse {
+    /// Total number of pages available.
+    pub total_page: u64,
+
+    /// Current page number.
+    pub current_page: u64,
+
+    /// List of purchase orders on the current page.
+    pub purchase_orders: Vec<PurchaseOrderResponse>,
+}
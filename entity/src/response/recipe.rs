@@ -2,7 +2,11 @@
 //! The module also defines the `RecipeResponseError` enum for error handling during
 //! recipe response construction, particularly for price and quantity conversions.
 
-use crate::error::impl_from_error_to_string;
+use crate::{
+    error::impl_from_error_to_string,
+    models::currency::CurrencyCode,
+    response::r#enum::{CurrencyResponse, UnitResponse},
+};
 use rust_decimal::{Decimal, Error as DecimalError};
 use serde_with::skip_serializing_none;
 
@@ -11,6 +15,9 @@ use serde_with::skip_serializing_none;
 pub enum RecipeResponseError {
     /// Error indicating that a price cannot be converted from Decimal.
     QuantityCannotBeConverted(Decimal, DecimalError),
+
+    /// Error indicating that a cost or a price cannot be converted from Decimal.
+    PriceCannotBeConverted(Decimal, DecimalError),
 }
 impl std::error::Error for RecipeResponseError {}
 
@@ -20,6 +27,9 @@ impl std::fmt::Display for RecipeResponseError {
             Self::QuantityCannotBeConverted(price, err) => {
                 write!(f, "Price \"{price}\" cannot be converted in : {err}")
             }
+            Self::PriceCannotBeConverted(price, err) => {
+                write!(f, "Price \"{price}\" cannot be converted in : {err}")
+            }
         }
     }
 }
@@ -54,10 +64,40 @@ pub struct RecipeResponse {
     /// indicating if the recipe is disabled.
     disabled: bool,
 
+    /// Cost of the recipe and margin made selling its product
+    cost: Option<RecipeCostResponse>,
+
     /// The timestamp indicating when the recipe was created.
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
+/// Response structure for the cost of a recipe and the margin made selling its product.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "currency": "euro",
+    "cost": 1.35,
+    "sell_price": 2.5,
+    "margin": 1.15,
+    "margin_rate": 0.46
+}))]
+pub struct RecipeCostResponse {
+    /// Currency of the cost, the sell price and the margin
+    pub currency: CurrencyResponse,
+
+    /// Cost of one unit of the product, missing when an ingredient has no known cost
+    pub cost: Option<f64>,
+
+    /// Sell price of the product, missing when it isn't sold
+    pub sell_price: Option<f64>,
+
+    /// Sell price minus cost
+    pub margin: Option<f64>,
+
+    /// Margin divided by the sell price
+    pub margin_rate: Option<f64>,
+}
+
 /// Response structure for a recipe ingredient, including its details.
 #[skip_serializing_none]
 #[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
@@ -93,11 +133,50 @@ impl TryFrom<crate::models::recipe::Model> for RecipeResponse {
             product: value.result_product_id,
             ingredients: vec![],
             disabled: value.disabled,
+            cost: None,
             created_at: value.created_at.into(),
         })
     }
 }
 
+impl RecipeResponse {
+    /// Sets the cost of the recipe, which only admins may see.
+    pub fn with_cost(mut self, cost: RecipeCostResponse) -> Self {
+        self.cost = Some(cost);
+        self
+    }
+}
+
+impl TryFrom<(CurrencyCode, Option<Decimal>, Option<Decimal>)> for RecipeCostResponse {
+    type Error = RecipeResponseError;
+
+    /// Constructs a RecipeCostResponse from a currency, a cost and a sell price, computing the
+    /// margin when both are known.
+    fn try_from(
+        (currency, cost, sell_price): (CurrencyCode, Option<Decimal>, Option<Decimal>),
+    ) -> Result<Self, Self::Error> {
+        let to_f64 = |price: Decimal| {
+            price
+                .try_into()
+                .map_err(|err| RecipeResponseError::PriceCannotBeConverted(price, err))
+        };
+
+        let margin = cost.zip(sell_price).map(|(cost, price)| price - cost);
+        let margin_rate = margin
+            .zip(sell_price)
+            .filter(|(_, price)| !price.is_zero())
+            .map(|(margin, price)| (margin / price).round_dp(4));
+
+        Ok(Self {
+            currency: currency.into(),
+            cost: cost.map(to_f64).transpose()?,
+            sell_price: sell_price.map(to_f64).transpose()?,
+            margin: margin.map(to_f64).transpose()?,
+            margin_rate: margin_rate.map(to_f64).transpose()?,
+        })
+    }
+}
+
 impl TryFrom<crate::models::recipe_ingredients::Model> for RecipeIngredientResponse {
     type Error = RecipeResponseError;
 
@@ -148,6 +227,7 @@ impl
             ingredients,
             created_at: recipe.created_at.into(),
             disabled: recipe.disabled,
+            cost: None,
         })
     }
 }
@@ -182,3 +262,89 @@ pub struct RecipeListResponse {
     /// List of recipes on the current page.
     pub recipes: Vec<RecipeResponse>,
 }
+
+/// Response structure for an ingredient of a bill of materials, expanded down to raw products.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct RecipeTreeNodeResponse {
+    /// Product used for this ingredient
+    pub product: uuid::Uuid,
+
+    /// Unit of the product
+    pub unit: UnitResponse,
+
+    /// Quantity of this ingredient needed to make one unit of the root recipe
+    pub quantity: f64,
+
+    /// Recipe making this ingredient, missing for a raw product
+    pub recipe: Option<uuid::Uuid>,
+
+    /// Ingredients of the recipe making this ingredient
+    #[schema(no_recursion)]
+    pub ingredients: Vec<RecipeTreeNodeResponse>,
+}
+
+/// Response structure for the total quantity of a raw product in a bill of materials.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct RecipeRawProductResponse {
+    /// The raw product
+    pub product: uuid::Uuid,
+
+    /// Unit of the product
+    pub unit: UnitResponse,
+
+    /// Total quantity of the product needed to make one unit of the recipe
+    pub quantity: f64,
+}
+
+/// Response structure for the total quantity of raw products counted in the same unit.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct RecipeUnitTotalResponse {
+    /// The unit of the products
+    pub unit: UnitResponse,
+
+    /// Total quantity of the raw products counted in this unit
+    pub quantity: f64,
+}
+
+/// Response structure for the bill of materials of a recipe.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "recipe": "1a731f58-18f1-4c95-8de5-611bde07f4f1",
+    "product": "2fa4c8d3-fd93-4066-a7f3-68a35ab72288",
+    "ingredients": [
+        {
+            "product": "afd0dac6-70b2-4752-a66f-d79437c53f01",
+            "unit": "unit",
+            "quantity": 2.0,
+            "recipe": "5b0e3f6a-4c1d-4e2b-9a8f-7d6c5b4a3f2e",
+            "ingredients": [
+                { "product": "f317ccf1-e196-4bd2-8fb0-106aa05aa899", "unit": "gram", "quantity": 300.0, "ingredients": [] },
+            ],
+        },
+        { "product": "c4d5e6f7-a8b9-4c0d-9e1f-2a3b4c5d6e7f", "unit": "gram", "quantity": 50.0, "ingredients": [] },
+    ],
+    "raw_products": [
+        { "product": "c4d5e6f7-a8b9-4c0d-9e1f-2a3b4c5d6e7f", "unit": "gram", "quantity": 50.0 },
+        { "product": "f317ccf1-e196-4bd2-8fb0-106aa05aa899", "unit": "gram", "quantity": 300.0 },
+    ],
+    "units": [
+        { "unit": "gram", "quantity": 350.0 },
+    ],
+}))]
+pub struct RecipeTreeResponse {
+    /// The expanded recipe
+    pub recipe: uuid::Uuid,
+
+    /// Resulting product of the recipe
+    pub product: uuid::Uuid,
+
+    /// Ingredients of the recipe, each expanded down to raw products
+    pub ingredients: Vec<RecipeTreeNodeResponse>,
+
+    /// Total quantity of each raw product needed
+    pub raw_products: Vec<RecipeRawProductResponse>,
+
+    /// Total quantity of the raw products needed, per unit
+    pub units: Vec<RecipeUnitTotalResponse>,
+}
@@ -0,0 +1,86 @@
+//! This module contains response structures for the balance reconciliation.
+//! It defines the format of data returned to clients regarding balance drifts.
+
+use rust_decimal::{Decimal, Error as DecimalError};
+
+use crate::{error::impl_from_error_to_string, models::currency::CurrencyCode};
+
+use super::r#enum::CurrencyResponse;
+
+/// Enum representing errors that can occur during reconcile response construction.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ReconcileResponseError {
+    /// Error when a balance could not be converted to a floating-point representation.
+    BalanceCannotBeConverted(Decimal, DecimalError),
+}
+
+impl std::error::Error for ReconcileResponseError {}
+
+/// Formats error messages for the `ReconcileResponseError` enum.
+impl std::fmt::Display for ReconcileResponseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BalanceCannotBeConverted(balance, err) => {
+                write!(f, "Balance \"{balance}\" cannot be converted in: {err}")
+            }
+        }
+    }
+}
+
+impl_from_error_to_string!(ReconcileResponseError, InternalError);
+
+/// Represents a user whose stored balance doesn't match the ledger.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct BalanceDriftResponse {
+    /// Unique identifier of the user.
+    pub user_id: uuid::Uuid,
+
+    /// Currency of the balance.
+    pub currency: CurrencyResponse,
+
+    /// Balance stored for the user.
+    pub balance: f64,
+
+    /// Balance computed from the completed transactions of the user.
+    pub expected: f64,
+
+    /// Difference between the stored and the computed balance.
+    pub drift: f64,
+}
+
+impl BalanceDriftResponse {
+    /// Builds the response from the stored and the computed balance of a user.
+    pub fn try_new(
+        user_id: uuid::Uuid,
+        currency: CurrencyCode,
+        balance: Decimal,
+        expected: Decimal,
+    ) -> Result<Self, ReconcileResponseError> {
+        let convert = |value: Decimal| {
+            value
+                .try_into()
+                .map_err(|err| ReconcileResponseError::BalanceCannotBeConverted(value, err))
+        };
+
+        Ok(Self {
+            user_id,
+            currency: currency.into(),
+            balance: convert(balance)?,
+            expected: convert(expected)?,
+            drift: convert(balance - expected)?,
+        })
+    }
+}
+
+/// Represents the outcome of a balance reconciliation returned by the API.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct ReconcileResponse {
+    /// The number of users checked.
+    pub checked: u64,
+
+    /// Users whose balance doesn't match the ledger.
+    pub drifts: Vec<BalanceDriftResponse>,
+
+    /// Whether the balances were rewritten from the ledger.
+    pub fixed: bool,
+}
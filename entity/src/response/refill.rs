@@ -121,3 +121,13 @@ pub struct RefillListResponse {
     /// A list of refill responses containing refill details.
     pub refills: Vec<RefillResponse>,
 }
+
+/// Represents the response returned when a user starts buying a refill.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct RefillPurchaseResponse {
+    /// Id of the transaction recording the purchase.
+    pub transaction_id: uuid::Uuid,
+
+    /// Where the user must be sent to pay.
+    pub checkout_url: String,
+}
@@ -0,0 +1,46 @@
+//! This module defines the response structures for report-related API responses.
+
+use crate::response::recipe::RecipeCostResponse;
+
+/// Response structure for the margin made on the product of a recipe.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct RecipeMarginResponse {
+    /// The costed recipe
+    pub recipe: uuid::Uuid,
+
+    /// Name of the recipe
+    pub name: Option<String>,
+
+    /// Resulting product of the recipe
+    pub product: uuid::Uuid,
+
+    /// Name of the resulting product
+    pub product_name: String,
+
+    /// Cost of the recipe and margin made selling its product
+    pub cost: RecipeCostResponse,
+}
+
+/// Response structure for the margin report of the enabled recipes.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "recipes": [
+        {
+            "recipe": "1a731f58-18f1-4c95-8de5-611bde07f4f1",
+            "name": "Recipe for The Cake",
+            "product": "2fa4c8d3-fd93-4066-a7f3-68a35ab72288",
+            "product_name": "The Cake",
+            "cost": {
+                "currency": "euro",
+                "cost": 1.35,
+                "sell_price": 2.5,
+                "margin": 1.15,
+                "margin_rate": 0.46
+            }
+        }
+    ]
+}))]
+pub struct MarginReportResponse {
+    /// Recipes from the lowest to the highest margin rate, the ones without margin rate last
+    pub recipes: Vec<RecipeMarginResponse>,
+}
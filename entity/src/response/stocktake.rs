@@ -0,0 +1,111 @@
+//! This module defines the response structures for stocktake-related API responses.
+
+use serde_with::skip_serializing_none;
+
+use crate::models::{stocktake, stocktake_count};
+
+use super::r#enum::StocktakeStatusResponse;
+
+/// Response structure for a stocktake, with its variance report when requested alone.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "id": "0c2a4b6e-8f10-4d3a-9b5c-7e1f2a3b4c5d",
+    "warehouse": "1a731f58-18f1-4c95-8de5-611bde07f4f1",
+    "status": "closed",
+    "author": "a2b1c3d4-5e6f-4a7b-8c9d-0e1f2a3b4c5d",
+    "closed_by": "a2b1c3d4-5e6f-4a7b-8c9d-0e1f2a3b4c5d",
+    "created_at": "2024-02-23T14:00:00Z",
+    "closed_at": "2024-02-23T16:30:00Z",
+    "products": [
+        {
+            "product": "afd0dac6-70b2-4752-a66f-d79437c53f01",
+            "counted_quantity": 10,
+            "expected_quantity": 12,
+            "variance": -2,
+        }
+    ],
+}))]
+pub struct StocktakeResponse {
+    /// Unique identifier for the stocktake.
+    pub id: uuid::Uuid,
+
+    /// The warehouse being counted.
+    pub warehouse: uuid::Uuid,
+
+    /// Whether the stocktake is open, closed or canceled.
+    pub status: StocktakeStatusResponse,
+
+    /// The user who opened the stocktake, if it still exists.
+    pub author: Option<uuid::Uuid>,
+
+    /// The user who closed or canceled the stocktake.
+    pub closed_by: Option<uuid::Uuid>,
+
+    /// The timestamp indicating when the stocktake was opened.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+
+    /// The timestamp indicating when the stocktake was closed or canceled.
+    pub closed_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// The counted products, only when a single stocktake is requested.
+    pub products: Option<Vec<StocktakeLineResponse>>,
+}
+
+impl From<stocktake::Model> for StocktakeResponse {
+    fn from(value: stocktake::Model) -> Self {
+        Self {
+            id: value.id,
+            warehouse: value.warehouse_id,
+            status: value.status.into(),
+            author: value.author_id,
+            closed_by: value.closed_by_id,
+            created_at: value.created_at.into(),
+            closed_at: value.closed_at.map(Into::into),
+            products: None,
+        }
+    }
+}
+
+/// Represent the count of a product, compared to the stock of the warehouse
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct StocktakeLineResponse {
+    /// The counted product.
+    pub product: uuid::Uuid,
+
+    /// The quantity found in the warehouse.
+    pub counted_quantity: rust_decimal::Decimal,
+
+    /// The quantity the warehouse held when the product was counted.
+    pub expected_quantity: rust_decimal::Decimal,
+
+    /// The counted quantity minus the expected one, negative when products are missing.
+    pub variance: rust_decimal::Decimal,
+}
+
+impl From<(stocktake_count::Model, rust_decimal::Decimal)> for StocktakeLineResponse {
+    /// Builds the line from a count and the current stock of its product, which is only used
+    /// when the count didn't record the expected quantity.
+    fn from((count, stock): (stocktake_count::Model, rust_decimal::Decimal)) -> Self {
+        let expected_quantity = count.expected_quantity.unwrap_or(stock);
+        Self {
+            product: count.product_id,
+            counted_quantity: count.quantity,
+            expected_quantity,
+            variance: count.quantity - expected_quantity,
+        }
+    }
+}
+
+/// Response structure for a list of stocktakes with pagination details.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct StocktakeListResponse {
+    /// Total number of pages available.
+    pub total_page: u64,
+
+    /// Current page number.
+    pub current_page: u64,
+
+    /// List of stocktakes on the current page.
+    pub stocktakes: Vec<StocktakeResponse>,
+}
@@ -0,0 +1,57 @@
+//! This module defines the response structures for supplier-related API responses.
+
+use serde_with::skip_serializing_none;
+
+use crate::models::supplier;
+
+/// Response structure for a supplier.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "id": "3f0c7b8e-2d4a-4c1e-9b6f-5a7d8e9f0a1b",
+    "name": "Metro",
+    "email": "orders@metro.example",
+    "disabled": false,
+    "created_at": "2024-02-23T14:00:00Z",
+}))]
+pub struct SupplierResponse {
+    /// Unique identifier for the supplier.
+    pub id: uuid::Uuid,
+
+    /// Name of the supplier.
+    pub name: String,
+
+    /// Address the purchase orders are sent to.
+    pub email: Option<String>,
+
+    /// Indicates whether the supplier is disabled.
+    pub disabled: bool,
+
+    /// The timestamp indicating when the supplier was created.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<supplier::Model> for SupplierResponse {
+    fn from(value: supplier::Model) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            email: value.email,
+            disabled: value.disabled,
+            created_at: value.created_at.into(),
+        }
+    }
+}
+
+/// Response structure for a list of suppliers with pagination details.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct SupplierListResponse {
+    /// Total number of pages available.
+    pub total_page: u64,
+
+    /// Current page number.
+    pub current_page: u64,
+
+    /// List of suppliers on the current page.
+    pub suppliers: Vec<SupplierResponse>,
+}
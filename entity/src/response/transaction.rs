@@ -0,0 +1,64 @@
+//! This module contains response structures for transaction-related API responses.
+//! It defines the format of data returned to clients regarding the ledger.
+
+use serde_with::skip_serializing_none;
+
+use super::r#enum::{TransactionStatusResponse, TransactionTypeResponse};
+
+/// Represents a response containing transaction information returned by the API.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "id": "8d2ac1b5-4c9c-4d87-b0c2-02b9a4c5e1f1",
+    "user_id": "3f5c5a0e-8c2e-4b5f-9a1c-0d3b2e6f7a8b",
+    "type": "purchase_with_balance",
+    "status": "completed",
+    "created_at": "2024-10-09T17:55:30.795279Z",
+    "completed_at": "2024-10-09T17:55:30.795279Z"
+}))]
+pub struct TransactionResponse {
+    /// Unique identifier for the transaction.
+    pub id: uuid::Uuid,
+
+    /// The user whose balance is affected by this transaction.
+    pub user_id: uuid::Uuid,
+
+    /// Kind of the transaction.
+    pub r#type: TransactionTypeResponse,
+
+    /// Current state of the transaction.
+    pub status: TransactionStatusResponse,
+
+    /// The timestamp indicating when the transaction was created.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+
+    /// The timestamp indicating when the transaction reached a final state.
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Converts a `Model` from the transaction module to a `TransactionResponse`.
+impl From<crate::models::transaction::Model> for TransactionResponse {
+    fn from(value: crate::models::transaction::Model) -> Self {
+        Self {
+            id: value.id,
+            user_id: value.user_id,
+            r#type: value.transaction_type.into(),
+            status: value.status.into(),
+            created_at: value.created_at.into(),
+            completed_at: value.completed_at.map(Into::into),
+        }
+    }
+}
+
+/// Represents a response containing a list of transactions returned by the API.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct TransactionListResponse {
+    /// The total number of pages available for transaction results.
+    pub total_page: u64,
+
+    /// The current page number being viewed.
+    pub current_page: u64,
+
+    /// A list of transaction responses containing transaction details.
+    pub transactions: Vec<TransactionResponse>,
+}
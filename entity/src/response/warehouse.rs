@@ -4,10 +4,17 @@ use serde_with::skip_serializing_none;
 
 use crate::{
     error::impl_from_error_to_string,
-    models::{product, warehouse, warehouse_product},
+    models::{
+        product, recipe, stock_movement, warehouse, warehouse_product, warehouse_product_batch,
+        warehouse_recipe,
+    },
 };
 
-use super::product::{ProductResponse, ProductResponseError};
+use super::{
+    r#enum::StockMovementReasonResponse,
+    product::{ProductResponse, ProductResponseError},
+    recipe::{RecipeResponse, RecipeResponseError},
+};
 
 /// Response structure for a warehouse, including its details.
 #[skip_serializing_none]
@@ -93,12 +100,25 @@ impl_from_error_to_string!(WarehouseProductResponseError, InternalError);
 #[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
 #[schema(example = json!({
     "quantity": 10.5,
+    "available_quantity": 14.5,
+    "min_quantity": 6,
+    "reorder_quantity": 24,
     "created_at": "2024-02-23T14:00:00Z",
 }))]
 pub struct WarehouseProductResponse {
     /// The product quantity in this warehouse
     pub quantity: rust_decimal::Decimal,
 
+    /// The quantity which can be sold right now from this warehouse, counting what its
+    /// recipes can make
+    pub available_quantity: rust_decimal::Decimal,
+
+    /// The quantity at or below which the product is running low
+    pub min_quantity: Option<rust_decimal::Decimal>,
+
+    /// The quantity to bring back when the product is running low
+    pub reorder_quantity: Option<rust_decimal::Decimal>,
+
     /// The product of this Warehouse
     pub product: ProductResponse,
 
@@ -106,15 +126,29 @@ pub struct WarehouseProductResponse {
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
-impl TryFrom<(warehouse_product::Model, product::Model)> for WarehouseProductResponse {
+impl
+    TryFrom<(
+        warehouse_product::Model,
+        product::Model,
+        rust_decimal::Decimal,
+    )> for WarehouseProductResponse
+{
     type Error = WarehouseProductResponseError;
 
-    /// Constructs a WarehouseResponse from a warehouse model, returning an error if conversion fails.
+    /// Constructs a WarehouseResponse from a warehouse model and the available quantity of its
+    /// product, returning an error if conversion fails.
     fn try_from(
-        (warehouse_product, product): (warehouse_product::Model, product::Model),
+        (warehouse_product, product, available_quantity): (
+            warehouse_product::Model,
+            product::Model,
+            rust_decimal::Decimal,
+        ),
     ) -> Result<Self, Self::Error> {
         Ok(Self {
             quantity: warehouse_product.quantity,
+            available_quantity,
+            min_quantity: warehouse_product.min_quantity,
+            reorder_quantity: warehouse_product.reorder_quantity,
             product: product
                 .try_into()
                 .map_err(WarehouseProductResponseError::ProductResponseError)?,
@@ -135,3 +169,239 @@ pub struct WarehouseProductsListResponse {
     /// Current page number.
     pub current_page: u64,
 }
+
+/// Enum representing errors that can occur during warehouse recipe response construction.
+#[derive(Debug, PartialEq, Clone)]
+pub enum WarehouseRecipeResponseError {
+    /// Error for the linked recipe
+    RecipeResponseError(RecipeResponseError),
+}
+impl std::error::Error for WarehouseRecipeResponseError {}
+
+impl std::fmt::Display for WarehouseRecipeResponseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RecipeResponseError(err) => {
+                write!(f, "Recipe Response Error: {err}")
+            }
+        }
+    }
+}
+impl_from_error_to_string!(WarehouseRecipeResponseError, InternalError);
+
+/// Represent a link between a Warehouse and a Recipe it can make
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "priority": 10,
+    "recipe": {
+        "id": "1a731f58-18f1-4c95-8de5-611bde07f4f1",
+        "product": "2fa4c8d3-fd93-4066-a7f3-68a35ab72288",
+        "name": "Recipe for The Cake",
+        "ingredients": [],
+        "disabled": false,
+        "created_at": "2024-02-23T14:00:00Z",
+    },
+    "created_at": "2024-02-23T14:00:00Z",
+}))]
+pub struct WarehouseRecipeResponse {
+    /// The priority of the recipe in this warehouse, the highest is tried first
+    pub priority: i32,
+
+    /// The recipe of this Warehouse
+    pub recipe: RecipeResponse,
+
+    /// The timestamp indicating when the link was created.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl TryFrom<(warehouse_recipe::Model, recipe::Model)> for WarehouseRecipeResponse {
+    type Error = WarehouseRecipeResponseError;
+
+    /// Constructs a WarehouseRecipeResponse from a warehouse recipe model, returning an error if conversion fails.
+    fn try_from(
+        (warehouse_recipe, recipe): (warehouse_recipe::Model, recipe::Model),
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            priority: warehouse_recipe.priority,
+            recipe: recipe
+                .try_into()
+                .map_err(WarehouseRecipeResponseError::RecipeResponseError)?,
+            created_at: warehouse_recipe.created_at.into(),
+        })
+    }
+}
+
+/// Represent the lists of recipes for this Warehouse
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct WarehouseRecipesListResponse {
+    /// The lists of recipes for this Warehouse
+    pub recipes: Vec<WarehouseRecipeResponse>,
+
+    /// Total number of pages available.
+    pub total_page: u64,
+
+    /// Current page number.
+    pub current_page: u64,
+}
+
+/// Represent a change of the stock of a product in a Warehouse
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "id": "0c2a4b6e-8f10-4d3a-9b5c-7e1f2a3b4c5d",
+    "product": "afd0dac6-70b2-4752-a66f-d79437c53f01",
+    "delta": -2.0,
+    "reason": "purchase",
+    "author": "a2b1c3d4-5e6f-4a7b-8c9d-0e1f2a3b4c5d",
+    "created_at": "2024-02-23T14:00:00Z",
+}))]
+pub struct StockMovementResponse {
+    /// Unique identifier for the movement.
+    pub id: uuid::Uuid,
+
+    /// The product whose stock changed.
+    pub product: uuid::Uuid,
+
+    /// Signed quantity added to the stock.
+    pub delta: rust_decimal::Decimal,
+
+    /// Why the stock changed.
+    pub reason: StockMovementReasonResponse,
+
+    /// The user who made the change, if it still exists.
+    pub author: Option<uuid::Uuid>,
+
+    /// The timestamp indicating when the stock changed.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<stock_movement::Model> for StockMovementResponse {
+    fn from(value: stock_movement::Model) -> Self {
+        Self {
+            id: value.id,
+            product: value.product_id,
+            delta: value.delta,
+            reason: value.reason.into(),
+            author: value.author_id,
+            created_at: value.created_at.into(),
+        }
+    }
+}
+
+/// Represent the history of the stock of this Warehouse
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct StockMovementListResponse {
+    /// The movements of the stock of this Warehouse
+    pub movements: Vec<StockMovementResponse>,
+
+    /// Total number of pages available.
+    pub total_page: u64,
+
+    /// Current page number.
+    pub current_page: u64,
+}
+
+/// Response structure for a batch of a product in a warehouse.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "id": "5c1f0e2a-7b3d-4e8f-9a6b-2d4c8e0f1a3b",
+    "warehouse": "1a731f58-18f1-4c95-8de5-611bde07f4f1",
+    "product": "afd0dac6-70b2-4752-a66f-d79437c53f01",
+    "quantity": 12,
+    "expires_at": "2024-06-30T00:00:00Z",
+    "received_at": "2024-02-23T14:00:00Z",
+}))]
+pub struct WarehouseProductBatchResponse {
+    /// Unique identifier for the batch.
+    pub id: uuid::Uuid,
+
+    /// The warehouse holding the batch.
+    pub warehouse: uuid::Uuid,
+
+    /// The product of the batch.
+    pub product: uuid::Uuid,
+
+    /// The quantity left in the batch.
+    pub quantity: rust_decimal::Decimal,
+
+    /// When the batch expires, if it does.
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// When the batch was received.
+    pub received_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<warehouse_product_batch::Model> for WarehouseProductBatchResponse {
+    fn from(value: warehouse_product_batch::Model) -> Self {
+        Self {
+            id: value.id,
+            warehouse: value.warehouse_id,
+            product: value.product_id,
+            quantity: value.quantity,
+            expires_at: value.expires_at.map(Into::into),
+            received_at: value.received_at.into(),
+        }
+    }
+}
+
+/// Represent the batches of a product in a Warehouse, the next one to be consumed first
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct WarehouseProductBatchesResponse {
+    /// The batches of the product
+    pub batches: Vec<WarehouseProductBatchResponse>,
+}
+
+/// Represent the batches of a Warehouse about to expire, the first to expire first
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct ExpiringBatchListResponse {
+    /// The batches expiring within the window
+    pub batches: Vec<WarehouseProductBatchResponse>,
+
+    /// Total number of pages available.
+    pub total_page: u64,
+
+    /// Current page number.
+    pub current_page: u64,
+}
+
+/// Represent the stock of a product after a transfer, in both warehouses
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+#[schema(example = json!({
+    "product": "afd0dac6-70b2-4752-a66f-d79437c53f01",
+    "from_quantity": 6.0,
+    "to_quantity": 24.0,
+}))]
+pub struct TransferredProductResponse {
+    /// The product moved.
+    pub product: uuid::Uuid,
+
+    /// The quantity left in the source warehouse.
+    pub from_quantity: rust_decimal::Decimal,
+
+    /// The quantity now in the destination warehouse.
+    pub to_quantity: rust_decimal::Decimal,
+}
+
+impl From<(warehouse_product::Model, warehouse_product::Model)> for TransferredProductResponse {
+    fn from((from, to): (warehouse_product::Model, warehouse_product::Model)) -> Self {
+        Self {
+            product: from.product_id,
+            from_quantity: from.quantity,
+            to_quantity: to.quantity,
+        }
+    }
+}
+
+/// Represent the outcome of a transfer between two Warehouses
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct TransferResponse {
+    /// The warehouse the products were taken from.
+    pub from: uuid::Uuid,
+
+    /// The warehouse the products were moved to.
+    pub to: uuid::Uuid,
+
+    /// The resulting stock of every product moved.
+    pub products: Vec<TransferredProductResponse>,
+}
@@ -27,3 +27,9 @@ pub struct SmaParams {
     /// Optional list of category IDs to filter API responses by specific categories.
     pub categories: Option<Vec<String>>,
 }
+
+/// Represents how long after an order its buyer can still cancel it.
+///
+/// Admins are not bound by it, they can cancel an order anytime.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrderCancelGracePeriod(pub std::time::Duration);
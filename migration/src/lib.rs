@@ -7,6 +7,19 @@ mod m20220101_000004_create_location_table;
 mod m20220101_000005_create_refill_table;
 mod m20220101_000006_create_recipe_table;
 mod m20220101_000007_create_warehouse_table;
+mod m20220101_000008_create_exchange_rate_table;
+mod m20220101_000009_currency_registry;
+mod m20220101_000010_create_stock_movement_table;
+mod m20220101_000011_add_warehouse_product_thresholds;
+mod m20220101_000012_add_warehouse_recipe_priority_index;
+mod m20220101_000013_create_stocktake_table;
+mod m20220101_000014_add_product_cost_price;
+mod m20220101_000015_create_location_warehouse_table;
+mod m20220101_000016_create_purchase_order_table;
+mod m20220101_000017_create_warehouse_product_batch_table;
+mod m20220101_000018_add_product_content;
+mod m20220101_000019_create_category_table;
+mod m20220101_999999_create_payments_table;
 
 pub struct Migrator;
 
@@ -21,6 +34,19 @@ impl MigratorTrait for Migrator {
             Box::new(m20220101_000005_create_refill_table::Migration),
             Box::new(m20220101_000006_create_recipe_table::Migration),
             Box::new(m20220101_000007_create_warehouse_table::Migration),
+            Box::new(m20220101_000008_create_exchange_rate_table::Migration),
+            Box::new(m20220101_000009_currency_registry::Migration),
+            Box::new(m20220101_000010_create_stock_movement_table::Migration),
+            Box::new(m20220101_000011_add_warehouse_product_thresholds::Migration),
+            Box::new(m20220101_000012_add_warehouse_recipe_priority_index::Migration),
+            Box::new(m20220101_000013_create_stocktake_table::Migration),
+            Box::new(m20220101_000014_add_product_cost_price::Migration),
+            Box::new(m20220101_000015_create_location_warehouse_table::Migration),
+            Box::new(m20220101_000016_create_purchase_order_table::Migration),
+            Box::new(m20220101_000017_create_warehouse_product_batch_table::Migration),
+            Box::new(m20220101_000018_add_product_content::Migration),
+            Box::new(m20220101_000019_create_category_table::Migration),
+            Box::new(m20220101_999999_create_payments_table::Migration),
         ]
     }
 }
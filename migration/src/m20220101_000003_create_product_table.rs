@@ -67,17 +67,18 @@ pub enum Product {
     // TODO: Gallery Images
     Name, // TODO: Change to i18n
     // TODO: Description / Details (i18n)
-    // TODO: Category
-    // TODO: Sub Category
+    CategoryId,
     // TODO: Tags
     DisplayOrder,
 
     SellPrice,
     SellPriceCurrency,
-    // TODO: BuyPrice,
-    // TODO: BuyPriceCurrency,
+    CostPrice,
+    CostPriceCurrency,
     MaxQuantityPerCommand,
     Unit,
+    ContentQuantity,
+    ContentUnit,
 
     Purchasable,
     Hidden,
@@ -128,6 +128,8 @@ pub enum WarehouseProduct {
     ProductId,
     Quantity,
     CreatedAt,
+    MinQuantity,
+    ReorderQuantity,
 }
 
 #[derive(DeriveIden)]
@@ -0,0 +1,62 @@
+use sea_orm::Iterable;
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20220101_000001_create_configuration_table::{Currency, CurrencyVariant};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ExchangeRate::Table)
+                    .if_not_exists()
+                    .col(uuid(ExchangeRate::Id).primary_key())
+                    .col(enumeration(
+                        ExchangeRate::FromCurrency,
+                        Currency,
+                        CurrencyVariant::iter(),
+                    ))
+                    .col(enumeration(
+                        ExchangeRate::ToCurrency,
+                        Currency,
+                        CurrencyVariant::iter(),
+                    ))
+                    .col(decimal_len(ExchangeRate::Rate, 16, 6))
+                    .col(timestamp_with_time_zone(ExchangeRate::EffectiveFrom))
+                    .col(
+                        timestamp_with_time_zone(ExchangeRate::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .index(
+                        Index::create()
+                            .col(ExchangeRate::FromCurrency)
+                            .col(ExchangeRate::ToCurrency)
+                            .col(ExchangeRate::EffectiveFrom)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ExchangeRate::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum ExchangeRate {
+    Table,
+    Id,
+    FromCurrency,
+    ToCurrency,
+    Rate,
+    EffectiveFrom,
+    CreatedAt,
+}
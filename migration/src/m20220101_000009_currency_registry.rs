@@ -0,0 +1,233 @@
+use extension::postgres::Type;
+use sea_orm::{DbBackend, Iterable};
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20220101_000001_create_configuration_table::{Currency as CurrencyType, CurrencyVariant},
+    m20220101_000002_create_user_table::User,
+    m20220101_000003_create_product_table::Product,
+    m20220101_000005_create_refill_table::Refill,
+    m20220101_000008_create_exchange_rate_table::ExchangeRate,
+};
+
+/// Maximum length of the code of a currency.
+pub const CURRENCY_CODE_LENGTH: u32 = 16;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let postgres = db.get_database_backend() == DbBackend::Postgres;
+
+        // The `currency` type has to go before the table, whose row type takes the same name.
+        if postgres {
+            for (table, column, nullable, default) in currency_columns() {
+                let mut column = if nullable {
+                    currency_code_null(column)
+                } else {
+                    currency_code(column)
+                };
+                if let Some(default) = default {
+                    column.default(default);
+                }
+
+                manager
+                    .alter_table(
+                        Table::alter()
+                            .table(table)
+                            .modify_column(&mut column)
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+
+            manager
+                .drop_type(Type::drop().name(CurrencyType).to_owned())
+                .await?;
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Currency::Table)
+                    .if_not_exists()
+                    .col(string_len(Currency::Code, CURRENCY_CODE_LENGTH).primary_key())
+                    .col(string(Currency::Symbol))
+                    .col(small_integer(Currency::DecimalPlaces).default(2))
+                    .col(boolean(Currency::IsInternal).default(false))
+                    .col(
+                        timestamp_with_time_zone(Currency::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .exec_stmt(
+                Query::insert()
+                    .into_table(Currency::Table)
+                    .columns([
+                        Currency::Code,
+                        Currency::Symbol,
+                        Currency::DecimalPlaces,
+                        Currency::IsInternal,
+                    ])
+                    .values_panic(["euro".into(), "€".into(), 2.into(), false.into()])
+                    .values_panic(["epicoin".into(), "Ep".into(), 2.into(), true.into()])
+                    .to_owned(),
+            )
+            .await?;
+
+        if postgres {
+            for (table, column, _, _) in currency_columns() {
+                manager
+                    .create_foreign_key(
+                        currency_foreign_key(table.clone(), column.clone())
+                            .name(foreign_key_name(&table, &column))
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let postgres = db.get_database_backend() == DbBackend::Postgres;
+
+        if postgres {
+            for (table, column, _, _) in currency_columns() {
+                manager
+                    .drop_foreign_key(
+                        ForeignKey::drop()
+                            .name(foreign_key_name(&table, &column))
+                            .table(table)
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+        }
+
+        manager
+            .drop_table(Table::drop().table(Currency::Table).to_owned())
+            .await?;
+
+        if postgres {
+            manager
+                .create_type(
+                    Type::create()
+                        .as_enum(CurrencyType)
+                        .values(CurrencyVariant::iter())
+                        .to_owned(),
+                )
+                .await?;
+
+            // The codes are cast back to the enum, which the schema builder can't express.
+            for (table, column, _, default) in currency_columns() {
+                let (table, column) = (table.to_string(), column.to_string());
+                db.execute_unprepared(&format!(
+                    r#"ALTER TABLE "{table}" ALTER COLUMN "{column}" DROP DEFAULT, ALTER COLUMN "{column}" TYPE currency USING "{column}"::currency"#
+                ))
+                .await?;
+
+                if let Some(default) = default {
+                    db.execute_unprepared(&format!(
+                        r#"ALTER TABLE "{table}" ALTER COLUMN "{column}" SET DEFAULT '{default}'"#
+                    ))
+                    .await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Every column holding a currency before the registry: its table, whether it is nullable and
+/// its default.
+fn currency_columns() -> Vec<(DynIden, DynIden, bool, Option<&'static str>)> {
+    vec![
+        (
+            User::Table.into_iden(),
+            User::BalanceCurrency.into_iden(),
+            false,
+            None,
+        ),
+        (
+            Product::Table.into_iden(),
+            Product::SellPriceCurrency.into_iden(),
+            true,
+            Some("euro"),
+        ),
+        (
+            Refill::Table.into_iden(),
+            Refill::PriceCurrency.into_iden(),
+            false,
+            None,
+        ),
+        (
+            Refill::Table.into_iden(),
+            Refill::CreditCurrency.into_iden(),
+            false,
+            None,
+        ),
+        (
+            ExchangeRate::Table.into_iden(),
+            ExchangeRate::FromCurrency.into_iden(),
+            false,
+            None,
+        ),
+        (
+            ExchangeRate::Table.into_iden(),
+            ExchangeRate::ToCurrency.into_iden(),
+            false,
+            None,
+        ),
+    ]
+}
+
+/// Name of the foreign key added from the currency `column` of `table`.
+fn foreign_key_name(table: &DynIden, column: &DynIden) -> String {
+    format!("fk_{}_{}", table.to_string(), column.to_string())
+}
+
+/// Column holding the code of a currency of the `currency` table.
+pub fn currency_code<T: IntoIden>(column: T) -> ColumnDef {
+    string_len(column, CURRENCY_CODE_LENGTH)
+}
+
+/// Column holding the code of a currency of the `currency` table, or nothing.
+pub fn currency_code_null<T: IntoIden>(column: T) -> ColumnDef {
+    string_len_null(column, CURRENCY_CODE_LENGTH)
+}
+
+/// Foreign key from the currency `column` of `table` to the `currency` table.
+///
+/// A currency cannot be deleted while something still refers to it.
+pub fn currency_foreign_key<T: IntoIden + 'static, C: IntoIden + 'static>(
+    table: T,
+    column: C,
+) -> ForeignKeyCreateStatement {
+    ForeignKey::create()
+        .from(table, column)
+        .to(Currency::Table, Currency::Code)
+        .on_delete(ForeignKeyAction::Restrict)
+        .on_update(ForeignKeyAction::Cascade)
+        .to_owned()
+}
+
+#[derive(DeriveIden)]
+pub enum Currency {
+    Table,
+    Code,
+    Symbol,
+    DecimalPlaces,
+    IsInternal,
+    CreatedAt,
+}
@@ -0,0 +1,118 @@
+use sea_orm::{DbBackend, EnumIter, Iterable};
+use sea_orm_migration::{
+    prelude::{extension::postgres::Type, *},
+    schema::*,
+};
+
+use crate::{
+    m20220101_000002_create_user_table::User, m20220101_000003_create_product_table::Product,
+    m20220101_000007_create_warehouse_table::Warehouse,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        match db.get_database_backend() {
+            DbBackend::MySql | DbBackend::Sqlite => {}
+            DbBackend::Postgres => {
+                manager
+                    .create_type(
+                        Type::create()
+                            .as_enum(StockMovementReason)
+                            .values(StockMovementReasonVariant::iter())
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(StockMovement::Table)
+                    .if_not_exists()
+                    .col(uuid(StockMovement::Id).primary_key())
+                    .col(uuid(StockMovement::WarehouseId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockMovement::Table, StockMovement::WarehouseId)
+                            .to(Warehouse::Table, Warehouse::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(uuid(StockMovement::ProductId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockMovement::Table, StockMovement::ProductId)
+                            .to(Product::Table, Product::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(decimal_len(StockMovement::Delta, 10, 2))
+                    .col(enumeration(
+                        StockMovement::Reason,
+                        StockMovementReason,
+                        StockMovementReasonVariant::iter(),
+                    ))
+                    .col(uuid_null(StockMovement::AuthorId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockMovement::Table, StockMovement::AuthorId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(
+                        timestamp_with_time_zone(StockMovement::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StockMovement::Table).to_owned())
+            .await?;
+
+        let db = manager.get_connection();
+        match db.get_database_backend() {
+            DbBackend::MySql | DbBackend::Sqlite => {}
+            DbBackend::Postgres => {
+                manager
+                    .drop_type(Type::drop().name(StockMovementReason).to_owned())
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum StockMovement {
+    Table,
+    Id,
+    WarehouseId,
+    ProductId,
+    Delta,
+    Reason,
+    AuthorId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub struct StockMovementReason;
+
+#[derive(DeriveIden, EnumIter)]
+pub enum StockMovementReasonVariant {
+    Purchase,
+    Restock,
+    Correction,
+    RecipeConsumption,
+    Transfer,
+}
@@ -0,0 +1,33 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20220101_000007_create_warehouse_table::WarehouseProduct;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WarehouseProduct::Table)
+                    .add_column(decimal_len_null(WarehouseProduct::MinQuantity, 10, 2))
+                    .add_column(decimal_len_null(WarehouseProduct::ReorderQuantity, 10, 2))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WarehouseProduct::Table)
+                    .drop_column(WarehouseProduct::MinQuantity)
+                    .drop_column(WarehouseProduct::ReorderQuantity)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,62 @@
+use sea_orm::DbBackend;
+use sea_orm_migration::prelude::*;
+
+use crate::m20220101_000007_create_warehouse_table::WarehouseRecipe;
+
+/// Name of the index keeping the priorities of the recipes of a warehouse unique.
+const INDEX_NAME: &str = "idx_warehouse_recipe_warehouse_id_priority";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Links sharing a priority in a warehouse are renumbered first, keeping their order.
+        let db = manager.get_connection();
+        match db.get_database_backend() {
+            DbBackend::MySql | DbBackend::Sqlite => {}
+            DbBackend::Postgres => {
+                db.execute_unprepared(
+                    r#"UPDATE "warehouse_recipe" AS "link" SET "priority" = "ranked"."priority"
+                    FROM (
+                        SELECT "warehouse_id", "recipe_id", (ROW_NUMBER() OVER (
+                            PARTITION BY "warehouse_id" ORDER BY "priority", "created_at", "recipe_id"
+                        ) - 1)::integer AS "priority"
+                        FROM "warehouse_recipe"
+                        WHERE "warehouse_id" IN (
+                            SELECT "warehouse_id" FROM "warehouse_recipe"
+                            GROUP BY "warehouse_id", "priority" HAVING COUNT(*) > 1
+                        )
+                    ) AS "ranked"
+                    WHERE "link"."warehouse_id" = "ranked"."warehouse_id"
+                        AND "link"."recipe_id" = "ranked"."recipe_id""#,
+                )
+                .await?;
+            }
+        }
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(INDEX_NAME)
+                    .table(WarehouseRecipe::Table)
+                    .col(WarehouseRecipe::WarehouseId)
+                    .col(WarehouseRecipe::Priority)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(INDEX_NAME)
+                    .table(WarehouseRecipe::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
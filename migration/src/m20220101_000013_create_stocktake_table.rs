@@ -0,0 +1,186 @@
+use sea_orm::{DbBackend, EnumIter, Iterable};
+use sea_orm_migration::{
+    prelude::{extension::postgres::Type, *},
+    schema::*,
+};
+
+use crate::{
+    m20220101_000002_create_user_table::User, m20220101_000003_create_product_table::Product,
+    m20220101_000007_create_warehouse_table::Warehouse,
+    m20220101_000010_create_stock_movement_table::StockMovementReason,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        match db.get_database_backend() {
+            DbBackend::MySql | DbBackend::Sqlite => {}
+            DbBackend::Postgres => {
+                manager
+                    .create_type(
+                        Type::create()
+                            .as_enum(StocktakeStatus)
+                            .values(StocktakeStatusVariant::iter())
+                            .to_owned(),
+                    )
+                    .await?;
+
+                // Closing a stocktake is a new reason for a stock movement. The value is kept when
+                // migrating down, so it may already exist.
+                db.execute_unprepared(&format!(
+                    r#"ALTER TYPE "{}" ADD VALUE IF NOT EXISTS 'stocktake'"#,
+                    StockMovementReason.to_string()
+                ))
+                .await?;
+            }
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Stocktake::Table)
+                    .if_not_exists()
+                    .col(uuid(Stocktake::Id).primary_key())
+                    .col(uuid(Stocktake::WarehouseId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Stocktake::Table, Stocktake::WarehouseId)
+                            .to(Warehouse::Table, Warehouse::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(enumeration(
+                        Stocktake::Status,
+                        StocktakeStatus,
+                        StocktakeStatusVariant::iter(),
+                    ))
+                    .col(uuid_null(Stocktake::AuthorId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Stocktake::Table, Stocktake::AuthorId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(uuid_null(Stocktake::ClosedById))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Stocktake::Table, Stocktake::ClosedById)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(
+                        timestamp_with_time_zone(Stocktake::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(timestamp_with_time_zone_null(Stocktake::ClosedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(StocktakeCount::Table)
+                    .if_not_exists()
+                    .col(uuid(StocktakeCount::StocktakeId))
+                    .col(uuid(StocktakeCount::ProductId))
+                    .primary_key(
+                        Index::create()
+                            .col(StocktakeCount::StocktakeId)
+                            .col(StocktakeCount::ProductId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StocktakeCount::Table, StocktakeCount::StocktakeId)
+                            .to(Stocktake::Table, Stocktake::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StocktakeCount::Table, StocktakeCount::ProductId)
+                            .to(Product::Table, Product::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(decimal_len(StocktakeCount::Quantity, 10, 2))
+                    .col(decimal_len_null(StocktakeCount::ExpectedQuantity, 10, 2))
+                    .col(uuid_null(StocktakeCount::AuthorId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StocktakeCount::Table, StocktakeCount::AuthorId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(
+                        timestamp_with_time_zone(StocktakeCount::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StocktakeCount::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Stocktake::Table).to_owned())
+            .await?;
+
+        let db = manager.get_connection();
+        match db.get_database_backend() {
+            DbBackend::MySql | DbBackend::Sqlite => {}
+            DbBackend::Postgres => {
+                manager
+                    .drop_type(Type::drop().name(StocktakeStatus).to_owned())
+                    .await?;
+
+                // The `stocktake` reason stays: an enum value can't be removed.
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Stocktake {
+    Table,
+    Id,
+    WarehouseId,
+    Status,
+    AuthorId,
+    ClosedById,
+    CreatedAt,
+    ClosedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum StocktakeCount {
+    Table,
+    StocktakeId,
+    ProductId,
+    Quantity,
+    ExpectedQuantity,
+    AuthorId,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub struct StocktakeStatus;
+
+#[derive(DeriveIden, EnumIter)]
+pub enum StocktakeStatusVariant {
+    Open,
+    Closed,
+    Canceled,
+}
@@ -0,0 +1,45 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20220101_000003_create_product_table::Product,
+    m20220101_000009_currency_registry::{currency_code_null, currency_foreign_key},
+};
+
+/// Name of the foreign key from the cost price currency to the `currency` table.
+const FOREIGN_KEY_NAME: &str = "fk_product_cost_price_currency";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Product::Table)
+                    .add_column(decimal_len_null(Product::CostPrice, 10, 2))
+                    .add_column(currency_code_null(Product::CostPriceCurrency))
+                    .add_foreign_key(
+                        currency_foreign_key(Product::Table, Product::CostPriceCurrency)
+                            .name(FOREIGN_KEY_NAME)
+                            .get_foreign_key(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Product::Table)
+                    .drop_foreign_key(Alias::new(FOREIGN_KEY_NAME))
+                    .drop_column(Product::CostPrice)
+                    .drop_column(Product::CostPriceCurrency)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,70 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20220101_000004_create_location_table::Location,
+    m20220101_000007_create_warehouse_table::Warehouse,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(LocationWarehouse::Table)
+                    .if_not_exists()
+                    .col(uuid(LocationWarehouse::LocationId))
+                    .col(uuid(LocationWarehouse::WarehouseId))
+                    .col(integer(LocationWarehouse::Priority).default(0))
+                    .col(
+                        timestamp_with_time_zone(LocationWarehouse::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(LocationWarehouse::LocationId)
+                            .col(LocationWarehouse::WarehouseId),
+                    )
+                    .index(
+                        Index::create()
+                            .col(LocationWarehouse::LocationId)
+                            .col(LocationWarehouse::Priority)
+                            .unique(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(LocationWarehouse::Table, LocationWarehouse::LocationId)
+                            .to(Location::Table, Location::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(LocationWarehouse::Table, LocationWarehouse::WarehouseId)
+                            .to(Warehouse::Table, Warehouse::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(LocationWarehouse::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum LocationWarehouse {
+    Table,
+    LocationId,
+    WarehouseId,
+    Priority,
+    CreatedAt,
+}
@@ -0,0 +1,210 @@
+use sea_orm::{DbBackend, EnumIter, Iterable};
+use sea_orm_migration::{
+    prelude::{extension::postgres::Type, *},
+    schema::*,
+};
+
+use crate::{
+    m20220101_000002_create_user_table::User,
+    m20220101_000003_create_product_table::Product,
+    m20220101_000007_create_warehouse_table::Warehouse,
+    m20220101_000009_currency_registry::{currency_code, currency_foreign_key},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        match db.get_database_backend() {
+            DbBackend::MySql | DbBackend::Sqlite => {}
+            DbBackend::Postgres => {
+                manager
+                    .create_type(
+                        Type::create()
+                            .as_enum(PurchaseOrderStatus)
+                            .values(PurchaseOrderStatusVariant::iter())
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Supplier::Table)
+                    .if_not_exists()
+                    .col(uuid(Supplier::Id).primary_key())
+                    .col(string(Supplier::Name))
+                    .col(string_null(Supplier::Email))
+                    .col(boolean(Supplier::Disabled).default(false))
+                    .col(
+                        timestamp_with_time_zone(Supplier::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(PurchaseOrder::Table)
+                    .if_not_exists()
+                    .col(uuid(PurchaseOrder::Id).primary_key())
+                    .col(uuid(PurchaseOrder::SupplierId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PurchaseOrder::Table, PurchaseOrder::SupplierId)
+                            .to(Supplier::Table, Supplier::Id)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(uuid(PurchaseOrder::WarehouseId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PurchaseOrder::Table, PurchaseOrder::WarehouseId)
+                            .to(Warehouse::Table, Warehouse::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(enumeration(
+                        PurchaseOrder::Status,
+                        PurchaseOrderStatus,
+                        PurchaseOrderStatusVariant::iter(),
+                    ))
+                    .col(currency_code(PurchaseOrder::Currency))
+                    .foreign_key(&mut currency_foreign_key(
+                        PurchaseOrder::Table,
+                        PurchaseOrder::Currency,
+                    ))
+                    .col(uuid_null(PurchaseOrder::AuthorId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PurchaseOrder::Table, PurchaseOrder::AuthorId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(
+                        timestamp_with_time_zone(PurchaseOrder::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(timestamp_with_time_zone_null(PurchaseOrder::SentAt))
+                    .col(timestamp_with_time_zone_null(PurchaseOrder::ReceivedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(PurchaseOrderLine::Table)
+                    .if_not_exists()
+                    .col(uuid(PurchaseOrderLine::PurchaseOrderId))
+                    .col(uuid(PurchaseOrderLine::ProductId))
+                    .primary_key(
+                        Index::create()
+                            .col(PurchaseOrderLine::PurchaseOrderId)
+                            .col(PurchaseOrderLine::ProductId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PurchaseOrderLine::Table, PurchaseOrderLine::PurchaseOrderId)
+                            .to(PurchaseOrder::Table, PurchaseOrder::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PurchaseOrderLine::Table, PurchaseOrderLine::ProductId)
+                            .to(Product::Table, Product::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(decimal_len(PurchaseOrderLine::Quantity, 10, 2))
+                    .col(
+                        decimal_len(PurchaseOrderLine::ReceivedQuantity, 10, 2)
+                            .default(sea_orm::prelude::Decimal::ZERO),
+                    )
+                    .col(decimal_len(PurchaseOrderLine::UnitCost, 10, 2))
+                    .col(timestamp_with_time_zone_null(PurchaseOrderLine::ExpectedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PurchaseOrderLine::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(PurchaseOrder::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Supplier::Table).to_owned())
+            .await?;
+
+        let db = manager.get_connection();
+        match db.get_database_backend() {
+            DbBackend::MySql | DbBackend::Sqlite => {}
+            DbBackend::Postgres => {
+                manager
+                    .drop_type(Type::drop().name(PurchaseOrderStatus).to_owned())
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Supplier {
+    Table,
+    Id,
+    Name,
+    Email,
+    Disabled,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum PurchaseOrder {
+    Table,
+    Id,
+    SupplierId,
+    WarehouseId,
+    Status,
+    Currency,
+    AuthorId,
+    CreatedAt,
+    SentAt,
+    ReceivedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum PurchaseOrderLine {
+    Table,
+    PurchaseOrderId,
+    ProductId,
+    Quantity,
+    ReceivedQuantity,
+    UnitCost,
+    ExpectedAt,
+}
+
+#[derive(DeriveIden)]
+pub struct PurchaseOrderStatus;
+
+#[derive(DeriveIden, EnumIter)]
+pub enum PurchaseOrderStatusVariant {
+    Draft,
+    Sent,
+    PartiallyReceived,
+    Received,
+}
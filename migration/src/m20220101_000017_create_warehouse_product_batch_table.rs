@@ -0,0 +1,59 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20220101_000007_create_warehouse_table::WarehouseProduct;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WarehouseProductBatch::Table)
+                    .if_not_exists()
+                    .col(uuid(WarehouseProductBatch::Id).primary_key())
+                    .col(uuid(WarehouseProductBatch::WarehouseId))
+                    .col(uuid(WarehouseProductBatch::ProductId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_tbl(WarehouseProductBatch::Table)
+                            .from_col(WarehouseProductBatch::WarehouseId)
+                            .from_col(WarehouseProductBatch::ProductId)
+                            .to_tbl(WarehouseProduct::Table)
+                            .to_col(WarehouseProduct::WarehouseId)
+                            .to_col(WarehouseProduct::ProductId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(decimal_len(WarehouseProductBatch::Quantity, 10, 2))
+                    .col(timestamp_with_time_zone_null(
+                        WarehouseProductBatch::ExpiresAt,
+                    ))
+                    .col(
+                        timestamp_with_time_zone(WarehouseProductBatch::ReceivedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WarehouseProductBatch::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum WarehouseProductBatch {
+    Table,
+    Id,
+    WarehouseId,
+    ProductId,
+    Quantity,
+    ExpiresAt,
+    ReceivedAt,
+}
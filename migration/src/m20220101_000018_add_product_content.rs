@@ -0,0 +1,41 @@
+use sea_orm::Iterable;
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20220101_000001_create_configuration_table::{Unit, UnitVariant},
+    m20220101_000003_create_product_table::Product,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Product::Table)
+                    .add_column(decimal_len_null(Product::ContentQuantity, 10, 3))
+                    .add_column(enumeration_null(
+                        Product::ContentUnit,
+                        Unit,
+                        UnitVariant::iter(),
+                    ))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Product::Table)
+                    .drop_column(Product::ContentQuantity)
+                    .drop_column(Product::ContentUnit)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,86 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20220101_000003_create_product_table::Product;
+
+/// Name of the foreign key from the category of a product to the `category` table.
+const FOREIGN_KEY_NAME: &str = "fk_product_category_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Category::Table)
+                    .if_not_exists()
+                    .col(uuid(Category::Id).primary_key())
+                    .col(string(Category::Name))
+                    .col(string_null(Category::Code).unique_key())
+                    .col(uuid_null(Category::ParentId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Category::Table, Category::ParentId)
+                            .to(Category::Table, Category::Id)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(
+                        timestamp_with_time_zone(Category::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(string_null(Category::SmaId).unique_key())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Product::Table)
+                    .add_column(uuid_null(Product::CategoryId))
+                    .add_foreign_key(
+                        ForeignKey::create()
+                            .name(FOREIGN_KEY_NAME)
+                            .from(Product::Table, Product::CategoryId)
+                            .to(Category::Table, Category::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .get_foreign_key(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Product::Table)
+                    .drop_foreign_key(Alias::new(FOREIGN_KEY_NAME))
+                    .drop_column(Product::CategoryId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Category::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Category {
+    Table,
+    Id,
+    Name,
+    Code,
+    ParentId,
+    CreatedAt,
+
+    // SMA
+    SmaId,
+}
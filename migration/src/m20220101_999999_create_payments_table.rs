@@ -5,9 +5,13 @@ use sea_orm_migration::{
 };
 
 use crate::{
-    m20220101_000001_create_configuration_table::{Currency, CurrencyVariant},
     m20220101_000002_create_user_table::User,
+    m20220101_000003_create_product_table::Product,
+    m20220101_000004_create_location_table::Location,
     m20220101_000005_create_refill_table::Refill,
+    m20220101_000006_create_recipe_table::Recipe,
+    m20220101_000007_create_warehouse_table::Warehouse,
+    m20220101_000009_currency_registry::{currency_code, currency_foreign_key},
 };
 
 #[derive(DeriveMigrationName)]
@@ -37,6 +41,15 @@ impl MigrationTrait for Migration {
                             .to_owned(),
                     )
                     .await?;
+
+                manager
+                    .create_type(
+                        Type::create()
+                            .as_enum(FulfilmentStatus)
+                            .values(FulfilmentStatusVariant::iter())
+                            .to_owned(),
+                    )
+                    .await?;
             }
         }
 
@@ -101,17 +114,18 @@ impl MigrationTrait for Migration {
                             .on_update(ForeignKeyAction::Cascade),
                     )
                     .col(decimal_len(TransactionRefillBalance::Price, 10, 2))
-                    .col(enumeration(
+                    .col(currency_code(TransactionRefillBalance::PriceCurrency))
+                    .foreign_key(&mut currency_foreign_key(
+                        TransactionRefillBalance::Table,
                         TransactionRefillBalance::PriceCurrency,
-                        Currency,
-                        CurrencyVariant::iter(),
                     ))
                     .col(decimal_len(TransactionRefillBalance::Credit, 10, 2))
-                    .col(enumeration(
+                    .col(currency_code(TransactionRefillBalance::CreditCurrency))
+                    .foreign_key(&mut currency_foreign_key(
+                        TransactionRefillBalance::Table,
                         TransactionRefillBalance::CreditCurrency,
-                        Currency,
-                        CurrencyVariant::iter(),
                     ))
+                    .col(string_null(TransactionRefillBalance::StripeTransactionId))
                     .to_owned(),
             )
             .await?;
@@ -132,6 +146,197 @@ impl MigrationTrait for Migration {
                             .on_delete(ForeignKeyAction::Cascade)
                             .on_update(ForeignKeyAction::Cascade),
                     )
+                    .col(decimal_len(
+                        TransactionPurchaseWithBalance::PriceTotal,
+                        10,
+                        2,
+                    ))
+                    .col(currency_code(
+                        TransactionPurchaseWithBalance::PriceTotalCurrency,
+                    ))
+                    .foreign_key(&mut currency_foreign_key(
+                        TransactionPurchaseWithBalance::Table,
+                        TransactionPurchaseWithBalance::PriceTotalCurrency,
+                    ))
+                    .col(uuid_null(TransactionPurchaseWithBalance::LocationId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                TransactionPurchaseWithBalance::Table,
+                                TransactionPurchaseWithBalance::LocationId,
+                            )
+                            .to(Location::Table, Location::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(enumeration_null(
+                        TransactionPurchaseWithBalance::FulfilmentStatus,
+                        FulfilmentStatus,
+                        FulfilmentStatusVariant::iter(),
+                    ))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TransactionProduct::Table)
+                    .if_not_exists()
+                    .col(uuid(TransactionProduct::Id).primary_key())
+                    .col(uuid(TransactionProduct::TransactionId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TransactionProduct::Table, TransactionProduct::TransactionId)
+                            .to(
+                                TransactionPurchaseWithBalance::Table,
+                                TransactionPurchaseWithBalance::Id,
+                            )
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(uuid_null(TransactionProduct::ProductId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TransactionProduct::Table, TransactionProduct::ProductId)
+                            .to(Product::Table, Product::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(string(TransactionProduct::Name))
+                    .col(integer(TransactionProduct::Quantity))
+                    .col(decimal_len(TransactionProduct::Price, 10, 2))
+                    .col(currency_code(TransactionProduct::PriceCurrency))
+                    .foreign_key(&mut currency_foreign_key(
+                        TransactionProduct::Table,
+                        TransactionProduct::PriceCurrency,
+                    ))
+                    .col(uuid_null(TransactionProduct::WarehouseId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TransactionProduct::Table, TransactionProduct::WarehouseId)
+                            .to(Warehouse::Table, Warehouse::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(uuid_null(TransactionProduct::RecipeId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TransactionProduct::Table, TransactionProduct::RecipeId)
+                            .to(Recipe::Table, Recipe::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TransactionProductStock::Table)
+                    .if_not_exists()
+                    .col(uuid(TransactionProductStock::Id).primary_key())
+                    .col(uuid(TransactionProductStock::TransactionProductId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                TransactionProductStock::Table,
+                                TransactionProductStock::TransactionProductId,
+                            )
+                            .to(TransactionProduct::Table, TransactionProduct::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(uuid(TransactionProductStock::ProductId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                TransactionProductStock::Table,
+                                TransactionProductStock::ProductId,
+                            )
+                            .to(Product::Table, Product::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(decimal_len(TransactionProductStock::Quantity, 10, 2))
+                    .col(timestamp_with_time_zone_null(
+                        TransactionProductStock::ExpiresAt,
+                    ))
+                    .col(timestamp_with_time_zone_null(
+                        TransactionProductStock::ReceivedAt,
+                    ))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TransactionBalanceEdit::Table)
+                    .if_not_exists()
+                    .col(uuid(TransactionBalanceEdit::Id).primary_key())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TransactionBalanceEdit::Table, TransactionBalanceEdit::Id)
+                            .to(Transaction::Table, Transaction::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(uuid_null(TransactionBalanceEdit::AuthorId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                TransactionBalanceEdit::Table,
+                                TransactionBalanceEdit::AuthorId,
+                            )
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(decimal_len(TransactionBalanceEdit::Delta, 10, 2))
+                    .col(currency_code(TransactionBalanceEdit::DeltaCurrency))
+                    .foreign_key(&mut currency_foreign_key(
+                        TransactionBalanceEdit::Table,
+                        TransactionBalanceEdit::DeltaCurrency,
+                    ))
+                    .col(string(TransactionBalanceEdit::Reason))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TransactionRefund::Table)
+                    .if_not_exists()
+                    .col(uuid(TransactionRefund::Id).primary_key())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TransactionRefund::Table, TransactionRefund::Id)
+                            .to(Transaction::Table, Transaction::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(uuid_null(TransactionRefund::AuthorId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TransactionRefund::Table, TransactionRefund::AuthorId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(decimal_len(TransactionRefund::Amount, 10, 2))
+                    .col(currency_code(TransactionRefund::AmountCurrency))
+                    .foreign_key(&mut currency_foreign_key(
+                        TransactionRefund::Table,
+                        TransactionRefund::AmountCurrency,
+                    ))
+                    .col(string(TransactionRefund::Reason))
+                    .col(
+                        timestamp_with_time_zone(TransactionRefund::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
                     .to_owned(),
             )
             .await
@@ -139,8 +344,29 @@ impl MigrationTrait for Migration {
 
     async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
         manager
-            .drop_table(Table::drop().table(Transaction::Table).to_owned())
+            .drop_table(Table::drop().table(TransactionRefund::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(TransactionBalanceEdit::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(TransactionProductStock::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(TransactionProduct::Table).to_owned())
             .await?;
+
         manager
             .drop_table(
                 Table::drop()
@@ -157,6 +383,10 @@ impl MigrationTrait for Migration {
             )
             .await?;
 
+        manager
+            .drop_table(Table::drop().table(Transaction::Table).to_owned())
+            .await?;
+
         let db = manager.get_connection();
         match db.get_database_backend() {
             DbBackend::MySql | DbBackend::Sqlite => {}
@@ -167,6 +397,9 @@ impl MigrationTrait for Migration {
                 manager
                     .drop_type(Type::drop().name(TransactionStatus).to_owned())
                     .await?;
+                manager
+                    .drop_type(Type::drop().name(FulfilmentStatus).to_owned())
+                    .await?;
             }
         }
         Ok(())
@@ -230,13 +463,64 @@ pub enum TransactionPurchaseWithBalance {
 
     PriceTotal,
     PriceTotalCurrency,
+
+    LocationId,
+    FulfilmentStatus,
 }
 
+#[derive(DeriveIden)]
+pub struct FulfilmentStatus;
+
+#[derive(DeriveIden, EnumIter)]
+pub enum FulfilmentStatusVariant {
+    Queued,
+    Preparing,
+    Ready,
+    Delivered,
+}
+
+#[derive(DeriveIden)]
 pub enum TransactionProduct {
     Table,
     Id,
+    TransactionId,
     ProductId,
     Name,
+    Quantity,
     Price,
     PriceCurrency,
+    WarehouseId,
+    RecipeId,
+}
+
+#[derive(DeriveIden)]
+pub enum TransactionProductStock {
+    Table,
+    Id,
+    TransactionProductId,
+    ProductId,
+    Quantity,
+    ExpiresAt,
+    ReceivedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum TransactionBalanceEdit {
+    Table,
+    Id,
+    AuthorId,
+    Delta,
+    DeltaCurrency,
+    Reason,
+}
+
+#[derive(DeriveIden)]
+pub enum TransactionRefund {
+    Table,
+    Id,
+    AuthorId,
+    Amount,
+    AmountCurrency,
+    Reason,
+    CreatedAt,
 }
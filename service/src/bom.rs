@@ -0,0 +1,305 @@
+//! Bill of materials of the recipes of the `scrounch_backend` application.
+//!
+//! An ingredient of a recipe may itself be the result of another recipe, so the recipes form a
+//! graph between products: a recipe links its resulting product to each of its ingredients.
+//! This graph must stay free of cycles, an ingredient can never require the product it is used
+//! for, see [`find_cycle`]. Every recipe and ingredient is part of the graph, even disabled, so
+//! enabling one back can't introduce a cycle.
+//!
+//! Expanding a recipe down to the products without any recipe gives its bill of materials, see
+//! [`expand`]. Only the enabled recipes and ingredients are used, and a product made by several
+//! recipes is expanded with the oldest one.
+
+use ::entity::models::{
+    prelude::{Product, Recipe, RecipeIngredients},
+    product, recipe, recipe_ingredients,
+    sea_orm_active_enums::Unit,
+};
+use sea_orm::{prelude::Decimal, *};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// An ingredient of a bill of materials, with the ingredients it is made of.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BomNode {
+    /// The product used as ingredient.
+    pub product_id: uuid::Uuid,
+
+    /// The quantity of the product needed to make one unit of the root recipe.
+    pub quantity: Decimal,
+
+    /// The recipe making the product, `None` for a raw product.
+    pub recipe_id: Option<uuid::Uuid>,
+
+    /// The ingredients of `recipe_id`, empty for a raw product.
+    pub ingredients: Vec<BomNode>,
+}
+
+/// The bill of materials of a recipe: everything needed to make one unit of its product.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bom {
+    /// The expanded recipe.
+    pub recipe: recipe::Model,
+
+    /// The direct ingredients of the recipe, each expanded down to raw products.
+    pub ingredients: Vec<BomNode>,
+
+    /// The total quantity of each raw product, ordered by product.
+    pub raw_products: Vec<(uuid::Uuid, Decimal)>,
+
+    /// The total quantity of the raw products counted in each unit.
+    pub units: Vec<(Unit, Decimal)>,
+
+    /// Every product appearing in the bill of materials, including the product of the recipe.
+    pub products: HashMap<uuid::Uuid, product::Model>,
+}
+
+/// Loads every recipe with its ingredients.
+pub(crate) async fn load_recipes<C: ConnectionTrait>(
+    db: &C,
+) -> Result<Vec<(recipe::Model, Vec<recipe_ingredients::Model>)>, DbErr> {
+    Recipe::find()
+        .find_with_related(RecipeIngredients)
+        .all(db)
+        .await
+}
+
+/// Loads the recipes matching `condition` with their ingredients, along with every enabled
+/// recipe making one of their enabled ingredients, recursively: all the recipes needed to
+/// expand them.
+pub(crate) async fn load_recipes_below<C: ConnectionTrait>(
+    db: &C,
+    condition: Condition,
+) -> Result<Vec<(recipe::Model, Vec<recipe_ingredients::Model>)>, DbErr> {
+    let mut recipes = Recipe::find()
+        .filter(condition)
+        .find_with_related(RecipeIngredients)
+        .all(db)
+        .await?;
+
+    let mut recipe_ids: HashSet<_> = recipes.iter().map(|(recipe, _)| recipe.id).collect();
+    let mut product_ids = HashSet::new();
+    let mut pending = enabled_ingredients(&recipes, &mut product_ids);
+
+    while !pending.is_empty() {
+        let found: Vec<_> = Recipe::find()
+            .filter(recipe::Column::ResultProductId.is_in(pending))
+            .filter(recipe::Column::Disabled.eq(false))
+            .find_with_related(RecipeIngredients)
+            .all(db)
+            .await?
+            .into_iter()
+            .filter(|(recipe, _)| recipe_ids.insert(recipe.id))
+            .collect();
+
+        pending = enabled_ingredients(&found, &mut product_ids);
+        recipes.extend(found);
+    }
+
+    Ok(recipes)
+}
+
+/// The enabled ingredients of `recipes` which aren't in `seen` yet, adding them to it.
+fn enabled_ingredients(
+    recipes: &[(recipe::Model, Vec<recipe_ingredients::Model>)],
+    seen: &mut HashSet<uuid::Uuid>,
+) -> Vec<uuid::Uuid> {
+    recipes
+        .iter()
+        .flat_map(|(_, ingredients)| ingredients)
+        .filter(|ingredient| !ingredient.disabled)
+        .map(|ingredient| ingredient.ingredient_id)
+        .filter(|product_id| seen.insert(*product_id))
+        .collect()
+}
+
+/// Looks for a cycle that a recipe making `result_product_id` out of `ingredient_ids` would
+/// create. The recipe `excluded_recipe` is ignored, it is the one being replaced when editing.
+///
+/// Returns the shortest cycle found as a path of products, starting and ending with
+/// `result_product_id`.
+pub(crate) async fn find_cycle<C: ConnectionTrait>(
+    db: &C,
+    result_product_id: uuid::Uuid,
+    ingredient_ids: &[uuid::Uuid],
+    excluded_recipe: Option<uuid::Uuid>,
+) -> Result<Option<Vec<uuid::Uuid>>, DbErr> {
+    let mut graph: HashMap<uuid::Uuid, HashSet<uuid::Uuid>> = HashMap::new();
+    for (recipe, ingredients) in load_recipes(db).await? {
+        if Some(recipe.id) == excluded_recipe {
+            continue;
+        }
+        graph.entry(recipe.result_product_id).or_default().extend(
+            ingredients
+                .iter()
+                .map(|ingredient| ingredient.ingredient_id),
+        );
+    }
+
+    // Breadth first from the new ingredients, remembering how each product was reached.
+    let mut parents: HashMap<uuid::Uuid, uuid::Uuid> = HashMap::new();
+    let mut queue: VecDeque<(uuid::Uuid, uuid::Uuid)> = ingredient_ids
+        .iter()
+        .map(|ingredient| (result_product_id, *ingredient))
+        .collect();
+    while let Some((from, product_id)) = queue.pop_front() {
+        if product_id == result_product_id {
+            let mut path = vec![result_product_id, from];
+            let mut current = from;
+            while current != result_product_id {
+                current = parents[&current];
+                path.push(current);
+            }
+            path.reverse();
+            return Ok(Some(path));
+        }
+
+        if parents.contains_key(&product_id) {
+            continue;
+        }
+        parents.insert(product_id, from);
+
+        for next in graph.get(&product_id).into_iter().flatten() {
+            queue.push_back((product_id, *next));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Maps each product to the recipe making it: the oldest enabled one when there are several.
+pub(crate) fn makers(
+    recipes: &[(recipe::Model, Vec<recipe_ingredients::Model>)],
+) -> HashMap<uuid::Uuid, &(recipe::Model, Vec<recipe_ingredients::Model>)> {
+    let mut recipes: Vec<_> = recipes
+        .iter()
+        .filter(|(recipe, _)| !recipe.disabled)
+        .collect();
+    recipes.sort_by_key(|(recipe, _)| (recipe.created_at, recipe.id));
+
+    let mut makers = HashMap::new();
+    for entry in recipes {
+        makers.entry(entry.0.result_product_id).or_insert(entry);
+    }
+    makers
+}
+
+/// Expands the enabled `ingredients` needed `factor` times, recursing into the products made by
+/// a recipe of `makers`. `path` holds the products being expanded, a product already in it is
+/// kept raw so a cycle left in the database can't recurse forever.
+fn expand_ingredients(
+    ingredients: &[recipe_ingredients::Model],
+    factor: Decimal,
+    makers: &HashMap<uuid::Uuid, &(recipe::Model, Vec<recipe_ingredients::Model>)>,
+    path: &mut Vec<uuid::Uuid>,
+    raw_products: &mut HashMap<uuid::Uuid, Decimal>,
+) -> Vec<BomNode> {
+    let mut nodes = Vec::new();
+    for ingredient in ingredients.iter().filter(|ingredient| !ingredient.disabled) {
+        let product_id = ingredient.ingredient_id;
+        let quantity = (factor * ingredient.quantity).normalize();
+
+        let maker = makers
+            .get(&product_id)
+            .filter(|_| !path.contains(&product_id));
+        let node = match maker {
+            Some((recipe, sub_ingredients)) => {
+                path.push(product_id);
+                let sub_nodes =
+                    expand_ingredients(sub_ingredients, quantity, makers, path, raw_products);
+                path.pop();
+                BomNode {
+                    product_id,
+                    quantity,
+                    recipe_id: Some(recipe.id),
+                    ingredients: sub_nodes,
+                }
+            }
+            None => {
+                *raw_products.entry(product_id).or_default() += quantity;
+                BomNode {
+                    product_id,
+                    quantity,
+                    recipe_id: None,
+                    ingredients: Vec::new(),
+                }
+            }
+        };
+        nodes.push(node);
+    }
+
+    nodes.sort_by_key(|node| node.product_id);
+    nodes
+}
+
+/// Collects every product of `nodes`, recursively.
+fn collect_products(nodes: &[BomNode], products: &mut HashSet<uuid::Uuid>) {
+    for node in nodes {
+        products.insert(node.product_id);
+        collect_products(&node.ingredients, products);
+    }
+}
+
+/// Expands the recipe `recipe_id` down to raw products, following the rules of this module.
+///
+/// Returns `None` when the recipe doesn't exist.
+pub(crate) async fn expand<C: ConnectionTrait>(
+    db: &C,
+    recipe_id: uuid::Uuid,
+) -> Result<Option<Bom>, DbErr> {
+    let recipes = load_recipes(db).await?;
+
+    let Some((root, root_ingredients)) = recipes.iter().find(|(recipe, _)| recipe.id == recipe_id)
+    else {
+        return Ok(None);
+    };
+
+    let makers = makers(&recipes);
+
+    let mut path = vec![root.result_product_id];
+    let mut raw_products = HashMap::new();
+    let ingredients = expand_ingredients(
+        root_ingredients,
+        Decimal::ONE,
+        &makers,
+        &mut path,
+        &mut raw_products,
+    );
+
+    let mut product_ids = HashSet::from([root.result_product_id]);
+    collect_products(&ingredients, &mut product_ids);
+    let products: HashMap<_, _> = Product::find()
+        .filter(product::Column::Id.is_in(product_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|product| (product.id, product))
+        .collect();
+
+    let mut raw_products: Vec<_> = raw_products.into_iter().collect();
+    raw_products.sort_by_key(|(product_id, _)| *product_id);
+
+    let units = Unit::iter()
+        .filter_map(|unit| {
+            let mut quantities = raw_products
+                .iter()
+                .filter(|(product_id, _)| {
+                    products
+                        .get(product_id)
+                        .is_some_and(|product| product.unit == unit)
+                })
+                .map(|(_, quantity)| *quantity)
+                .peekable();
+            quantities.peek()?;
+            let total = quantities.sum();
+            Some((unit, total))
+        })
+        .collect();
+
+    Ok(Some(Bom {
+        recipe: root.clone(),
+        ingredients,
+        raw_products,
+        units,
+        products,
+    }))
+}
@@ -0,0 +1,211 @@
+//! Cost of the recipes of the `scrounch_backend` application.
+//!
+//! A purchased product carries a cost price for one unit, liter or meter of the product, or
+//! for one kilogram when it is counted in grams, grams being too small to be priced. The cost of a recipe is the sum of the cost of its enabled
+//! ingredients times their quantity, an ingredient without cost price being costed with the
+//! recipe making it (see `bom::makers`).
+//!
+//! A recipe is costed in the currency its product is sold in, or in euro when it has no sell
+//! price. Cost prices in another currency are converted with the rate effective now and the
+//! total is rounded up to the decimal places of that currency, see the `exchange` module.
+
+use crate::{
+    Connection, Query, bom,
+    exchange::{self, Rounding},
+};
+use ::entity::models::{
+    currency::{CurrencyCode, EURO},
+    prelude::Product,
+    product, recipe, recipe_ingredients,
+    sea_orm_active_enums::Unit,
+};
+use sea_orm::{prelude::Decimal, *};
+use std::collections::{HashMap, HashSet};
+
+/// The cost of a recipe, and the price its product is sold at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipeCost {
+    /// The costed recipe.
+    pub recipe: recipe::Model,
+
+    /// The product made by the recipe.
+    pub product: product::Model,
+
+    /// The currency of `cost` and `sell_price`.
+    pub currency: CurrencyCode,
+
+    /// The cost of one unit of the product, `None` when an ingredient has no known cost.
+    pub cost: Option<Decimal>,
+
+    /// The sell price of the product, `None` when it isn't sold.
+    pub sell_price: Option<Decimal>,
+}
+
+/// The data needed to cost the recipes in a currency.
+struct Costing<'a> {
+    products: &'a HashMap<uuid::Uuid, product::Model>,
+    makers: &'a HashMap<uuid::Uuid, &'a (recipe::Model, Vec<recipe_ingredients::Model>)>,
+
+    /// The cost price of the products converted to the currency, `None` when no rate applies.
+    prices: &'a HashMap<uuid::Uuid, Option<Decimal>>,
+}
+
+impl Costing<'_> {
+    /// Cost of one unit of a product, or of one gram for a product counted in grams.
+    /// `path` holds the products being costed, a cycle left in the database has no cost.
+    fn product_cost(&self, product_id: uuid::Uuid, path: &mut Vec<uuid::Uuid>) -> Option<Decimal> {
+        let product = self.products.get(&product_id)?;
+        if let Some(price) = self.prices.get(&product_id) {
+            return match product.unit {
+                Unit::Gram => price.map(|price| price / Decimal::ONE_THOUSAND),
+                Unit::Liter | Unit::Meter | Unit::Unit => *price,
+            };
+        }
+
+        if path.contains(&product_id) {
+            return None;
+        }
+        let (_, ingredients) = self.makers.get(&product_id)?;
+        path.push(product_id);
+        let cost = self.ingredients_cost(ingredients, path);
+        path.pop();
+        cost
+    }
+
+    /// Cost of the enabled `ingredients`, `None` if any of them has no cost.
+    fn ingredients_cost(
+        &self,
+        ingredients: &[recipe_ingredients::Model],
+        path: &mut Vec<uuid::Uuid>,
+    ) -> Option<Decimal> {
+        ingredients
+            .iter()
+            .filter(|ingredient| !ingredient.disabled)
+            .map(|ingredient| {
+                self.product_cost(ingredient.ingredient_id, path)?
+                    .checked_mul(ingredient.quantity)
+            })
+            .try_fold(Decimal::ZERO, |total, cost| total.checked_add(cost?))
+    }
+}
+
+/// Costs the recipes `recipe_ids`, or every enabled recipe when `None`.
+///
+/// Only the recipes and products of their bill of materials are loaded, and the rate of each
+/// currency pair is looked up once.
+///
+/// The costs are ordered like the recipes were created, a recipe that doesn't exist is skipped.
+pub(crate) async fn cost_recipes(
+    conn: &Connection,
+    recipe_ids: Option<&[uuid::Uuid]>,
+) -> Result<Vec<RecipeCost>, DbErr> {
+    let condition = match recipe_ids {
+        Some(ids) => Condition::all().add(recipe::Column::Id.is_in(ids.iter().copied())),
+        None => Condition::all().add(recipe::Column::Disabled.eq(false)),
+    };
+    let mut recipes = bom::load_recipes_below(&conn.db_connection, condition).await?;
+    recipes.sort_by_key(|(recipe, _)| (recipe.created_at, recipe.id));
+    let makers = bom::makers(&recipes);
+
+    let product_ids: HashSet<_> = recipes
+        .iter()
+        .flat_map(|(recipe, ingredients)| {
+            ingredients
+                .iter()
+                .map(|ingredient| ingredient.ingredient_id)
+                .chain([recipe.result_product_id])
+        })
+        .collect();
+    let products: HashMap<_, _> = Product::find()
+        .filter(product::Column::Id.is_in(product_ids))
+        .all(&conn.db_connection)
+        .await?
+        .into_iter()
+        .map(|product| (product.id, product))
+        .collect();
+
+    let costed: Vec<_> = recipes
+        .iter()
+        .filter(|(recipe, _)| match recipe_ids {
+            Some(ids) => ids.contains(&recipe.id),
+            None => !recipe.disabled,
+        })
+        .filter_map(|(recipe, ingredients)| {
+            let product = products.get(&recipe.result_product_id)?;
+            let currency = product
+                .sell_price_currency
+                .clone()
+                .unwrap_or_else(|| CurrencyCode(EURO.to_string()));
+            Some((recipe, ingredients, product, currency))
+        })
+        .collect();
+
+    let currencies: HashSet<_> = costed.iter().map(|(.., currency)| currency).collect();
+    let now = chrono::Utc::now();
+    let mut rates = HashMap::new();
+    let mut costs = Vec::with_capacity(costed.len());
+    for currency in currencies {
+        let Some(target) = Query::find_currency_by_code(conn, &currency.0).await? else {
+            continue;
+        };
+        let decimal_places = u32::try_from(target.decimal_places).unwrap_or(0);
+
+        let mut prices = HashMap::new();
+        for product in products.values() {
+            let (Some(price), Some(from)) = (product.cost_price, &product.cost_price_currency)
+            else {
+                continue;
+            };
+            if from == currency {
+                prices.insert(product.id, Some(price));
+                continue;
+            }
+
+            let pair = (from.clone(), currency.clone());
+            let rate = match rates.get(&pair) {
+                Some(rate) => *rate,
+                None => {
+                    let rate = Query::find_effective_exchange_rate(
+                        conn,
+                        from.clone(),
+                        currency.clone(),
+                        now,
+                    )
+                    .await?
+                    .map(|rate| rate.rate);
+                    rates.insert(pair, rate);
+                    rate
+                }
+            };
+            let price =
+                rate.and_then(|rate| exchange::convert(price, rate, decimal_places, Rounding::Up));
+            prices.insert(product.id, price);
+        }
+
+        let costing = Costing {
+            products: &products,
+            makers: &makers,
+            prices: &prices,
+        };
+        for (recipe, ingredients, product, _) in costed.iter().filter(|(.., c)| c == currency) {
+            let cost = costing
+                .ingredients_cost(ingredients, &mut vec![product.id])
+                .map(|cost| {
+                    cost.round_dp_with_strategy(
+                        decimal_places,
+                        rust_decimal::RoundingStrategy::AwayFromZero,
+                    )
+                });
+            costs.push(RecipeCost {
+                recipe: (*recipe).clone(),
+                product: (*product).clone(),
+                currency: currency.clone(),
+                cost,
+                sell_price: product.sell_price,
+            });
+        }
+    }
+
+    costs.sort_by_key(|cost| (cost.recipe.created_at, cost.recipe.id));
+    Ok(costs)
+}
@@ -0,0 +1,40 @@
+//! Currency conversion for the `scrounch_backend` application.
+//!
+//! Amounts are converted with the exchange rate of the currency pair effective at a given
+//! date, see `Query::find_effective_exchange_rate`. A converted amount is always rounded to
+//! the decimal places of the target currency, in the direction given by a `Rounding`, so
+//! the rounding never goes against the shop:
+//! - a price charged to a user is rounded up (`Rounding::Up`)
+//! - an amount credited to a user is rounded down (`Rounding::Down`)
+
+use sea_orm::prelude::Decimal;
+
+/// Direction in which a converted amount is rounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Away from zero, for the prices charged to users.
+    Up,
+
+    /// Toward zero, for the amounts credited to users.
+    Down,
+}
+
+/// Converts `amount` with `rate` (the amount of the target currency given for one unit of the
+/// source currency), rounded to `decimal_places` as told by `rounding`.
+///
+/// Returns `None` if the result overflows.
+pub fn convert(
+    amount: Decimal,
+    rate: Decimal,
+    decimal_places: u32,
+    rounding: Rounding,
+) -> Option<Decimal> {
+    let strategy = match rounding {
+        Rounding::Up => rust_decimal::RoundingStrategy::AwayFromZero,
+        Rounding::Down => rust_decimal::RoundingStrategy::ToZero,
+    };
+
+    amount
+        .checked_mul(rate)
+        .map(|converted| converted.round_dp_with_strategy(decimal_places, strategy))
+}
@@ -5,10 +5,18 @@
 //! tasks such as data retrieval, processing, and manipulation. They act as an intermediary
 //! between request handlers and the underlying data sources or other services.
 
+pub mod bom;
+pub mod costing;
+pub mod exchange;
 mod r#macro;
 mod mutation;
+pub mod notifier;
+pub mod payment;
 mod query;
+pub mod reconcile;
 pub mod s3;
+pub mod stock;
+pub mod unit;
 mod utils;
 
 pub use mutation::Mutation;
@@ -23,8 +31,12 @@ pub use sea_orm;
 /// The `Connection` struct encapsulates the connections used in the `scrounch_backend` application.
 /// It provides a way to access both the database connection and caching connection throughout the
 /// application, ensuring consistent management of these resources.
+///
+/// It also carries the stock notifier, if any, so every stock change can send its alerts once
+/// committed.
 pub struct Connection {
     pub db_connection: sea_orm::DbConn,
     #[cfg(feature = "cache")]
     pub cache_connection: Option<fred::prelude::RedisPool>,
+    pub stock_notifier: Option<notifier::StockNotifierGateway>,
 }
@@ -0,0 +1,63 @@
+#[cfg(feature = "cache")]
+use crate::r#macro::{cache_del, cache_mdel, cache_set};
+use crate::{Connection, mutation::Mutation};
+use ::entity::models::category;
+use sea_orm::*;
+
+impl Mutation {
+    pub async fn create_category<M: IntoActiveModel<category::ActiveModel>>(
+        conn: &Connection,
+        form_data: M,
+    ) -> Result<category::Model, DbErr> {
+        let form_data = form_data.into_active_model();
+
+        let result = form_data.insert(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if let Ok(model) = &result {
+            let id = model.id;
+            cache_set!(conn, format!("category:{id}"), model, 60 * 15);
+            cache_mdel!(conn, "categories");
+        }
+
+        result
+    }
+
+    pub async fn update_category<M: IntoActiveModel<category::ActiveModel>>(
+        conn: &Connection,
+        id: uuid::Uuid,
+        form_data: M,
+    ) -> Result<category::Model, DbErr> {
+        let mut form_data = form_data.into_active_model();
+        form_data.id = ActiveValue::Set(id);
+
+        let result = form_data.update(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if let Ok(model) = &result {
+            cache_set!(conn, format!("category:{id}"), model, 60 * 15);
+            cache_mdel!(conn, "categories");
+        }
+
+        result
+    }
+
+    /// Deletes the category `id`, its products are left without a category.
+    ///
+    /// The database refuses to delete a category which still contains other categories.
+    pub async fn delete_category(conn: &Connection, id: uuid::Uuid) -> Result<DeleteResult, DbErr> {
+        let result = category::Entity::delete_by_id(id)
+            .exec(&conn.db_connection)
+            .await;
+
+        #[cfg(feature = "cache")]
+        if result.is_ok() {
+            cache_del!(conn, format!("category:{id}"));
+            cache_mdel!(conn, "categories");
+            // The products of the category lost it
+            cache_mdel!(conn, "product");
+        }
+
+        result
+    }
+}
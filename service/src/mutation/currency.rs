@@ -0,0 +1,58 @@
+#[cfg(feature = "cache")]
+use crate::r#macro::{cache_del, cache_mdel, cache_set};
+use crate::{Connection, mutation::Mutation};
+use ::entity::models::{currency, currency::Entity as Currency};
+use sea_orm::*;
+
+impl Mutation {
+    pub async fn create_currency<M: IntoActiveModel<currency::ActiveModel>>(
+        conn: &Connection,
+        form_data: M,
+    ) -> Result<currency::Model, DbErr> {
+        let form_data = form_data.into_active_model();
+
+        let result = form_data.insert(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if let Ok(model) = &result {
+            let code = &model.code;
+            cache_set!(conn, format!("currency:{code}"), model, 60 * 15);
+            cache_mdel!(conn, "currencies");
+        }
+
+        result
+    }
+
+    pub async fn update_currency<M: IntoActiveModel<currency::ActiveModel>>(
+        conn: &Connection,
+        code: &str,
+        form_data: M,
+    ) -> Result<currency::Model, DbErr> {
+        let mut form_data = form_data.into_active_model();
+        form_data.code = ActiveValue::Set(code.to_string());
+
+        let result = form_data.update(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if let Ok(model) = &result {
+            cache_set!(conn, format!("currency:{code}"), model, 60 * 15);
+            cache_mdel!(conn, "currencies");
+        }
+
+        result
+    }
+
+    /// Deletes the currency `code`, which fails with a foreign key violation while an
+    /// amount is still expressed in it.
+    pub async fn delete_currency(conn: &Connection, code: &str) -> Result<DeleteResult, DbErr> {
+        let result = Currency::delete_by_id(code).exec(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if result.is_ok() {
+            cache_del!(conn, format!("currency:{code}"));
+            cache_mdel!(conn, "currencies");
+        }
+
+        result
+    }
+}
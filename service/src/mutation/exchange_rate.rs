@@ -0,0 +1,61 @@
+#[cfg(feature = "cache")]
+use crate::r#macro::{cache_del, cache_mdel, cache_set};
+use crate::{Connection, mutation::Mutation};
+use ::entity::models::{exchange_rate, exchange_rate::Entity as ExchangeRate};
+use sea_orm::*;
+
+impl Mutation {
+    pub async fn create_exchange_rate<M: IntoActiveModel<exchange_rate::ActiveModel>>(
+        conn: &Connection,
+        form_data: M,
+    ) -> Result<exchange_rate::Model, DbErr> {
+        let form_data = form_data.into_active_model();
+
+        let result = form_data.insert(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if let Ok(model) = &result {
+            let id = model.id;
+            cache_set!(conn, format!("exchange_rate:{id}"), model, 60 * 15);
+            cache_mdel!(conn, "exchange_rates");
+        }
+
+        result
+    }
+
+    pub async fn update_exchange_rate<M: IntoActiveModel<exchange_rate::ActiveModel>>(
+        conn: &Connection,
+        id: uuid::Uuid,
+        form_data: M,
+    ) -> Result<exchange_rate::Model, DbErr> {
+        let mut form_data = form_data.into_active_model();
+        form_data.id = ActiveValue::Set(id);
+
+        let result = form_data.update(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if let Ok(model) = &result {
+            cache_set!(conn, format!("exchange_rate:{id}"), model, 60 * 15);
+            cache_mdel!(conn, "exchange_rates");
+        }
+
+        result
+    }
+
+    pub async fn delete_exchange_rate(
+        conn: &Connection,
+        id: uuid::Uuid,
+    ) -> Result<DeleteResult, DbErr> {
+        let result = ExchangeRate::delete_by_id(id)
+            .exec(&conn.db_connection)
+            .await;
+
+        #[cfg(feature = "cache")]
+        if result.is_ok() {
+            cache_del!(conn, format!("exchange_rate:{id}"));
+            cache_mdel!(conn, "exchange_rates");
+        }
+
+        result
+    }
+}
@@ -1,7 +1,9 @@
 #[cfg(feature = "cache")]
 use crate::r#macro::{cache_del, cache_mdel, cache_set};
 use crate::{Connection, mutation::Mutation};
-use ::entity::models::{location, location::Entity as Location};
+use ::entity::models::{
+    location, location::Entity as Location, location_warehouse, prelude::LocationWarehouse,
+};
 use sea_orm::*;
 use sqlx::types::Uuid;
 
@@ -71,4 +73,75 @@ impl Mutation {
     pub async fn delete_all_locations(db: &DbConn) -> Result<DeleteResult, DbErr> {
         Location::delete_many().exec(db).await
     }
+
+    pub async fn create_location_warehouse<M: IntoActiveModel<location_warehouse::ActiveModel>>(
+        conn: &Connection,
+        location_id: Uuid,
+        warehouse_id: Uuid,
+        form_data: M,
+    ) -> Result<location_warehouse::Model, DbErr> {
+        let mut form_data = form_data.into_active_model();
+        form_data.location_id = Set(location_id);
+        form_data.warehouse_id = Set(warehouse_id);
+        let result = form_data.insert(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if let Ok(_model) = &result {
+            cache_mdel!(conn, format!("location_warehouses:{location_id}"));
+        }
+
+        result
+    }
+
+    pub async fn update_location_warehouse<M: IntoActiveModel<location_warehouse::ActiveModel>>(
+        conn: &Connection,
+        location_id: Uuid,
+        warehouse_id: Uuid,
+        form_data: M,
+    ) -> Result<location_warehouse::Model, DbErr> {
+        let mut form_data = form_data.into_active_model();
+        form_data.location_id = Set(location_id);
+        form_data.warehouse_id = Set(warehouse_id);
+
+        let result = form_data.update(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if let Ok(_model) = &result {
+            cache_del!(
+                conn,
+                format!("location_warehouse:{location_id}/{warehouse_id}")
+            );
+            cache_mdel!(conn, format!("location_warehouses:{location_id}"));
+        }
+
+        result
+    }
+
+    pub async fn delete_location_warehouse(
+        conn: &Connection,
+        location_id: Uuid,
+        warehouse_id: Uuid,
+    ) -> Result<DeleteResult, DbErr> {
+        let location_warehouse: location_warehouse::ActiveModel =
+            LocationWarehouse::find_by_id((location_id, warehouse_id))
+                .one(&conn.db_connection)
+                .await?
+                .ok_or(DbErr::Custom(format!(
+                    "Cannot find Location/Warehouse: \"{location_id}\"/\"{warehouse_id}\""
+                )))
+                .map(Into::into)?;
+
+        let result = location_warehouse.delete(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if result.is_ok() {
+            cache_del!(
+                conn,
+                format!("location_warehouse:{location_id}/{warehouse_id}")
+            );
+            cache_mdel!(conn, format!("location_warehouses:{location_id}"));
+        }
+
+        result
+    }
 }
@@ -5,10 +5,17 @@
 //! updating, and deleting records in the database. They encapsulate the logic for applying changes
 //! to the data, ensuring that mutations are executed correctly and consistently across the application.
 
+mod category;
+mod currency;
+mod exchange_rate;
 mod location;
 mod product;
+mod purchase_order;
 mod recipe;
 mod refill;
+mod stocktake;
+mod supplier;
+mod transaction;
 mod user;
 mod warehouse;
 
@@ -0,0 +1,279 @@
+#[cfg(feature = "cache")]
+use crate::r#macro::{cache_del, cache_mdel};
+use crate::{
+    Connection,
+    mutation::Mutation,
+    notifier,
+    stock::{ReceiptRejection, StockJournal},
+};
+use ::entity::models::{
+    prelude::{Product, PurchaseOrder, PurchaseOrderLine},
+    product, purchase_order, purchase_order_line,
+    sea_orm_active_enums::{PurchaseOrderStatus, StockMovementReason},
+};
+use sea_orm::{
+    prelude::{DateTimeWithTimeZone, Decimal},
+    *,
+};
+use std::collections::HashMap;
+
+/// Locks the purchase order `id` inside `txn` if it is in one of `statuses`.
+async fn lock_purchase_order(
+    txn: &DatabaseTransaction,
+    id: uuid::Uuid,
+    statuses: &[PurchaseOrderStatus],
+) -> Result<Option<purchase_order::Model>, DbErr> {
+    PurchaseOrder::find_by_id(id)
+        .filter(purchase_order::Column::Status.is_in(statuses.iter().cloned()))
+        .lock_exclusive()
+        .one(txn)
+        .await
+}
+
+/// Inserts `lines` into the purchase order `id`.
+async fn insert_lines(
+    txn: &DatabaseTransaction,
+    id: uuid::Uuid,
+    lines: Vec<purchase_order_line::ActiveModel>,
+) -> Result<(), DbErr> {
+    let lines: Vec<_> = lines
+        .into_iter()
+        .map(|mut line| {
+            line.purchase_order_id = Set(id);
+            line
+        })
+        .collect();
+    if lines.is_empty() {
+        return Ok(());
+    }
+
+    PurchaseOrderLine::insert_many(lines).exec(txn).await?;
+    Ok(())
+}
+
+impl Mutation {
+    /// Drafts a purchase order ordering `lines`, on behalf of `author_id`.
+    pub async fn create_purchase_order<M: IntoActiveModel<purchase_order::ActiveModel>>(
+        conn: &Connection,
+        form_data: M,
+        lines: Vec<purchase_order_line::ActiveModel>,
+        author_id: uuid::Uuid,
+    ) -> Result<purchase_order::Model, DbErr> {
+        let mut form_data = form_data.into_active_model();
+        form_data.author_id = Set(Some(author_id));
+
+        let txn = conn.db_connection.begin().await?;
+
+        let result = form_data.insert(&txn).await?;
+        insert_lines(&txn, result.id, lines).await?;
+
+        txn.commit().await?;
+
+        Ok(result)
+    }
+
+    /// Updates the draft purchase order `id`, replacing all of its lines by `lines` if any.
+    ///
+    /// Returns `None` when the purchase order doesn't exist or isn't a draft anymore.
+    pub async fn update_purchase_order<M: IntoActiveModel<purchase_order::ActiveModel>>(
+        conn: &Connection,
+        id: uuid::Uuid,
+        form_data: M,
+        lines: Option<Vec<purchase_order_line::ActiveModel>>,
+    ) -> Result<Option<purchase_order::Model>, DbErr> {
+        let txn = conn.db_connection.begin().await?;
+
+        let Some(purchase_order) =
+            lock_purchase_order(&txn, id, &[PurchaseOrderStatus::Draft]).await?
+        else {
+            return Ok(None);
+        };
+
+        let mut form_data = form_data.into_active_model();
+        form_data.id = Set(id);
+        let result = if form_data.is_changed() {
+            form_data.update(&txn).await?
+        } else {
+            purchase_order
+        };
+
+        if let Some(lines) = lines {
+            PurchaseOrderLine::delete_many()
+                .filter(purchase_order_line::Column::PurchaseOrderId.eq(id))
+                .exec(&txn)
+                .await?;
+            insert_lines(&txn, id, lines).await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(Some(result))
+    }
+
+    /// Deletes the draft purchase order `id` with its lines.
+    ///
+    /// Returns `None` when the purchase order doesn't exist or isn't a draft anymore.
+    pub async fn delete_purchase_order(
+        conn: &Connection,
+        id: uuid::Uuid,
+    ) -> Result<Option<DeleteResult>, DbErr> {
+        let txn = conn.db_connection.begin().await?;
+
+        let Some(purchase_order) =
+            lock_purchase_order(&txn, id, &[PurchaseOrderStatus::Draft]).await?
+        else {
+            return Ok(None);
+        };
+
+        let result = purchase_order.delete(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(Some(result))
+    }
+
+    /// Marks the draft purchase order `id` as sent to its supplier, its lines can't change
+    /// anymore.
+    ///
+    /// Returns `None` when the purchase order doesn't exist or isn't a draft anymore.
+    pub async fn send_purchase_order(
+        conn: &Connection,
+        id: uuid::Uuid,
+    ) -> Result<Option<purchase_order::Model>, DbErr> {
+        let txn = conn.db_connection.begin().await?;
+
+        let Some(purchase_order) =
+            lock_purchase_order(&txn, id, &[PurchaseOrderStatus::Draft]).await?
+        else {
+            return Ok(None);
+        };
+
+        let mut purchase_order: purchase_order::ActiveModel = purchase_order.into();
+        purchase_order.status = Set(PurchaseOrderStatus::Sent);
+        purchase_order.sent_at = Set(Some(chrono::offset::Local::now().into()));
+        let result = purchase_order.update(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(Some(result))
+    }
+
+    /// Receives `lines` of the sent purchase order `id` on behalf of `author_id`: the stock of
+    /// each product in the warehouse of the purchase order grows by its received quantity, the
+    /// increase being recorded as a restock in the stock journal and tracked as a batch expiring
+    /// at the date given with the quantity, if any.
+    ///
+    /// The purchase order becomes received once every ordered quantity was received, partially
+    /// received otherwise. When `update_cost_price` is set, the unit cost of each received
+    /// product becomes its cost price.
+    ///
+    /// Nothing is received when the purchase order isn't waiting for products, when a product
+    /// isn't ordered, or when more of a product is received than what remains to receive.
+    pub async fn receive_purchase_order(
+        conn: &Connection,
+        id: uuid::Uuid,
+        lines: Vec<(uuid::Uuid, Decimal, Option<DateTimeWithTimeZone>)>,
+        update_cost_price: bool,
+        author_id: uuid::Uuid,
+    ) -> Result<Result<purchase_order::Model, ReceiptRejection>, DbErr> {
+        let txn = conn.db_connection.begin().await?;
+
+        let statuses = [
+            PurchaseOrderStatus::Sent,
+            PurchaseOrderStatus::PartiallyReceived,
+        ];
+        let Some(purchase_order) = lock_purchase_order(&txn, id, &statuses).await? else {
+            return Ok(Err(ReceiptRejection::NotReceivable));
+        };
+        let warehouse_id = purchase_order.warehouse_id;
+
+        let mut ordered: HashMap<uuid::Uuid, purchase_order_line::Model> =
+            PurchaseOrderLine::find()
+                .filter(purchase_order_line::Column::PurchaseOrderId.eq(id))
+                .all(&txn)
+                .await?
+                .into_iter()
+                .map(|line| (line.product_id, line))
+                .collect();
+
+        let mut journal = StockJournal::new(author_id);
+        for (product_id, quantity, expires_at) in &lines {
+            let Some(line) = ordered.get_mut(product_id) else {
+                return Ok(Err(ReceiptRejection::NotOrdered(*product_id)));
+            };
+            let remaining = line.quantity - line.received_quantity;
+            if *quantity > remaining {
+                return Ok(Err(ReceiptRejection::ExceedsOrdered(
+                    *product_id,
+                    remaining,
+                )));
+            }
+
+            journal
+                .give_batch(
+                    &txn,
+                    warehouse_id,
+                    *product_id,
+                    *quantity,
+                    *expires_at,
+                    chrono::offset::Local::now().into(),
+                    StockMovementReason::Restock,
+                )
+                .await?;
+
+            let mut received: purchase_order_line::ActiveModel = line.clone().into();
+            received.received_quantity = Set(line.received_quantity + quantity);
+            *line = received.update(&txn).await?;
+
+            if update_cost_price {
+                Product::update_many()
+                    .col_expr(
+                        product::Column::CostPrice,
+                        sea_query::Expr::value(line.unit_cost),
+                    )
+                    .col_expr(
+                        product::Column::CostPriceCurrency,
+                        sea_query::Expr::value(purchase_order.currency.clone()),
+                    )
+                    .filter(product::Column::Id.eq(*product_id))
+                    .exec(&txn)
+                    .await?;
+            }
+        }
+
+        let fully_received = ordered
+            .values()
+            .all(|line| line.received_quantity >= line.quantity);
+        let mut purchase_order: purchase_order::ActiveModel = purchase_order.into();
+        if fully_received {
+            purchase_order.status = Set(PurchaseOrderStatus::Received);
+            purchase_order.received_at = Set(Some(chrono::offset::Local::now().into()));
+        } else {
+            purchase_order.status = Set(PurchaseOrderStatus::PartiallyReceived);
+        }
+        let result = purchase_order.update(&txn).await?;
+
+        txn.commit().await?;
+
+        #[cfg(feature = "cache")]
+        {
+            for (product_id, _, _) in &lines {
+                cache_del!(
+                    conn,
+                    format!("warehouse_product:{warehouse_id}/{product_id}")
+                );
+                if update_cost_price {
+                    cache_del!(conn, format!("product:{product_id}"));
+                }
+            }
+            cache_mdel!(conn, format!("warehouse_products:{warehouse_id}"));
+            if update_cost_price {
+                cache_mdel!(conn, "products");
+            }
+        }
+
+        notifier::dispatch(conn, journal.alerts);
+
+        Ok(Ok(result))
+    }
+}
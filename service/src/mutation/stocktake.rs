@@ -0,0 +1,195 @@
+#[cfg(feature = "cache")]
+use crate::r#macro::{cache_del, cache_mdel};
+use crate::{Connection, mutation::Mutation, notifier, stock::StockJournal};
+use ::entity::models::{
+    prelude::{Stocktake, StocktakeCount, WarehouseProduct},
+    sea_orm_active_enums::{StockMovementReason, StocktakeStatus},
+    stocktake, stocktake_count,
+};
+use sea_orm::{
+    prelude::{DateTimeWithTimeZone, Decimal},
+    *,
+};
+
+impl Mutation {
+    /// Opens a stocktake on behalf of `author_id`.
+    pub async fn create_stocktake<M: IntoActiveModel<stocktake::ActiveModel>>(
+        conn: &Connection,
+        form_data: M,
+        author_id: uuid::Uuid,
+    ) -> Result<stocktake::Model, DbErr> {
+        let mut form_data = form_data.into_active_model();
+        form_data.author_id = Set(Some(author_id));
+
+        form_data.insert(&conn.db_connection).await
+    }
+
+    /// Records the quantities counted by `author_id` in the stocktake `id`, replacing the
+    /// previous count of each product, along with the quantity the warehouse holds right now.
+    ///
+    /// Returns `None` when the stocktake doesn't exist or isn't open anymore.
+    pub async fn count_stocktake(
+        conn: &Connection,
+        id: uuid::Uuid,
+        lines: Vec<(uuid::Uuid, Decimal)>,
+        author_id: uuid::Uuid,
+    ) -> Result<Option<stocktake::Model>, DbErr> {
+        let txn = conn.db_connection.begin().await?;
+
+        // Counts can run side by side, but not alongside the closing of the stocktake.
+        let stocktake = Stocktake::find_by_id(id)
+            .filter(stocktake::Column::Status.eq(StocktakeStatus::Open))
+            .lock_shared()
+            .one(&txn)
+            .await?;
+        let Some(stocktake) = stocktake else {
+            return Ok(None);
+        };
+
+        let now: DateTimeWithTimeZone = chrono::offset::Local::now().into();
+        for (product_id, quantity) in lines {
+            let expected_quantity =
+                WarehouseProduct::find_by_id((stocktake.warehouse_id, product_id))
+                    .one(&txn)
+                    .await?
+                    .map(|stock| stock.quantity)
+                    .unwrap_or_default();
+
+            StocktakeCount::insert(stocktake_count::ActiveModel {
+                stocktake_id: Set(id),
+                product_id: Set(product_id),
+                quantity: Set(quantity),
+                expected_quantity: Set(Some(expected_quantity)),
+                author_id: Set(Some(author_id)),
+                updated_at: Set(now),
+            })
+            .on_conflict(
+                sea_query::OnConflict::columns([
+                    stocktake_count::Column::StocktakeId,
+                    stocktake_count::Column::ProductId,
+                ])
+                .update_columns([
+                    stocktake_count::Column::Quantity,
+                    stocktake_count::Column::ExpectedQuantity,
+                    stocktake_count::Column::AuthorId,
+                    stocktake_count::Column::UpdatedAt,
+                ])
+                .to_owned(),
+            )
+            .exec(&txn)
+            .await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(Some(stocktake))
+    }
+
+    /// Closes the stocktake `id` on behalf of `author_id`: the stock of every counted product is
+    /// corrected by the difference between its counted and expected quantities, recorded in the
+    /// stock journal. The stock keeps whatever moved since the count, such as sales.
+    ///
+    /// Products which weren't counted are left untouched.
+    ///
+    /// Returns `None` when the stocktake doesn't exist or isn't open anymore.
+    pub async fn close_stocktake(
+        conn: &Connection,
+        id: uuid::Uuid,
+        author_id: uuid::Uuid,
+    ) -> Result<Option<stocktake::Model>, DbErr> {
+        let txn = conn.db_connection.begin().await?;
+
+        let stocktake = Stocktake::find_by_id(id)
+            .filter(stocktake::Column::Status.eq(StocktakeStatus::Open))
+            .lock_exclusive()
+            .one(&txn)
+            .await?;
+        let Some(stocktake) = stocktake else {
+            return Ok(None);
+        };
+        let warehouse_id = stocktake.warehouse_id;
+
+        let counts = StocktakeCount::find()
+            .filter(stocktake_count::Column::StocktakeId.eq(id))
+            .all(&txn)
+            .await?;
+
+        let mut journal = StockJournal::new(author_id);
+        for count in &counts {
+            let product_id = count.product_id;
+            let reason = StockMovementReason::Stocktake;
+            match count.expected_quantity {
+                Some(expected_quantity) => {
+                    let delta = count.quantity - expected_quantity;
+                    journal
+                        .adjust(&txn, warehouse_id, product_id, delta, reason)
+                        .await?;
+                }
+                // Counted before the expected quantity was recorded
+                None => {
+                    let (previous, _) = journal
+                        .set(&txn, warehouse_id, product_id, count.quantity, reason)
+                        .await?;
+
+                    let mut count: stocktake_count::ActiveModel = count.clone().into();
+                    count.expected_quantity = Set(Some(previous));
+                    count.update(&txn).await?;
+                }
+            }
+        }
+
+        let mut stocktake: stocktake::ActiveModel = stocktake.into();
+        stocktake.status = Set(StocktakeStatus::Closed);
+        stocktake.closed_by_id = Set(Some(author_id));
+        stocktake.closed_at = Set(Some(chrono::offset::Local::now().into()));
+        let result = stocktake.update(&txn).await?;
+
+        txn.commit().await?;
+
+        #[cfg(feature = "cache")]
+        {
+            for count in &counts {
+                let product_id = count.product_id;
+                cache_del!(
+                    conn,
+                    format!("warehouse_product:{warehouse_id}/{product_id}")
+                );
+            }
+            cache_mdel!(conn, format!("warehouse_products:{warehouse_id}"));
+        }
+
+        notifier::dispatch(conn, journal.alerts);
+
+        Ok(Some(result))
+    }
+
+    /// Abandons the stocktake `id` on behalf of `author_id`, leaving the stock untouched.
+    ///
+    /// Returns `None` when the stocktake doesn't exist or isn't open anymore.
+    pub async fn cancel_stocktake(
+        conn: &Connection,
+        id: uuid::Uuid,
+        author_id: uuid::Uuid,
+    ) -> Result<Option<stocktake::Model>, DbErr> {
+        let txn = conn.db_connection.begin().await?;
+
+        let stocktake = Stocktake::find_by_id(id)
+            .filter(stocktake::Column::Status.eq(StocktakeStatus::Open))
+            .lock_exclusive()
+            .one(&txn)
+            .await?;
+        let Some(stocktake) = stocktake else {
+            return Ok(None);
+        };
+
+        let mut stocktake: stocktake::ActiveModel = stocktake.into();
+        stocktake.status = Set(StocktakeStatus::Canceled);
+        stocktake.closed_by_id = Set(Some(author_id));
+        stocktake.closed_at = Set(Some(chrono::offset::Local::now().into()));
+        let result = stocktake.update(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(Some(result))
+    }
+}
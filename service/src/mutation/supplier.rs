@@ -0,0 +1,60 @@
+#[cfg(feature = "cache")]
+use crate::r#macro::{cache_mdel, cache_set};
+use crate::{Connection, mutation::Mutation};
+use ::entity::models::supplier;
+use sea_orm::*;
+
+impl Mutation {
+    pub async fn create_supplier<M: IntoActiveModel<supplier::ActiveModel>>(
+        conn: &Connection,
+        form_data: M,
+    ) -> Result<supplier::Model, DbErr> {
+        let form_data = form_data.into_active_model();
+
+        let result = form_data.insert(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if let Ok(model) = &result {
+            let id = model.id;
+            cache_set!(conn, format!("supplier:{id}"), model, 60 * 15);
+            cache_mdel!(conn, "suppliers");
+        }
+
+        result
+    }
+
+    pub async fn update_supplier<M: IntoActiveModel<supplier::ActiveModel>>(
+        conn: &Connection,
+        id: uuid::Uuid,
+        form_data: M,
+    ) -> Result<supplier::Model, DbErr> {
+        let mut form_data = form_data.into_active_model();
+        form_data.id = ActiveValue::Set(id);
+
+        let result = form_data.update(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if let Ok(model) = &result {
+            cache_set!(conn, format!("supplier:{id}"), model, 60 * 15);
+            cache_mdel!(conn, "suppliers");
+        }
+
+        result
+    }
+
+    /// Disables the supplier `id`, it is kept for the purchase orders already made to it.
+    pub async fn delete_supplier(
+        conn: &Connection,
+        id: uuid::Uuid,
+    ) -> Result<supplier::Model, DbErr> {
+        Self::update_supplier(
+            conn,
+            id,
+            supplier::ActiveModel {
+                disabled: ActiveValue::Set(true),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+}
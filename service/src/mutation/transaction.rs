@@ -0,0 +1,686 @@
+#[cfg(feature = "cache")]
+use crate::r#macro::{cache_del, cache_mdel, cache_set};
+use crate::{
+    Connection,
+    mutation::Mutation,
+    notifier,
+    stock::{self, CancelRejection, Consumption, PurchaseRejection, StockJournal},
+};
+use ::entity::{
+    models::{
+        currency::CurrencyCode,
+        prelude::{
+            Transaction, TransactionProduct, TransactionProductStock,
+            TransactionPurchaseWithBalance, TransactionRefillBalance, User,
+        },
+        refill,
+        sea_orm_active_enums::{FulfilmentStatus, TransactionStatus, TransactionType},
+        transaction, transaction_balance_edit, transaction_product, transaction_product_stock,
+        transaction_purchase_with_balance, transaction_refill_balance, transaction_refund, user,
+    },
+    request::{
+        order::{OrderCancellation, OrderLine},
+        user::BalanceEdit,
+    },
+};
+use sea_orm::*;
+
+impl Mutation {
+    pub async fn create_transaction<M: IntoActiveModel<transaction::ActiveModel>>(
+        conn: &Connection,
+        form_data: M,
+    ) -> Result<transaction::Model, DbErr> {
+        let form_data = form_data.into_active_model();
+
+        let result = form_data.insert(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if let Ok(model) = &result {
+            let id = model.id;
+            cache_set!(conn, format!("transaction:{id}"), model, 60 * 15);
+            cache_mdel!(conn, "transactions");
+        }
+
+        result
+    }
+
+    pub async fn update_transaction<M: IntoActiveModel<transaction::ActiveModel>>(
+        conn: &Connection,
+        id: uuid::Uuid,
+        form_data: M,
+    ) -> Result<transaction::Model, DbErr> {
+        let mut form_data = form_data.into_active_model();
+        form_data.id = ActiveValue::Set(id);
+
+        let result = form_data.update(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if let Ok(model) = &result {
+            cache_set!(conn, format!("transaction:{id}"), model, 60 * 15);
+            cache_mdel!(conn, "transactions");
+        }
+
+        result
+    }
+
+    pub async fn create_transaction_refill_balance<
+        M: IntoActiveModel<transaction_refill_balance::ActiveModel>,
+    >(
+        conn: &Connection,
+        form_data: M,
+    ) -> Result<transaction_refill_balance::Model, DbErr> {
+        let form_data = form_data.into_active_model();
+
+        let result = form_data.insert(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if let Ok(model) = &result {
+            let id = model.id;
+            cache_set!(
+                conn,
+                format!("transaction_refill_balance:{id}"),
+                model,
+                60 * 15
+            );
+        }
+
+        result
+    }
+
+    pub async fn create_transaction_purchase_with_balance<
+        M: IntoActiveModel<transaction_purchase_with_balance::ActiveModel>,
+    >(
+        conn: &Connection,
+        form_data: M,
+    ) -> Result<transaction_purchase_with_balance::Model, DbErr> {
+        let form_data = form_data.into_active_model();
+
+        let result = form_data.insert(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if let Ok(model) = &result {
+            let id = model.id;
+            cache_set!(
+                conn,
+                format!("transaction_purchase_with_balance:{id}"),
+                model,
+                60 * 15
+            );
+        }
+
+        result
+    }
+
+    /// Debits the user balance and records the matching purchase transaction, all inside a
+    /// single database transaction.
+    ///
+    /// The balance is only debited if it still covers the total at the time of the update,
+    /// so concurrent orders cannot drive it below zero. Every line is then taken from the
+    /// stock of a warehouse, see [`crate::stock`]. Nothing is recorded when the purchase is
+    /// rejected, the reason being returned as `Ok(Err(_))`.
+    ///
+    /// A purchase with a `location_id` is queued to be delivered there, and only taken from the
+    /// warehouses serving it when it has any.
+    pub async fn create_purchase_with_balance(
+        conn: &Connection,
+        user_id: uuid::Uuid,
+        currency: CurrencyCode,
+        lines: Vec<OrderLine>,
+        location_id: Option<uuid::Uuid>,
+    ) -> Result<Result<transaction::Model, PurchaseRejection>, DbErr> {
+        let total = lines.iter().map(OrderLine::total).sum::<prelude::Decimal>();
+
+        let txn = conn.db_connection.begin().await?;
+
+        let debit = User::update_many()
+            .col_expr(
+                user::Column::Balance,
+                sea_query::Expr::col(user::Column::Balance).sub(total),
+            )
+            .filter(user::Column::Id.eq(user_id))
+            .filter(user::Column::BalanceCurrency.eq(currency.clone()))
+            .filter(user::Column::Balance.gte(total))
+            .exec(&txn)
+            .await?;
+
+        if debit.rows_affected == 0 {
+            txn.rollback().await?;
+            return Ok(Err(PurchaseRejection::InsufficientBalance));
+        }
+
+        let warehouse_ids = match location_id {
+            Some(location_id) => stock::location_warehouses(&txn, location_id).await?,
+            None => None,
+        };
+
+        let mut journal = StockJournal::new(user_id);
+        let mut sources = Vec::with_capacity(lines.len());
+        for line in &lines {
+            let quantity = prelude::Decimal::from(line.quantity);
+            let warehouse_ids = warehouse_ids.as_deref();
+            match stock::consume(&txn, &mut journal, line.product.id, quantity, warehouse_ids)
+                .await?
+            {
+                Consumption::Untracked => sources.push(None),
+                Consumption::Consumed(source) => sources.push(Some(source)),
+                Consumption::Insufficient => {
+                    txn.rollback().await?;
+                    return Ok(Err(PurchaseRejection::InsufficientStock(line.product.id)));
+                }
+            }
+        }
+
+        let now = chrono::offset::Local::now();
+        let result = transaction::ActiveModel {
+            id: Set(uuid::Uuid::new_v4()),
+            user_id: Set(user_id),
+            created_at: Set(now.into()),
+            completed_at: Set(Some(now.into())),
+            status: Set(TransactionStatus::Completed),
+            transaction_type: Set(TransactionType::PurchaseWithBalance),
+        }
+        .insert(&txn)
+        .await?;
+
+        transaction_purchase_with_balance::ActiveModel {
+            id: Set(result.id),
+            price_total: Set(total),
+            price_total_currency: Set(currency.clone()),
+            location_id: Set(location_id),
+            fulfilment_status: Set(location_id.map(|_| FulfilmentStatus::Queued)),
+        }
+        .insert(&txn)
+        .await?;
+
+        for (line, source) in lines.into_iter().zip(&sources) {
+            let line_id = uuid::Uuid::new_v4();
+            transaction_product::ActiveModel {
+                id: Set(line_id),
+                transaction_id: Set(result.id),
+                product_id: Set(Some(line.product.id)),
+                name: Set(line.product.name),
+                quantity: Set(line.quantity),
+                price: Set(line.price),
+                price_currency: Set(currency.clone()),
+                warehouse_id: Set(source.as_ref().map(|source| source.warehouse_id)),
+                recipe_id: Set(source.as_ref().and_then(|source| source.recipe_id)),
+            }
+            .insert(&txn)
+            .await?;
+
+            // What wasn't taken from a batch is recorded without any date
+            for taken in source.iter().flat_map(|source| &source.products) {
+                let tracked = taken
+                    .batches
+                    .iter()
+                    .map(|batch| batch.quantity)
+                    .sum::<prelude::Decimal>();
+                let untracked = taken.quantity - tracked;
+                let parts = taken
+                    .batches
+                    .iter()
+                    .map(|batch| (batch.quantity, batch.expires_at, Some(batch.received_at)))
+                    .chain((untracked > prelude::Decimal::ZERO).then_some((untracked, None, None)));
+
+                for (quantity, expires_at, received_at) in parts {
+                    transaction_product_stock::ActiveModel {
+                        id: Set(uuid::Uuid::new_v4()),
+                        transaction_product_id: Set(line_id),
+                        product_id: Set(taken.product_id),
+                        quantity: Set(quantity),
+                        expires_at: Set(expires_at),
+                        received_at: Set(received_at),
+                    }
+                    .insert(&txn)
+                    .await?;
+                }
+            }
+        }
+
+        txn.commit().await?;
+
+        #[cfg(feature = "cache")]
+        {
+            let id = result.id;
+            cache_set!(conn, format!("transaction:{id}"), result, 60 * 15);
+            cache_mdel!(conn, "transactions");
+            cache_del!(conn, format!("user:{user_id}"));
+            cache_mdel!(conn, "users");
+            for source in sources.iter().flatten() {
+                let warehouse_id = source.warehouse_id;
+                for taken in &source.products {
+                    let product_id = taken.product_id;
+                    cache_del!(
+                        conn,
+                        format!("warehouse_product:{warehouse_id}/{product_id}")
+                    );
+                }
+                cache_mdel!(conn, format!("warehouse_products:{warehouse_id}"));
+            }
+        }
+
+        notifier::dispatch(conn, journal.alerts);
+
+        Ok(Ok(result))
+    }
+
+    /// Moves the fulfilment of an order from `current` to `status`.
+    ///
+    /// The status is only updated if it is still `current`, so two runners cannot take
+    /// the same order at once. Returns `Ok(None)` when it isn't.
+    pub async fn update_order_fulfilment(
+        conn: &Connection,
+        id: uuid::Uuid,
+        current: FulfilmentStatus,
+        status: FulfilmentStatus,
+    ) -> Result<Option<transaction_purchase_with_balance::Model>, DbErr> {
+        let updated = TransactionPurchaseWithBalance::update_many()
+            .col_expr(
+                transaction_purchase_with_balance::Column::FulfilmentStatus,
+                status.as_enum(),
+            )
+            .filter(transaction_purchase_with_balance::Column::Id.eq(id))
+            .filter(transaction_purchase_with_balance::Column::FulfilmentStatus.eq(current))
+            .exec(&conn.db_connection)
+            .await?;
+
+        if updated.rows_affected == 0 {
+            return Ok(None);
+        }
+
+        let result = TransactionPurchaseWithBalance::find_by_id(id)
+            .one(&conn.db_connection)
+            .await?;
+
+        #[cfg(feature = "cache")]
+        if let Some(model) = &result {
+            cache_set!(
+                conn,
+                format!("transaction_purchase_with_balance:{id}"),
+                model,
+                60 * 15
+            );
+        }
+
+        Ok(result)
+    }
+
+    /// Records a new `Created` refill transaction for `user_id`, with a snapshot of `refill`.
+    ///
+    /// `credit` is what will be credited to the balance, in `credit_currency`: the credit of
+    /// `refill` converted in the currency of the balance when it isn't already in it.
+    pub async fn create_refill_balance_transaction(
+        conn: &Connection,
+        user_id: uuid::Uuid,
+        refill: &refill::Model,
+        credit: prelude::Decimal,
+        credit_currency: CurrencyCode,
+    ) -> Result<transaction::Model, DbErr> {
+        let txn = conn.db_connection.begin().await?;
+
+        let result = transaction::ActiveModel {
+            id: Set(uuid::Uuid::new_v4()),
+            user_id: Set(user_id),
+            created_at: Set(chrono::offset::Local::now().into()),
+            completed_at: Set(None),
+            status: Set(TransactionStatus::Created),
+            transaction_type: Set(TransactionType::RefillBalance),
+        }
+        .insert(&txn)
+        .await?;
+
+        transaction_refill_balance::ActiveModel {
+            id: Set(result.id),
+            refill_id: Set(Some(refill.id)),
+            price: Set(refill.price),
+            price_currency: Set(refill.price_currency.clone()),
+            credit: Set(credit),
+            credit_currency: Set(credit_currency),
+            stripe_transaction_id: Set(None),
+        }
+        .insert(&txn)
+        .await?;
+
+        txn.commit().await?;
+
+        #[cfg(feature = "cache")]
+        {
+            let id = result.id;
+            cache_set!(conn, format!("transaction:{id}"), result, 60 * 15);
+            cache_mdel!(conn, "transactions");
+        }
+
+        Ok(result)
+    }
+
+    pub async fn update_transaction_refill_balance<
+        M: IntoActiveModel<transaction_refill_balance::ActiveModel>,
+    >(
+        conn: &Connection,
+        id: uuid::Uuid,
+        form_data: M,
+    ) -> Result<transaction_refill_balance::Model, DbErr> {
+        let mut form_data = form_data.into_active_model();
+        form_data.id = ActiveValue::Set(id);
+
+        let result = form_data.update(&conn.db_connection).await;
+
+        #[cfg(feature = "cache")]
+        if let Ok(model) = &result {
+            cache_set!(
+                conn,
+                format!("transaction_refill_balance:{id}"),
+                model,
+                60 * 15
+            );
+        }
+
+        result
+    }
+
+    /// Completes a refill transaction and credits the user balance, all inside a single
+    /// database transaction.
+    ///
+    /// Only a `Created` or `Pending` transaction can be completed, so a payment confirmed
+    /// twice is only credited once. Returns `Ok(None)` when the transaction was already closed.
+    pub async fn complete_refill_balance_transaction(
+        conn: &Connection,
+        id: uuid::Uuid,
+    ) -> Result<Option<transaction::Model>, DbErr> {
+        let txn = conn.db_connection.begin().await?;
+
+        let closed = Transaction::update_many()
+            .col_expr(
+                transaction::Column::Status,
+                TransactionStatus::Completed.as_enum(),
+            )
+            .col_expr(
+                transaction::Column::CompletedAt,
+                sea_query::Expr::value(chrono::offset::Local::now().fixed_offset()),
+            )
+            .filter(transaction::Column::Id.eq(id))
+            .filter(transaction::Column::TransactionType.eq(TransactionType::RefillBalance))
+            .filter(
+                transaction::Column::Status
+                    .is_in([TransactionStatus::Created, TransactionStatus::Pending]),
+            )
+            .exec(&txn)
+            .await?;
+
+        if closed.rows_affected == 0 {
+            txn.rollback().await?;
+            return Ok(None);
+        }
+
+        let result = Transaction::find_by_id(id)
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound(format!("transaction:{id}")))?;
+
+        let refill = TransactionRefillBalance::find_by_id(id)
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound(format!(
+                "transaction_refill_balance:{id}"
+            )))?;
+
+        let credit = User::update_many()
+            .col_expr(
+                user::Column::Balance,
+                sea_query::Expr::col(user::Column::Balance).add(refill.credit),
+            )
+            .filter(user::Column::Id.eq(result.user_id))
+            .filter(user::Column::BalanceCurrency.eq(refill.credit_currency))
+            .exec(&txn)
+            .await?;
+
+        if credit.rows_affected == 0 {
+            txn.rollback().await?;
+            return Err(DbErr::RecordNotUpdated);
+        }
+
+        txn.commit().await?;
+
+        #[cfg(feature = "cache")]
+        {
+            let user_id = result.user_id;
+            cache_set!(conn, format!("transaction:{id}"), result, 60 * 15);
+            cache_mdel!(conn, "transactions");
+            cache_del!(conn, format!("user:{user_id}"));
+            cache_mdel!(conn, "users");
+        }
+
+        Ok(Some(result))
+    }
+
+    /// Moves a transaction still open (`Created` or `Pending`) to `status`, without touching
+    /// any balance. A transaction moved to a final status is marked as completed now.
+    /// Returns `Ok(None)` when the transaction was already closed.
+    pub async fn update_open_transaction_status(
+        conn: &Connection,
+        id: uuid::Uuid,
+        status: TransactionStatus,
+    ) -> Result<Option<transaction::Model>, DbErr> {
+        let is_final = !matches!(
+            status,
+            TransactionStatus::Created | TransactionStatus::Pending
+        );
+
+        let mut update =
+            Transaction::update_many().col_expr(transaction::Column::Status, status.as_enum());
+        if is_final {
+            let now: sea_orm::prelude::DateTimeWithTimeZone = chrono::offset::Local::now().into();
+            update = update.col_expr(transaction::Column::CompletedAt, now.into());
+        }
+
+        let updated = update
+            .filter(transaction::Column::Id.eq(id))
+            .filter(
+                transaction::Column::Status
+                    .is_in([TransactionStatus::Created, TransactionStatus::Pending]),
+            )
+            .exec(&conn.db_connection)
+            .await?;
+
+        if updated.rows_affected == 0 {
+            return Ok(None);
+        }
+
+        let result = Transaction::find_by_id(id).one(&conn.db_connection).await?;
+
+        #[cfg(feature = "cache")]
+        if let Some(model) = &result {
+            cache_set!(conn, format!("transaction:{id}"), model, 60 * 15);
+            cache_mdel!(conn, "transactions");
+        }
+
+        Ok(result)
+    }
+
+    /// Applies a manual balance edit made by `author_id` and records the matching
+    /// `BalanceEdit` transaction, all inside a single database transaction.
+    ///
+    /// The edit is only applied if the balance is in `currency` and stays positive,
+    /// returns `Ok(None)` when it isn't.
+    pub async fn create_balance_edit(
+        conn: &Connection,
+        user_id: uuid::Uuid,
+        author_id: uuid::Uuid,
+        edit: BalanceEdit,
+    ) -> Result<Option<transaction::Model>, DbErr> {
+        let txn = conn.db_connection.begin().await?;
+
+        let updated = User::update_many()
+            .col_expr(
+                user::Column::Balance,
+                sea_query::Expr::col(user::Column::Balance).add(edit.delta),
+            )
+            .filter(user::Column::Id.eq(user_id))
+            .filter(user::Column::BalanceCurrency.eq(edit.currency.clone()))
+            .filter(user::Column::Balance.gte(-edit.delta))
+            .exec(&txn)
+            .await?;
+
+        if updated.rows_affected == 0 {
+            txn.rollback().await?;
+            return Ok(None);
+        }
+
+        let now = chrono::offset::Local::now();
+        let result = transaction::ActiveModel {
+            id: Set(uuid::Uuid::new_v4()),
+            user_id: Set(user_id),
+            created_at: Set(now.into()),
+            completed_at: Set(Some(now.into())),
+            status: Set(TransactionStatus::Completed),
+            transaction_type: Set(TransactionType::BalanceEdit),
+        }
+        .insert(&txn)
+        .await?;
+
+        transaction_balance_edit::ActiveModel {
+            id: Set(result.id),
+            author_id: Set(Some(author_id)),
+            delta: Set(edit.delta),
+            delta_currency: Set(edit.currency),
+            reason: Set(edit.reason),
+        }
+        .insert(&txn)
+        .await?;
+
+        txn.commit().await?;
+
+        #[cfg(feature = "cache")]
+        {
+            let id = result.id;
+            cache_set!(conn, format!("transaction:{id}"), result, 60 * 15);
+            cache_mdel!(conn, "transactions");
+            cache_del!(conn, format!("user:{user_id}"));
+            cache_mdel!(conn, "users");
+        }
+
+        Ok(Some(result))
+    }
+
+    /// Cancels a completed purchase made by `author_id`, all inside a single database transaction:
+    /// the purchase moves to `Canceled`, its total is credited back to the balance, every line
+    /// taken from a warehouse is put back into it (or the ingredients it was made of, in the
+    /// quantities used at the time of the purchase), and the refund is recorded.
+    ///
+    /// Only a `Completed` purchase can be canceled, so a purchase is never refunded twice.
+    /// Nothing changes when the cancellation is rejected, the reason being returned as
+    /// `Ok(Err(_))`.
+    pub async fn cancel_purchase_with_balance(
+        conn: &Connection,
+        id: uuid::Uuid,
+        author_id: uuid::Uuid,
+        cancellation: OrderCancellation,
+    ) -> Result<Result<transaction::Model, CancelRejection>, DbErr> {
+        let txn = conn.db_connection.begin().await?;
+
+        let canceled = Transaction::update_many()
+            .col_expr(
+                transaction::Column::Status,
+                TransactionStatus::Canceled.as_enum(),
+            )
+            .filter(transaction::Column::Id.eq(id))
+            .filter(transaction::Column::TransactionType.eq(TransactionType::PurchaseWithBalance))
+            .filter(transaction::Column::Status.eq(TransactionStatus::Completed))
+            .exec(&txn)
+            .await?;
+
+        if canceled.rows_affected == 0 {
+            txn.rollback().await?;
+            return Ok(Err(CancelRejection::NotCompleted));
+        }
+
+        let result = Transaction::find_by_id(id)
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound(format!("transaction:{id}")))?;
+
+        let purchase = TransactionPurchaseWithBalance::find_by_id(id)
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound(format!(
+                "transaction_purchase_with_balance:{id}"
+            )))?;
+
+        let credit = User::update_many()
+            .col_expr(
+                user::Column::Balance,
+                sea_query::Expr::col(user::Column::Balance).add(purchase.price_total),
+            )
+            .filter(user::Column::Id.eq(result.user_id))
+            .filter(user::Column::BalanceCurrency.eq(purchase.price_total_currency.clone()))
+            .exec(&txn)
+            .await?;
+
+        if credit.rows_affected == 0 {
+            txn.rollback().await?;
+            return Ok(Err(CancelRejection::BalanceCurrencyDoesntMatch));
+        }
+
+        let lines = TransactionProduct::find()
+            .filter(transaction_product::Column::TransactionId.eq(id))
+            .all(&txn)
+            .await?;
+
+        let mut journal = StockJournal::new(author_id);
+        let mut restocked = Vec::new();
+        for line in lines {
+            let Some(warehouse_id) = line.warehouse_id else {
+                continue;
+            };
+
+            let products = TransactionProductStock::find()
+                .filter(transaction_product_stock::Column::TransactionProductId.eq(line.id))
+                .all(&txn)
+                .await?;
+
+            stock::restock(&txn, &mut journal, warehouse_id, line.recipe_id, &products).await?;
+            restocked.extend(
+                products
+                    .into_iter()
+                    .map(|taken| (warehouse_id, taken.product_id)),
+            );
+        }
+
+        transaction_refund::ActiveModel {
+            id: Set(id),
+            author_id: Set(Some(author_id)),
+            amount: Set(purchase.price_total),
+            amount_currency: Set(purchase.price_total_currency),
+            reason: Set(cancellation.reason),
+            created_at: Set(chrono::offset::Local::now().into()),
+        }
+        .insert(&txn)
+        .await?;
+
+        txn.commit().await?;
+
+        #[cfg(feature = "cache")]
+        {
+            let user_id = result.user_id;
+            cache_set!(conn, format!("transaction:{id}"), result, 60 * 15);
+            cache_mdel!(conn, "transactions");
+            cache_del!(conn, format!("user:{user_id}"));
+            cache_mdel!(conn, "users");
+            for (warehouse_id, product_id) in &restocked {
+                cache_del!(
+                    conn,
+                    format!("warehouse_product:{warehouse_id}/{product_id}")
+                );
+                cache_mdel!(conn, format!("warehouse_products:{warehouse_id}"));
+            }
+        }
+
+        notifier::dispatch(conn, journal.alerts);
+
+        Ok(Ok(result))
+    }
+
+    pub async fn delete_all_transactions(db: &DbConn) -> Result<DeleteResult, DbErr> {
+        Transaction::delete_many().exec(db).await
+    }
+}
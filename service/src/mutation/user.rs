@@ -7,7 +7,7 @@
 
 #[cfg(feature = "cache")]
 use crate::r#macro::{cache_del, cache_mdel, cache_set};
-use crate::{Connection, mutation::Mutation};
+use crate::{Connection, mutation::Mutation, reconcile::BalanceDrift};
 use ::entity::models::{user, user::Entity as User};
 use sea_orm::*;
 use sqlx::types::Uuid;
@@ -95,6 +95,29 @@ impl Mutation {
         result
     }
 
+    /// Rewrites the stored balance of a user with the one computed from the ledger.
+    ///
+    /// The balance is only rewritten if it didn't change since the drift was computed,
+    /// returns `false` when it did.
+    pub async fn fix_balance_drift(conn: &Connection, drift: &BalanceDrift) -> Result<bool, DbErr> {
+        let result = User::update_many()
+            .col_expr(user::Column::Balance, drift.expected.into())
+            .filter(user::Column::Id.eq(drift.user_id))
+            .filter(user::Column::Balance.eq(drift.balance))
+            .filter(user::Column::BalanceCurrency.eq(drift.currency.clone()))
+            .exec(&conn.db_connection)
+            .await?;
+
+        #[cfg(feature = "cache")]
+        if result.rows_affected > 0 {
+            let id = drift.user_id;
+            cache_del!(conn, format!("user:{id}"));
+            cache_mdel!(conn, "users");
+        }
+
+        Ok(result.rows_affected > 0)
+    }
+
     pub async fn delete_all_users(db: &DbConn) -> Result<DeleteResult, DbErr> {
         User::delete_many().exec(db).await
     }
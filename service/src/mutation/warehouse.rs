@@ -1,9 +1,15 @@
 #[cfg(feature = "cache")]
 use crate::r#macro::{cache_del, cache_mdel, cache_set};
-use crate::{Connection, mutation::Mutation};
+use crate::{
+    Connection,
+    mutation::Mutation,
+    notifier,
+    stock::{InsufficientStock, StockJournal},
+};
 use ::entity::models::{
     prelude::{Warehouse, WarehouseProduct, WarehouseRecipe},
-    warehouse, warehouse_product, warehouse_recipe,
+    sea_orm_active_enums::StockMovementReason,
+    warehouse, warehouse_product, warehouse_product_batch, warehouse_recipe,
 };
 use sea_orm::*;
 
@@ -69,39 +75,82 @@ impl Mutation {
         Warehouse::delete_many().exec(db).await
     }
 
+    /// Stocks a product in a warehouse, recording its initial quantity as a restock made by
+    /// `author_id`.
     pub async fn create_warehouse_product<M: IntoActiveModel<warehouse_product::ActiveModel>>(
         conn: &Connection,
         warehouse_id: uuid::Uuid,
         product_id: uuid::Uuid,
         form_data: M,
+        author_id: uuid::Uuid,
     ) -> Result<warehouse_product::Model, DbErr> {
         let mut form_data = form_data.into_active_model();
         form_data.warehouse_id = Set(warehouse_id);
         form_data.product_id = Set(product_id);
-        let result = form_data.insert(&conn.db_connection).await;
+
+        let txn = conn.db_connection.begin().await?;
+
+        let mut journal = StockJournal::new(author_id);
+        let result = form_data.insert(&txn).await?;
+        journal
+            .record(
+                &txn,
+                warehouse_id,
+                product_id,
+                result.quantity,
+                StockMovementReason::Restock,
+            )
+            .await?;
+
+        txn.commit().await?;
 
         #[cfg(feature = "cache")]
-        if let Ok(_model) = &result {
-            cache_mdel!(conn, "warehouse_products:{warehouse_id}");
-        }
+        cache_mdel!(conn, format!("warehouse_products:{warehouse_id}"));
 
-        result
+        notifier::dispatch(conn, journal.alerts);
+
+        Ok(result)
     }
 
+    /// Updates the stock of a product in a warehouse, recording the difference with the
+    /// previous quantity as a correction made by `author_id`.
     pub async fn update_warehouse_product<M: IntoActiveModel<warehouse_product::ActiveModel>>(
         conn: &Connection,
         warehouse_id: uuid::Uuid,
         product_id: uuid::Uuid,
         form_data: M,
+        author_id: uuid::Uuid,
     ) -> Result<warehouse_product::Model, DbErr> {
         let mut form_data = form_data.into_active_model();
         form_data.warehouse_id = Set(warehouse_id);
         form_data.product_id = Set(product_id);
 
-        let result = form_data.update(&conn.db_connection).await;
+        let txn = conn.db_connection.begin().await?;
+
+        let previous = WarehouseProduct::find_by_id((warehouse_id, product_id))
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound(format!(
+                "warehouse_product:{warehouse_id}/{product_id}"
+            )))?;
+
+        let mut journal = StockJournal::new(author_id);
+        let result = form_data.update(&txn).await?;
+        journal
+            .record(
+                &txn,
+                warehouse_id,
+                product_id,
+                result.quantity - previous.quantity,
+                StockMovementReason::Correction,
+            )
+            .await?;
+
+        txn.commit().await?;
 
         #[cfg(feature = "cache")]
-        if let Ok(_model) = &result {
+        {
             cache_del!(
                 conn,
                 format!("warehouse_product:{warehouse_id}/{product_id}")
@@ -109,27 +158,46 @@ impl Mutation {
             cache_mdel!(conn, format!("warehouse_products:{warehouse_id}"));
         }
 
-        result
+        notifier::dispatch(conn, journal.alerts);
+
+        Ok(result)
     }
 
+    /// Removes a product from a warehouse, recording its remaining quantity as a correction
+    /// made by `author_id`.
     pub async fn delete_warehouse_product(
         conn: &Connection,
         warehouse_id: uuid::Uuid,
         product_id: uuid::Uuid,
+        author_id: uuid::Uuid,
     ) -> Result<DeleteResult, DbErr> {
-        let warehouse: warehouse_product::ActiveModel =
-            WarehouseProduct::find_by_id((warehouse_id, product_id))
-                .one(&conn.db_connection)
-                .await?
-                .ok_or(DbErr::Custom(format!(
-                    "Cannot find Warehouse/Products: \"{warehouse_id}\"/\"{product_id}\""
-                )))
-                .map(Into::into)?;
+        let txn = conn.db_connection.begin().await?;
 
-        let result = warehouse.delete(&conn.db_connection).await;
+        let warehouse_product = WarehouseProduct::find_by_id((warehouse_id, product_id))
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::Custom(format!(
+                "Cannot find Warehouse/Products: \"{warehouse_id}\"/\"{product_id}\""
+            )))?;
+        let quantity = warehouse_product.quantity;
+
+        let mut journal = StockJournal::new(author_id);
+        let result = warehouse_product.delete(&txn).await?;
+        journal
+            .record(
+                &txn,
+                warehouse_id,
+                product_id,
+                -quantity,
+                StockMovementReason::Correction,
+            )
+            .await?;
+
+        txn.commit().await?;
 
         #[cfg(feature = "cache")]
-        if result.is_ok() {
+        {
             cache_del!(
                 conn,
                 format!("warehouse_product:{warehouse_id}/{product_id}")
@@ -137,21 +205,209 @@ impl Mutation {
             cache_mdel!(conn, format!("warehouse_products:{warehouse_id}"));
         }
 
-        result
+        notifier::dispatch(conn, journal.alerts);
+
+        Ok(result)
+    }
+
+    /// Adds `quantity` of a product to the stock of a warehouse as a new batch received now,
+    /// stocking the product there if it wasn't yet. The increase is recorded as a restock made
+    /// by `author_id`.
+    pub async fn create_warehouse_product_batch(
+        conn: &Connection,
+        warehouse_id: uuid::Uuid,
+        product_id: uuid::Uuid,
+        quantity: prelude::Decimal,
+        expires_at: Option<prelude::DateTimeWithTimeZone>,
+        author_id: uuid::Uuid,
+    ) -> Result<warehouse_product_batch::Model, DbErr> {
+        let txn = conn.db_connection.begin().await?;
+
+        let mut journal = StockJournal::new(author_id);
+        let result = journal
+            .give_batch(
+                &txn,
+                warehouse_id,
+                product_id,
+                quantity,
+                expires_at,
+                chrono::offset::Local::now().into(),
+                StockMovementReason::Restock,
+            )
+            .await?;
+
+        txn.commit().await?;
+
+        #[cfg(feature = "cache")]
+        {
+            cache_del!(
+                conn,
+                format!("warehouse_product:{warehouse_id}/{product_id}")
+            );
+            cache_mdel!(conn, format!("warehouse_products:{warehouse_id}"));
+        }
+
+        notifier::dispatch(conn, journal.alerts);
+
+        Ok(result)
+    }
+
+    /// Sets the stock of many products of a warehouse at once, stocking the products which
+    /// weren't yet, all inside a single database transaction. Each difference with the previous
+    /// quantity is recorded as a correction made by `author_id`.
+    ///
+    /// Returns the resulting stock of every product, in the order of `lines`.
+    pub async fn set_warehouse_product_quantities(
+        conn: &Connection,
+        warehouse_id: uuid::Uuid,
+        lines: Vec<(uuid::Uuid, prelude::Decimal)>,
+        author_id: uuid::Uuid,
+    ) -> Result<Vec<warehouse_product::Model>, DbErr> {
+        let txn = conn.db_connection.begin().await?;
+
+        let mut journal = StockJournal::new(author_id);
+        let mut result = Vec::with_capacity(lines.len());
+        for (product_id, quantity) in lines {
+            let (_, model) = journal
+                .set(
+                    &txn,
+                    warehouse_id,
+                    product_id,
+                    quantity,
+                    StockMovementReason::Correction,
+                )
+                .await?;
+            result.push(model);
+        }
+
+        txn.commit().await?;
+
+        #[cfg(feature = "cache")]
+        {
+            for model in &result {
+                let product_id = model.product_id;
+                cache_del!(
+                    conn,
+                    format!("warehouse_product:{warehouse_id}/{product_id}")
+                );
+            }
+            cache_mdel!(conn, format!("warehouse_products:{warehouse_id}"));
+        }
+
+        notifier::dispatch(conn, journal.alerts);
+
+        Ok(result)
+    }
+
+    /// Moves products from the warehouse `from` to the warehouse `to` on behalf of `author_id`,
+    /// all inside a single database transaction.
+    ///
+    /// Each product is only taken from `from` if it still holds enough of it, and is stocked
+    /// in `to` if it wasn't yet. The batches it was taken from are moved along with their
+    /// expiry and reception dates. Both sides are recorded as transfers in the journal. Nothing
+    /// is moved when a product is short, the product being returned as `Ok(Err(_))`.
+    ///
+    /// Returns the resulting stock of every product moved, in `from` then in `to`.
+    pub async fn transfer_stock(
+        conn: &Connection,
+        from: uuid::Uuid,
+        to: uuid::Uuid,
+        lines: Vec<(uuid::Uuid, prelude::Decimal)>,
+        author_id: uuid::Uuid,
+    ) -> Result<
+        Result<Vec<(warehouse_product::Model, warehouse_product::Model)>, InsufficientStock>,
+        DbErr,
+    > {
+        let txn = conn.db_connection.begin().await?;
+
+        let mut journal = StockJournal::new(author_id);
+        let mut products = Vec::with_capacity(lines.len());
+        for (product_id, quantity) in lines {
+            let reason = StockMovementReason::Transfer;
+            let Some(batches) = journal
+                .take(&txn, from, product_id, quantity, reason.clone())
+                .await?
+            else {
+                txn.rollback().await?;
+                return Ok(Err(InsufficientStock(product_id)));
+            };
+
+            // The batches keep their expiry and reception dates in the destination
+            let mut untracked = quantity;
+            for batch in batches {
+                untracked -= batch.quantity;
+                journal
+                    .give_batch(
+                        &txn,
+                        to,
+                        product_id,
+                        batch.quantity,
+                        batch.expires_at,
+                        batch.received_at,
+                        reason.clone(),
+                    )
+                    .await?;
+            }
+            if untracked > prelude::Decimal::ZERO {
+                journal
+                    .give(&txn, to, product_id, untracked, reason)
+                    .await?;
+            }
+
+            if !products.contains(&product_id) {
+                products.push(product_id);
+            }
+        }
+
+        let mut result = Vec::with_capacity(products.len());
+        for &product_id in &products {
+            let source = WarehouseProduct::find_by_id((from, product_id))
+                .one(&txn)
+                .await?
+                .ok_or(DbErr::RecordNotFound(format!(
+                    "warehouse_product:{from}/{product_id}"
+                )))?;
+            let destination = WarehouseProduct::find_by_id((to, product_id))
+                .one(&txn)
+                .await?
+                .ok_or(DbErr::RecordNotFound(format!(
+                    "warehouse_product:{to}/{product_id}"
+                )))?;
+            result.push((source, destination));
+        }
+
+        txn.commit().await?;
+
+        #[cfg(feature = "cache")]
+        for warehouse_id in [from, to] {
+            for product_id in &products {
+                cache_del!(
+                    conn,
+                    format!("warehouse_product:{warehouse_id}/{product_id}")
+                );
+            }
+            cache_mdel!(conn, format!("warehouse_products:{warehouse_id}"));
+        }
+
+        notifier::dispatch(conn, journal.alerts);
+
+        Ok(Ok(result))
     }
 
     pub async fn create_warehouse_recipe<M: IntoActiveModel<warehouse_recipe::ActiveModel>>(
         conn: &Connection,
         warehouse_id: uuid::Uuid,
+        recipe_id: uuid::Uuid,
         form_data: M,
     ) -> Result<warehouse_recipe::Model, DbErr> {
         let mut form_data = form_data.into_active_model();
         form_data.warehouse_id = Set(warehouse_id);
+        form_data.recipe_id = Set(recipe_id);
         let result = form_data.insert(&conn.db_connection).await;
 
         #[cfg(feature = "cache")]
         if let Ok(_model) = &result {
-            cache_mdel!(conn, "warehouse_recipes:{warehouse_id}");
+            cache_mdel!(conn, format!("warehouse_recipes:{warehouse_id}"));
         }
 
         result
@@ -0,0 +1,59 @@
+//! In-memory stock notifier.
+//!
+//! This notifier only keeps the alerts it receives, so they can be checked afterwards. It exists
+//! for tests, nobody gets warned.
+
+use super::{NotifierError, StockAlert, StockNotifier};
+use std::sync::{Arc, Mutex};
+
+/// Notifier keeping every alert in memory, see the module documentation.
+///
+/// Clones share the same alerts, so a clone can be handed to the application while the
+/// original is used to read them.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStockNotifier {
+    /// Alerts received so far, oldest first
+    pub alerts: Arc<Mutex<Vec<StockAlert>>>,
+}
+
+impl MemoryStockNotifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the alerts received so far, oldest first.
+    pub fn alerts(&self) -> Vec<StockAlert> {
+        self.alerts
+            .lock()
+            .map(|alerts| alerts.clone())
+            .unwrap_or_default()
+    }
+
+    /// Waits until `count` alerts were received, as they are sent in the background, and
+    /// returns them. Gives up after a second, returning the alerts received so far.
+    pub async fn wait_alerts(&self, count: usize) -> Vec<StockAlert> {
+        for _ in 0..100 {
+            let alerts = self.alerts();
+            if alerts.len() >= count {
+                return alerts;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        self.alerts()
+    }
+}
+
+#[async_trait::async_trait]
+impl StockNotifier for MemoryStockNotifier {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+
+    async fn notify(&self, alert: &StockAlert) -> Result<(), NotifierError> {
+        self.alerts
+            .lock()
+            .map_err(|err| NotifierError::Refused(err.to_string()))?
+            .push(alert.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+//! Stock notifiers for the `scrounch_backend` application.
+//!
+//! This module defines the `StockNotifier` trait, implemented by every channel able to warn
+//! whoever restocks the warehouses. A `StockAlert` is emitted each time a stock change crosses
+//! the `min_quantity` threshold of a warehouse product, in either direction:
+//! - `Low` when the stock drops at or below the threshold
+//! - `Replenished` when it goes back above it
+//!
+//! The alerts are only sent once the change is committed, in the background. The application
+//! only talks to the notifier through a `StockNotifierGateway`, so the channel can be swapped
+//! by configuration.
+
+pub mod memory;
+pub mod webhook;
+
+use crate::Connection;
+use sea_orm::prelude::Decimal;
+use std::sync::Arc;
+
+/// Direction in which a stock crossed its threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StockAlertKind {
+    /// The stock dropped at or below its threshold.
+    Low,
+    /// The stock went back above its threshold.
+    Replenished,
+}
+
+/// A stock which crossed its threshold.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct StockAlert {
+    /// Direction of the crossing
+    pub kind: StockAlertKind,
+
+    /// Id of the warehouse holding the stock
+    pub warehouse_id: uuid::Uuid,
+
+    /// Id of the product stocked
+    pub product_id: uuid::Uuid,
+
+    /// Stock after the change
+    pub quantity: Decimal,
+
+    /// Threshold crossed
+    pub min_quantity: Decimal,
+
+    /// Quantity to bring back, if any
+    pub reorder_quantity: Option<Decimal>,
+}
+
+/// Errors a notifier can return.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NotifierError {
+    /// The channel couldn't be reached.
+    Unreachable(String),
+    /// The channel refused the alert.
+    Refused(String),
+}
+
+impl std::error::Error for NotifierError {}
+
+impl std::fmt::Display for NotifierError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unreachable(err) => write!(f, "Notifier couldn't be reached: {err}"),
+            Self::Refused(err) => write!(f, "Notifier refused the alert: {err}"),
+        }
+    }
+}
+
+/// A channel warning about stocks crossing their threshold.
+#[async_trait::async_trait]
+pub trait StockNotifier: std::fmt::Debug + Send + Sync {
+    /// Short name of the notifier, used in logs.
+    fn name(&self) -> &'static str;
+
+    /// Sends an alert.
+    async fn notify(&self, alert: &StockAlert) -> Result<(), NotifierError>;
+}
+
+/// Shared handle on the configured stock notifier.
+#[derive(Debug, Clone)]
+pub struct StockNotifierGateway {
+    /// The notifier in use
+    pub notifier: Arc<dyn StockNotifier>,
+}
+
+impl StockNotifierGateway {
+    pub fn new<N: StockNotifier + 'static>(notifier: N) -> Self {
+        Self {
+            notifier: Arc::new(notifier),
+        }
+    }
+}
+
+/// Sends `alerts` through the notifier of `conn`, if any, in the background so the request
+/// doesn't wait on the channel.
+///
+/// The stock change already happened, so a failing notifier is only logged.
+pub(crate) fn dispatch(conn: &Connection, alerts: Vec<StockAlert>) {
+    let Some(gateway) = conn.stock_notifier.clone() else {
+        return;
+    };
+    if alerts.is_empty() {
+        return;
+    }
+
+    tokio::spawn(async move {
+        for alert in alerts {
+            if let Err(err) = gateway.notifier.notify(&alert).await {
+                log::warn!(
+                    "{} couldn't send stock alert {alert:?}: {err}",
+                    gateway.notifier.name()
+                );
+            }
+        }
+    });
+}
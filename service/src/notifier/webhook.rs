@@ -0,0 +1,55 @@
+//! Webhook stock notifier.
+//!
+//! Every alert is sent as a JSON `StockAlert` in the body of a `POST` request to the configured
+//! URL, so it can be forwarded to any chat or paging service.
+
+use super::{NotifierError, StockAlert, StockNotifier};
+use std::time::Duration;
+
+/// How long a webhook has to answer before the alert is given up.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Notifier posting every alert to a webhook.
+#[derive(Debug, Clone)]
+pub struct WebhookStockNotifier {
+    /// URL the alerts are posted to
+    pub url: String,
+
+    /// HTTP client used to reach the webhook
+    pub client: reqwest::Client,
+}
+
+impl WebhookStockNotifier {
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            client: reqwest::Client::builder()
+                .timeout(WEBHOOK_TIMEOUT)
+                .build()
+                .expect("Couldn't build the webhook HTTP client"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl StockNotifier for WebhookStockNotifier {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn notify(&self, alert: &StockAlert) -> Result<(), NotifierError> {
+        let response = self
+            .client
+            .post(&self.url)
+            .json(alert)
+            .send()
+            .await
+            .map_err(|err| NotifierError::Unreachable(err.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(NotifierError::Refused(response.status().to_string()));
+        }
+
+        Ok(())
+    }
+}
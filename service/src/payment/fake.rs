@@ -0,0 +1,37 @@
+//! Local fake payment provider.
+//!
+//! This provider never moves any money: the checkout url points back to the backend, where
+//! the payment can be confirmed or canceled by the user. It exists to run the whole payment
+//! flow in development and tests, it must never be enabled in production.
+
+use super::{Checkout, CheckoutSession, PaymentError, PaymentProvider};
+
+/// Payment provider accepting every payment, see the module documentation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FakePaymentProvider {
+    /// Base URL of the backend, used to build the checkout url
+    pub backend_url: String,
+}
+
+impl FakePaymentProvider {
+    pub fn new(backend_url: String) -> Self {
+        Self { backend_url }
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentProvider for FakePaymentProvider {
+    fn name(&self) -> &'static str {
+        "fake"
+    }
+
+    async fn create_checkout(&self, checkout: &Checkout) -> Result<CheckoutSession, PaymentError> {
+        let id = checkout.transaction_id;
+        let backend_url = self.backend_url.trim_end_matches('/');
+
+        Ok(CheckoutSession {
+            id: format!("fake_{id}"),
+            url: format!("{backend_url}/payment/fake/{id}"),
+        })
+    }
+}
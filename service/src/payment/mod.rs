@@ -0,0 +1,142 @@
+//! Payment providers for the `scrounch_backend` application.
+//!
+//! This module defines the `PaymentProvider` trait, implemented by every payment service
+//! provider (PSP) able to collect money for a purchase. The application only talks to the
+//! provider through a `PaymentGateway`, so the provider can be swapped by configuration.
+
+pub mod fake;
+pub mod stripe;
+
+use ::entity::models::{
+    currency::{self, CurrencyCode},
+    sea_orm_active_enums::TransactionStatus,
+};
+use sea_orm::prelude::Decimal;
+use std::sync::Arc;
+
+/// Everything a provider needs to know to collect the money of a purchase.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Checkout {
+    /// Id of the transaction paid by this checkout
+    pub transaction_id: uuid::Uuid,
+
+    /// Name of what is bought, displayed to the user
+    pub name: String,
+
+    /// Amount to collect
+    pub price: Decimal,
+
+    /// Currency of the amount to collect, as registered
+    pub currency: currency::Model,
+
+    /// Where the user is sent back once the payment is done
+    pub success_url: String,
+
+    /// Where the user is sent back if the payment is abandoned
+    pub cancel_url: String,
+}
+
+/// A checkout session opened on the provider side.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckoutSession {
+    /// Identifier of the session on the provider side
+    pub id: String,
+
+    /// Where the user must be sent to pay
+    pub url: String,
+}
+
+/// The outcome of a payment, as notified by the provider through a webhook.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaymentEvent {
+    /// Id of the transaction paid by the checkout session
+    pub transaction_id: uuid::Uuid,
+
+    /// Identifier of the checkout session on the provider side
+    pub session_id: String,
+
+    /// Status the transaction must be moved to
+    pub status: TransactionStatus,
+}
+
+/// Errors a payment provider can return.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaymentError {
+    /// The provider doesn't handle this currency.
+    UnsupportedCurrency(CurrencyCode),
+    /// The provider couldn't be reached.
+    Unreachable(String),
+    /// The provider refused the request.
+    Refused(String),
+    /// The signature of a webhook payload is missing or doesn't match.
+    InvalidSignature(String),
+    /// The webhook payload couldn't be understood.
+    InvalidPayload(String),
+}
+
+impl std::error::Error for PaymentError {}
+
+impl std::fmt::Display for PaymentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedCurrency(currency) => {
+                write!(f, "Currency {currency} is not supported by the provider")
+            }
+            Self::Unreachable(err) => write!(f, "Payment provider couldn't be reached: {err}"),
+            Self::Refused(err) => write!(f, "Payment provider refused the request: {err}"),
+            Self::InvalidSignature(err) => write!(f, "Webhook signature is invalid: {err}"),
+            Self::InvalidPayload(err) => write!(f, "Webhook payload is invalid: {err}"),
+        }
+    }
+}
+
+/// A payment service provider (PSP).
+///
+/// The provider only opens checkout sessions, the confirmation of a payment is received
+/// asynchronously (webhook, redirection, ...) and applied with
+/// `Mutation::complete_refill_balance_transaction`.
+///
+/// Providers notifying payments through webhooks also implement `webhook_signature_header`
+/// and `parse_webhook`.
+#[async_trait::async_trait]
+pub trait PaymentProvider: std::fmt::Debug + Send + Sync {
+    /// Short name of the provider, used in logs.
+    fn name(&self) -> &'static str;
+
+    /// Opens a checkout session, the user must then be sent to `CheckoutSession::url`.
+    async fn create_checkout(&self, checkout: &Checkout) -> Result<CheckoutSession, PaymentError>;
+
+    /// Name of the header holding the signature of webhook payloads,
+    /// `None` if the provider doesn't send webhooks.
+    fn webhook_signature_header(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Verifies the `signature` of a webhook `payload` and extracts the payment outcome from it.
+    /// Returns `Ok(None)` for the events the application doesn't care about.
+    fn parse_webhook(
+        &self,
+        _signature: &str,
+        _payload: &[u8],
+    ) -> Result<Option<PaymentEvent>, PaymentError> {
+        Err(PaymentError::InvalidPayload(format!(
+            "{} doesn't send webhooks",
+            self.name()
+        )))
+    }
+}
+
+/// Shared handle on the configured payment provider.
+#[derive(Debug, Clone)]
+pub struct PaymentGateway {
+    /// The provider in use
+    pub provider: Arc<dyn PaymentProvider>,
+}
+
+impl PaymentGateway {
+    pub fn new<P: PaymentProvider + 'static>(provider: P) -> Self {
+        Self {
+            provider: Arc::new(provider),
+        }
+    }
+}
@@ -0,0 +1,282 @@
+//! Stripe-compatible payment provider.
+//!
+//! Checkout sessions are opened through the `/v1/checkout/sessions` HTTP API, and the outcome
+//! of each payment is received through signed webhooks. The base URL of the API is
+//! configurable, so any Stripe-compatible server (or a local mock) can be used.
+
+use super::{Checkout, CheckoutSession, PaymentError, PaymentEvent, PaymentProvider};
+use ::entity::models::{currency::EURO, sea_orm_active_enums::TransactionStatus};
+use hmac::{Hmac, Mac};
+use rust_decimal::prelude::ToPrimitive;
+use sha2::Sha256;
+
+/// Maximum age of a webhook payload, older payloads are rejected to prevent replays.
+pub const WEBHOOK_TOLERANCE_SECS: i64 = 300;
+
+/// Payment provider talking to a Stripe-compatible API.
+#[derive(Debug, Clone)]
+pub struct StripePaymentProvider {
+    /// Base URL of the API, e.g. `https://api.stripe.com`
+    pub base_url: String,
+
+    /// Secret API key, sent as a bearer token
+    pub secret_key: String,
+
+    /// Secret used to sign the webhook payloads
+    pub webhook_secret: String,
+
+    /// HTTP client used to reach the API
+    pub client: reqwest::Client,
+}
+
+impl StripePaymentProvider {
+    pub fn new(base_url: String, secret_key: String, webhook_secret: String) -> Self {
+        Self {
+            base_url,
+            secret_key,
+            webhook_secret,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+/// Body of a checkout session returned by the API.
+#[derive(Debug, serde::Deserialize)]
+struct StripeCheckoutSession {
+    /// Identifier of the session
+    id: String,
+
+    /// Where the user must be sent to pay
+    url: Option<String>,
+
+    /// Id of the transaction, given when the session was created
+    client_reference_id: Option<String>,
+
+    /// `paid`, `unpaid` or `no_payment_required`
+    payment_status: Option<String>,
+}
+
+/// Body of an error returned by the API.
+#[derive(Debug, serde::Deserialize)]
+struct StripeErrorBody {
+    /// Details of the error
+    error: StripeError,
+}
+
+/// Details of an error returned by the API.
+#[derive(Debug, serde::Deserialize)]
+struct StripeError {
+    /// Human readable message
+    message: Option<String>,
+}
+
+/// A webhook event, only checkout session events are used.
+#[derive(Debug, serde::Deserialize)]
+struct StripeEvent {
+    /// Kind of event, e.g. `checkout.session.completed`
+    #[serde(rename = "type")]
+    event_type: String,
+
+    /// Object the event is about
+    data: StripeEventData,
+}
+
+/// Object a webhook event is about.
+#[derive(Debug, serde::Deserialize)]
+struct StripeEventData {
+    /// The checkout session
+    object: StripeCheckoutSession,
+}
+
+/// Verifies a `Stripe-Signature` header (`t=<timestamp>,v1=<hex hmac>,...`) against `payload`.
+///
+/// The signature is an HMAC-SHA256 of `<timestamp>.<payload>` keyed with `secret`.
+/// Several `v1` entries may be present while a secret is rolled, one matching is enough.
+pub fn verify_webhook_signature(
+    header: &str,
+    payload: &[u8],
+    secret: &str,
+    now: i64,
+) -> Result<(), PaymentError> {
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+    for part in header.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", value)) => timestamp = value.parse::<i64>().ok(),
+            Some(("v1", value)) => signatures.push(value),
+            _ => {}
+        }
+    }
+
+    let Some(timestamp) = timestamp else {
+        return Err(PaymentError::InvalidSignature(
+            "Missing timestamp".to_string(),
+        ));
+    };
+
+    if (now - timestamp).abs() > WEBHOOK_TOLERANCE_SECS {
+        return Err(PaymentError::InvalidSignature(format!(
+            "Timestamp {timestamp} is outside of the tolerance"
+        )));
+    }
+
+    let matches = signatures.iter().any(|signature| {
+        let Ok(signature) = hex::decode(signature) else {
+            return false;
+        };
+        let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+            return false;
+        };
+        mac.update(timestamp.to_string().as_bytes());
+        mac.update(b".");
+        mac.update(payload);
+        mac.verify_slice(&signature).is_ok()
+    });
+
+    match matches {
+        true => Ok(()),
+        false => Err(PaymentError::InvalidSignature(
+            "No signature matches the payload".to_string(),
+        )),
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentProvider for StripePaymentProvider {
+    fn name(&self) -> &'static str {
+        "stripe"
+    }
+
+    async fn create_checkout(&self, checkout: &Checkout) -> Result<CheckoutSession, PaymentError> {
+        if checkout.currency.is_internal {
+            return Err(PaymentError::UnsupportedCurrency(
+                checkout.currency.clone().into(),
+            ));
+        }
+
+        // Stripe expects ISO 4217 codes, only the Euro is registered under another name
+        let currency = match checkout.currency.code.as_str() {
+            EURO => "eur",
+            code => code,
+        };
+
+        // Stripe expects the amount in the smallest unit of the currency
+        let unit_amount = u32::try_from(checkout.currency.decimal_places)
+            .ok()
+            .and_then(|decimal_places| 10_i64.checked_pow(decimal_places))
+            .and_then(|factor| {
+                (checkout.price * rust_decimal::Decimal::from(factor))
+                    .round()
+                    .to_i64()
+            })
+            .ok_or(PaymentError::Refused(format!(
+                "Price {} cannot be converted in the smallest unit of {}",
+                checkout.price, checkout.currency.code
+            )))?;
+
+        let transaction_id = checkout.transaction_id.to_string();
+        let unit_amount = unit_amount.to_string();
+        let form = [
+            ("mode", "payment"),
+            ("success_url", checkout.success_url.as_str()),
+            ("cancel_url", checkout.cancel_url.as_str()),
+            ("client_reference_id", transaction_id.as_str()),
+            ("metadata[transaction_id]", transaction_id.as_str()),
+            ("line_items[0][quantity]", "1"),
+            ("line_items[0][price_data][currency]", currency),
+            (
+                "line_items[0][price_data][unit_amount]",
+                unit_amount.as_str(),
+            ),
+            (
+                "line_items[0][price_data][product_data][name]",
+                checkout.name.as_str(),
+            ),
+        ];
+
+        let base_url = self.base_url.trim_end_matches('/');
+        let response = self
+            .client
+            .post(format!("{base_url}/v1/checkout/sessions"))
+            .bearer_auth(&self.secret_key)
+            .header("Idempotency-Key", transaction_id.as_str())
+            .form(&form)
+            .send()
+            .await
+            .map_err(|err| PaymentError::Unreachable(err.to_string()))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let message = response
+                .json::<StripeErrorBody>()
+                .await
+                .ok()
+                .and_then(|body| body.error.message)
+                .unwrap_or(status.to_string());
+            return Err(PaymentError::Refused(message));
+        }
+
+        let session = response
+            .json::<StripeCheckoutSession>()
+            .await
+            .map_err(|err| PaymentError::Refused(err.to_string()))?;
+
+        match session.url {
+            Some(url) => Ok(CheckoutSession {
+                id: session.id,
+                url,
+            }),
+            None => Err(PaymentError::Refused(format!(
+                "Checkout session {} has no url",
+                session.id
+            ))),
+        }
+    }
+
+    fn webhook_signature_header(&self) -> Option<&'static str> {
+        Some("Stripe-Signature")
+    }
+
+    fn parse_webhook(
+        &self,
+        signature: &str,
+        payload: &[u8],
+    ) -> Result<Option<PaymentEvent>, PaymentError> {
+        verify_webhook_signature(
+            signature,
+            payload,
+            &self.webhook_secret,
+            chrono::Utc::now().timestamp(),
+        )?;
+
+        let event = serde_json::from_slice::<StripeEvent>(payload)
+            .map_err(|err| PaymentError::InvalidPayload(err.to_string()))?;
+
+        let session = event.data.object;
+        let status = match event.event_type.as_str() {
+            "checkout.session.completed" => match session.payment_status.as_deref() {
+                Some("paid") | Some("no_payment_required") => TransactionStatus::Completed,
+                _ => TransactionStatus::Pending,
+            },
+            "checkout.session.async_payment_succeeded" => TransactionStatus::Completed,
+            "checkout.session.async_payment_failed" => TransactionStatus::Failed,
+            "checkout.session.expired" => TransactionStatus::Canceled,
+            _ => return Ok(None),
+        };
+
+        let transaction_id = session
+            .client_reference_id
+            .as_deref()
+            .and_then(|id| uuid::Uuid::parse_str(id).ok())
+            .ok_or(PaymentError::InvalidPayload(format!(
+                "Checkout session {} has no transaction",
+                session.id
+            )))?;
+
+        Ok(Some(PaymentEvent {
+            transaction_id,
+            session_id: session.id,
+            status,
+        }))
+    }
+}
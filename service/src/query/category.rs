@@ -0,0 +1,131 @@
+//! Category query services for the `scrounch_backend` application.
+//!
+//! This module defines services and functions related to querying the categories the products
+//! are grouped in, and the way they are nested inside each other.
+
+#[cfg(feature = "cache")]
+use crate::r#macro::{cache_get, cache_mget, cache_mset, cache_set};
+use crate::{Connection, query::Query};
+use ::entity::models::{category, category::Entity as Category};
+use sea_orm::*;
+
+impl Query {
+    pub async fn find_category_by_id(
+        conn: &Connection,
+        id: uuid::Uuid,
+    ) -> Result<Option<category::Model>, DbErr> {
+        #[cfg(feature = "cache")]
+        cache_get!(conn, format!("category:{id}"), category::Model);
+
+        let result = Category::find_by_id(id).one(&conn.db_connection).await?;
+
+        #[cfg(feature = "cache")]
+        if let Some(model) = &result {
+            cache_set!(conn, format!("category:{id}"), model, 60 * 15);
+        }
+
+        Ok(result)
+    }
+
+    pub async fn find_category_by_sma_id(
+        conn: &Connection,
+        sma_id: String,
+    ) -> Result<Option<category::Model>, DbErr> {
+        Category::find()
+            .filter(category::Column::SmaId.eq(sma_id))
+            .one(&conn.db_connection)
+            .await
+    }
+
+    pub async fn list_categories_with_condition<
+        F: sea_query::IntoCondition + std::fmt::Debug + Clone,
+        S: IntoIterator<Item = (impl IntoSimpleExpr, Order)> + std::fmt::Debug + Clone,
+        A: Into<u64> + Copy,
+        P: Into<u64> + Copy,
+    >(
+        conn: &Connection,
+        filter: F,
+        sort: S,
+        page: A,
+        per_page: P,
+    ) -> Result<Vec<category::Model>, DbErr> {
+        #[cfg(feature = "cache")]
+        cache_mget!(
+            conn,
+            format!(
+                "categories:{filter:?}-{sort:?}-{}/{}",
+                page.into(),
+                per_page.into()
+            ),
+            category::Model
+        );
+
+        let mut query = Category::find().filter(filter.clone());
+        for (column, order) in sort.clone() {
+            query = query.order_by_with_nulls(column, order, sea_query::NullOrdering::Last);
+        }
+        let query = query.paginate(&conn.db_connection, per_page.into());
+
+        let result = query.fetch_page(page.into()).await?;
+
+        #[cfg(feature = "cache")]
+        cache_mset!(
+            conn,
+            format!(
+                "categories:{filter:?}-{sort:?}-{}/{}",
+                page.into(),
+                per_page.into()
+            ),
+            result,
+            60 * 15,
+            |x: &category::Model| format!("category:{}", x.id)
+        );
+
+        Ok(result)
+    }
+
+    pub async fn count_categories_with_condition<F: sea_query::IntoCondition>(
+        conn: &Connection,
+        filter: F,
+    ) -> Result<u64, DbErr> {
+        Category::find()
+            .filter(filter)
+            .count(&conn.db_connection)
+            .await
+    }
+
+    /// Returns the category `id` followed by every category nested inside it, at any depth.
+    pub async fn category_descendants(
+        conn: &Connection,
+        id: uuid::Uuid,
+    ) -> Result<Vec<uuid::Uuid>, DbErr> {
+        let categories: Vec<(uuid::Uuid, Option<uuid::Uuid>)> = Category::find()
+            .select_only()
+            .column(category::Column::Id)
+            .column(category::Column::ParentId)
+            .into_tuple()
+            .all(&conn.db_connection)
+            .await?;
+
+        let mut children = std::collections::HashMap::<uuid::Uuid, Vec<uuid::Uuid>>::new();
+        for (child, parent) in categories {
+            if let Some(parent) = parent {
+                children.entry(parent).or_default().push(child);
+            }
+        }
+
+        let mut result = vec![id];
+        let mut index = 0;
+        while let Some(current) = result.get(index).copied() {
+            for &child in children.get(&current).into_iter().flatten() {
+                // The tree is kept acyclic, but never loop on a corrupted one
+                if !result.contains(&child) {
+                    result.push(child);
+                }
+            }
+            index += 1;
+        }
+
+        Ok(result)
+    }
+}
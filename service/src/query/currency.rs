@@ -0,0 +1,85 @@
+//! Currency query services for the `scrounch_backend` application.
+//!
+//! This module defines services and functions related to querying the currency registry.
+
+#[cfg(feature = "cache")]
+use crate::r#macro::{cache_get, cache_mget, cache_mset, cache_set};
+use crate::{Connection, query::Query};
+use ::entity::models::{currency, currency::Entity as Currency};
+use sea_orm::*;
+
+impl Query {
+    pub async fn find_currency_by_code(
+        conn: &Connection,
+        code: &str,
+    ) -> Result<Option<currency::Model>, DbErr> {
+        #[cfg(feature = "cache")]
+        cache_get!(conn, format!("currency:{code}"), currency::Model);
+
+        let result = Currency::find_by_id(code).one(&conn.db_connection).await?;
+
+        #[cfg(feature = "cache")]
+        if let Some(model) = &result {
+            cache_set!(conn, format!("currency:{code}"), model, 60 * 15);
+        }
+
+        Ok(result)
+    }
+
+    pub async fn list_currencies_with_condition<
+        F: sea_query::IntoCondition + std::fmt::Debug + Clone,
+        S: IntoIterator<Item = (impl IntoSimpleExpr, Order)> + std::fmt::Debug + Clone,
+        A: Into<u64> + Copy,
+        P: Into<u64> + Copy,
+    >(
+        conn: &Connection,
+        filter: F,
+        sort: S,
+        page: A,
+        per_page: P,
+    ) -> Result<Vec<currency::Model>, DbErr> {
+        #[cfg(feature = "cache")]
+        cache_mget!(
+            conn,
+            format!(
+                "currencies:{filter:?}-{sort:?}-{}/{}",
+                page.into(),
+                per_page.into()
+            ),
+            currency::Model
+        );
+
+        let mut query = Currency::find().filter(filter.clone());
+        for (column, order) in sort.clone() {
+            query = query.order_by_with_nulls(column, order, sea_query::NullOrdering::Last);
+        }
+        let query = query.paginate(&conn.db_connection, per_page.into());
+
+        let result = query.fetch_page(page.into()).await?;
+
+        #[cfg(feature = "cache")]
+        cache_mset!(
+            conn,
+            format!(
+                "currencies:{filter:?}-{sort:?}-{}/{}",
+                page.into(),
+                per_page.into()
+            ),
+            result,
+            60 * 15,
+            |x: &currency::Model| format!("currency:{}", x.code)
+        );
+
+        Ok(result)
+    }
+
+    pub async fn count_currencies_with_condition<F: sea_query::IntoCondition>(
+        conn: &Connection,
+        filter: F,
+    ) -> Result<u64, DbErr> {
+        Currency::find()
+            .filter(filter)
+            .count(&conn.db_connection)
+            .await
+    }
+}
@@ -0,0 +1,136 @@
+//! Exchange rate query services for the `scrounch_backend` application.
+//!
+//! This module defines services and functions related to querying the exchange rates between
+//! currencies, and to converting amounts with the rate effective at a given date.
+
+#[cfg(feature = "cache")]
+use crate::r#macro::{cache_get, cache_mget, cache_mset, cache_set};
+use crate::{
+    Connection,
+    exchange::{Rounding, convert},
+    query::Query,
+};
+use ::entity::models::{
+    currency::CurrencyCode, exchange_rate, exchange_rate::Entity as ExchangeRate,
+};
+use sea_orm::{prelude::Decimal, *};
+
+impl Query {
+    pub async fn find_exchange_rate_by_id(
+        conn: &Connection,
+        id: uuid::Uuid,
+    ) -> Result<Option<exchange_rate::Model>, DbErr> {
+        #[cfg(feature = "cache")]
+        cache_get!(conn, format!("exchange_rate:{id}"), exchange_rate::Model);
+
+        let result = ExchangeRate::find_by_id(id)
+            .one(&conn.db_connection)
+            .await?;
+
+        #[cfg(feature = "cache")]
+        if let Some(model) = &result {
+            cache_set!(conn, format!("exchange_rate:{id}"), model, 60 * 15);
+        }
+
+        Ok(result)
+    }
+
+    pub async fn list_exchange_rates_with_condition<
+        F: sea_query::IntoCondition + std::fmt::Debug + Clone,
+        S: IntoIterator<Item = (impl IntoSimpleExpr, Order)> + std::fmt::Debug + Clone,
+        A: Into<u64> + Copy,
+        P: Into<u64> + Copy,
+    >(
+        conn: &Connection,
+        filter: F,
+        sort: S,
+        page: A,
+        per_page: P,
+    ) -> Result<Vec<exchange_rate::Model>, DbErr> {
+        #[cfg(feature = "cache")]
+        cache_mget!(
+            conn,
+            format!(
+                "exchange_rates:{filter:?}-{sort:?}-{}/{}",
+                page.into(),
+                per_page.into()
+            ),
+            exchange_rate::Model
+        );
+
+        let mut query = ExchangeRate::find().filter(filter.clone());
+        for (column, order) in sort.clone() {
+            query = query.order_by_with_nulls(column, order, sea_query::NullOrdering::Last);
+        }
+        let query = query.paginate(&conn.db_connection, per_page.into());
+
+        let result = query.fetch_page(page.into()).await?;
+
+        #[cfg(feature = "cache")]
+        cache_mset!(
+            conn,
+            format!(
+                "exchange_rates:{filter:?}-{sort:?}-{}/{}",
+                page.into(),
+                per_page.into()
+            ),
+            result,
+            60 * 15,
+            |x: &exchange_rate::Model| format!("exchange_rate:{}", x.id)
+        );
+
+        Ok(result)
+    }
+
+    pub async fn count_exchange_rates_with_condition<F: sea_query::IntoCondition>(
+        conn: &Connection,
+        filter: F,
+    ) -> Result<u64, DbErr> {
+        ExchangeRate::find()
+            .filter(filter)
+            .count(&conn.db_connection)
+            .await
+    }
+
+    /// Finds the rate converting `from` to `to` at `at`: the one of this currency pair with the
+    /// latest `effective_from` which isn't after `at`.
+    pub async fn find_effective_exchange_rate(
+        conn: &Connection,
+        from: CurrencyCode,
+        to: CurrencyCode,
+        at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Option<exchange_rate::Model>, DbErr> {
+        ExchangeRate::find()
+            .filter(exchange_rate::Column::FromCurrency.eq(from))
+            .filter(exchange_rate::Column::ToCurrency.eq(to))
+            .filter(exchange_rate::Column::EffectiveFrom.lte(at))
+            .order_by_desc(exchange_rate::Column::EffectiveFrom)
+            .one(&conn.db_connection)
+            .await
+    }
+
+    /// Converts `amount` from `from` to `to` with the rate effective now, rounded to the
+    /// decimal places of `to`, see the `exchange` module for the rounding rules.
+    ///
+    /// An amount already in `to` is returned as is, returns `Ok(None)` when no rate applies.
+    pub async fn convert_amount(
+        conn: &Connection,
+        amount: Decimal,
+        from: CurrencyCode,
+        to: CurrencyCode,
+        rounding: Rounding,
+    ) -> Result<Option<Decimal>, DbErr> {
+        if from == to {
+            return Ok(Some(amount));
+        }
+
+        let Some(currency) = Self::find_currency_by_code(conn, &to.0).await? else {
+            return Ok(None);
+        };
+        let decimal_places = u32::try_from(currency.decimal_places).unwrap_or(0);
+
+        let rate = Self::find_effective_exchange_rate(conn, from, to, chrono::Utc::now()).await?;
+
+        Ok(rate.and_then(|rate| convert(amount, rate.rate, decimal_places, rounding)))
+    }
+}
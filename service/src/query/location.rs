@@ -8,9 +8,15 @@
 
 #[cfg(feature = "cache")]
 use crate::r#macro::{cache_get, cache_mget, cache_mset, cache_set};
-use crate::{Connection, query::Query};
-use ::entity::models::{location, location::Entity as Location};
-use sea_orm::*;
+use crate::{Connection, query::Query, stock};
+use ::entity::models::{
+    location,
+    location::Entity as Location,
+    location_warehouse,
+    prelude::{LocationWarehouse, Product, Warehouse},
+    product, warehouse,
+};
+use sea_orm::{prelude::Decimal, *};
 
 impl Query {
     pub async fn find_location_by_id(
@@ -86,4 +92,125 @@ impl Query {
             .count(&conn.db_connection)
             .await
     }
+
+    pub async fn find_location_warehouse_by_id(
+        conn: &Connection,
+        location_id: uuid::Uuid,
+        warehouse_id: uuid::Uuid,
+    ) -> Result<Option<(location_warehouse::Model, warehouse::Model)>, DbErr> {
+        #[cfg(feature = "cache")]
+        cache_get!(
+            conn,
+            format!("location_warehouse:{location_id}/{warehouse_id}"),
+            (location_warehouse::Model, warehouse::Model)
+        );
+
+        let result = LocationWarehouse::find_by_id((location_id, warehouse_id))
+            .find_also_related(Warehouse)
+            .one(&conn.db_connection)
+            .await?;
+
+        match result {
+            Some((location_warehouse, Some(warehouse))) => {
+                let result = (location_warehouse, warehouse);
+                #[cfg(feature = "cache")]
+                cache_set!(
+                    conn,
+                    format!("location_warehouse:{location_id}/{warehouse_id}"),
+                    result,
+                    60 * 60 * 3
+                );
+
+                Ok(Some(result))
+            }
+            Some(_) => Ok(None),
+            None => Ok(None),
+        }
+    }
+
+    /// Finds the warehouse of a location using `priority`, if any.
+    pub async fn find_location_warehouse_by_priority(
+        conn: &Connection,
+        location_id: uuid::Uuid,
+        priority: i32,
+    ) -> Result<Option<location_warehouse::Model>, DbErr> {
+        LocationWarehouse::find()
+            .filter(location_warehouse::Column::LocationId.eq(location_id))
+            .filter(location_warehouse::Column::Priority.eq(priority))
+            .one(&conn.db_connection)
+            .await
+    }
+
+    /// Lists the warehouses serving a location, from the highest priority to the lowest.
+    pub async fn list_location_warehouses(
+        conn: &Connection,
+        location_id: uuid::Uuid,
+    ) -> Result<Vec<(location_warehouse::Model, warehouse::Model)>, DbErr> {
+        #[cfg(feature = "cache")]
+        cache_mget!(
+            conn,
+            format!("location_warehouses:{location_id}"),
+            (location_warehouse::Model, warehouse::Model)
+        );
+
+        let result = LocationWarehouse::find()
+            .find_also_related(Warehouse)
+            .filter(location_warehouse::Column::LocationId.eq(location_id))
+            .order_by_desc(location_warehouse::Column::Priority)
+            .all(&conn.db_connection)
+            .await?
+            .into_iter()
+            .filter_map(|(location_warehouse, warehouse)| Some((location_warehouse, warehouse?)))
+            .collect::<Vec<_>>();
+
+        #[cfg(feature = "cache")]
+        cache_mset!(
+            conn,
+            format!("location_warehouses:{location_id}"),
+            result,
+            60 * 60 * 3,
+            |x: &(location_warehouse::Model, warehouse::Model)| format!(
+                "location_warehouse:{}/{}",
+                x.0.location_id, x.0.warehouse_id
+            )
+        );
+
+        Ok(result)
+    }
+
+    /// Lists the products that can be bought at a location right now, with the quantity
+    /// available in the warehouses serving it (every warehouse when it isn't linked to any),
+    /// see [`crate::stock`].
+    ///
+    /// Only the purchasable products with a sell price are listed, in their display order.
+    pub async fn find_location_menu(
+        conn: &Connection,
+        location_id: uuid::Uuid,
+    ) -> Result<Vec<(product::Model, Decimal)>, DbErr> {
+        let products = Product::find()
+            .filter(product::Column::Purchasable.eq(true))
+            .filter(product::Column::Hidden.eq(false))
+            .filter(product::Column::Disabled.eq(false))
+            .filter(product::Column::SellPrice.is_not_null())
+            .order_by_asc(product::Column::DisplayOrder)
+            .order_by_asc(product::Column::Name)
+            .all(&conn.db_connection)
+            .await?;
+
+        let warehouse_ids = stock::location_warehouses(&conn.db_connection, location_id).await?;
+        let product_ids = products
+            .iter()
+            .map(|product| product.id)
+            .collect::<Vec<_>>();
+        let mut available =
+            stock::available(&conn.db_connection, warehouse_ids.as_deref(), &product_ids).await?;
+
+        Ok(products
+            .into_iter()
+            .filter_map(|product| {
+                let quantity = available.remove(&product.id)?;
+                (quantity > Decimal::ZERO).then_some((product, quantity))
+            })
+            .collect())
+    }
 }
@@ -5,10 +5,17 @@
 //! storage systems based on various criteria. They encapsulate the logic for constructing and
 //! executing queries, ensuring that data retrieval is handled efficiently and consistently.
 
+mod category;
+mod currency;
+mod exchange_rate;
 mod location;
 mod product;
+mod purchase_order;
 mod recipe;
 mod refill;
+mod stocktake;
+mod supplier;
+mod transaction;
 mod user;
 mod warehouse;
 
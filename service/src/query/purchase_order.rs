@@ -0,0 +1,59 @@
+use crate::{Connection, query::Query};
+use ::entity::models::{
+    prelude::{PurchaseOrder, PurchaseOrderLine},
+    purchase_order, purchase_order_line,
+};
+use sea_orm::*;
+
+impl Query {
+    pub async fn find_purchase_order_by_id(
+        conn: &Connection,
+        id: uuid::Uuid,
+    ) -> Result<Option<purchase_order::Model>, DbErr> {
+        PurchaseOrder::find_by_id(id).one(&conn.db_connection).await
+    }
+
+    /// Lists the lines of the purchase order `id`, ordered by product.
+    pub async fn list_purchase_order_lines(
+        conn: &Connection,
+        id: uuid::Uuid,
+    ) -> Result<Vec<purchase_order_line::Model>, DbErr> {
+        PurchaseOrderLine::find()
+            .filter(purchase_order_line::Column::PurchaseOrderId.eq(id))
+            .order_by_asc(purchase_order_line::Column::ProductId)
+            .all(&conn.db_connection)
+            .await
+    }
+
+    pub async fn list_purchase_orders_with_condition<
+        F: sea_query::IntoCondition,
+        S: IntoIterator<Item = (impl IntoSimpleExpr, Order)>,
+        A: Into<u64>,
+        P: Into<u64>,
+    >(
+        conn: &Connection,
+        filter: F,
+        sort: S,
+        page: A,
+        per_page: P,
+    ) -> Result<Vec<purchase_order::Model>, DbErr> {
+        let mut query = PurchaseOrder::find().filter(filter);
+        for (column, order) in sort {
+            query = query.order_by_with_nulls(column, order, sea_query::NullOrdering::Last);
+        }
+        query
+            .paginate(&conn.db_connection, per_page.into())
+            .fetch_page(page.into())
+            .await
+    }
+
+    pub async fn count_purchase_orders_with_condition<F: sea_query::IntoCondition>(
+        conn: &Connection,
+        filter: F,
+    ) -> Result<u64, DbErr> {
+        PurchaseOrder::find()
+            .filter(filter)
+            .count(&conn.db_connection)
+            .await
+    }
+}
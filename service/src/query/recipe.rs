@@ -1,11 +1,17 @@
 #[cfg(feature = "cache")]
 use crate::r#macro::{cache_get, cache_mget, cache_mset, cache_set};
-use crate::{Connection, query::Query};
+use crate::{
+    Connection,
+    bom::{self, Bom},
+    costing::{self, RecipeCost},
+    query::Query,
+};
 use ::entity::models::{
     prelude::{Recipe, RecipeIngredients},
     recipe, recipe_ingredients,
 };
 use sea_orm::*;
+use std::collections::HashMap;
 
 impl Query {
     pub async fn find_recipe_by_id(
@@ -95,4 +101,45 @@ impl Query {
             .count(&conn.db_connection)
             .await
     }
+
+    /// Looks for a cycle between recipes that a recipe making `result_product_id` out of
+    /// `ingredient_ids` would create, ignoring the recipe `excluded_recipe` it replaces.
+    ///
+    /// Returns the products of the cycle, from `result_product_id` back to itself.
+    pub async fn find_recipe_cycle(
+        conn: &Connection,
+        result_product_id: uuid::Uuid,
+        ingredient_ids: &[uuid::Uuid],
+        excluded_recipe: Option<uuid::Uuid>,
+    ) -> Result<Option<Vec<uuid::Uuid>>, DbErr> {
+        bom::find_cycle(
+            &conn.db_connection,
+            result_product_id,
+            ingredient_ids,
+            excluded_recipe,
+        )
+        .await
+    }
+
+    /// Expands a recipe into its bill of materials, down to raw products.
+    pub async fn find_recipe_tree(conn: &Connection, id: uuid::Uuid) -> Result<Option<Bom>, DbErr> {
+        bom::expand(&conn.db_connection, id).await
+    }
+
+    /// Computes the cost of the recipes `ids`, see the `costing` module.
+    pub async fn find_recipe_costs(
+        conn: &Connection,
+        ids: &[uuid::Uuid],
+    ) -> Result<HashMap<uuid::Uuid, RecipeCost>, DbErr> {
+        Ok(costing::cost_recipes(conn, Some(ids))
+            .await?
+            .into_iter()
+            .map(|cost| (cost.recipe.id, cost))
+            .collect())
+    }
+
+    /// Computes the cost of every enabled recipe, in the order they were created.
+    pub async fn list_recipe_costs(conn: &Connection) -> Result<Vec<RecipeCost>, DbErr> {
+        costing::cost_recipes(conn, None).await
+    }
 }
@@ -0,0 +1,94 @@
+use crate::{Connection, query::Query};
+use ::entity::models::{
+    prelude::{Stocktake, StocktakeCount, WarehouseProduct},
+    sea_orm_active_enums::StocktakeStatus,
+    stocktake, stocktake_count, warehouse_product,
+};
+use sea_orm::{prelude::Decimal, *};
+use std::collections::HashMap;
+
+impl Query {
+    pub async fn find_stocktake_by_id(
+        conn: &Connection,
+        id: uuid::Uuid,
+    ) -> Result<Option<stocktake::Model>, DbErr> {
+        Stocktake::find_by_id(id).one(&conn.db_connection).await
+    }
+
+    /// Finds the stocktake currently counting `warehouse_id`, if any.
+    pub async fn find_open_stocktake(
+        conn: &Connection,
+        warehouse_id: uuid::Uuid,
+    ) -> Result<Option<stocktake::Model>, DbErr> {
+        Stocktake::find()
+            .filter(stocktake::Column::WarehouseId.eq(warehouse_id))
+            .filter(stocktake::Column::Status.eq(StocktakeStatus::Open))
+            .one(&conn.db_connection)
+            .await
+    }
+
+    pub async fn list_stocktakes_with_condition<
+        F: sea_query::IntoCondition,
+        S: IntoIterator<Item = (impl IntoSimpleExpr, Order)>,
+        A: Into<u64>,
+        P: Into<u64>,
+    >(
+        conn: &Connection,
+        filter: F,
+        sort: S,
+        page: A,
+        per_page: P,
+    ) -> Result<Vec<stocktake::Model>, DbErr> {
+        let mut query = Stocktake::find().filter(filter);
+        for (column, order) in sort {
+            query = query.order_by_with_nulls(column, order, sea_query::NullOrdering::Last);
+        }
+        query
+            .paginate(&conn.db_connection, per_page.into())
+            .fetch_page(page.into())
+            .await
+    }
+
+    pub async fn count_stocktakes_with_condition<F: sea_query::IntoCondition>(
+        conn: &Connection,
+        filter: F,
+    ) -> Result<u64, DbErr> {
+        Stocktake::find()
+            .filter(filter)
+            .count(&conn.db_connection)
+            .await
+    }
+
+    /// Lists the counts of a stocktake, each with the quantity its warehouse holds right now,
+    /// zero when the product isn't stocked there.
+    pub async fn list_stocktake_counts(
+        conn: &Connection,
+        stocktake: &stocktake::Model,
+    ) -> Result<Vec<(stocktake_count::Model, Decimal)>, DbErr> {
+        let counts = StocktakeCount::find()
+            .filter(stocktake_count::Column::StocktakeId.eq(stocktake.id))
+            .order_by_asc(stocktake_count::Column::ProductId)
+            .all(&conn.db_connection)
+            .await?;
+
+        let stock: HashMap<uuid::Uuid, Decimal> = WarehouseProduct::find()
+            .filter(warehouse_product::Column::WarehouseId.eq(stocktake.warehouse_id))
+            .filter(
+                warehouse_product::Column::ProductId
+                    .is_in(counts.iter().map(|count| count.product_id)),
+            )
+            .all(&conn.db_connection)
+            .await?
+            .into_iter()
+            .map(|stock| (stock.product_id, stock.quantity))
+            .collect();
+
+        Ok(counts
+            .into_iter()
+            .map(|count| {
+                let quantity = stock.get(&count.product_id).copied().unwrap_or_default();
+                (count, quantity)
+            })
+            .collect())
+    }
+}
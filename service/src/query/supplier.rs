@@ -0,0 +1,86 @@
+//! Supplier query services for the `scrounch_backend` application.
+//!
+//! This module defines services and functions related to querying the suppliers the products
+//! are bought from.
+
+#[cfg(feature = "cache")]
+use crate::r#macro::{cache_get, cache_mget, cache_mset, cache_set};
+use crate::{Connection, query::Query};
+use ::entity::models::{supplier, supplier::Entity as Supplier};
+use sea_orm::*;
+
+impl Query {
+    pub async fn find_supplier_by_id(
+        conn: &Connection,
+        id: uuid::Uuid,
+    ) -> Result<Option<supplier::Model>, DbErr> {
+        #[cfg(feature = "cache")]
+        cache_get!(conn, format!("supplier:{id}"), supplier::Model);
+
+        let result = Supplier::find_by_id(id).one(&conn.db_connection).await?;
+
+        #[cfg(feature = "cache")]
+        if let Some(model) = &result {
+            cache_set!(conn, format!("supplier:{id}"), model, 60 * 15);
+        }
+
+        Ok(result)
+    }
+
+    pub async fn list_suppliers_with_condition<
+        F: sea_query::IntoCondition + std::fmt::Debug + Clone,
+        S: IntoIterator<Item = (impl IntoSimpleExpr, Order)> + std::fmt::Debug + Clone,
+        A: Into<u64> + Copy,
+        P: Into<u64> + Copy,
+    >(
+        conn: &Connection,
+        filter: F,
+        sort: S,
+        page: A,
+        per_page: P,
+    ) -> Result<Vec<supplier::Model>, DbErr> {
+        #[cfg(feature = "cache")]
+        cache_mget!(
+            conn,
+            format!(
+                "suppliers:{filter:?}-{sort:?}-{}/{}",
+                page.into(),
+                per_page.into()
+            ),
+            supplier::Model
+        );
+
+        let mut query = Supplier::find().filter(filter.clone());
+        for (column, order) in sort.clone() {
+            query = query.order_by_with_nulls(column, order, sea_query::NullOrdering::Last);
+        }
+        let query = query.paginate(&conn.db_connection, per_page.into());
+
+        let result = query.fetch_page(page.into()).await?;
+
+        #[cfg(feature = "cache")]
+        cache_mset!(
+            conn,
+            format!(
+                "suppliers:{filter:?}-{sort:?}-{}/{}",
+                page.into(),
+                per_page.into()
+            ),
+            result,
+            60 * 15,
+            |x: &supplier::Model| format!("supplier:{}", x.id)
+        );
+
+        Ok(result)
+    }
+
+    pub async fn count_suppliers_with_condition<F: sea_query::IntoCondition>(
+        conn: &Connection,
+        filter: F,
+    ) -> Result<u64, DbErr> {
+        Supplier::find()
+            .filter(filter)
+            .count(&conn.db_connection)
+            .await
+    }
+}
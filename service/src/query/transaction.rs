@@ -0,0 +1,209 @@
+//! Transaction query services for the `scrounch_backend` application.
+//!
+//! This module defines services and functions related to querying the transaction ledger.
+//! It encapsulates the logic for retrieving transactions and their type-specific details
+//! from the database, providing a layer of abstraction over database interactions.
+
+#[cfg(feature = "cache")]
+use crate::r#macro::{cache_get, cache_mget, cache_mset, cache_set};
+use crate::{Connection, query::Query};
+use ::entity::models::{
+    prelude::{
+        Transaction, TransactionProduct, TransactionPurchaseWithBalance, TransactionRefillBalance,
+    },
+    sea_orm_active_enums::{FulfilmentStatus, TransactionStatus},
+    transaction, transaction_product, transaction_purchase_with_balance,
+    transaction_refill_balance,
+};
+use sea_orm::*;
+
+impl Query {
+    pub async fn find_transaction_by_id(
+        conn: &Connection,
+        id: uuid::Uuid,
+    ) -> Result<Option<transaction::Model>, DbErr> {
+        #[cfg(feature = "cache")]
+        cache_get!(conn, format!("transaction:{id}"), transaction::Model);
+
+        let result = Transaction::find_by_id(id).one(&conn.db_connection).await?;
+
+        #[cfg(feature = "cache")]
+        if let Some(model) = &result {
+            cache_set!(conn, format!("transaction:{id}"), model, 60 * 15);
+        }
+
+        Ok(result)
+    }
+
+    pub async fn list_transactions_with_condition<
+        F: sea_query::IntoCondition + std::fmt::Debug + Clone,
+        S: IntoIterator<Item = (impl IntoSimpleExpr, Order)> + std::fmt::Debug + Clone,
+        A: Into<u64> + Copy,
+        P: Into<u64> + Copy,
+    >(
+        conn: &Connection,
+        filter: F,
+        sort: S,
+        page: A,
+        per_page: P,
+    ) -> Result<Vec<transaction::Model>, DbErr> {
+        #[cfg(feature = "cache")]
+        cache_mget!(
+            conn,
+            format!(
+                "transactions:{filter:?}-{sort:?}-{}/{}",
+                page.into(),
+                per_page.into()
+            ),
+            transaction::Model
+        );
+
+        let mut query = Transaction::find().filter(filter.clone());
+        for (column, order) in sort.clone() {
+            query = query.order_by_with_nulls(column, order, sea_query::NullOrdering::Last);
+        }
+        let query = query.paginate(&conn.db_connection, per_page.into());
+
+        let result = query.fetch_page(page.into()).await?;
+
+        #[cfg(feature = "cache")]
+        cache_mset!(
+            conn,
+            format!(
+                "transactions:{filter:?}-{sort:?}-{}/{}",
+                page.into(),
+                per_page.into()
+            ),
+            result,
+            60 * 15,
+            |x: &transaction::Model| format!("transaction:{}", x.id)
+        );
+
+        Ok(result)
+    }
+
+    pub async fn count_transactions_with_condition<F: sea_query::IntoCondition>(
+        conn: &Connection,
+        filter: F,
+    ) -> Result<u64, DbErr> {
+        Transaction::find()
+            .filter(filter)
+            .count(&conn.db_connection)
+            .await
+    }
+
+    pub async fn find_transaction_refill_balance_by_id(
+        conn: &Connection,
+        id: uuid::Uuid,
+    ) -> Result<Option<transaction_refill_balance::Model>, DbErr> {
+        #[cfg(feature = "cache")]
+        cache_get!(
+            conn,
+            format!("transaction_refill_balance:{id}"),
+            transaction_refill_balance::Model
+        );
+
+        let result = TransactionRefillBalance::find_by_id(id)
+            .one(&conn.db_connection)
+            .await?;
+
+        #[cfg(feature = "cache")]
+        if let Some(model) = &result {
+            cache_set!(
+                conn,
+                format!("transaction_refill_balance:{id}"),
+                model,
+                60 * 15
+            );
+        }
+
+        Ok(result)
+    }
+
+    pub async fn find_transaction_purchase_with_balance_by_id(
+        conn: &Connection,
+        id: uuid::Uuid,
+    ) -> Result<Option<transaction_purchase_with_balance::Model>, DbErr> {
+        #[cfg(feature = "cache")]
+        cache_get!(
+            conn,
+            format!("transaction_purchase_with_balance:{id}"),
+            transaction_purchase_with_balance::Model
+        );
+
+        let result = TransactionPurchaseWithBalance::find_by_id(id)
+            .one(&conn.db_connection)
+            .await?;
+
+        #[cfg(feature = "cache")]
+        if let Some(model) = &result {
+            cache_set!(
+                conn,
+                format!("transaction_purchase_with_balance:{id}"),
+                model,
+                60 * 15
+            );
+        }
+
+        Ok(result)
+    }
+
+    /// Condition matching the orders still to deliver to `location_id`.
+    fn open_orders_condition(location_id: uuid::Uuid) -> Condition {
+        Condition::all()
+            .add(transaction::Column::Status.eq(TransactionStatus::Completed))
+            .add(transaction_purchase_with_balance::Column::LocationId.eq(location_id))
+            .add(
+                transaction_purchase_with_balance::Column::FulfilmentStatus.is_in([
+                    FulfilmentStatus::Queued,
+                    FulfilmentStatus::Preparing,
+                    FulfilmentStatus::Ready,
+                ]),
+            )
+    }
+
+    /// Lists the completed orders to deliver to `location_id` which are not delivered yet,
+    /// the oldest first. Never cached, the queue changes all the time.
+    pub async fn list_open_orders_by_location<A: Into<u64> + Copy, P: Into<u64> + Copy>(
+        conn: &Connection,
+        location_id: uuid::Uuid,
+        page: A,
+        per_page: P,
+    ) -> Result<Vec<(transaction::Model, transaction_purchase_with_balance::Model)>, DbErr> {
+        let result = Transaction::find()
+            .find_also_related(TransactionPurchaseWithBalance)
+            .filter(Self::open_orders_condition(location_id))
+            .order_by_asc(transaction::Column::CreatedAt)
+            .paginate(&conn.db_connection, per_page.into())
+            .fetch_page(page.into())
+            .await?;
+
+        Ok(result
+            .into_iter()
+            .filter_map(|(transaction, purchase)| Some((transaction, purchase?)))
+            .collect())
+    }
+
+    pub async fn count_open_orders_by_location(
+        conn: &Connection,
+        location_id: uuid::Uuid,
+    ) -> Result<u64, DbErr> {
+        Transaction::find()
+            .inner_join(TransactionPurchaseWithBalance)
+            .filter(Self::open_orders_condition(location_id))
+            .count(&conn.db_connection)
+            .await
+    }
+
+    /// Lists the lines of the given purchases.
+    pub async fn list_transaction_products_by_transaction_ids(
+        conn: &Connection,
+        transaction_ids: Vec<uuid::Uuid>,
+    ) -> Result<Vec<transaction_product::Model>, DbErr> {
+        TransactionProduct::find()
+            .filter(transaction_product::Column::TransactionId.is_in(transaction_ids))
+            .order_by_asc(transaction_product::Column::Name)
+            .all(&conn.db_connection)
+            .await
+    }
+}
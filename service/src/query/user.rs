@@ -7,9 +7,24 @@
 
 #[cfg(feature = "cache")]
 use crate::r#macro::{cache_get, cache_mget, cache_mset, cache_set};
-use crate::{Connection, query::Query};
-use ::entity::models::{user, user::Entity as User};
-use sea_orm::*;
+use crate::{
+    Connection,
+    query::Query,
+    reconcile::{BalanceDrift, ReconcileReport},
+};
+use ::entity::models::{
+    currency::CurrencyCode,
+    prelude::{
+        Transaction, TransactionBalanceEdit, TransactionPurchaseWithBalance,
+        TransactionRefillBalance,
+    },
+    sea_orm_active_enums::TransactionStatus,
+    transaction, transaction_balance_edit, transaction_purchase_with_balance,
+    transaction_refill_balance, user,
+    user::Entity as User,
+};
+use sea_orm::{prelude::Decimal, *};
+use std::collections::HashMap;
 
 impl Query {
     pub async fn find_user_by_id(
@@ -81,4 +96,93 @@ impl Query {
     ) -> Result<u64, DbErr> {
         User::find().filter(filter).count(&conn.db_connection).await
     }
+
+    /// Recomputes the balance of every user from its completed transactions, see the
+    /// `reconcile` module for the rules. Never cached, it must see the database as it is.
+    pub async fn reconcile_balances(conn: &Connection) -> Result<ReconcileReport, DbErr> {
+        let db = &conn.db_connection;
+
+        let credits: Vec<(uuid::Uuid, CurrencyCode, Decimal)> = Transaction::find()
+            .select_only()
+            .column(transaction::Column::UserId)
+            .column(transaction_refill_balance::Column::CreditCurrency)
+            .column_as(transaction_refill_balance::Column::Credit.sum(), "total")
+            .inner_join(TransactionRefillBalance)
+            .filter(transaction::Column::Status.eq(TransactionStatus::Completed))
+            .group_by(transaction::Column::UserId)
+            .group_by(transaction_refill_balance::Column::CreditCurrency)
+            .into_tuple()
+            .all(db)
+            .await?;
+
+        let edits: Vec<(uuid::Uuid, CurrencyCode, Decimal)> = Transaction::find()
+            .select_only()
+            .column(transaction::Column::UserId)
+            .column(transaction_balance_edit::Column::DeltaCurrency)
+            .column_as(transaction_balance_edit::Column::Delta.sum(), "total")
+            .inner_join(TransactionBalanceEdit)
+            .filter(transaction::Column::Status.eq(TransactionStatus::Completed))
+            .group_by(transaction::Column::UserId)
+            .group_by(transaction_balance_edit::Column::DeltaCurrency)
+            .into_tuple()
+            .all(db)
+            .await?;
+
+        let purchases: Vec<(uuid::Uuid, CurrencyCode, Decimal)> = Transaction::find()
+            .select_only()
+            .column(transaction::Column::UserId)
+            .column(transaction_purchase_with_balance::Column::PriceTotalCurrency)
+            .column_as(
+                transaction_purchase_with_balance::Column::PriceTotal.sum(),
+                "total",
+            )
+            .inner_join(TransactionPurchaseWithBalance)
+            .filter(transaction::Column::Status.eq(TransactionStatus::Completed))
+            .group_by(transaction::Column::UserId)
+            .group_by(transaction_purchase_with_balance::Column::PriceTotalCurrency)
+            .into_tuple()
+            .all(db)
+            .await?;
+
+        let users = User::find().all(db).await?;
+
+        let mut expected = users
+            .iter()
+            .map(|user| (user.id, Decimal::ZERO))
+            .collect::<HashMap<_, _>>();
+        let currencies = users
+            .iter()
+            .map(|user| (user.id, &user.balance_currency))
+            .collect::<HashMap<_, _>>();
+
+        let movements = credits
+            .into_iter()
+            .chain(edits)
+            .chain(purchases.into_iter().map(|(id, c, total)| (id, c, -total)));
+        for (user_id, currency, total) in movements {
+            if currencies.get(&user_id) == Some(&&currency) {
+                if let Some(balance) = expected.get_mut(&user_id) {
+                    *balance += total;
+                }
+            }
+        }
+
+        let drifts = users
+            .iter()
+            .filter_map(|user| {
+                let expected = expected.get(&user.id).copied().unwrap_or_default();
+                (expected != user.balance).then(|| BalanceDrift {
+                    user_id: user.id,
+                    currency: user.balance_currency.clone(),
+                    balance: user.balance,
+                    expected,
+                })
+            })
+            .collect();
+
+        Ok(ReconcileReport {
+            checked: users.len() as u64,
+            drifts,
+        })
+    }
 }
@@ -1,11 +1,16 @@
 #[cfg(feature = "cache")]
 use crate::r#macro::{cache_get, cache_mget, cache_mset, cache_set};
-use crate::{Connection, query::Query};
+use crate::{Connection, query::Query, stock};
 use ::entity::models::{
-    prelude::{Product, Recipe, Warehouse, WarehouseProduct, WarehouseRecipe},
-    product, recipe, warehouse, warehouse_product, warehouse_recipe,
+    prelude::{
+        Product, Recipe, StockMovement, Warehouse, WarehouseProduct, WarehouseProductBatch,
+        WarehouseRecipe,
+    },
+    product, recipe, stock_movement, warehouse, warehouse_product, warehouse_product_batch,
+    warehouse_recipe,
 };
 use sea_orm::*;
+use std::collections::HashMap;
 impl Query {
     pub async fn find_warehouse_by_id(
         conn: &Connection,
@@ -200,6 +205,111 @@ impl Query {
         }
     }
 
+    /// Computes how much of each of `product_ids` can be sold right now, from `warehouse_id` or
+    /// from every warehouse when `None`, see [`crate::stock`].
+    pub async fn available_quantities(
+        conn: &Connection,
+        warehouse_id: Option<uuid::Uuid>,
+        product_ids: &[uuid::Uuid],
+    ) -> Result<HashMap<uuid::Uuid, prelude::Decimal>, DbErr> {
+        let warehouse_ids = warehouse_id.map(|id| [id]);
+        stock::available(
+            &conn.db_connection,
+            warehouse_ids.as_ref().map(|ids| &ids[..]),
+            product_ids,
+        )
+        .await
+    }
+
+    /// Condition matching the products stocked at or below their `min_quantity`.
+    fn low_stock_condition() -> Condition {
+        Condition::all()
+            .add(warehouse_product::Column::MinQuantity.is_not_null())
+            .add(
+                sea_query::Expr::col((WarehouseProduct, warehouse_product::Column::Quantity)).lte(
+                    sea_query::Expr::col((
+                        WarehouseProduct,
+                        warehouse_product::Column::MinQuantity,
+                    )),
+                ),
+            )
+    }
+
+    /// Lists the products of a warehouse whose stock is at or below their `min_quantity`.
+    pub async fn list_low_stock_products<
+        Filter: sea_query::IntoCondition + std::fmt::Debug + Clone,
+        Sort: IntoIterator<Item = (impl IntoSimpleExpr, Order)> + std::fmt::Debug + Clone,
+        Page: Into<u64> + Copy,
+        PerPage: Into<u64> + Copy,
+    >(
+        conn: &Connection,
+        warehouse_id: uuid::Uuid,
+        filter: Filter,
+        sort: Sort,
+        page: Page,
+        per_page: PerPage,
+    ) -> Result<Vec<(warehouse_product::Model, product::Model)>, DbErr> {
+        let filter = Self::low_stock_condition().add(filter.into_condition());
+        Self::list_warehouse_products(conn, warehouse_id, filter, sort, page, per_page).await
+    }
+
+    pub async fn count_low_stock_products<Filter: sea_query::IntoCondition>(
+        conn: &Connection,
+        warehouse_id: uuid::Uuid,
+        filter: Filter,
+    ) -> Result<u64, DbErr> {
+        let filter = Self::low_stock_condition().add(filter.into_condition());
+        Self::count_warehouse_products(conn, warehouse_id, filter).await
+    }
+
+    pub async fn find_warehouse_recipe_by_id(
+        conn: &Connection,
+        warehouse_id: uuid::Uuid,
+        recipe_id: uuid::Uuid,
+    ) -> Result<Option<(warehouse_recipe::Model, recipe::Model)>, DbErr> {
+        #[cfg(feature = "cache")]
+        cache_get!(
+            conn,
+            format!("warehouse_recipe:{warehouse_id}/{recipe_id}"),
+            (warehouse_recipe::Model, recipe::Model)
+        );
+
+        let result = WarehouseRecipe::find_by_id((warehouse_id, recipe_id))
+            .find_also_related(Recipe)
+            .one(&conn.db_connection)
+            .await?;
+
+        match result {
+            Some((warehouse_recipe, Some(recipe))) => {
+                let result = (warehouse_recipe, recipe);
+                #[cfg(feature = "cache")]
+                cache_set!(
+                    conn,
+                    format!("warehouse_recipe:{warehouse_id}/{recipe_id}"),
+                    result,
+                    60 * 60 * 3
+                );
+
+                Ok(Some(result))
+            }
+            Some(_) => Ok(None),
+            None => Ok(None),
+        }
+    }
+
+    /// Finds the recipe of a warehouse using `priority`, if any.
+    pub async fn find_warehouse_recipe_by_priority(
+        conn: &Connection,
+        warehouse_id: uuid::Uuid,
+        priority: i32,
+    ) -> Result<Option<warehouse_recipe::Model>, DbErr> {
+        WarehouseRecipe::find()
+            .filter(warehouse_recipe::Column::WarehouseId.eq(warehouse_id))
+            .filter(warehouse_recipe::Column::Priority.eq(priority))
+            .one(&conn.db_connection)
+            .await
+    }
+
     pub async fn find_warehouse_recipes_by_id<
         Filter: sea_query::IntoCondition + std::fmt::Debug + Clone,
         Sort: IntoIterator<Item = (impl IntoSimpleExpr, Order)> + std::fmt::Debug + Clone,
@@ -283,4 +393,102 @@ impl Query {
             None => Ok(0),
         }
     }
+
+    /// Lists the movements of the stock of a warehouse.
+    ///
+    /// The journal changes with every purchase, so it is never cached.
+    pub async fn list_stock_movements<
+        Filter: sea_query::IntoCondition,
+        Sort: IntoIterator<Item = (impl IntoSimpleExpr, Order)>,
+        Page: Into<u64> + Copy,
+        PerPage: Into<u64> + Copy,
+    >(
+        conn: &Connection,
+        warehouse_id: uuid::Uuid,
+        filter: Filter,
+        sort: Sort,
+        page: Page,
+        per_page: PerPage,
+    ) -> Result<Vec<stock_movement::Model>, DbErr> {
+        let mut query = StockMovement::find()
+            .filter(stock_movement::Column::WarehouseId.eq(warehouse_id))
+            .filter(filter);
+        for (column, order) in sort {
+            query = query.order_by_with_nulls(column, order, sea_query::NullOrdering::Last);
+        }
+
+        query
+            .paginate(&conn.db_connection, per_page.into())
+            .fetch_page(page.into())
+            .await
+    }
+
+    pub async fn count_stock_movements<Filter: sea_query::IntoCondition>(
+        conn: &Connection,
+        warehouse_id: uuid::Uuid,
+        filter: Filter,
+    ) -> Result<u64, DbErr> {
+        StockMovement::find()
+            .filter(stock_movement::Column::WarehouseId.eq(warehouse_id))
+            .filter(filter)
+            .count(&conn.db_connection)
+            .await
+    }
+
+    /// Lists the batches of `product_id` in `warehouse_id`, in the order they are consumed: the
+    /// oldest received first.
+    ///
+    /// Batches change with every purchase, so they are never cached.
+    pub async fn list_warehouse_product_batches(
+        conn: &Connection,
+        warehouse_id: uuid::Uuid,
+        product_id: uuid::Uuid,
+    ) -> Result<Vec<warehouse_product_batch::Model>, DbErr> {
+        WarehouseProductBatch::find()
+            .filter(warehouse_product_batch::Column::WarehouseId.eq(warehouse_id))
+            .filter(warehouse_product_batch::Column::ProductId.eq(product_id))
+            .order_by_asc(warehouse_product_batch::Column::ReceivedAt)
+            .order_by_asc(warehouse_product_batch::Column::Id)
+            .all(&conn.db_connection)
+            .await
+    }
+
+    /// Lists the batches of a warehouse expiring at or before `before`, already expired ones
+    /// included, the first to expire first.
+    pub async fn list_expiring_batches<
+        Filter: sea_query::IntoCondition,
+        Page: Into<u64> + Copy,
+        PerPage: Into<u64> + Copy,
+    >(
+        conn: &Connection,
+        warehouse_id: uuid::Uuid,
+        before: chrono::DateTime<chrono::Utc>,
+        filter: Filter,
+        page: Page,
+        per_page: PerPage,
+    ) -> Result<Vec<warehouse_product_batch::Model>, DbErr> {
+        WarehouseProductBatch::find()
+            .filter(warehouse_product_batch::Column::WarehouseId.eq(warehouse_id))
+            .filter(warehouse_product_batch::Column::ExpiresAt.lte(before))
+            .filter(filter)
+            .order_by_asc(warehouse_product_batch::Column::ExpiresAt)
+            .order_by_asc(warehouse_product_batch::Column::ReceivedAt)
+            .paginate(&conn.db_connection, per_page.into())
+            .fetch_page(page.into())
+            .await
+    }
+
+    pub async fn count_expiring_batches<Filter: sea_query::IntoCondition>(
+        conn: &Connection,
+        warehouse_id: uuid::Uuid,
+        before: chrono::DateTime<chrono::Utc>,
+        filter: Filter,
+    ) -> Result<u64, DbErr> {
+        WarehouseProductBatch::find()
+            .filter(warehouse_product_batch::Column::WarehouseId.eq(warehouse_id))
+            .filter(warehouse_product_batch::Column::ExpiresAt.lte(before))
+            .filter(filter)
+            .count(&conn.db_connection)
+            .await
+    }
 }
@@ -0,0 +1,50 @@
+//! Balance reconciliation for the `scrounch_backend` application.
+//!
+//! The balance of a user is stored in a mutable column, updated by every flow spending or
+//! crediting it. The reconciliation recomputes each balance from the completed transactions
+//! of the ledger, so a bug in one of these flows shows up as a drift before it costs anyone money:
+//! - `RefillBalance` adds its credit
+//! - `BalanceEdit` adds its (signed) delta
+//! - `PurchaseWithBalance` removes its total
+//!
+//! A canceled purchase isn't completed anymore, so it counts for nothing: its refund
+//! only cancels its debit.
+//!
+//! Only the amounts in the currency of the balance are taken into account.
+
+use ::entity::models::currency::CurrencyCode;
+use sea_orm::prelude::Decimal;
+
+/// A user whose stored balance doesn't match its ledger.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceDrift {
+    /// Id of the user
+    pub user_id: uuid::Uuid,
+
+    /// Currency of the balance
+    pub currency: CurrencyCode,
+
+    /// Balance stored in the `user` table
+    pub balance: Decimal,
+
+    /// Balance computed from the ledger
+    pub expected: Decimal,
+}
+
+impl BalanceDrift {
+    /// Difference between the stored balance and the ledger, positive when the user has
+    /// more money than it should.
+    pub fn drift(&self) -> Decimal {
+        self.balance - self.expected
+    }
+}
+
+/// Outcome of a reconciliation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconcileReport {
+    /// Number of users checked
+    pub checked: u64,
+
+    /// Users whose balance doesn't match the ledger
+    pub drifts: Vec<BalanceDrift>,
+}
@@ -0,0 +1,743 @@
+//! Stock keeping for the `scrounch_backend` application.
+//!
+//! When a purchase completes, each of its lines is taken from a warehouse:
+//! - a warehouse holding the product in its `warehouse_product` stock is used first, the one with
+//!   the most of it when there are several
+//! - otherwise the product is made in a warehouse from one of its recipes: the enabled
+//!   `warehouse_recipe` links are tried from the highest `priority` to the lowest, and the first
+//!   one whose warehouse holds every enabled ingredient is used, a recipe without any enabled
+//!   ingredient never is
+//!
+//! Disabled warehouses and recipes are never used. A purchase delivered to a location linked to
+//! warehouses (see `location_warehouse`) only uses those, from the highest `priority` to the
+//! lowest before the rules above, see [`location_warehouses`].
+//!
+//! Every change of a stock is recorded in the `stock_movement` journal, along with who made it
+//! and why, in the same database transaction as the change itself. A change crossing the
+//! `min_quantity` threshold of the stock raises a `StockAlert`, see [`crate::notifier`].
+//!
+//! Part of a stock can be tracked in `warehouse_product_batch` batches, each received at once and
+//! possibly expiring. Whatever decreases a stock consumes its batches too, the oldest received
+//! first, so the batches never hold more than the stock.
+//!
+//! A product that isn't stocked anywhere and has no recipe isn't tracked: it is sold without
+//! touching any stock. A tracked product that can't be taken from anywhere rejects the sale.
+//!
+//! Following the same rules, the quantity of a product available right now is its stock plus
+//! the units its recipes can make, see [`available`].
+
+use crate::notifier::{StockAlert, StockAlertKind};
+use ::entity::models::{
+    location_warehouse,
+    prelude::{
+        LocationWarehouse, Recipe, RecipeIngredients, Warehouse, WarehouseProduct,
+        WarehouseProductBatch, WarehouseRecipe,
+    },
+    recipe, recipe_ingredients,
+    sea_orm_active_enums::StockMovementReason,
+    stock_movement, transaction_product_stock, warehouse, warehouse_product,
+    warehouse_product_batch, warehouse_recipe,
+};
+use sea_orm::{
+    prelude::{DateTimeWithTimeZone, Decimal},
+    *,
+};
+use std::collections::HashMap;
+
+/// Why a purchase was rejected by `Mutation::create_purchase_with_balance`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PurchaseRejection {
+    /// The balance doesn't cover the total of the purchase.
+    InsufficientBalance,
+
+    /// There isn't enough of this product, nor of the ingredients of its recipes, in any
+    /// warehouse.
+    InsufficientStock(uuid::Uuid),
+}
+
+/// Why a cancellation was rejected by `Mutation::cancel_purchase_with_balance`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CancelRejection {
+    /// The purchase isn't completed, it was already canceled for instance.
+    NotCompleted,
+
+    /// The balance of the buyer isn't in the currency the purchase was paid in anymore.
+    BalanceCurrencyDoesntMatch,
+}
+
+/// Why a transfer was rejected by `Mutation::transfer_stock`: the source warehouse doesn't
+/// hold enough of this product.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsufficientStock(pub uuid::Uuid);
+
+/// Why a receipt was rejected by `Mutation::receive_purchase_order`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReceiptRejection {
+    /// The purchase order isn't waiting for any product: it is a draft, or fully received.
+    NotReceivable,
+
+    /// The product isn't ordered by the purchase order.
+    NotOrdered(uuid::Uuid),
+
+    /// More of the product was received than the quantity remaining to receive.
+    ExceedsOrdered(uuid::Uuid, Decimal),
+}
+
+/// Where the products of a line were taken from.
+pub(crate) struct StockSource {
+    /// The warehouse the products were taken from.
+    pub warehouse_id: uuid::Uuid,
+
+    /// The recipe used to make the products, `None` when they were taken as is.
+    pub recipe_id: Option<uuid::Uuid>,
+
+    /// Every product taken from the warehouse.
+    pub products: Vec<TakenStock>,
+}
+
+/// A quantity of a product taken from a warehouse.
+pub(crate) struct TakenStock {
+    /// The product taken.
+    pub product_id: uuid::Uuid,
+
+    /// The quantity taken.
+    pub quantity: Decimal,
+
+    /// The parts of the batches the quantity was taken from, the rest wasn't tracked in batches.
+    pub batches: Vec<warehouse_product_batch::Model>,
+}
+
+/// Outcome of taking a line from the stock.
+pub(crate) enum Consumption {
+    /// The product isn't tracked, no stock changed.
+    Untracked,
+
+    /// The products were taken from the stock.
+    Consumed(StockSource),
+
+    /// The product is tracked but no warehouse can provide it.
+    Insufficient,
+}
+
+/// Records the stock changes made by a user inside a database transaction, and collects the
+/// alerts they raise so they can be sent once the transaction is committed.
+pub(crate) struct StockJournal {
+    /// The user making the changes.
+    author_id: uuid::Uuid,
+
+    /// The alerts raised so far.
+    pub alerts: Vec<StockAlert>,
+}
+
+impl StockJournal {
+    pub(crate) fn new(author_id: uuid::Uuid) -> Self {
+        Self {
+            author_id,
+            alerts: Vec::new(),
+        }
+    }
+
+    /// Removes `quantity` of `product_id` from `warehouse_id` if it holds enough of it, and
+    /// records the movement.
+    ///
+    /// Returns `None` when the warehouse doesn't hold enough, otherwise the parts of the batches
+    /// the quantity was taken from, see [`consume_batches`].
+    pub(crate) async fn take<C: ConnectionTrait>(
+        &mut self,
+        db: &C,
+        warehouse_id: uuid::Uuid,
+        product_id: uuid::Uuid,
+        quantity: Decimal,
+        reason: StockMovementReason,
+    ) -> Result<Option<Vec<warehouse_product_batch::Model>>, DbErr> {
+        let taken = WarehouseProduct::update_many()
+            .col_expr(
+                warehouse_product::Column::Quantity,
+                sea_query::Expr::col(warehouse_product::Column::Quantity).sub(quantity),
+            )
+            .filter(warehouse_product::Column::WarehouseId.eq(warehouse_id))
+            .filter(warehouse_product::Column::ProductId.eq(product_id))
+            .filter(warehouse_product::Column::Quantity.gte(quantity))
+            .exec(db)
+            .await?;
+
+        if taken.rows_affected == 0 {
+            return Ok(None);
+        }
+
+        self.record(db, warehouse_id, product_id, -quantity, reason)
+            .await
+            .map(Some)
+    }
+
+    /// Adds `quantity` of `product_id` to `warehouse_id`, stocking it there if it wasn't yet,
+    /// and records the movement.
+    pub(crate) async fn give<C: ConnectionTrait>(
+        &mut self,
+        db: &C,
+        warehouse_id: uuid::Uuid,
+        product_id: uuid::Uuid,
+        quantity: Decimal,
+        reason: StockMovementReason,
+    ) -> Result<(), DbErr> {
+        let given = WarehouseProduct::update_many()
+            .col_expr(
+                warehouse_product::Column::Quantity,
+                sea_query::Expr::col(warehouse_product::Column::Quantity).add(quantity),
+            )
+            .filter(warehouse_product::Column::WarehouseId.eq(warehouse_id))
+            .filter(warehouse_product::Column::ProductId.eq(product_id))
+            .exec(db)
+            .await?;
+
+        if given.rows_affected == 0 {
+            warehouse_product::ActiveModel {
+                warehouse_id: Set(warehouse_id),
+                product_id: Set(product_id),
+                quantity: Set(quantity),
+                min_quantity: Set(None),
+                reorder_quantity: Set(None),
+                created_at: Set(chrono::offset::Local::now().into()),
+            }
+            .insert(db)
+            .await?;
+        }
+
+        self.record(db, warehouse_id, product_id, quantity, reason)
+            .await?;
+        Ok(())
+    }
+
+    /// Adds `quantity` of `product_id` to `warehouse_id` like [`Self::give`], and tracks it as a
+    /// batch received at `received_at`.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn give_batch<C: ConnectionTrait>(
+        &mut self,
+        db: &C,
+        warehouse_id: uuid::Uuid,
+        product_id: uuid::Uuid,
+        quantity: Decimal,
+        expires_at: Option<DateTimeWithTimeZone>,
+        received_at: DateTimeWithTimeZone,
+        reason: StockMovementReason,
+    ) -> Result<warehouse_product_batch::Model, DbErr> {
+        self.give(db, warehouse_id, product_id, quantity, reason)
+            .await?;
+
+        warehouse_product_batch::ActiveModel {
+            id: Set(uuid::Uuid::new_v4()),
+            warehouse_id: Set(warehouse_id),
+            product_id: Set(product_id),
+            quantity: Set(quantity),
+            expires_at: Set(expires_at),
+            received_at: Set(received_at),
+        }
+        .insert(db)
+        .await
+    }
+
+    /// Changes the stock of `product_id` in `warehouse_id` by `delta`, stocking it there if it
+    /// wasn't yet, and records the movement.
+    ///
+    /// Unlike [`Self::take`], a decrease is applied even if the warehouse doesn't hold enough, the
+    /// stock then dropping to zero.
+    pub(crate) async fn adjust<C: ConnectionTrait>(
+        &mut self,
+        db: &C,
+        warehouse_id: uuid::Uuid,
+        product_id: uuid::Uuid,
+        delta: Decimal,
+        reason: StockMovementReason,
+    ) -> Result<(), DbErr> {
+        if !delta.is_sign_negative() {
+            return self.give(db, warehouse_id, product_id, delta, reason).await;
+        }
+
+        let stock = WarehouseProduct::find_by_id((warehouse_id, product_id))
+            .lock_exclusive()
+            .one(db)
+            .await?;
+        let Some(stock) = stock else {
+            return Ok(());
+        };
+
+        let quantity = (stock.quantity + delta).max(Decimal::ZERO);
+        let delta = quantity - stock.quantity;
+        let mut model: warehouse_product::ActiveModel = stock.into();
+        model.quantity = Set(quantity);
+        model.update(db).await?;
+
+        self.record(db, warehouse_id, product_id, delta, reason)
+            .await?;
+        Ok(())
+    }
+
+    /// Sets the stock of `product_id` in `warehouse_id` to `quantity`, stocking it there if it
+    /// wasn't yet, and records the difference as a movement.
+    ///
+    /// Returns the quantity held before, zero when the product wasn't stocked, and the stock.
+    pub(crate) async fn set<C: ConnectionTrait>(
+        &mut self,
+        db: &C,
+        warehouse_id: uuid::Uuid,
+        product_id: uuid::Uuid,
+        quantity: Decimal,
+        reason: StockMovementReason,
+    ) -> Result<(Decimal, warehouse_product::Model), DbErr> {
+        let previous = WarehouseProduct::find_by_id((warehouse_id, product_id))
+            .lock_exclusive()
+            .one(db)
+            .await?;
+
+        let (previous, model) = match previous {
+            Some(previous) => {
+                let quantity_before = previous.quantity;
+                let mut model: warehouse_product::ActiveModel = previous.into();
+                model.quantity = Set(quantity);
+                (quantity_before, model.update(db).await?)
+            }
+            None => {
+                let model = warehouse_product::ActiveModel {
+                    warehouse_id: Set(warehouse_id),
+                    product_id: Set(product_id),
+                    quantity: Set(quantity),
+                    min_quantity: Set(None),
+                    reorder_quantity: Set(None),
+                    created_at: Set(chrono::offset::Local::now().into()),
+                };
+                (Decimal::ZERO, model.insert(db).await?)
+            }
+        };
+
+        self.record(db, warehouse_id, product_id, quantity - previous, reason)
+            .await?;
+        Ok((previous, model))
+    }
+
+    /// Records a change of `delta` of the stock of `product_id` in `warehouse_id`, already
+    /// applied, in the journal, and raises an alert if it crossed the threshold of the stock.
+    ///
+    /// A null `delta` changed nothing, so it isn't recorded. Returns the parts of the batches a
+    /// decrease was taken from, see [`consume_batches`].
+    pub(crate) async fn record<C: ConnectionTrait>(
+        &mut self,
+        db: &C,
+        warehouse_id: uuid::Uuid,
+        product_id: uuid::Uuid,
+        delta: Decimal,
+        reason: StockMovementReason,
+    ) -> Result<Vec<warehouse_product_batch::Model>, DbErr> {
+        if delta.is_zero() {
+            return Ok(Vec::new());
+        }
+
+        stock_movement::ActiveModel {
+            id: Set(uuid::Uuid::new_v4()),
+            warehouse_id: Set(warehouse_id),
+            product_id: Set(product_id),
+            delta: Set(delta),
+            reason: Set(reason),
+            author_id: Set(Some(self.author_id)),
+            created_at: Set(chrono::offset::Local::now().into()),
+        }
+        .insert(db)
+        .await?;
+
+        let batches = match delta.is_sign_negative() {
+            true => consume_batches(db, warehouse_id, product_id, -delta).await?,
+            false => Vec::new(),
+        };
+
+        let stock = WarehouseProduct::find_by_id((warehouse_id, product_id))
+            .one(db)
+            .await?;
+        let Some(stock) = stock else {
+            return Ok(batches);
+        };
+        let Some(min_quantity) = stock.min_quantity else {
+            return Ok(batches);
+        };
+
+        let previous = stock.quantity - delta;
+        let kind = match (previous <= min_quantity, stock.quantity <= min_quantity) {
+            (false, true) => StockAlertKind::Low,
+            (true, false) => StockAlertKind::Replenished,
+            _ => return Ok(batches),
+        };
+
+        self.alerts.push(StockAlert {
+            kind,
+            warehouse_id,
+            product_id,
+            quantity: stock.quantity,
+            min_quantity,
+            reorder_quantity: stock.reorder_quantity,
+        });
+
+        Ok(batches)
+    }
+}
+
+/// Removes `quantity` of `product_id` from its batches in `warehouse_id`, the oldest received
+/// first, deleting the emptied ones. What the batches don't hold was taken from the untracked
+/// part of the stock.
+///
+/// Returns the part taken from each batch, as the batch holding only the quantity taken.
+async fn consume_batches<C: ConnectionTrait>(
+    db: &C,
+    warehouse_id: uuid::Uuid,
+    product_id: uuid::Uuid,
+    mut quantity: Decimal,
+) -> Result<Vec<warehouse_product_batch::Model>, DbErr> {
+    let batches = WarehouseProductBatch::find()
+        .filter(warehouse_product_batch::Column::WarehouseId.eq(warehouse_id))
+        .filter(warehouse_product_batch::Column::ProductId.eq(product_id))
+        .order_by_asc(warehouse_product_batch::Column::ReceivedAt)
+        .order_by_asc(warehouse_product_batch::Column::Id)
+        .lock_exclusive()
+        .all(db)
+        .await?;
+
+    let mut taken = Vec::new();
+    for batch in batches {
+        if quantity.is_zero() {
+            break;
+        }
+
+        if batch.quantity <= quantity {
+            quantity -= batch.quantity;
+            taken.push(batch.clone());
+            batch.delete(db).await?;
+        } else {
+            let left = batch.quantity - quantity;
+            taken.push(warehouse_product_batch::Model {
+                quantity,
+                ..batch.clone()
+            });
+            quantity = Decimal::ZERO;
+            let mut batch: warehouse_product_batch::ActiveModel = batch.into();
+            batch.quantity = Set(left);
+            batch.update(db).await?;
+        }
+    }
+
+    Ok(taken)
+}
+
+/// Finds the enabled warehouses serving `location_id`, from the highest priority to the lowest.
+///
+/// Returns `None` when the location isn't linked to any warehouse, it is then served by all of
+/// them.
+pub(crate) async fn location_warehouses<C: ConnectionTrait>(
+    db: &C,
+    location_id: uuid::Uuid,
+) -> Result<Option<Vec<uuid::Uuid>>, DbErr> {
+    let links = LocationWarehouse::find()
+        .find_also_related(Warehouse)
+        .filter(location_warehouse::Column::LocationId.eq(location_id))
+        .order_by_desc(location_warehouse::Column::Priority)
+        .all(db)
+        .await?;
+
+    if links.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(
+        links
+            .into_iter()
+            .filter_map(|(link, warehouse)| {
+                warehouse
+                    .filter(|warehouse| !warehouse.disabled)
+                    .map(|_| link.warehouse_id)
+            })
+            .collect(),
+    ))
+}
+
+/// Condition matching the enabled warehouses among `warehouse_ids`, or all of them when `None`.
+fn warehouse_condition(warehouse_ids: Option<&[uuid::Uuid]>) -> Condition {
+    Condition::all()
+        .add(warehouse::Column::Disabled.eq(false))
+        .add_option(warehouse_ids.map(|ids| warehouse::Column::Id.is_in(ids.iter().copied())))
+}
+
+/// Rank of `warehouse_id` in `warehouse_ids`, the warehouses being equal when `None`.
+fn warehouse_rank(warehouse_ids: Option<&[uuid::Uuid]>, warehouse_id: uuid::Uuid) -> usize {
+    warehouse_ids
+        .and_then(|ids| ids.iter().position(|id| *id == warehouse_id))
+        .unwrap_or_default()
+}
+
+/// Takes `quantity` of `product_id` from the stock following the rules of this module, only
+/// using `warehouse_ids` in this order when given.
+///
+/// Every decrement only applies if the stock still covers it, so concurrent purchases
+/// cannot drive it below zero. Nothing changes when the outcome isn't `Consumed`.
+pub(crate) async fn consume(
+    txn: &DatabaseTransaction,
+    journal: &mut StockJournal,
+    product_id: uuid::Uuid,
+    quantity: Decimal,
+    warehouse_ids: Option<&[uuid::Uuid]>,
+) -> Result<Consumption, DbErr> {
+    let mut stocks = WarehouseProduct::find()
+        .inner_join(Warehouse)
+        .filter(warehouse_product::Column::ProductId.eq(product_id))
+        .filter(warehouse_condition(warehouse_ids))
+        .order_by_desc(warehouse_product::Column::Quantity)
+        .all(txn)
+        .await?;
+    stocks.sort_by_key(|stock| warehouse_rank(warehouse_ids, stock.warehouse_id));
+
+    for stock in stocks.iter().filter(|stock| stock.quantity >= quantity) {
+        let reason = StockMovementReason::Purchase;
+        if let Some(batches) = journal
+            .take(txn, stock.warehouse_id, product_id, quantity, reason)
+            .await?
+        {
+            return Ok(Consumption::Consumed(StockSource {
+                warehouse_id: stock.warehouse_id,
+                recipe_id: None,
+                products: vec![TakenStock {
+                    product_id,
+                    quantity,
+                    batches,
+                }],
+            }));
+        }
+    }
+
+    let mut recipes = WarehouseRecipe::find()
+        .inner_join(recipe::Entity)
+        .inner_join(Warehouse)
+        .filter(recipe::Column::ResultProductId.eq(product_id))
+        .filter(recipe::Column::Disabled.eq(false))
+        .filter(warehouse_condition(warehouse_ids))
+        .order_by_desc(warehouse_recipe::Column::Priority)
+        .all(txn)
+        .await?;
+    recipes.sort_by_key(|link| warehouse_rank(warehouse_ids, link.warehouse_id));
+
+    for link in &recipes {
+        let ingredients = RecipeIngredients::find()
+            .filter(recipe_ingredients::Column::RecipeId.eq(link.recipe_id))
+            .filter(recipe_ingredients::Column::Disabled.eq(false))
+            .all(txn)
+            .await?;
+
+        // A recipe without ingredients would make the product out of nothing
+        if ingredients.is_empty() {
+            continue;
+        }
+
+        let savepoint = txn.begin().await?;
+        let alerts = journal.alerts.len();
+        let mut products = Vec::with_capacity(ingredients.len());
+        for ingredient in &ingredients {
+            let needed = ingredient.quantity * quantity;
+            let reason = StockMovementReason::RecipeConsumption;
+            let Some(batches) = journal
+                .take(
+                    &savepoint,
+                    link.warehouse_id,
+                    ingredient.ingredient_id,
+                    needed,
+                    reason,
+                )
+                .await?
+            else {
+                break;
+            };
+            products.push(TakenStock {
+                product_id: ingredient.ingredient_id,
+                quantity: needed,
+                batches,
+            });
+        }
+
+        if products.len() < ingredients.len() {
+            savepoint.rollback().await?;
+            journal.alerts.truncate(alerts);
+            continue;
+        }
+        savepoint.commit().await?;
+
+        return Ok(Consumption::Consumed(StockSource {
+            warehouse_id: link.warehouse_id,
+            recipe_id: Some(link.recipe_id),
+            products,
+        }));
+    }
+
+    if !stocks.is_empty() || !recipes.is_empty() {
+        return Ok(Consumption::Insufficient);
+    }
+
+    // A product tracked in the other warehouses can't be sold from the given ones.
+    match warehouse_ids {
+        Some(_) if is_tracked(txn, product_id).await? => Ok(Consumption::Insufficient),
+        _ => Ok(Consumption::Untracked),
+    }
+}
+
+/// Tells if `product_id` is stocked or made by a recipe in any enabled warehouse.
+async fn is_tracked<C: ConnectionTrait>(db: &C, product_id: uuid::Uuid) -> Result<bool, DbErr> {
+    let stocked = WarehouseProduct::find()
+        .inner_join(Warehouse)
+        .filter(warehouse_product::Column::ProductId.eq(product_id))
+        .filter(warehouse_condition(None))
+        .count(db)
+        .await?;
+    if stocked > 0 {
+        return Ok(true);
+    }
+
+    let made = WarehouseRecipe::find()
+        .inner_join(recipe::Entity)
+        .inner_join(Warehouse)
+        .filter(recipe::Column::ResultProductId.eq(product_id))
+        .filter(recipe::Column::Disabled.eq(false))
+        .filter(warehouse_condition(None))
+        .count(db)
+        .await?;
+    Ok(made > 0)
+}
+
+/// Puts back in `warehouse_id` the `products` taken from it for a line, made from `recipe_id`
+/// when it was made from its ingredients, with the quantities recorded when they were taken.
+/// What was taken from a batch goes back as a batch, with its expiry and reception dates.
+pub(crate) async fn restock(
+    txn: &DatabaseTransaction,
+    journal: &mut StockJournal,
+    warehouse_id: uuid::Uuid,
+    recipe_id: Option<uuid::Uuid>,
+    products: &[transaction_product_stock::Model],
+) -> Result<(), DbErr> {
+    let reason = match recipe_id {
+        Some(_) => StockMovementReason::RecipeConsumption,
+        None => StockMovementReason::Purchase,
+    };
+
+    for taken in products {
+        let (product_id, quantity) = (taken.product_id, taken.quantity);
+        match taken.received_at {
+            Some(received_at) => {
+                journal
+                    .give_batch(
+                        txn,
+                        warehouse_id,
+                        product_id,
+                        quantity,
+                        taken.expires_at,
+                        received_at,
+                        reason.clone(),
+                    )
+                    .await?;
+            }
+            None => {
+                journal
+                    .give(txn, warehouse_id, product_id, quantity, reason.clone())
+                    .await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Computes how much of each of `product_ids` can be sold right now, from `warehouse_ids` or
+/// from every warehouse when `None`.
+///
+/// A product is available from its stock, plus the units its recipes can make in each
+/// warehouse linking them, a recipe making as many units as its scarcest ingredient allows.
+/// Disabled warehouses, recipes and ingredients are left out, as well as recipes without any
+/// ingredient. Recipes sharing ingredients each count the whole stock of them.
+pub(crate) async fn available<C: ConnectionTrait>(
+    db: &C,
+    warehouse_ids: Option<&[uuid::Uuid]>,
+    product_ids: &[uuid::Uuid],
+) -> Result<HashMap<uuid::Uuid, Decimal>, DbErr> {
+    let recipes = Recipe::find()
+        .filter(recipe::Column::ResultProductId.is_in(product_ids.iter().copied()))
+        .filter(recipe::Column::Disabled.eq(false))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|recipe| (recipe.id, recipe.result_product_id))
+        .collect::<HashMap<_, _>>();
+
+    let links = WarehouseRecipe::find()
+        .inner_join(Warehouse)
+        .filter(warehouse_recipe::Column::RecipeId.is_in(recipes.keys().copied()))
+        .filter(warehouse_condition(warehouse_ids))
+        .all(db)
+        .await?;
+
+    let mut ingredients = HashMap::<uuid::Uuid, Vec<recipe_ingredients::Model>>::new();
+    for ingredient in RecipeIngredients::find()
+        .filter(recipe_ingredients::Column::RecipeId.is_in(recipes.keys().copied()))
+        .filter(recipe_ingredients::Column::Disabled.eq(false))
+        .all(db)
+        .await?
+    {
+        ingredients
+            .entry(ingredient.recipe_id)
+            .or_default()
+            .push(ingredient);
+    }
+
+    let stocked = product_ids.iter().copied().chain(
+        ingredients
+            .values()
+            .flatten()
+            .map(|ingredient| ingredient.ingredient_id),
+    );
+    let stocks = WarehouseProduct::find()
+        .inner_join(Warehouse)
+        .filter(warehouse_product::Column::ProductId.is_in(stocked))
+        .filter(warehouse_condition(warehouse_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|stock| ((stock.warehouse_id, stock.product_id), stock.quantity))
+        .collect::<HashMap<_, _>>();
+
+    let mut result = product_ids
+        .iter()
+        .map(|&product_id| (product_id, Decimal::ZERO))
+        .collect::<HashMap<_, _>>();
+
+    for (&(_, product_id), &quantity) in &stocks {
+        if let Some(available) = result.get_mut(&product_id) {
+            *available += quantity;
+        }
+    }
+
+    for link in links {
+        let (Some(product_id), Some(ingredients)) = (
+            recipes.get(&link.recipe_id),
+            ingredients.get(&link.recipe_id),
+        ) else {
+            continue;
+        };
+
+        let craftable = ingredients
+            .iter()
+            .filter(|ingredient| ingredient.quantity > Decimal::ZERO)
+            .map(|ingredient| {
+                let stock = stocks
+                    .get(&(link.warehouse_id, ingredient.ingredient_id))
+                    .copied()
+                    .unwrap_or_default();
+                (stock / ingredient.quantity).floor()
+            })
+            .min();
+
+        if let (Some(available), Some(craftable)) = (result.get_mut(product_id), craftable) {
+            *available += craftable.max(Decimal::ZERO);
+        }
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,37 @@
+//! Conversion of quantities between units for the `scrounch_backend` application.
+//!
+//! A product is counted in a single unit: grams, liters, meters or units. A quantity can
+//! still be expressed in any unit of the same dimension, it is first scaled to grams, liters
+//! or meters (see `QuantityUnitRequest::to_base`), then converted here into the unit of the
+//! product.
+//!
+//! A product whose single unit holds a known content, such as a can of 0.33 liter, can also be
+//! counted across dimensions: 0.66 liter of a product counted in units is 2 units, and 2 units
+//! of a product counted in liters is 0.66 liter.
+
+use ::entity::models::{product, sea_orm_active_enums::Unit};
+use sea_orm::prelude::Decimal;
+
+/// Converts `quantity`, counted in `unit`, into the unit `product` is counted in.
+///
+/// Returns `None` when the units can't be converted, the product being counted in another
+/// dimension without a content bridging them.
+pub fn convert(product: &product::Model, unit: &Unit, quantity: Decimal) -> Option<Decimal> {
+    if *unit == product.unit {
+        return Some(quantity);
+    }
+
+    let (Some(content), Some(content_unit)) = (product.content_quantity, &product.content_unit)
+    else {
+        return None;
+    };
+    if content.is_zero() {
+        return None;
+    }
+
+    match (&product.unit, unit) {
+        (Unit::Unit, unit) if unit == content_unit => Some(quantity / content),
+        (product_unit, Unit::Unit) if product_unit == content_unit => Some(quantity * content),
+        _ => None,
+    }
+}
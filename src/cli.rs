@@ -109,4 +109,56 @@ pub struct Arguments {
     /// If not provided, all product categories will be imported.
     #[arg(env, long, value_delimiter = ' ', num_args = 1..)]
     pub sma_categories: Option<Vec<String>>,
+
+    /// How long after an order its buyer can still cancel it, admins can cancel it anytime.
+    #[arg(env, long, value_parser = humantime::parse_duration, default_value = "5m")]
+    pub order_cancel_grace_period: std::time::Duration,
+
+    /// The payment provider used to collect the money when a user buys a refill.
+    /// If not provided, refills cannot be bought.
+    #[arg(env, long, value_enum)]
+    pub payment_provider: Option<PaymentProviderKind>,
+
+    /// The base URL of the Stripe-compatible API, used by the `stripe` payment provider.
+    /// Example: https://api.stripe.com
+    #[arg(env, long, default_value = "https://api.stripe.com")]
+    pub stripe_api_url: String,
+
+    /// The secret API key of the `stripe` payment provider.
+    #[arg(env, long)]
+    pub stripe_secret_key: Option<String>,
+
+    /// The secret used to verify the webhooks sent by the `stripe` payment provider.
+    #[arg(env, long)]
+    pub stripe_webhook_secret: Option<String>,
+
+    /// The URL every stock alert is posted to, when a stock crosses its `min_quantity`.
+    /// If not provided, no stock alert is sent.
+    #[arg(env, long)]
+    pub stock_webhook_url: Option<String>,
+
+    /// A maintenance task to run instead of starting the server.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Maintenance tasks which can be run from the command line.
+#[derive(clap::Subcommand, Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Checks the balance of every user against the transaction ledger.
+    Reconcile {
+        /// Rewrites the balances drifting from the ledger.
+        #[arg(long, default_value_t = false)]
+        fix: bool,
+    },
+}
+
+/// The payment providers the application can be configured with.
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentProviderKind {
+    /// A local provider accepting every payment, for development and tests only.
+    Fake,
+
+    /// A Stripe-compatible provider, see the `stripe_*` arguments.
+    Stripe,
 }
@@ -13,9 +13,13 @@ use std::time::Duration;
 
 use axum::{error_handling::HandleErrorLayer, http::Method};
 use axum_oidc::EmptyAdditionalClaims;
-pub use cli::Arguments;
+pub use cli::{Arguments, Command, PaymentProviderKind};
 use migration::MigratorTrait;
 use oidc::handle_axum_oidc_middleware_error;
+use service::{
+    notifier::{StockNotifierGateway, webhook::WebhookStockNotifier},
+    payment::{PaymentGateway, fake::FakePaymentProvider, stripe::StripePaymentProvider},
+};
 use utoipa::OpenApi;
 use utoipa_axum::{router::OpenApiRouter, routes};
 
@@ -26,6 +30,20 @@ use utoipa_axum::{router::OpenApiRouter, routes};
 /// It wires up the backend services such as authentication, database connections,
 /// and any other business logic needed to manage the beverage sales system.
 pub async fn app(arguments: Arguments) -> axum::Router {
+    let stock_notifier = arguments
+        .stock_webhook_url
+        .clone()
+        .map(|url| StockNotifierGateway::new(WebhookStockNotifier::new(url)));
+
+    app_with_stock_notifier(arguments, stock_notifier).await
+}
+
+/// Creates and configures the Axum application like [`app`], sending the stock alerts
+/// through `stock_notifier` instead of the one configured by the `arguments`.
+pub async fn app_with_stock_notifier(
+    arguments: Arguments,
+    stock_notifier: Option<StockNotifierGateway>,
+) -> axum::Router {
     let db_pool = get_database_conn(&arguments.database_url, None)
         .await
         .expect("Couldn't connect to the database");
@@ -43,36 +61,42 @@ pub async fn app(arguments: Arguments) -> axum::Router {
         .await
         .expect("Migration couldn't proceed correctly");
 
+    let payment = match arguments.payment_provider {
+        Some(PaymentProviderKind::Fake) => {
+            tracing::warn!("Fake payment provider is enabled, refills are free");
+            Some(PaymentGateway::new(FakePaymentProvider::new(
+                arguments.backend_url.clone(),
+            )))
+        }
+        Some(PaymentProviderKind::Stripe) => Some(PaymentGateway::new(StripePaymentProvider::new(
+            arguments.stripe_api_url.clone(),
+            arguments
+                .stripe_secret_key
+                .clone()
+                .expect("Stripe payment provider needs a secret key"),
+            arguments
+                .stripe_webhook_secret
+                .clone()
+                .expect("Stripe payment provider needs a webhook secret"),
+        ))),
+        None => None,
+    };
+
     let mut state = state::AppState {
         arguments: Arguments::default(),
         db_pool,
         #[cfg(feature = "cache")]
         cache_pool: None,
         s3_storage: s3_bucket,
+        payment,
+        stock_notifier,
     };
 
     state.arguments = arguments.clone();
 
     #[cfg(feature = "cache")]
     if let Some(cache_url) = arguments.cache_url.clone() {
-        use fred::interfaces::ClientLike;
-
-        let config = fred::types::RedisConfig::from_url(&cache_url)
-            .expect("Cache URL is not correctly formatted");
-        let mut builder = fred::types::Builder::from_config(config);
-        builder
-            .set_policy(fred::types::ReconnectPolicy::new_constant(4, 1500))
-            .with_config(|c| {
-                c.fail_fast = false;
-                c.tracing.enabled = true;
-            });
-        let cache_pool = builder
-            .build_pool(8)
-            .expect("Could not connect to Cache Pool");
-        cache_pool.init().await.expect("Failed to connect to Cache");
-        tracing::info!("Cache is connected");
-
-        state.cache_pool = Some(cache_pool)
+        state.cache_pool = Some(get_cache_pool(&cache_url).await);
     }
 
     let login_service = tower::ServiceBuilder::new()
@@ -119,6 +143,7 @@ pub async fn app(arguments: Arguments) -> axum::Router {
         .merge(auth_required_routes(&path))
         .layer(login_service)
         .merge(auth_optional_routes(&path))
+        .merge(payment_routes(&path, arguments.payment_provider))
         .layer(axum::extract::DefaultBodyLimit::max(64000000))
         .split_for_parts();
 
@@ -142,6 +167,56 @@ pub async fn app(arguments: Arguments) -> axum::Router {
         .with_state(state)
 }
 
+/// Checks the balance of every user against the transaction ledger, without starting the server.
+///
+/// Every drift found is printed, and rewritten from the ledger if `fix` is set.
+/// Returns the number of drifts found.
+pub async fn reconcile(arguments: Arguments, fix: bool) -> Result<usize, sea_orm::DbErr> {
+    let db_pool = get_database_conn(&arguments.database_url, None).await?;
+
+    migration::Migrator::up(&db_pool, None).await?;
+
+    let conn = service::Connection {
+        db_connection: db_pool,
+        #[cfg(feature = "cache")]
+        cache_connection: match arguments.cache_url {
+            Some(cache_url) => Some(get_cache_pool(&cache_url).await),
+            None => None,
+        },
+        stock_notifier: None,
+    };
+
+    let report = service::Query::reconcile_balances(&conn).await?;
+
+    for drift in &report.drifts {
+        println!(
+            "{}: balance {} {:?}, ledger {} {:?}, drift {}",
+            drift.user_id,
+            drift.balance,
+            drift.currency,
+            drift.expected,
+            drift.currency,
+            drift.drift()
+        );
+
+        if fix && !service::Mutation::fix_balance_drift(&conn, drift).await? {
+            println!("{}: balance changed meanwhile, skipped", drift.user_id);
+        }
+    }
+
+    println!(
+        "{} users checked, {} drifting{}",
+        report.checked,
+        report.drifts.len(),
+        match fix && !report.drifts.is_empty() {
+            true => ", fixed",
+            false => "",
+        }
+    );
+
+    Ok(report.drifts.len())
+}
+
 /// Defines routes that require user authentication.
 ///
 /// This function creates an `axum::Router` specifically for routes that are
@@ -185,7 +260,8 @@ fn auth_optional_routes(path: &str) -> OpenApiRouter<state::AppState> {
                 OpenApiRouter::new()
                     .routes(routes!(api::user::get::get_user))
                     .routes(routes!(api::user::get::get_all_users))
-                    .routes(routes!(api::user::edit::edit_user)),
+                    .routes(routes!(api::user::edit::edit_user))
+                    .routes(routes!(api::user::balance::edit_user_balance)),
             )
             .nest(
                 "/location",
@@ -194,7 +270,13 @@ fn auth_optional_routes(path: &str) -> OpenApiRouter<state::AppState> {
                     .routes(routes!(api::location::get::get_all_locations))
                     .routes(routes!(api::location::new::post_new_location))
                     .routes(routes!(api::location::edit::edit_location))
-                    .routes(routes!(api::location::delete::delete_location)),
+                    .routes(routes!(api::location::delete::delete_location))
+                    .routes(routes!(api::location::orders::get_location_orders))
+                    .routes(routes!(api::location::menu::get_location_menu))
+                    .routes(routes!(api::location::get::get_location_warehouses))
+                    .routes(routes!(api::location::new::post_new_location_warehouse))
+                    .routes(routes!(api::location::edit::edit_location_warehouse))
+                    .routes(routes!(api::location::delete::delete_location_warehouse)),
             )
             .nest(
                 "/refill",
@@ -203,13 +285,15 @@ fn auth_optional_routes(path: &str) -> OpenApiRouter<state::AppState> {
                     .routes(routes!(api::refill::get::get_all_refills))
                     .routes(routes!(api::refill::new::post_new_refill))
                     .routes(routes!(api::refill::edit::edit_refill))
-                    .routes(routes!(api::refill::delete::delete_refill)),
+                    .routes(routes!(api::refill::delete::delete_refill))
+                    .routes(routes!(api::refill::purchase::post_purchase_refill)),
             )
             .nest(
                 "/recipe",
                 OpenApiRouter::new()
                     .routes(routes!(api::recipe::get::get_recipe))
                     .routes(routes!(api::recipe::get::get_all_recipes))
+                    .routes(routes!(api::recipe::get::get_recipe_tree))
                     .routes(routes!(api::recipe::new::post_new_recipe))
                     .routes(routes!(api::recipe::edit::edit_recipe))
                     .routes(routes!(api::recipe::delete::delete_recipe)),
@@ -224,11 +308,133 @@ fn auth_optional_routes(path: &str) -> OpenApiRouter<state::AppState> {
                     .routes(routes!(api::warehouse::delete::delete_warehouse))
                     .routes(routes!(api::warehouse::new::post_new_warehouse_product))
                     .routes(routes!(api::warehouse::get::get_warehouse_product))
-                    .routes(routes!(api::warehouse::get::get_all_warehouse_products)),
+                    .routes(routes!(api::warehouse::get::get_all_warehouse_products))
+                    .routes(routes!(api::warehouse::edit::edit_warehouse_product))
+                    .routes(routes!(api::warehouse::edit::edit_warehouse_products))
+                    .routes(routes!(api::warehouse::delete::delete_warehouse_product))
+                    .routes(routes!(api::warehouse::get::get_warehouse_movements))
+                    .routes(routes!(api::warehouse::get::get_warehouse_low_stock))
+                    .routes(routes!(api::warehouse::get::get_warehouse_expiring))
+                    .routes(routes!(
+                        api::warehouse::new::post_new_warehouse_product_batch
+                    ))
+                    .routes(routes!(api::warehouse::get::get_warehouse_product_batches))
+                    .routes(routes!(api::warehouse::get::get_warehouse_recipe))
+                    .routes(routes!(api::warehouse::get::get_all_warehouse_recipes))
+                    .routes(routes!(api::warehouse::new::post_new_warehouse_recipe))
+                    .routes(routes!(api::warehouse::edit::edit_warehouse_recipe))
+                    .routes(routes!(api::warehouse::delete::delete_warehouse_recipe))
+                    .routes(routes!(api::warehouse::transfer::post_warehouse_transfer)),
+            )
+            .nest(
+                "/transaction",
+                OpenApiRouter::new()
+                    .routes(routes!(api::transaction::get::get_transaction))
+                    .routes(routes!(api::transaction::get::get_all_transactions)),
+            )
+            .nest(
+                "/order",
+                OpenApiRouter::new()
+                    .routes(routes!(api::order::new::post_new_order))
+                    .routes(routes!(api::order::cancel::post_cancel_order))
+                    .routes(routes!(api::order::fulfilment::edit_order_fulfilment)),
+            )
+            .nest(
+                "/currency",
+                OpenApiRouter::new()
+                    .routes(routes!(api::currency::get::get_currency))
+                    .routes(routes!(api::currency::get::get_all_currencies))
+                    .routes(routes!(api::currency::new::post_new_currency))
+                    .routes(routes!(api::currency::edit::edit_currency))
+                    .routes(routes!(api::currency::delete::delete_currency)),
+            )
+            .nest(
+                "/stocktake",
+                OpenApiRouter::new()
+                    .routes(routes!(api::stocktake::get::get_stocktake))
+                    .routes(routes!(api::stocktake::get::get_all_stocktakes))
+                    .routes(routes!(api::stocktake::new::post_new_stocktake))
+                    .routes(routes!(api::stocktake::edit::put_stocktake_count))
+                    .routes(routes!(api::stocktake::edit::close_stocktake))
+                    .routes(routes!(api::stocktake::delete::delete_stocktake)),
+            )
+            .nest(
+                "/supplier",
+                OpenApiRouter::new()
+                    .routes(routes!(api::supplier::get::get_supplier))
+                    .routes(routes!(api::supplier::get::get_all_suppliers))
+                    .routes(routes!(api::supplier::new::post_new_supplier))
+                    .routes(routes!(api::supplier::edit::edit_supplier))
+                    .routes(routes!(api::supplier::delete::delete_supplier)),
+            )
+            .nest(
+                "/purchase_order",
+                OpenApiRouter::new()
+                    .routes(routes!(api::purchase_order::get::get_purchase_order))
+                    .routes(routes!(api::purchase_order::get::get_all_purchase_orders))
+                    .routes(routes!(api::purchase_order::new::post_new_purchase_order))
+                    .routes(routes!(api::purchase_order::edit::edit_purchase_order))
+                    .routes(routes!(api::purchase_order::edit::send_purchase_order))
+                    .routes(routes!(api::purchase_order::edit::receive_purchase_order))
+                    .routes(routes!(api::purchase_order::delete::delete_purchase_order)),
+            )
+            .nest(
+                "/category",
+                OpenApiRouter::new()
+                    .routes(routes!(api::category::get::get_category))
+                    .routes(routes!(api::category::get::get_all_categories))
+                    .routes(routes!(api::category::new::post_new_category))
+                    .routes(routes!(api::category::edit::edit_category))
+                    .routes(routes!(api::category::delete::delete_category)),
+            )
+            .nest(
+                "/report",
+                OpenApiRouter::new().routes(routes!(api::report::margin::get_margin_report)),
+            )
+            .nest(
+                "/exchange_rate",
+                OpenApiRouter::new()
+                    .routes(routes!(api::exchange_rate::get::get_exchange_rate))
+                    .routes(routes!(api::exchange_rate::get::get_all_exchange_rates))
+                    .routes(routes!(api::exchange_rate::new::post_new_exchange_rate))
+                    .routes(routes!(api::exchange_rate::edit::edit_exchange_rate))
+                    .routes(routes!(api::exchange_rate::delete::delete_exchange_rate)),
+            )
+            .nest(
+                "/admin",
+                OpenApiRouter::new().routes(routes!(
+                    api::admin::reconcile::get_reconcile,
+                    api::admin::reconcile::post_reconcile
+                )),
             ),
     )
 }
 
+/// Defines the routes called back by the configured payment provider.
+///
+/// These routes don't require user authentication: webhooks are authenticated by their
+/// signature, and the checkout routes of the fake provider will only accept the user who
+/// started the payment.
+fn payment_routes(
+    path: &str,
+    provider: Option<PaymentProviderKind>,
+) -> OpenApiRouter<state::AppState> {
+    let router = match provider {
+        Some(PaymentProviderKind::Fake) => OpenApiRouter::new()
+            .routes(routes!(api::payment::fake::get_fake_payment_confirm))
+            .routes(routes!(api::payment::fake::get_fake_payment_cancel)),
+        Some(PaymentProviderKind::Stripe) | None => OpenApiRouter::new(),
+    };
+
+    OpenApiRouter::new().nest(
+        path,
+        OpenApiRouter::new().nest("/payment", router).nest(
+            "/webhook",
+            OpenApiRouter::new().routes(routes!(api::payment::webhook::post_payment_webhook)),
+        ),
+    )
+}
+
 async fn get_database_conn(
     url: &str,
     default_schema: Option<String>,
@@ -248,6 +454,28 @@ async fn get_database_conn(
     sea_orm::Database::connect(opt).await
 }
 
+#[cfg(feature = "cache")]
+async fn get_cache_pool(cache_url: &str) -> fred::clients::RedisPool {
+    use fred::interfaces::ClientLike;
+
+    let config = fred::types::RedisConfig::from_url(cache_url)
+        .expect("Cache URL is not correctly formatted");
+    let mut builder = fred::types::Builder::from_config(config);
+    builder
+        .set_policy(fred::types::ReconnectPolicy::new_constant(4, 1500))
+        .with_config(|c| {
+            c.fail_fast = false;
+            c.tracing.enabled = true;
+        });
+    let cache_pool = builder
+        .build_pool(8)
+        .expect("Could not connect to Cache Pool");
+    cache_pool.init().await.expect("Failed to connect to Cache");
+    tracing::info!("Cache is connected");
+
+    cache_pool
+}
+
 async fn get_bucket_conn(
     bucket: String,
     region_name: String,
@@ -43,6 +43,16 @@ async fn main() {
             .init();
     }
 
+    if let Some(scrounch_backend::Command::Reconcile { fix }) = cli.command {
+        let drifts = scrounch_backend::reconcile(cli, fix)
+            .await
+            .expect("Reconciliation couldn't proceed correctly");
+        std::process::exit(match drifts > 0 && !fix {
+            true => 1,
+            false => 0,
+        });
+    }
+
     let app = scrounch_backend::app(cli.clone())
         .await
         .layer(TraceLayer::new_for_http());
@@ -1,7 +1,7 @@
 //! This module defines the `AppState` struct, which holds shared state for the `scrounch_backend` application.
 
 use crate::cli::Arguments;
-use extractor::utils::{FrontendUrl, SmaParams};
+use extractor::utils::{FrontendUrl, OrderCancelGracePeriod, SmaParams};
 use sea_orm::DatabaseConnection;
 
 /// Global application state.
@@ -15,6 +15,10 @@ pub struct AppState {
     pub cache_pool: Option<fred::clients::RedisPool>,
 
     pub s3_storage: entity::s3::S3FileStorage,
+
+    pub payment: Option<service::payment::PaymentGateway>,
+
+    pub stock_notifier: Option<service::notifier::StockNotifierGateway>,
 }
 
 impl axum::extract::FromRef<AppState> for FrontendUrl {
@@ -23,6 +27,12 @@ impl axum::extract::FromRef<AppState> for FrontendUrl {
     }
 }
 
+impl axum::extract::FromRef<AppState> for OrderCancelGracePeriod {
+    fn from_ref(state: &AppState) -> Self {
+        Self(state.arguments.order_cancel_grace_period)
+    }
+}
+
 impl axum::extract::FromRef<AppState> for SmaParams {
     fn from_ref(state: &AppState) -> Self {
         Self {
@@ -59,6 +69,14 @@ impl axum::extract::FromRef<AppState> for service::Connection {
             db_connection: state.db_pool.clone(),
             #[cfg(feature = "cache")]
             cache_connection: state.cache_pool.clone(),
+            stock_notifier: state.stock_notifier.clone(),
         }
     }
 }
+
+/// Allows Axum to extract the configured payment provider from `AppState`.
+impl axum::extract::FromRef<AppState> for Option<service::payment::PaymentGateway> {
+    fn from_ref(state: &AppState) -> Self {
+        state.payment.clone()
+    }
+}
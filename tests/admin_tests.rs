@@ -0,0 +1,121 @@
+mod utils;
+
+use axum::http::StatusCode;
+use serde_json::json;
+use utils::{containers::keycloak::User, create_basic_session, create_realm_session};
+
+use crate::utils::containers::keycloak::{Client, Realm};
+
+#[test_log::test(tokio::test)]
+async fn admin_reconcile_test() {
+    let realm = Realm {
+        name: "admin_reconcile_test".to_string(),
+        clients: vec![Client::default()],
+        users: vec![
+            User {
+                username: "admin".to_string(),
+                email: "admin@example.com".to_string(),
+                ..Default::default()
+            },
+            User {
+                username: "user_1".to_string(),
+                email: "user_1@example.com".to_string(),
+                ..Default::default()
+            },
+        ],
+    };
+
+    let (mut server, ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    // Only an admin can reconcile the balances
+    let response = server.get("/admin/reconcile").await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    let response = server
+        .get("/admin/reconcile")
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    let response = server
+        .post("/admin/reconcile")
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    let response = server
+        .get("/admin/reconcile")
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json(&json!({ "checked": 2, "drifts": [], "fixed": false }));
+
+    // Every flow updating a balance goes through the ledger
+    let response = server
+        .post("/refill")
+        .json(&json!({
+            "price": 5.0,
+            "price_currency": "euro",
+            "credit": 5.0,
+            "credit_currency": "epicoin",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let refill_id = response.text();
+
+    let response = server
+        .post(&format!("/refill/{refill_id}/purchase"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let json: serde_json::Value = response.json();
+    let transaction_id = json.get("transaction_id").unwrap().as_str().unwrap();
+
+    let response = server
+        .get(&format!("/payment/fake/{transaction_id}"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::SEE_OTHER);
+
+    let response = server
+        .post(&format!("/user/{}/balance", ids[1]))
+        .json(&json!({ "delta": 10.0, "currency": "epicoin", "reason": "Gift" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Bug Magnet",
+            "sell_price": 2.50,
+            "sell_price_currency": "epicoin",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let product_id = response.text();
+
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [{ "product": product_id, "quantity": 2 }] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .get("/admin/reconcile")
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json(&json!({ "checked": 2, "drifts": [], "fixed": false }));
+
+    let response = server
+        .post("/admin/reconcile")
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json(&json!({ "checked": 2, "drifts": [], "fixed": true }));
+}
@@ -22,7 +22,6 @@ async fn basic_login_oidc() {
     let basic_client = Client {
         client_id: "scrouch-backend-example-basic".to_string(),
         client_secret: Some("123456".to_string()),
-        ..Default::default()
     };
 
     let realm_name = "test";
@@ -53,19 +52,20 @@ async fn basic_login_oidc() {
     let minio_user = "minioadmin";
     let minio_pass = "minioadmin";
 
-    let mut arguments = scrounch_backend::Arguments::default();
-    arguments.openid_issuer = issuer.clone();
-    arguments.openid_client_id = basic_client.client_id;
-    arguments.openid_client_secret = basic_client.client_secret;
-    arguments.openid_token_duration = std::time::Duration::from_secs(360);
-    arguments.backend_url = "http://localhost:3000".to_string();
-    arguments.frontend_url = "http://localhost:5173".to_string();
-    arguments.database_url = db_url.to_string();
-
-    arguments.aws_access_key_id = minio_user.to_string();
-    arguments.aws_secret_access_key = minio_pass.to_string();
-    arguments.aws_endpoint_url = minio_url.to_string();
-    arguments.aws_s3_bucket = "miniobucket".to_string();
+    let arguments = scrounch_backend::Arguments {
+        openid_issuer: issuer.clone(),
+        openid_client_id: basic_client.client_id,
+        openid_client_secret: basic_client.client_secret,
+        openid_token_duration: std::time::Duration::from_secs(360),
+        backend_url: "http://localhost:3000".to_string(),
+        frontend_url: "http://localhost:5173".to_string(),
+        database_url: db_url.to_string(),
+        aws_access_key_id: minio_user.to_string(),
+        aws_secret_access_key: minio_pass.to_string(),
+        aws_endpoint_url: minio_url.to_string(),
+        aws_s3_bucket: "miniobucket".to_string(),
+        ..Default::default()
+    };
 
     let app = app(arguments).await;
 
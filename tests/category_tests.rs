@@ -0,0 +1,216 @@
+mod utils;
+
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+use utils::{assert_error_kind, create_basic_session, create_realm_session};
+
+use crate::utils::containers::keycloak::Realm;
+
+#[test_log::test(tokio::test)]
+async fn category_hierarchy() {
+    let realm = Realm::default();
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    // Only an admin can manage the categories
+    let response = server
+        .post("/category")
+        .json(&json!({ "name": "Drinks" }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_forbidden();
+
+    let response = server
+        .post("/category")
+        .json(&json!({ "name": "" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "NameCannotBeEmpty");
+
+    let response = server
+        .post("/category")
+        .json(&json!({ "name": "Drinks", "parent": uuid::Uuid::new_v4() }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "ParentDoesntExist");
+
+    // Drinks > Sodas > Colas, and Snacks aside
+    let response = server
+        .post("/category")
+        .json(&json!({ "name": "Drinks", "code": "DRINK" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let drinks_id = response.text();
+
+    let response = server
+        .post("/category")
+        .json(&json!({ "name": "Sodas", "parent": drinks_id }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let sodas_id = response.text();
+
+    let response = server
+        .post("/category")
+        .json(&json!({ "name": "Colas", "parent": sodas_id }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let colas_id = response.text();
+
+    let response = server
+        .post("/category")
+        .json(&json!({ "name": "Snacks" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let snacks_id = response.text();
+
+    let response = server.get(&format!("/category/{sodas_id}")).await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "name": "Sodas",
+        "parent": drinks_id,
+    }));
+
+    let response = server
+        .get(&format!("/category?parent_id_eq={drinks_id}"))
+        .await;
+    response.assert_status_ok();
+    let json: Value = response.json();
+    assert_eq!(json["categories"].as_array().unwrap().len(), 1);
+    assert_eq!(json["categories"][0]["id"], sodas_id.as_str());
+
+    // A category can't be nested inside itself, nor inside one of its subcategories
+    let response = server
+        .put(&format!("/category/{drinks_id}"))
+        .json(&json!({ "parent": drinks_id }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "ParentCreatesCycle");
+
+    let response = server
+        .put(&format!("/category/{drinks_id}"))
+        .json(&json!({ "parent": colas_id }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "ParentCreatesCycle");
+
+    // Products are listed with the products of the subcategories
+    let mut products = Vec::new();
+    for (name, category) in [
+        ("Water", Some(drinks_id.as_str())),
+        ("Cola", Some(colas_id.as_str())),
+        ("Chips", Some(snacks_id.as_str())),
+        ("Napkin", None),
+    ] {
+        let response = server
+            .post("/product")
+            .json(&json!({
+                "name": name,
+                "sell_price": 1.0,
+                "sell_price_currency": "euro",
+                "category": category,
+            }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        products.push(response.text());
+    }
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Lost",
+            "sell_price": 1.0,
+            "sell_price_currency": "euro",
+            "category": uuid::Uuid::new_v4(),
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "CategoryDoesntExist");
+
+    let response = server.get(&format!("/product/{}", products[1])).await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({ "category": colas_id }));
+
+    let list_names = |json: Value| {
+        let mut names = json["products"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|product| product["name"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    };
+
+    let response = server
+        .get(&format!("/product?category_id_eq={drinks_id}"))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(list_names(response.json()), ["Cola", "Water"]);
+
+    let response = server
+        .get(&format!("/product?category_id_eq={sodas_id}"))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(list_names(response.json()), ["Cola"]);
+
+    let response = server
+        .get(&format!(
+            "/product?category_id_eq={sodas_id}&category_id_eq={snacks_id}"
+        ))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(list_names(response.json()), ["Chips", "Cola"]);
+
+    // Moving Sodas under Snacks moves its products with it
+    let response = server
+        .put(&format!("/category/{sodas_id}"))
+        .json(&json!({ "parent": snacks_id }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get(&format!("/product?category_id_eq={snacks_id}"))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(list_names(response.json()), ["Chips", "Cola"]);
+
+    let response = server
+        .put(&format!("/product/{}", products[2]))
+        .json(&json!({ "category": drinks_id }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get(&format!("/product?category_id_eq={snacks_id}"))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(list_names(response.json()), ["Cola"]);
+
+    // A category containing subcategories can't be deleted, its products are kept
+    let response = server
+        .delete(&format!("/category/{sodas_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "CategoryHasChildren");
+
+    let response = server
+        .delete(&format!("/category/{colas_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server.get(&format!("/category/{colas_id}")).await;
+    response.assert_status_not_found();
+
+    let response = server.get(&format!("/product/{}", products[1])).await;
+    response.assert_status_ok();
+    let json: Value = response.json();
+    assert!(json.get("category").is_none());
+}
@@ -0,0 +1,148 @@
+mod utils;
+
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+use utils::{
+    assert_error_kind, containers::keycloak::User, create_basic_session, create_realm_session,
+};
+
+use crate::utils::containers::keycloak::{Client, Realm};
+
+#[test_log::test(tokio::test)]
+async fn currency_test_1() {
+    let realm = Realm {
+        name: "currency_test".to_string(),
+        clients: vec![Client::default()],
+        users: vec![
+            User {
+                username: "admin".to_string(),
+                email: "admin@example.com".to_string(),
+                ..Default::default()
+            },
+            User {
+                username: "user_1".to_string(),
+                email: "user_1@example.com".to_string(),
+                ..Default::default()
+            },
+        ],
+    };
+
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    // The Euro and the Epicoin are registered by the migrations
+    let response = server.get("/currency").await;
+    response.assert_status_ok();
+    let json: Value = response.json();
+    assert_eq!(json["currencies"].as_array().unwrap().len(), 2);
+
+    let response = server.get("/currency/epicoin").await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "code": "epicoin",
+        "decimal_places": 2,
+        "is_internal": true,
+    }));
+
+    let usd = json!({ "code": "usd", "symbol": "$" });
+
+    // Only an admin can register a currency
+    let response = server
+        .post("/currency")
+        .json(&usd)
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    let response = server
+        .post("/currency")
+        .json(&json!({ "code": "US Dollar", "symbol": "$" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "CodeIsInvalid");
+
+    let response = server
+        .post("/currency")
+        .json(&json!({ "code": "usd", "symbol": "$", "decimal_places": 3 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "DecimalPlacesCannotBeAbove");
+
+    let response = server
+        .post("/currency")
+        .json(&usd)
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    assert_eq!(response.text(), "usd");
+
+    let response = server
+        .post("/currency")
+        .json(&usd)
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "CurrencyAlreadyExist");
+
+    let response = server
+        .put("/currency/usd")
+        .json(&json!({ "symbol": "US$" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server.get("/currency/usd").await;
+    response.assert_json_contains(&json!({ "code": "usd", "symbol": "US$" }));
+
+    // Currencies are checked against the registry
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Yen Magnet",
+            "sell_price": 100.0,
+            "sell_price_currency": "yen",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "CurrencyDoesntExist");
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Dollar Magnet",
+            "sell_price": 1.0,
+            "sell_price_currency": "usd",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let product_id = response.text();
+
+    // A currency still in use cannot be deleted
+    let response = server
+        .delete("/currency/usd")
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "CurrencyIsInUse");
+
+    let response = server
+        .put(&format!("/product/{product_id}"))
+        .json(&json!({ "sell_price_currency": "euro" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .delete("/currency/usd")
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    let response = server
+        .delete("/currency/usd")
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server.get("/currency/usd").await;
+    response.assert_status_not_found();
+}
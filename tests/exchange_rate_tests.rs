@@ -0,0 +1,166 @@
+mod utils;
+
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+use utils::{
+    assert_error_kind, containers::keycloak::User, create_basic_session, create_realm_session,
+};
+
+use crate::utils::containers::keycloak::{Client, Realm};
+
+#[test_log::test(tokio::test)]
+async fn exchange_rate_test_1() {
+    let realm = Realm {
+        name: "exchange_rate_test".to_string(),
+        clients: vec![Client::default()],
+        users: vec![
+            User {
+                username: "admin".to_string(),
+                email: "admin@example.com".to_string(),
+                ..Default::default()
+            },
+            User {
+                username: "user_1".to_string(),
+                email: "user_1@example.com".to_string(),
+                ..Default::default()
+            },
+        ],
+    };
+
+    let (mut server, ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let rate = json!({ "from_currency": "euro", "to_currency": "epicoin", "rate": 1.0 });
+
+    // Only an admin can add a rate
+    let response = server.post("/exchange_rate").json(&rate).await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    let response = server
+        .post("/exchange_rate")
+        .json(&rate)
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    let response = server
+        .post("/exchange_rate")
+        .json(&json!({ "from_currency": "euro", "to_currency": "euro", "rate": 1.0 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "CurrenciesCannotBeTheSame");
+
+    let response = server
+        .post("/exchange_rate")
+        .json(&json!({ "from_currency": "euro", "to_currency": "epicoin", "rate": 0.0 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "RateCannotBeNullOrNegative");
+
+    let response = server
+        .post("/exchange_rate")
+        .json(&rate)
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let rate_id = response.text();
+
+    let response = server.get(&format!("/exchange_rate/{rate_id}")).await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "id": rate_id,
+        "from_currency": "euro",
+        "to_currency": "epicoin",
+        "rate": 1.0,
+    }));
+
+    let response = server
+        .put(&format!("/exchange_rate/{rate_id}"))
+        .json(&json!({ "rate": 1.333 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    // A rate which only applies tomorrow is listed but not used yet
+    let response = server
+        .post("/exchange_rate")
+        .json(&json!({
+            "from_currency": "euro",
+            "to_currency": "epicoin",
+            "rate": 100.0,
+            "effective_from": chrono::Utc::now() + chrono::Duration::days(1),
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server.get("/exchange_rate").await;
+    response.assert_status_ok();
+    let json: Value = response.json();
+    assert_eq!(json["exchange_rates"].as_array().unwrap().len(), 2);
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Euro Magnet",
+            "sell_price": 1.00,
+            "sell_price_currency": "euro",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let euro_product_id = response.text();
+
+    let response = server
+        .post(&format!("/user/{}/balance", ids[1]))
+        .json(&json!({ "delta": 1.33, "currency": "epicoin", "reason": "Gift" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    // 1.00 euro is 1.333 epicoin, rounded up to 1.34
+    let order = json!({ "products": [{ "product": euro_product_id, "quantity": 1 }] });
+    let response = server
+        .post("/order")
+        .json(&order)
+        .add_cookie(cookies[1].clone())
+        .await;
+    assert_error_kind(response, "InsufficientBalance");
+
+    let response = server
+        .post(&format!("/user/{}/balance", ids[1]))
+        .json(&json!({ "delta": 0.01, "currency": "epicoin", "reason": "Gift" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .post("/order")
+        .json(&order)
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    // Without a rate, the product cannot be paid from an epicoin balance anymore
+    let response = server
+        .delete(&format!("/exchange_rate/{rate_id}"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    let response = server
+        .delete(&format!("/exchange_rate/{rate_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server.get(&format!("/exchange_rate/{rate_id}")).await;
+    response.assert_status_not_found();
+
+    let response = server
+        .post("/order")
+        .json(&order)
+        .add_cookie(cookies[1].clone())
+        .await;
+    assert_error_kind(response, "ProductCurrencyDoesntMatch");
+}
@@ -1,8 +1,10 @@
 mod utils;
 
 use axum::http::StatusCode;
-use serde_json::json;
-use utils::{containers::keycloak::User, create_basic_session, create_realm_session};
+use serde_json::{Value, json};
+use utils::{
+    assert_error_kind, containers::keycloak::User, create_basic_session, create_realm_session,
+};
 
 use crate::utils::containers::keycloak::{Client, Realm};
 
@@ -120,3 +122,210 @@ async fn location_test_1() {
         "locations": []
     }));
 }
+
+#[test_log::test(tokio::test)]
+async fn location_warehouse_test() {
+    let realm = Realm {
+        name: "location_warehouse_test".to_string(),
+        clients: vec![Client::default()],
+        users: vec![
+            User {
+                username: "admin".to_string(),
+                email: "admin@example.com".to_string(),
+                ..Default::default()
+            },
+            User {
+                username: "user_1".to_string(),
+                email: "user_1@example.com".to_string(),
+                ..Default::default()
+            },
+        ],
+    };
+
+    let (mut server, ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let response = server
+        .post("/location")
+        .json(&json!({ "name": "Dispenser 1", "category": "dispenser" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let location_id = response.text();
+
+    let mut warehouse_ids = Vec::new();
+    let mut product_ids = Vec::new();
+    for (warehouse, product) in [("Stock room", "Coffee"), ("Basement", "Tea")] {
+        let response = server
+            .post("/warehouse")
+            .json(&json!({ "name": warehouse }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        let warehouse_id = response.text();
+
+        let response = server
+            .post("/product")
+            .json(&json!({
+                "name": product,
+                "sell_price": 1.00,
+                "sell_price_currency": "epicoin",
+            }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        let product_id = response.text();
+
+        let response = server
+            .post(&format!("/warehouse/{warehouse_id}/product/{product_id}"))
+            .json(&json!({ "quantity": 5 }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+
+        warehouse_ids.push(warehouse_id);
+        product_ids.push(product_id);
+    }
+    let ([stock_room_id, basement_id], [coffee_id, tea_id]) =
+        (&warehouse_ids[..], &product_ids[..])
+    else {
+        unreachable!()
+    };
+
+    // Without any warehouse, the location is served by all of them
+    let response = server
+        .get(&format!("/location/{location_id}/menu"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_ok();
+    let json: Value = response.json();
+    assert_eq!(json["products"].as_array().unwrap().len(), 2);
+
+    let response = server
+        .post(&format!(
+            "/location/{location_id}/warehouse/{stock_room_id}"
+        ))
+        .json(&json!({ "priority": 1 }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_forbidden();
+
+    let response = server
+        .post(&format!(
+            "/location/{location_id}/warehouse/{}",
+            uuid::Uuid::new_v4()
+        ))
+        .json(&json!({ "priority": 1 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "WarehouseDoesntExist");
+
+    let response = server
+        .post(&format!(
+            "/location/{location_id}/warehouse/{stock_room_id}"
+        ))
+        .json(&json!({ "priority": 1 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .post(&format!(
+            "/location/{location_id}/warehouse/{stock_room_id}"
+        ))
+        .json(&json!({ "priority": 2 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "LocationWarehouseAlreadyExist");
+
+    let response = server
+        .post(&format!("/location/{location_id}/warehouse/{basement_id}"))
+        .json(&json!({ "priority": 1 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "PriorityAlreadyUsed");
+
+    let response = server
+        .get(&format!("/location/{location_id}/warehouse"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "warehouses": [{ "warehouse": stock_room_id, "name": "Stock room", "priority": 1 }]
+    }));
+
+    // Only the stock of the linked warehouse is on the menu
+    let response = server
+        .get(&format!("/location/{location_id}/menu"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "location": location_id,
+        "products": [{ "id": coffee_id, "available_quantity": "5.00" }]
+    }));
+    let json: Value = response.json();
+    assert_eq!(json["products"].as_array().unwrap().len(), 1);
+
+    let response = server
+        .post(&format!("/user/{}/balance", ids[1]))
+        .json(&json!({ "delta": 10.0, "currency": "epicoin", "reason": "Gift" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    // The tea is stocked, but not in a warehouse serving the location
+    let response = server
+        .post("/order")
+        .json(&json!({
+            "products": [{ "product": tea_id, "quantity": 1 }],
+            "location": location_id,
+        }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    assert_error_kind(response, "InsufficientStock");
+
+    let response = server
+        .post("/order")
+        .json(&json!({
+            "products": [{ "product": coffee_id, "quantity": 2 }],
+            "location": location_id,
+        }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .get(&format!("/warehouse/{stock_room_id}/product/{coffee_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({ "quantity": "3.00" }));
+
+    // Once unlinked, the location is served by every warehouse again
+    let response = server
+        .delete(&format!(
+            "/location/{location_id}/warehouse/{stock_room_id}"
+        ))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .delete(&format!(
+            "/location/{location_id}/warehouse/{stock_room_id}"
+        ))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_not_found();
+
+    let response = server
+        .post("/order")
+        .json(&json!({
+            "products": [{ "product": tea_id, "quantity": 1 }],
+            "location": location_id,
+        }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+}
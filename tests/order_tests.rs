@@ -0,0 +1,688 @@
+mod utils;
+
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+use utils::{
+    assert_error_kind, containers::keycloak::User, create_basic_session, create_realm_session,
+};
+
+use crate::utils::containers::keycloak::{Client, Realm};
+
+#[test_log::test(tokio::test)]
+async fn order_test_1() {
+    let realm = Realm {
+        name: "order_test".to_string(),
+        clients: vec![Client::default()],
+        users: vec![
+            User {
+                username: "admin".to_string(),
+                email: "admin@example.com".to_string(),
+                ..Default::default()
+            },
+            User {
+                username: "user_1".to_string(),
+                email: "user_1@example.com".to_string(),
+                ..Default::default()
+            },
+        ],
+    };
+
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Bug Magnet",
+            "sell_price": 2.50,
+            "sell_price_currency": "epicoin",
+            "max_quantity_per_command": 2,
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let product_id = response.text();
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Euro Magnet",
+            "sell_price": 1.00,
+            "sell_price_currency": "euro",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let euro_product_id = response.text();
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Ingredient",
+            "sell_price": 1.00,
+            "sell_price_currency": "epicoin",
+            "purchasable": false,
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let ingredient_id = response.text();
+
+    // POST /order without being logged in
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [{ "product": product_id, "quantity": 1 }] }))
+        .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    assert_error_kind(response, "OrderCannotBeEmpty");
+
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [{ "product": product_id, "quantity": 0 }] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    assert_error_kind(response, "QuantityCannotBeNull");
+
+    // Lines of the same product are merged before checking the maximum
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [
+            { "product": product_id, "quantity": 2 },
+            { "product": product_id, "quantity": 1 },
+        ] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    assert_error_kind(response, "QuantityCannotBeAboveMaximum");
+
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [{ "product": uuid::Uuid::new_v4(), "quantity": 1 }] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    assert_error_kind(response, "ProductDoesntExist");
+
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [{ "product": ingredient_id, "quantity": 1 }] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    assert_error_kind(response, "ProductIsNotPurchasable");
+
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [{ "product": euro_product_id, "quantity": 1 }] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    assert_error_kind(response, "ProductCurrencyDoesntMatch");
+
+    // A new user has an empty balance
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [{ "product": product_id, "quantity": 1 }] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    assert_error_kind(response, "InsufficientBalance");
+
+    // Nothing was recorded in the ledger
+    let response = server
+        .get("/transaction")
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json(&json!({
+        "current_page": 0,
+        "total_page": 1,
+        "transactions": []
+    }));
+}
+
+#[test_log::test(tokio::test)]
+async fn order_cancel_test() {
+    let realm = Realm {
+        name: "order_cancel_test".to_string(),
+        clients: vec![Client::default()],
+        users: vec![
+            User {
+                username: "admin".to_string(),
+                email: "admin@example.com".to_string(),
+                ..Default::default()
+            },
+            User {
+                username: "user_1".to_string(),
+                email: "user_1@example.com".to_string(),
+                ..Default::default()
+            },
+        ],
+    };
+
+    let (mut server, ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Bug Magnet",
+            "sell_price": 2.50,
+            "sell_price_currency": "epicoin",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let product_id = response.text();
+
+    let response = server
+        .post(&format!("/user/{}/balance", ids[1]))
+        .json(&json!({ "delta": 5.0, "currency": "epicoin", "reason": "Gift" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let balance_edit_id = response.text();
+
+    let order = json!({ "products": [{ "product": product_id, "quantity": 2 }] });
+    let response = server
+        .post("/order")
+        .json(&order)
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let order_id = response.text();
+
+    let response = server
+        .post("/order")
+        .json(&order)
+        .add_cookie(cookies[1].clone())
+        .await;
+    assert_error_kind(response, "InsufficientBalance");
+
+    // POST /order/{id}/cancel without being logged in
+    let response = server
+        .post(&format!("/order/{order_id}/cancel"))
+        .json(&json!({ "reason": "Changed my mind" }))
+        .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    let response = server
+        .post(&format!("/order/{}/cancel", uuid::Uuid::new_v4()))
+        .json(&json!({ "reason": "Changed my mind" }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_not_found();
+
+    let response = server
+        .post(&format!("/order/{order_id}/cancel"))
+        .json(&json!({ "reason": " " }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    assert_error_kind(response, "ReasonCannotBeEmpty");
+
+    let response = server
+        .post(&format!("/order/{balance_edit_id}/cancel"))
+        .json(&json!({ "reason": "Changed my mind" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "TransactionIsNotAnOrder");
+
+    // The buyer cancels its order during the grace period
+    let response = server
+        .post(&format!("/order/{order_id}/cancel"))
+        .json(&json!({ "reason": "Changed my mind" }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get(&format!("/transaction/{order_id}"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_json_contains(&json!({ "status": "canceled" }));
+
+    // An order is only refunded once
+    let response = server
+        .post(&format!("/order/{order_id}/cancel"))
+        .json(&json!({ "reason": "Changed my mind" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "OrderIsNotCompleted");
+
+    // The balance was credited back, so it can pay again
+    let response = server
+        .post("/order")
+        .json(&order)
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let order_id = response.text();
+
+    // An admin can cancel any order
+    let response = server
+        .post(&format!("/order/{order_id}/cancel"))
+        .json(&json!({ "reason": "Out of stock" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get("/admin/reconcile")
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json(&json!({ "checked": 2, "drifts": [], "fixed": false }));
+}
+
+#[test_log::test(tokio::test)]
+async fn order_fulfilment_test() {
+    let realm = Realm {
+        name: "order_fulfilment_test".to_string(),
+        clients: vec![Client::default()],
+        users: vec![
+            User {
+                username: "admin".to_string(),
+                email: "admin@example.com".to_string(),
+                ..Default::default()
+            },
+            User {
+                username: "user_1".to_string(),
+                email: "user_1@example.com".to_string(),
+                ..Default::default()
+            },
+        ],
+    };
+
+    let (mut server, ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let response = server
+        .post("/location")
+        .json(&json!({ "name": "Salle 401", "category": "room" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let location_id = response.text();
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Bug Magnet",
+            "sell_price": 2.50,
+            "sell_price_currency": "epicoin",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let product_id = response.text();
+
+    let response = server
+        .post(&format!("/user/{}/balance", ids[1]))
+        .json(&json!({ "delta": 10.0, "currency": "epicoin", "reason": "Gift" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .post("/order")
+        .json(&json!({
+            "products": [{ "product": product_id, "quantity": 1 }],
+            "location": uuid::Uuid::new_v4(),
+        }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    assert_error_kind(response, "LocationDoesntExist");
+
+    // An order without location isn't delivered
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [{ "product": product_id, "quantity": 1 }] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let counter_order_id = response.text();
+
+    let response = server
+        .post("/order")
+        .json(&json!({
+            "products": [{ "product": product_id, "quantity": 2 }],
+            "location": location_id,
+        }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let order_id = response.text();
+
+    // Only an admin can see the queue of a location
+    let response = server
+        .get(&format!("/location/{location_id}/orders"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    let response = server
+        .get(&format!("/location/{}/orders", uuid::Uuid::new_v4()))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_not_found();
+
+    let response = server
+        .get(&format!("/location/{location_id}/orders"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "current_page": 0,
+        "total_page": 1,
+        "orders": [{
+            "id": order_id,
+            "user_id": ids[1],
+            "location_id": location_id,
+            "status": "queued",
+            "products": [{ "product_id": product_id, "name": "Bug Magnet", "quantity": 2 }],
+        }],
+    }));
+
+    let response = server
+        .put(&format!("/order/{counter_order_id}/fulfilment"))
+        .json(&json!({ "status": "preparing" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "OrderHasNoLocation");
+
+    let response = server
+        .put(&format!("/order/{order_id}/fulfilment"))
+        .json(&json!({ "status": "preparing" }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    let response = server
+        .put(&format!("/order/{order_id}/fulfilment"))
+        .json(&json!({ "status": "ready" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .put(&format!("/order/{order_id}/fulfilment"))
+        .json(&json!({ "status": "preparing" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "StatusCannotGoBack");
+
+    let response = server
+        .put(&format!("/order/{order_id}/fulfilment"))
+        .json(&json!({ "status": "delivered" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    // A delivered order leaves the queue
+    let response = server
+        .get(&format!("/location/{location_id}/orders"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json(&json!({
+        "current_page": 0,
+        "total_page": 1,
+        "orders": [],
+    }));
+}
+
+#[test_log::test(tokio::test)]
+async fn order_stock_test() {
+    let realm = Realm {
+        name: "order_stock_test".to_string(),
+        clients: vec![Client::default()],
+        users: vec![
+            User {
+                username: "admin".to_string(),
+                email: "admin@example.com".to_string(),
+                ..Default::default()
+            },
+            User {
+                username: "user_1".to_string(),
+                email: "user_1@example.com".to_string(),
+                ..Default::default()
+            },
+        ],
+    };
+
+    let (mut server, ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Bug Magnet",
+            "sell_price": 1.00,
+            "sell_price_currency": "epicoin",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let product_id = response.text();
+
+    let response = server
+        .post("/warehouse")
+        .json(&json!({ "name": "Warehouse 1" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let warehouse_id = response.text();
+
+    let response = server
+        .post(&format!("/warehouse/{warehouse_id}/product/{product_id}"))
+        .json(&json!({ "quantity": 3 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .post(&format!("/user/{}/balance", ids[1]))
+        .json(&json!({ "delta": 10.0, "currency": "epicoin", "reason": "Gift" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let stock = |response: axum_test::TestResponse| {
+        response.assert_status_ok();
+        let json: Value = response.json();
+        json.get("quantity").unwrap().clone()
+    };
+
+    let order = json!({ "products": [{ "product": product_id, "quantity": 2 }] });
+    let response = server
+        .post("/order")
+        .json(&order)
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let order_id = response.text();
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/product/{product_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_eq!(stock(response), json!("1.00"));
+
+    // The balance still covers it but the warehouse doesn't
+    let response = server
+        .post("/order")
+        .json(&order)
+        .add_cookie(cookies[1].clone())
+        .await;
+    assert_error_kind(response, "InsufficientStock");
+
+    // The rejected order didn't debit the balance
+    let response = server
+        .get("/admin/reconcile")
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json(&json!({ "checked": 2, "drifts": [], "fixed": false }));
+
+    // Canceling the order puts its products back in the warehouse
+    let response = server
+        .post(&format!("/order/{order_id}/cancel"))
+        .json(&json!({ "reason": "Changed my mind" }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/product/{product_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_eq!(stock(response), json!("3.00"));
+
+    // An order made from a recipe puts back what it used, even once the recipe changed
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Magnet Cake",
+            "sell_price": 1.00,
+            "sell_price_currency": "epicoin",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let cake_id = response.text();
+
+    let response = server
+        .post("/recipe")
+        .json(&json!({
+            "name": "Recipe for a Magnet Cake",
+            "product": cake_id,
+            "ingredients": [{ "product": product_id, "quantity": 1 }],
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let recipe_id = response.text();
+
+    let response = server
+        .post(&format!("/warehouse/{warehouse_id}/recipe/{recipe_id}"))
+        .json(&json!({ "priority": 10 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [{ "product": cake_id, "quantity": 1 }] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let order_id = response.text();
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/product/{product_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_eq!(stock(response), json!("2.00"));
+
+    let response = server
+        .put(&format!("/recipe/{recipe_id}"))
+        .json(&json!({ "ingredients": [{ "product": product_id, "quantity": 3 }] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .post(&format!("/order/{order_id}/cancel"))
+        .json(&json!({ "reason": "Changed my mind" }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/product/{product_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_eq!(stock(response), json!("3.00"));
+
+    // The batches an order was taken from are put back with their expiry date
+    let response = server
+        .post(&format!(
+            "/warehouse/{warehouse_id}/product/{product_id}/batch"
+        ))
+        .json(
+            &json!({ "quantity": 2, "expires_at": chrono::Utc::now() + chrono::Duration::days(3) }),
+        )
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let batches = |response: axum_test::TestResponse| {
+        response.assert_status_ok();
+        let json: Value = response.json();
+        json.get("batches")
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|batch| (batch["quantity"].clone(), batch["expires_at"].clone()))
+            .collect::<Vec<_>>()
+    };
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/expiring"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    let expiring = batches(response);
+    assert_eq!(expiring.len(), 1);
+
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [{ "product": product_id, "quantity": 4 }] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let order_id = response.text();
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/expiring"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert!(batches(response).is_empty());
+
+    let response = server
+        .post(&format!("/order/{order_id}/cancel"))
+        .json(&json!({ "reason": "Changed my mind" }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/expiring"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_eq!(batches(response), expiring);
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/product/{product_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_eq!(stock(response), json!("5.00"));
+
+    // A recipe without any enabled ingredient can't make its product
+    let response = server
+        .put(&format!("/recipe/{recipe_id}"))
+        .json(&json!({
+            "ingredients": [{ "product": product_id, "quantity": 1, "disabled": true }],
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [{ "product": cake_id, "quantity": 1 }] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    assert_error_kind(response, "InsufficientStock");
+}
@@ -0,0 +1,230 @@
+mod utils;
+
+use std::collections::HashMap;
+
+use axum::{Form, Json, Router, http::StatusCode, routing::post};
+use hmac::{Hmac, Mac};
+use serde_json::{Value, json};
+use sha2::Sha256;
+use utils::{
+    containers::keycloak::User, create_basic_session_with_arguments, create_realm_session,
+};
+
+use crate::utils::containers::keycloak::{Client, Realm};
+
+const WEBHOOK_SECRET: &str = "whsec_test";
+
+/// Starts a server answering like the checkout sessions API of Stripe.
+async fn start_stripe_mock() -> String {
+    let router = Router::new().route(
+        "/v1/checkout/sessions",
+        post(|Form(form): Form<HashMap<String, String>>| async move {
+            let transaction_id = form.get("client_reference_id").unwrap();
+            assert_eq!(form.get("mode").unwrap(), "payment");
+            assert_eq!(
+                form.get("line_items[0][price_data][currency]").unwrap(),
+                "eur"
+            );
+            assert_eq!(
+                form.get("line_items[0][price_data][unit_amount]").unwrap(),
+                "500"
+            );
+            Json(json!({
+                "id": format!("cs_test_{transaction_id}"),
+                "url": format!("https://checkout.example.com/cs_test_{transaction_id}"),
+            }))
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+    format!("http://{address}")
+}
+
+fn sign(payload: &str, secret: &str) -> String {
+    let timestamp = chrono::Utc::now().timestamp();
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(format!("{timestamp}.{payload}").as_bytes());
+    let signature = hex::encode(mac.finalize().into_bytes());
+    format!("t={timestamp},v1={signature}")
+}
+
+fn checkout_event(event_type: &str, transaction_id: &str, payment_status: &str) -> String {
+    json!({
+        "id": "evt_test",
+        "type": event_type,
+        "data": {
+            "object": {
+                "id": format!("cs_test_{transaction_id}"),
+                "client_reference_id": transaction_id,
+                "payment_status": payment_status,
+            }
+        }
+    })
+    .to_string()
+}
+
+#[test_log::test(tokio::test)]
+async fn payment_stripe_test() {
+    let realm = Realm {
+        name: "payment_stripe_test".to_string(),
+        clients: vec![Client::default()],
+        users: vec![
+            User {
+                username: "admin".to_string(),
+                email: "admin@example.com".to_string(),
+                ..Default::default()
+            },
+            User {
+                username: "user_1".to_string(),
+                email: "user_1@example.com".to_string(),
+                ..Default::default()
+            },
+        ],
+    };
+
+    let stripe_url = start_stripe_mock().await;
+    let (mut server, _ids, _nodes) = create_basic_session_with_arguments(realm.clone(), |args| {
+        args.payment_provider = Some(scrounch_backend::PaymentProviderKind::Stripe);
+        args.stripe_api_url = stripe_url;
+        args.stripe_secret_key = Some("sk_test".to_string());
+        args.stripe_webhook_secret = Some(WEBHOOK_SECRET.to_string());
+    })
+    .await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let response = server
+        .post("/refill")
+        .json(&json!({
+            "price": 5.0,
+            "price_currency": "euro",
+            "credit": 5.0,
+            "credit_currency": "epicoin",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let refill_id = response.text();
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Bug Magnet",
+            "sell_price": 3.0,
+            "sell_price_currency": "epicoin",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let product_id = response.text();
+
+    let response = server
+        .post(&format!("/refill/{refill_id}/purchase"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let json: Value = response.json();
+    let transaction_id = json.get("transaction_id").unwrap().as_str().unwrap();
+    response.assert_json(&json!({
+        "transaction_id": transaction_id,
+        "checkout_url": format!("https://checkout.example.com/cs_test_{transaction_id}"),
+    }));
+
+    let payload = checkout_event("checkout.session.completed", transaction_id, "paid");
+
+    // Webhooks must be signed
+    let response = server.post("/webhook/payment").text(payload.clone()).await;
+    response.assert_status_bad_request();
+
+    let response = server
+        .post("/webhook/payment")
+        .text(payload.clone())
+        .add_header("Stripe-Signature", sign(&payload, "whsec_wrong"))
+        .await;
+    response.assert_status_bad_request();
+
+    let response = server
+        .get(&format!("/transaction/{transaction_id}"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_json_contains(&json!({ "status": "pending" }));
+
+    // Events the application doesn't care about are acknowledged
+    let ignored = checkout_event("invoice.paid", transaction_id, "paid");
+    let response = server
+        .post("/webhook/payment")
+        .text(ignored.clone())
+        .add_header("Stripe-Signature", sign(&ignored, WEBHOOK_SECRET))
+        .await;
+    response.assert_status_ok();
+
+    // The same event delivered twice only credits the balance once
+    for _ in 0..2 {
+        let response = server
+            .post("/webhook/payment")
+            .text(payload.clone())
+            .add_header("Stripe-Signature", sign(&payload, WEBHOOK_SECRET))
+            .await;
+        response.assert_status_ok();
+    }
+
+    let response = server
+        .get(&format!("/transaction/{transaction_id}"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_json_contains(&json!({ "status": "completed" }));
+
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [{ "product": product_id, "quantity": 1 }] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [{ "product": product_id, "quantity": 1 }] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_bad_request();
+
+    // An expired checkout session cancels the transaction
+    let response = server
+        .post(&format!("/refill/{refill_id}/purchase"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let json: Value = response.json();
+    let transaction_id = json.get("transaction_id").unwrap().as_str().unwrap();
+
+    let payload = checkout_event("checkout.session.expired", transaction_id, "unpaid");
+    let response = server
+        .post("/webhook/payment")
+        .text(payload.clone())
+        .add_header("Stripe-Signature", sign(&payload, WEBHOOK_SECRET))
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get(&format!("/transaction/{transaction_id}"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_json_contains(&json!({ "status": "canceled" }));
+
+    // A closed transaction cannot be completed anymore
+    let payload = checkout_event("checkout.session.completed", transaction_id, "paid");
+    let response = server
+        .post("/webhook/payment")
+        .text(payload.clone())
+        .add_header("Stripe-Signature", sign(&payload, WEBHOOK_SECRET))
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get(&format!("/transaction/{transaction_id}"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_json_contains(&json!({ "status": "canceled" }));
+}
@@ -72,6 +72,7 @@ async fn product_test_1() {
                 "purchasable": true,
                 "created_at": created_at,
                 "disabled": false,
+                "available_quantity": "0",
             }
         ]
     }));
@@ -90,6 +91,7 @@ async fn product_test_1() {
         "purchasable": true,
         "created_at": created_at,
         "disabled": false,
+        "available_quantity": "0",
     }));
 
     let response = server
@@ -121,6 +123,7 @@ async fn product_test_1() {
         "purchasable": true,
         "created_at": created_at,
         "disabled": false,
+        "available_quantity": "0",
     }));
 
     let response = server
@@ -147,6 +150,7 @@ async fn product_test_1() {
         "purchasable": true,
         "created_at": created_at,
         "disabled": false,
+        "available_quantity": "0",
     }));
 
     let response = server
@@ -0,0 +1,305 @@
+mod utils;
+
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+use utils::{assert_error_kind, create_basic_session, create_realm_session};
+
+use crate::utils::containers::keycloak::Realm;
+
+#[test_log::test(tokio::test)]
+async fn purchase_order_receive() {
+    let realm = Realm::default();
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    // Only an admin can manage the suppliers
+    let response = server
+        .post("/supplier")
+        .json(&json!({ "name": "Metro" }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_forbidden();
+
+    let response = server
+        .post("/supplier")
+        .json(&json!({ "name": "" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "NameCannotBeEmpty");
+
+    let response = server
+        .post("/supplier")
+        .json(&json!({ "name": "Metro", "email": "metro" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "EmailIsInvalid");
+
+    let response = server
+        .post("/supplier")
+        .json(&json!({ "name": "Metro", "email": "orders@metro.example" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let supplier_id = response.text();
+
+    let response = server
+        .post("/supplier")
+        .json(&json!({ "name": "Closed" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let closed_supplier_id = response.text();
+
+    let response = server
+        .delete(&format!("/supplier/{closed_supplier_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get(&format!("/supplier/{closed_supplier_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({ "name": "Closed", "disabled": true }));
+
+    let response = server
+        .post("/warehouse")
+        .json(&json!({ "name": "Warehouse 1" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let warehouse_id = response.text();
+
+    let mut product_ids = Vec::new();
+    for name in ["Product 1", "Product 2", "Product 3"] {
+        let response = server
+            .post("/product")
+            .json(&json!({
+                "name": name,
+                "sell_price": 1.00,
+                "sell_price_currency": "epicoin",
+            }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        product_ids.push(response.text());
+    }
+    let (product_1, product_2, product_3) = (&product_ids[0], &product_ids[1], &product_ids[2]);
+
+    let response = server
+        .post(&format!("/warehouse/{warehouse_id}/product/{product_1}"))
+        .json(&json!({ "quantity": 5 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let purchase_order = |supplier: &str, lines: Value| {
+        json!({
+            "supplier": supplier,
+            "warehouse": warehouse_id,
+            "currency": "euro",
+            "lines": lines,
+        })
+    };
+
+    let response = server
+        .post("/purchase_order")
+        .json(&purchase_order(&supplier_id, json!([])))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "LinesCannotBeEmpty");
+
+    let response = server
+        .post("/purchase_order")
+        .json(&purchase_order(
+            &supplier_id,
+            json!([{ "product": product_1, "quantity": 0, "unit_cost": 1 }]),
+        ))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "QuantityMustBePositive");
+
+    let response = server
+        .post("/purchase_order")
+        .json(&purchase_order(
+            &supplier_id,
+            json!([
+                { "product": product_1, "quantity": 1, "unit_cost": 1 },
+                { "product": product_1, "quantity": 2, "unit_cost": 1 },
+            ]),
+        ))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "ProductCannotBeRepeated");
+
+    let response = server
+        .post("/purchase_order")
+        .json(&purchase_order(
+            &closed_supplier_id,
+            json!([{ "product": product_1, "quantity": 1, "unit_cost": 1 }]),
+        ))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "SupplierIsDisabled");
+
+    let response = server
+        .post("/purchase_order")
+        .json(&purchase_order(
+            &supplier_id,
+            json!([{ "product": uuid::Uuid::new_v4(), "quantity": 1, "unit_cost": 1 }]),
+        ))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "ProductDoesntExist");
+
+    let response = server
+        .post("/purchase_order")
+        .json(&purchase_order(
+            &supplier_id,
+            json!([{ "product": product_1, "quantity": 10, "unit_cost": 0.40 }]),
+        ))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let purchase_order_id = response.text();
+
+    // A draft can't be received
+    let response = server
+        .post(&format!("/purchase_order/{purchase_order_id}/receive"))
+        .json(&json!({ "products": [{ "product": product_1, "quantity": 1 }] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "PurchaseOrderCannotBeReceived");
+
+    // Editing the lines replaces them
+    let response = server
+        .put(&format!("/purchase_order/{purchase_order_id}"))
+        .json(&json!({ "lines": [
+            { "product": product_1, "quantity": 10, "unit_cost": 0.45 },
+            { "product": product_2, "quantity": 6, "unit_cost": 1.20 },
+        ] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .post(&format!("/purchase_order/{purchase_order_id}/send"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .post(&format!("/purchase_order/{purchase_order_id}/send"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "PurchaseOrderIsNotDraft");
+
+    let response = server
+        .put(&format!("/purchase_order/{purchase_order_id}"))
+        .json(&json!({ "currency": "epicoin" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "PurchaseOrderIsNotDraft");
+
+    let response = server
+        .delete(&format!("/purchase_order/{purchase_order_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "PurchaseOrderIsNotDraft");
+
+    let response = server
+        .post(&format!("/purchase_order/{purchase_order_id}/receive"))
+        .json(&json!({ "products": [{ "product": product_3, "quantity": 1 }] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "ProductNotOrdered");
+
+    let response = server
+        .post(&format!("/purchase_order/{purchase_order_id}/receive"))
+        .json(&json!({ "products": [{ "product": product_2, "quantity": 7 }] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "QuantityExceedsOrdered");
+
+    // First delivery, the unit cost becomes the cost price
+    let response = server
+        .post(&format!("/purchase_order/{purchase_order_id}/receive"))
+        .json(&json!({
+            "products": [
+                { "product": product_1, "quantity": 10 },
+                { "product": product_2, "quantity": 4 },
+            ],
+            "update_cost_price": true,
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "status": "partially_received",
+        "lines": [
+            { "product": product_1, "quantity": "10.00", "received_quantity": "10.00" },
+            { "product": product_2, "quantity": "6.00", "received_quantity": "4.00" },
+        ],
+    }));
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/product/{product_1}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({ "quantity": "15.00" }));
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/product/{product_2}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({ "quantity": "4.00" }));
+
+    let response = server
+        .get(&format!("/product/{product_1}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "cost_price": 0.45,
+        "cost_price_currency": "euro",
+    }));
+
+    // Second delivery, without touching the cost price
+    let response = server
+        .post(&format!("/purchase_order/{purchase_order_id}/receive"))
+        .json(&json!({ "products": [{ "product": product_2, "quantity": 2 }] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({ "status": "received" }));
+    let json: Value = response.json();
+    assert!(json.get("received_at").is_some());
+
+    let response = server
+        .get(&format!("/product/{product_2}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({ "cost_price": 1.2 }));
+
+    let response = server
+        .post(&format!("/purchase_order/{purchase_order_id}/receive"))
+        .json(&json!({ "products": [{ "product": product_2, "quantity": 1 }] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "PurchaseOrderCannotBeReceived");
+
+    let response = server
+        .get("/purchase_order?status_eq=received")
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "total_page": 1,
+        "current_page": 0,
+        "purchase_orders": [{ "id": purchase_order_id, "supplier": supplier_id }],
+    }));
+}
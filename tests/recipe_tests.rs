@@ -1,7 +1,7 @@
 mod utils;
 
 use axum::http::StatusCode;
-use serde_json::json;
+use serde_json::{Value, json};
 use utils::{create_basic_session, create_realm_session};
 
 use crate::utils::containers::keycloak::Realm;
@@ -744,3 +744,519 @@ async fn recipe_edit_quantity() {
             }
     ));
 }
+
+#[test_log::test(tokio::test)]
+async fn recipe_cycle() {
+    let realm = Realm::default();
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let expect_cycle = |response: axum_test::TestResponse| {
+        response.assert_status_bad_request();
+        let json: Value = response.json();
+        assert_eq!(json.get("kind").unwrap(), "IngredientCreatesCycle");
+    };
+
+    let mut product_ids = Vec::new();
+    for name in ["Cake", "Dough", "Cream"] {
+        let response = server
+            .post("/product")
+            .json(&json!({
+                "name": name,
+                "sell_price": 1.00,
+                "sell_price_currency": "euro",
+            }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        product_ids.push(response.text());
+    }
+    let (cake_id, dough_id, cream_id) = (&product_ids[0], &product_ids[1], &product_ids[2]);
+
+    // Cake <- Dough <- Cream
+    let response = server
+        .post("/recipe")
+        .json(&json!({
+            "product": cake_id,
+            "ingredients": [{ "product": dough_id, "quantity": 1 }]
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .post("/recipe")
+        .json(&json!({
+            "product": dough_id,
+            "ingredients": [{ "product": cream_id, "quantity": 1 }]
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let dough_recipe_id = response.text();
+
+    // Cream can't be made out of Cake, nor of Dough
+    let response = server
+        .post("/recipe")
+        .json(&json!({
+            "product": cream_id,
+            "ingredients": [{ "product": cake_id, "quantity": 1 }]
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    expect_cycle(response);
+
+    let response = server
+        .post("/recipe")
+        .json(&json!({
+            "product": cream_id,
+            "ingredients": [{ "product": dough_id, "quantity": 1 }]
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    expect_cycle(response);
+
+    // Dough can't be edited to need Cake or itself
+    let response = server
+        .put(&format!("/recipe/{dough_recipe_id}"))
+        .json(&json!({
+            "ingredients": [{ "product": cake_id, "quantity": 1 }]
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    expect_cycle(response);
+
+    let response = server
+        .put(&format!("/recipe/{dough_recipe_id}"))
+        .json(&json!({
+            "ingredients": [{ "product": dough_id, "quantity": 1 }]
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    expect_cycle(response);
+
+    // Once Dough doesn't need Cream anymore, Cream can be made out of Dough
+    let response = server
+        .put(&format!("/recipe/{dough_recipe_id}"))
+        .json(&json!({ "ingredients": [] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .post("/recipe")
+        .json(&json!({
+            "product": cream_id,
+            "ingredients": [{ "product": dough_id, "quantity": 1 }]
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+}
+
+#[test_log::test(tokio::test)]
+async fn recipe_tree() {
+    let realm = Realm::default();
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let mut product_ids = Vec::new();
+    for (name, unit) in [
+        ("Cake", "unit"),
+        ("Dough", "unit"),
+        ("Sugar", "gram"),
+        ("Flour", "gram"),
+        ("Milk", "liter"),
+    ] {
+        let response = server
+            .post("/product")
+            .json(&json!({
+                "name": name,
+                "sell_price": 1.00,
+                "sell_price_currency": "euro",
+                "unit": unit,
+            }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        product_ids.push(response.text());
+    }
+    let [cake_id, dough_id, sugar_id, flour_id, milk_id] = &product_ids[..] else {
+        unreachable!()
+    };
+
+    let response = server
+        .post("/recipe")
+        .json(&json!({
+            "product": dough_id,
+            "ingredients": [
+                { "product": flour_id, "quantity": 150 },
+                { "product": milk_id, "quantity": 0.5 },
+                { "product": sugar_id, "quantity": 10 },
+            ]
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let dough_recipe_id = response.text();
+
+    let response = server
+        .post("/recipe")
+        .json(&json!({
+            "product": cake_id,
+            "ingredients": [
+                { "product": dough_id, "quantity": 2 },
+                { "product": sugar_id, "quantity": 50 },
+            ]
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let cake_recipe_id = response.text();
+
+    let response = server
+        .get(&format!("/recipe/{cake_recipe_id}/tree"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_forbidden();
+
+    let response = server
+        .get(&format!("/recipe/{}/tree", uuid::Uuid::new_v4()))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_not_found();
+
+    let response = server
+        .get(&format!("/recipe/{cake_recipe_id}/tree"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "recipe": cake_recipe_id,
+        "product": cake_id,
+        "units": [
+            { "unit": "gram", "quantity": 370.0 },
+            { "unit": "liter", "quantity": 1.0 },
+        ],
+    }));
+
+    let json: Value = response.json();
+    let dough = json["ingredients"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|node| node["product"] == dough_id.as_str())
+        .unwrap();
+    assert_eq!(dough["recipe"], dough_recipe_id.as_str());
+    assert_eq!(dough["quantity"], 2.0);
+    assert_eq!(dough["ingredients"].as_array().unwrap().len(), 3);
+
+    let raw_products = json["raw_products"].as_array().unwrap();
+    assert_eq!(raw_products.len(), 3);
+    for (product_id, unit, quantity) in [
+        (sugar_id, "gram", 70.0),
+        (flour_id, "gram", 300.0),
+        (milk_id, "liter", 1.0),
+    ] {
+        let raw = raw_products
+            .iter()
+            .find(|raw| raw["product"] == product_id.as_str())
+            .unwrap();
+        assert_eq!(raw["unit"], unit);
+        assert_eq!(raw["quantity"], quantity);
+    }
+}
+
+#[test_log::test(tokio::test)]
+async fn recipe_cost() {
+    let realm = Realm::default();
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Salt",
+            "unit": "gram",
+            "cost_price": -1.00,
+            "cost_price_currency": "euro",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_bad_request();
+
+    let response = server
+        .post("/product")
+        .json(&json!({ "name": "Salt", "unit": "gram", "cost_price": 1.00 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_bad_request();
+    let json: Value = response.json();
+    assert_eq!(json.get("kind").unwrap(), "CostPriceRequiresCurrency");
+
+    let response = server
+        .post("/product")
+        .json(&json!({ "name": "Salt", "unit": "gram", "cost_price_currency": "euro" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_bad_request();
+    let json: Value = response.json();
+    assert_eq!(json.get("kind").unwrap(), "CostPriceCurrencyRequiresPrice");
+
+    let mut product_ids = Vec::new();
+    for (name, unit, sell_price, cost_price) in [
+        ("Cake", "unit", Some(5.00), None),
+        ("Dough", "unit", Some(1.00), None),
+        ("Sugar", "gram", None, Some(1.50)),
+        ("Flour", "gram", None, Some(2.00)),
+        ("Milk", "liter", None, Some(1.20)),
+    ] {
+        let response = server
+            .post("/product")
+            .json(&json!({
+                "name": name,
+                "unit": unit,
+                "sell_price": sell_price,
+                "sell_price_currency": sell_price.map(|_| "euro"),
+                "cost_price": cost_price,
+                "cost_price_currency": cost_price.map(|_| "euro"),
+            }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        product_ids.push(response.text());
+    }
+    let [cake_id, dough_id, sugar_id, flour_id, milk_id] = &product_ids[..] else {
+        unreachable!()
+    };
+
+    // The cost price is only shown to admins
+    let response = server
+        .get(&format!("/product/{flour_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "cost_price": 2.0,
+        "cost_price_currency": "euro",
+    }));
+    let response = server
+        .get(&format!("/product/{flour_id}"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_ok();
+    let json: Value = response.json();
+    assert!(json.get("cost_price").is_none());
+
+    let response = server
+        .post("/recipe")
+        .json(&json!({
+            "product": dough_id,
+            "ingredients": [
+                { "product": flour_id, "quantity": 150 },
+                { "product": milk_id, "quantity": 0.5 },
+                { "product": sugar_id, "quantity": 10 },
+            ]
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let dough_recipe_id = response.text();
+
+    let response = server
+        .post("/recipe")
+        .json(&json!({
+            "product": cake_id,
+            "ingredients": [
+                { "product": dough_id, "quantity": 2 },
+                { "product": sugar_id, "quantity": 50 },
+            ]
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let cake_recipe_id = response.text();
+
+    // Grams are priced per kilogram: 2 * (0.30 + 0.60 + 0.015) + 0.075 = 1.905
+    let response = server
+        .get(&format!("/recipe/{cake_recipe_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "cost": {
+            "currency": "euro",
+            "cost": 1.91,
+            "sell_price": 5.0,
+            "margin": 3.09,
+            "margin_rate": 0.618,
+        },
+    }));
+
+    let response = server
+        .get("/report/margins")
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_forbidden();
+
+    let response = server
+        .get("/report/margins")
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json(&json!({
+        "recipes": [
+            {
+                "recipe": dough_recipe_id,
+                "name": null,
+                "product": dough_id,
+                "product_name": "Dough",
+                "cost": {
+                    "currency": "euro",
+                    "cost": 0.92,
+                    "sell_price": 1.0,
+                    "margin": 0.08,
+                    "margin_rate": 0.08,
+                },
+            },
+            {
+                "recipe": cake_recipe_id,
+                "name": null,
+                "product": cake_id,
+                "product_name": "Cake",
+                "cost": {
+                    "currency": "euro",
+                    "cost": 1.91,
+                    "sell_price": 5.0,
+                    "margin": 3.09,
+                    "margin_rate": 0.618,
+                },
+            },
+        ]
+    }));
+}
+
+#[test_log::test(tokio::test)]
+async fn recipe_ingredient_units() {
+    let realm = Realm::default();
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Cola",
+            "content": { "quantity": 1, "unit": "unit" },
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_bad_request();
+    let json: Value = response.json();
+    assert_eq!(json.get("kind").unwrap(), "ContentCannotBeUnit");
+
+    let mut ids = Vec::new();
+    for product in [
+        json!({ "name": "Cuba Libre", "sell_price": 5.00, "sell_price_currency": "euro" }),
+        json!({ "name": "Rum", "purchasable": false, "unit": "liter" }),
+        json!({ "name": "Cola", "purchasable": false, "content": { "quantity": 33, "unit": "cl" } }),
+        json!({ "name": "Sugar", "purchasable": false, "unit": "gram" }),
+    ] {
+        let response = server
+            .post("/product")
+            .json(&product)
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        ids.push(response.text());
+    }
+    let (cuba_libre, rum, cola, sugar) = (&ids[0], &ids[1], &ids[2], &ids[3]);
+
+    let response = server
+        .get(&format!("/product/{cola}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "unit": "unit",
+        "content": { "quantity": "0.330", "unit": "liter" },
+    }));
+
+    let response = server
+        .post("/recipe")
+        .json(&json!({
+            "product": cuba_libre,
+            "ingredients": [{ "product": sugar, "quantity": 1, "unit": "l" }],
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_bad_request();
+    let json: Value = response.json();
+    assert_eq!(json.get("kind").unwrap(), "IngredientUnitDoesntMatch");
+
+    let response = server
+        .post("/recipe")
+        .json(&json!({
+            "product": cuba_libre,
+            "ingredients": [
+                { "product": rum, "quantity": 4, "unit": "cl" },
+                { "product": cola, "quantity": 16.5, "unit": "centiliter" },
+                { "product": sugar, "quantity": 0.01, "unit": "kg" },
+            ],
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let recipe_id = response.text();
+
+    let response = server
+        .get(&format!("/recipe/{recipe_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    let json: Value = response.json();
+    let mut quantities: Vec<_> = json
+        .get("ingredients")
+        .unwrap()
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|ingredient| {
+            (
+                ingredient
+                    .get("product")
+                    .unwrap()
+                    .as_str()
+                    .unwrap()
+                    .to_string(),
+                ingredient.get("quantity").unwrap().as_f64().unwrap(),
+            )
+        })
+        .collect();
+    quantities.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut expected = vec![
+        (rum.clone(), 0.04),
+        (cola.clone(), 0.5),
+        (sugar.clone(), 10.0),
+    ];
+    expected.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(quantities, expected);
+
+    // A recipe can make a product counted in liters
+    let response = server
+        .post("/product")
+        .json(&json!({ "name": "Syrup", "purchasable": false, "unit": "liter" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let syrup = response.text();
+
+    let response = server
+        .post("/recipe")
+        .json(&json!({
+            "product": syrup,
+            "ingredients": [{ "product": sugar, "quantity": 1, "unit": "kg" }],
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+}
@@ -1,7 +1,7 @@
 mod utils;
 
 use axum::http::StatusCode;
-use serde_json::json;
+use serde_json::{Value, json};
 use utils::{containers::keycloak::User, create_basic_session, create_realm_session};
 
 use crate::utils::containers::keycloak::{Client, Realm};
@@ -133,3 +133,149 @@ async fn refill_test_1() {
         "refills": []
     }));
 }
+
+#[test_log::test(tokio::test)]
+async fn refill_purchase_test() {
+    let realm = Realm {
+        name: "refill_purchase_test".to_string(),
+        clients: vec![Client::default()],
+        users: vec![
+            User {
+                username: "admin".to_string(),
+                email: "admin@example.com".to_string(),
+                ..Default::default()
+            },
+            User {
+                username: "user_1".to_string(),
+                email: "user_1@example.com".to_string(),
+                ..Default::default()
+            },
+        ],
+    };
+
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let response = server
+        .post("/refill")
+        .json(&json!({
+            "price": 5.0,
+            "price_currency": "euro",
+            "credit": 5.0,
+            "credit_currency": "epicoin",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let refill_id = response.text();
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Bug Magnet",
+            "sell_price": 2.50,
+            "sell_price_currency": "epicoin",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let product_id = response.text();
+
+    // POST /refill/{id}/purchase without being logged in
+    let response = server.post(&format!("/refill/{refill_id}/purchase")).await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    let response = server
+        .post(&format!("/refill/{}/purchase", uuid::Uuid::new_v4()))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_not_found();
+
+    // Buy the refill, then cancel it
+    let response = server
+        .post(&format!("/refill/{refill_id}/purchase"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let json: Value = response.json();
+    let transaction_id = json.get("transaction_id").unwrap().as_str().unwrap();
+    let checkout_url = json.get("checkout_url").unwrap().as_str().unwrap();
+    assert_eq!(
+        checkout_url,
+        format!("http://localhost:3000/payment/fake/{transaction_id}")
+    );
+
+    let response = server
+        .get(&format!("/transaction/{transaction_id}"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_json_contains(&json!({ "type": "refill_balance", "status": "pending" }));
+
+    // Only the buyer can go through the checkout
+    let response = server
+        .get(&format!("/payment/fake/{transaction_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_not_found();
+
+    let response = server
+        .get(&format!("/payment/fake/{transaction_id}/cancel"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::SEE_OTHER);
+
+    let response = server
+        .get(&format!("/transaction/{transaction_id}"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_json_contains(&json!({ "status": "canceled" }));
+
+    let response = server
+        .get(&format!("/payment/fake/{transaction_id}"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_not_found();
+
+    // Buy the refill, then pay it
+    let response = server
+        .post(&format!("/refill/{refill_id}/purchase"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let json: Value = response.json();
+    let transaction_id = json.get("transaction_id").unwrap().as_str().unwrap();
+
+    let response = server
+        .get(&format!("/payment/fake/{transaction_id}"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::SEE_OTHER);
+
+    let response = server
+        .get(&format!("/transaction/{transaction_id}"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_json_contains(&json!({ "status": "completed" }));
+
+    // A payment cannot be confirmed twice
+    let response = server
+        .get(&format!("/payment/fake/{transaction_id}"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_not_found();
+
+    // The credited balance can now be spent
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [{ "product": product_id, "quantity": 2 }] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [{ "product": product_id, "quantity": 1 }] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_bad_request();
+}
@@ -0,0 +1,296 @@
+mod utils;
+
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+use utils::{assert_error_kind, create_basic_session, create_realm_session};
+
+use crate::utils::containers::keycloak::Realm;
+
+#[test_log::test(tokio::test)]
+async fn stocktake_count_close() {
+    let realm = Realm::default();
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let response = server
+        .post("/warehouse")
+        .json(&json!({ "name": "Warehouse 1" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let warehouse_id = response.text();
+
+    let mut product_ids = Vec::new();
+    for name in ["Product 1", "Product 2", "Product 3"] {
+        let response = server
+            .post("/product")
+            .json(&json!({
+                "name": name,
+                "sell_price": 1.00,
+                "sell_price_currency": "epicoin",
+            }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        product_ids.push(response.text());
+    }
+    let (product_1, product_2, product_3) = (&product_ids[0], &product_ids[1], &product_ids[2]);
+
+    for (product, quantity) in [(product_1, 10), (product_3, 4)] {
+        let response = server
+            .post(&format!("/warehouse/{warehouse_id}/product/{product}"))
+            .json(&json!({ "quantity": quantity }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+    }
+
+    // Only an admin can count
+    let response = server
+        .post("/stocktake")
+        .json(&json!({ "warehouse": warehouse_id }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_forbidden();
+
+    let response = server
+        .post("/stocktake")
+        .json(&json!({ "warehouse": uuid::Uuid::new_v4() }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "WarehouseDoesntExist");
+
+    let response = server
+        .post("/stocktake")
+        .json(&json!({ "warehouse": warehouse_id }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let stocktake_id = response.text();
+
+    let response = server
+        .post("/stocktake")
+        .json(&json!({ "warehouse": warehouse_id }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "StocktakeAlreadyOpen");
+
+    let response = server
+        .put(&format!("/stocktake/{stocktake_id}/count"))
+        .json(&json!({ "products": [] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "CountCannotBeEmpty");
+
+    let response = server
+        .put(&format!("/stocktake/{stocktake_id}/count"))
+        .json(&json!({ "products": [{ "product": product_1, "quantity": -1 }] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "QuantityCannotBeNegative");
+
+    let response = server
+        .put(&format!("/stocktake/{stocktake_id}/count"))
+        .json(&json!({ "products": [{ "product": uuid::Uuid::new_v4(), "quantity": 1 }] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "ProductDoesntExist");
+
+    // Two devices counting, the last count of a product wins
+    let response = server
+        .put(&format!("/stocktake/{stocktake_id}/count"))
+        .json(&json!({ "products": [
+            { "product": product_1, "quantity": 7 },
+            { "product": product_2, "quantity": 2 },
+        ] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .put(&format!("/stocktake/{stocktake_id}/count"))
+        .json(&json!({ "products": [{ "product": product_1, "quantity": 8 }] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    // The stock is untouched while the stocktake is open
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/product/{product_1}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({ "quantity": "10.00" }));
+
+    let response = server
+        .get(&format!("/stocktake/{stocktake_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "warehouse": warehouse_id,
+        "status": "open",
+    }));
+    let json: Value = response.json();
+    assert_eq!(json["products"].as_array().unwrap().len(), 2);
+
+    // What moves after the count is kept when closing
+    let response = server
+        .post(&format!(
+            "/warehouse/{warehouse_id}/product/{product_1}/batch"
+        ))
+        .json(&json!({ "quantity": 5 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .post(&format!("/stocktake/{stocktake_id}/close"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    let json: Value = response.json();
+    assert_eq!(json["status"], "closed");
+    let products = json["products"].as_array().unwrap();
+    let line = |product: &str| {
+        products
+            .iter()
+            .find(|line| line["product"] == product)
+            .unwrap()
+            .clone()
+    };
+    assert_eq!(line(product_1)["expected_quantity"], "10.00");
+    assert_eq!(line(product_1)["variance"], "-2.00");
+    assert_eq!(line(product_2)["expected_quantity"], "0.00");
+    assert_eq!(line(product_2)["variance"], "2.00");
+
+    // The counted products are corrected, the others are left untouched
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/product"))
+        .add_query_param("sort", "quantity_desc")
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "products": [
+            { "quantity": "13.00", "product": { "id": product_1 } },
+            { "quantity": "4.00", "product": { "id": product_3 } },
+            { "quantity": "2.00", "product": { "id": product_2 } },
+        ]
+    }));
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/movements"))
+        .add_query_param("reason_eq", "stocktake")
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    let json: Value = response.json();
+    assert_eq!(json["movements"].as_array().unwrap().len(), 2);
+
+    let response = server
+        .put(&format!("/stocktake/{stocktake_id}/count"))
+        .json(&json!({ "products": [{ "product": product_1, "quantity": 1 }] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "StocktakeIsNotOpen");
+
+    let response = server
+        .post(&format!("/stocktake/{stocktake_id}/close"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "StocktakeIsNotOpen");
+}
+
+#[test_log::test(tokio::test)]
+async fn stocktake_cancel() {
+    let realm = Realm::default();
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let response = server
+        .post("/warehouse")
+        .json(&json!({ "name": "Warehouse 1" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let warehouse_id = response.text();
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Product 1",
+            "sell_price": 1.00,
+            "sell_price_currency": "epicoin",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let product_id = response.text();
+
+    let response = server
+        .post(&format!("/warehouse/{warehouse_id}/product/{product_id}"))
+        .json(&json!({ "quantity": 10 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .post("/stocktake")
+        .json(&json!({ "warehouse": warehouse_id }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let stocktake_id = response.text();
+
+    let response = server
+        .put(&format!("/stocktake/{stocktake_id}/count"))
+        .json(&json!({ "products": [{ "product": product_id, "quantity": 3 }] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .delete(&format!("/stocktake/{stocktake_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .delete(&format!("/stocktake/{stocktake_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_bad_request();
+
+    let response = server
+        .delete(&format!("/stocktake/{}", uuid::Uuid::new_v4()))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_not_found();
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/product/{product_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({ "quantity": "10.00" }));
+
+    // A new stocktake can be opened once the previous one is canceled
+    let response = server
+        .post("/stocktake")
+        .json(&json!({ "warehouse": warehouse_id }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .get("/stocktake")
+        .add_query_param("status_eq", "canceled")
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "total_page": 1,
+        "stocktakes": [{ "id": stocktake_id, "status": "canceled" }],
+    }));
+}
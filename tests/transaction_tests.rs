@@ -0,0 +1,152 @@
+mod utils;
+
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+use utils::{containers::keycloak::User, create_basic_session, create_realm_session};
+
+use crate::utils::containers::keycloak::{Client, Realm};
+
+#[test_log::test(tokio::test)]
+async fn transaction_test_1() {
+    let realm = Realm {
+        name: "transaction_test".to_string(),
+        clients: vec![Client::default()],
+        users: vec![
+            User {
+                username: "user_1".to_string(),
+                email: "user_1@example.com".to_string(),
+                ..Default::default()
+            },
+            User {
+                username: "user_2".to_string(),
+                email: "user_2@example.com".to_string(),
+                ..Default::default()
+            },
+        ],
+    };
+
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    // GET /transaction without being logged in
+    let response = server.get("/transaction").await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    let response = server
+        .get("/transaction")
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json(&json!({
+        "current_page": 0,
+        "total_page": 1,
+        "transactions": []
+    }));
+
+    let response = server
+        .get("/transaction")
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json(&json!({
+        "current_page": 0,
+        "total_page": 1,
+        "transactions": []
+    }));
+
+    let response = server
+        .get(&format!("/transaction/{}", uuid::Uuid::new_v4()))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_not_found();
+}
+
+#[test_log::test(tokio::test)]
+async fn transaction_list_scope() {
+    let realm = Realm {
+        name: "transaction_test".to_string(),
+        clients: vec![Client::default()],
+        users: vec![
+            User {
+                username: "user_1".to_string(),
+                email: "user_1@example.com".to_string(),
+                ..Default::default()
+            },
+            User {
+                username: "user_2".to_string(),
+                email: "user_2@example.com".to_string(),
+                ..Default::default()
+            },
+        ],
+    };
+
+    let (mut server, ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    // One balance edit for each user
+    let mut transactions = Vec::new();
+    for id in &ids {
+        let response = server
+            .post(&format!("/user/{id}/balance"))
+            .json(&json!({ "delta": 5.0, "currency": "epicoin", "reason": "Gift" }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        transactions.push(response.text());
+    }
+
+    // A user only lists its own transactions
+    let response = server
+        .get("/transaction")
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_ok();
+    let json: Value = response.json();
+    let listed = json["transactions"].as_array().unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0]["id"], transactions[1].as_str());
+    assert_eq!(listed[0]["user_id"], ids[1].as_str());
+    assert_eq!(listed[0]["type"], "balance_edit");
+
+    // Even when filtering on another user
+    let response = server
+        .get(&format!("/transaction?user_id_eq={}", ids[0]))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_ok();
+    let json: Value = response.json();
+    let listed = json["transactions"].as_array().unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0]["id"], transactions[1].as_str());
+
+    let response = server
+        .get(&format!("/transaction/{}", transactions[0]))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_not_found();
+
+    // An admin lists every transaction
+    let response = server
+        .get("/transaction")
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    let json: Value = response.json();
+    let mut listed = json["transactions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|transaction| transaction["id"].as_str().unwrap().to_string())
+        .collect::<Vec<_>>();
+    listed.sort();
+    let mut expected = transactions.clone();
+    expected.sort();
+    assert_eq!(listed, expected);
+
+    let response = server
+        .get(&format!("/transaction/{}", transactions[1]))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({ "user_id": ids[1] }));
+}
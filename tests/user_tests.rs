@@ -163,3 +163,114 @@ async fn user_test_1() {
         &json!({"id": ids[2], "email": "user_3@example.com" , "username": "user_3", "name": "John Doe", "is_admin": false, "is_banned": true }),
     );
 }
+
+#[test_log::test(tokio::test)]
+async fn user_balance_edit_test() {
+    let realm = Realm {
+        name: "user_balance_edit_test".to_string(),
+        clients: vec![Client::default()],
+        users: vec![
+            User {
+                username: "admin".to_string(),
+                email: "admin@example.com".to_string(),
+                ..Default::default()
+            },
+            User {
+                username: "user_1".to_string(),
+                email: "user_1@example.com".to_string(),
+                ..Default::default()
+            },
+        ],
+    };
+
+    let (mut server, ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    // Only an admin can edit a balance
+    let response = server
+        .post(&format!("/user/{}/balance", ids[1]))
+        .json(&json!({ "delta": 10.0, "currency": "epicoin", "reason": "Gift" }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::FORBIDDEN);
+
+    let response = server
+        .post(&format!("/user/{}/balance", uuid::Uuid::new_v4()))
+        .json(&json!({ "delta": 10.0, "currency": "epicoin", "reason": "Gift" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_not_found();
+
+    // The reason is mandatory
+    let response = server
+        .post(&format!("/user/{}/balance", ids[1]))
+        .json(&json!({ "delta": 10.0, "currency": "epicoin", "reason": "  " }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_bad_request();
+
+    let response = server
+        .post(&format!("/user/{}/balance", ids[1]))
+        .json(&json!({ "delta": 0.0, "currency": "epicoin", "reason": "Nothing" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_bad_request();
+
+    let response = server
+        .post(&format!("/user/{}/balance", ids[1]))
+        .json(&json!({ "delta": 10.0, "currency": "euro", "reason": "Gift" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_bad_request();
+
+    // A balance cannot become negative
+    let response = server
+        .post(&format!("/user/{}/balance", ids[1]))
+        .json(&json!({ "delta": -1.0, "currency": "epicoin", "reason": "Debt" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_bad_request();
+
+    let response = server
+        .post(&format!("/user/{}/balance", ids[1]))
+        .json(&json!({ "delta": 10.0, "currency": "epicoin", "reason": "Gift" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let transaction_id = response.text();
+
+    let response = server
+        .post(&format!("/user/{}/balance", ids[1]))
+        .json(&json!({ "delta": -4.5, "currency": "epicoin", "reason": "Correction" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    // Every edit is recorded in the ledger of the user
+    let response = server
+        .get(&format!("/transaction/{transaction_id}"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "id": transaction_id,
+        "user_id": ids[1],
+        "type": "balance_edit",
+        "status": "completed",
+    }));
+
+    let response = server
+        .get("/transaction")
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_ok();
+    let json: serde_json::Value = response.json();
+    assert_eq!(json["transactions"].as_array().unwrap().len(), 2);
+
+    let response = server
+        .post(&format!("/user/{}/balance", ids[1]))
+        .json(&json!({ "delta": -5.6, "currency": "epicoin", "reason": "Debt" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_bad_request();
+}
@@ -212,7 +212,7 @@ impl Keycloak {
                         "create",
                         "clients",
                         "-r",
-                        &realm,
+                        realm,
                         "-s",
                         &format!("clientId={client_id}"),
                         "-s",
@@ -232,7 +232,7 @@ impl Keycloak {
                         "create",
                         "clients",
                         "-r",
-                        &realm,
+                        realm,
                         "-s",
                         &format!("clientId={client_id}"),
                         "-s",
@@ -254,7 +254,7 @@ impl Keycloak {
                     "create",
                     "users",
                     "-r",
-                    &realm,
+                    realm,
                     "-s",
                     &format!("username={}", user.username),
                     "-s",
@@ -285,7 +285,7 @@ impl Keycloak {
                     "/opt/keycloak/bin/kcadm.sh",
                     "set-password",
                     "-r",
-                    &realm,
+                    realm,
                     "--username",
                     &user.username,
                     "--new-password",
@@ -11,7 +11,7 @@ pub async fn get_multipart_random_image(image_name: &str, part_name: &str) -> Mu
 
     let image_part = Part::bytes(image_bytes)
         .file_name(format!("{image_name}.jpeg"))
-        .mime_type(&"image/jpeg");
+        .mime_type("image/jpeg");
 
     MultipartForm::new().add_part(part_name, image_part)
 }
@@ -2,7 +2,8 @@ use axum::http::StatusCode;
 use axum_test::{TestServer, TestServerBuilder};
 use containers::keycloak::{Keycloak, Realm, User};
 use futures::future::join_all;
-use scrounch_backend::app;
+use scrounch_backend::{app, app_with_stock_notifier};
+use service::notifier::StockNotifierGateway;
 use testcontainers::{ContainerAsync, runners::AsyncRunner};
 use testcontainers_modules::{minio::MinIO, postgres::Postgres, redis::Redis};
 
@@ -24,6 +25,57 @@ pub async fn create_basic_session(
         ContainerAsync<MinIO>,
         Option<ContainerAsync<Redis>>,
     ),
+) {
+    create_basic_session_with_arguments(realm, |_| {}).await
+}
+
+#[allow(dead_code)]
+pub async fn create_basic_session_with_arguments(
+    realm: Realm,
+    edit_arguments: impl FnOnce(&mut scrounch_backend::Arguments),
+) -> (
+    TestServer,
+    Vec<std::string::String>,
+    (
+        Keycloak,
+        ContainerAsync<Postgres>,
+        ContainerAsync<MinIO>,
+        Option<ContainerAsync<Redis>>,
+    ),
+) {
+    start_basic_session(realm, edit_arguments, None).await
+}
+
+#[allow(dead_code)]
+pub async fn create_basic_session_with_stock_notifier(
+    realm: Realm,
+    stock_notifier: StockNotifierGateway,
+) -> (
+    TestServer,
+    Vec<std::string::String>,
+    (
+        Keycloak,
+        ContainerAsync<Postgres>,
+        ContainerAsync<MinIO>,
+        Option<ContainerAsync<Redis>>,
+    ),
+) {
+    start_basic_session(realm, |_| {}, Some(stock_notifier)).await
+}
+
+async fn start_basic_session(
+    realm: Realm,
+    edit_arguments: impl FnOnce(&mut scrounch_backend::Arguments),
+    stock_notifier: Option<StockNotifierGateway>,
+) -> (
+    TestServer,
+    Vec<std::string::String>,
+    (
+        Keycloak,
+        ContainerAsync<Postgres>,
+        ContainerAsync<MinIO>,
+        Option<ContainerAsync<Redis>>,
+    ),
 ) {
     let keycloak = Keycloak::start(vec![Realm {
         name: realm.name.clone(),
@@ -61,25 +113,26 @@ pub async fn create_basic_session(
     #[allow(unused_assignments, unused_mut)]
     let mut redis_node_opt = None;
 
-    let mut arguments = scrounch_backend::Arguments::default();
-    arguments.openid_issuer = issuer.clone();
-    arguments.openid_client_id = realm.clients[0].client_id.clone();
-    arguments.openid_client_secret = realm.clients[0].client_secret.clone();
-    arguments.openid_token_duration = std::time::Duration::from_secs(360);
-    arguments.backend_url = "http://localhost:3000".to_string();
-    arguments.frontend_url = "http://localhost:5173".to_string();
-    arguments.database_url = db_url.to_string();
-    arguments.aws_access_key_id = minio_user.to_string();
-    arguments.aws_secret_access_key = minio_pass.to_string();
-    arguments.aws_endpoint_url = minio_url.to_string();
-    arguments.aws_s3_bucket = "miniobucket".to_string();
+    let mut arguments = scrounch_backend::Arguments {
+        openid_issuer: issuer.clone(),
+        openid_client_id: realm.clients[0].client_id.clone(),
+        openid_client_secret: realm.clients[0].client_secret.clone(),
+        openid_token_duration: std::time::Duration::from_secs(360),
+        order_cancel_grace_period: std::time::Duration::from_secs(300),
+        backend_url: "http://localhost:3000".to_string(),
+        frontend_url: "http://localhost:5173".to_string(),
+        database_url: db_url.to_string(),
+        aws_access_key_id: minio_user.to_string(),
+        aws_secret_access_key: minio_pass.to_string(),
+        aws_endpoint_url: minio_url.to_string(),
+        aws_s3_bucket: "miniobucket".to_string(),
+        payment_provider: Some(scrounch_backend::PaymentProviderKind::Fake),
+        ..Default::default()
+    };
 
     #[cfg(feature = "cache")]
     {
-        let redis_node = testcontainers_modules::redis::Redis::default()
-            .start()
-            .await
-            .unwrap();
+        let redis_node = testcontainers_modules::redis::Redis.start().await.unwrap();
         arguments.cache_url = Some(format!(
             "redis://127.0.0.1:{}",
             redis_node
@@ -90,7 +143,12 @@ pub async fn create_basic_session(
         redis_node_opt = Some(redis_node);
     }
 
-    let app = app(arguments).await;
+    edit_arguments(&mut arguments);
+
+    let app = match stock_notifier {
+        Some(stock_notifier) => app_with_stock_notifier(arguments, Some(stock_notifier)).await,
+        None => app(arguments).await,
+    };
 
     let server = TestServerBuilder::new()
         .mock_transport()
@@ -166,3 +224,11 @@ pub async fn create_realm_session(
     }
     result
 }
+
+/// Asserts the response is a bad request whose error is of the given `kind`.
+#[allow(dead_code)]
+pub fn assert_error_kind(response: axum_test::TestResponse, kind: &str) {
+    response.assert_status_bad_request();
+    let json: serde_json::Value = response.json();
+    assert_eq!(json.get("kind").unwrap(), kind);
+}
@@ -1,8 +1,12 @@
 mod utils;
 
 use axum::http::StatusCode;
-use serde_json::json;
-use utils::{create_basic_session, create_realm_session};
+use serde_json::{Value, json};
+use service::notifier::{StockAlertKind, StockNotifierGateway, memory::MemoryStockNotifier};
+use utils::{
+    assert_error_kind, create_basic_session, create_basic_session_with_stock_notifier,
+    create_realm_session,
+};
 
 use crate::utils::containers::keycloak::Realm;
 
@@ -860,3 +864,954 @@ async fn warehouse_product_create_negative_quantity() {
         "current_page": 0,
     }));
 }
+
+#[test_log::test(tokio::test)]
+async fn warehouse_movements() {
+    let realm = Realm::default();
+    let (mut server, ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let response = server
+        .post("/warehouse")
+        .json(&json!({
+            "name": "Warehouse 1",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let warehouse_id = response.text();
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Product 1",
+            "sell_price": 1.00,
+            "sell_price_currency": "epicoin",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let product_id = response.text();
+
+    let response = server
+        .post(&format!("/warehouse/{warehouse_id}/product/{product_id}"))
+        .json(&json!({
+            "quantity": 10
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .post(&format!("/user/{}/balance", ids[1]))
+        .json(&json!({ "delta": 5.0, "currency": "epicoin", "reason": "Gift" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .post("/order")
+        .json(&json!({ "products": [{ "product": product_id, "quantity": 2 }] }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    // Only an admin can read the journal
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/movements"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_forbidden();
+
+    let response = server
+        .get(&format!("/warehouse/{}/movements", uuid::Uuid::new_v4()))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_not_found();
+
+    let response = server
+        .get(&format!(
+            "/warehouse/{warehouse_id}/movements?sort=created_at_asc"
+        ))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "current_page": 0,
+        "total_page": 1,
+        "movements": [
+            {
+                "product": product_id,
+                "delta": "10.00",
+                "reason": "restock",
+                "author": ids[0],
+            },
+            {
+                "product": product_id,
+                "delta": "-2.00",
+                "reason": "purchase",
+                "author": ids[1],
+            },
+        ]
+    }));
+
+    let response = server
+        .get(&format!(
+            "/warehouse/{warehouse_id}/movements?reason_eq=purchase"
+        ))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "movements": [{ "reason": "purchase" }]
+    }));
+}
+
+#[test_log::test(tokio::test)]
+async fn warehouse_transfer() {
+    let realm = Realm::default();
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let mut warehouse_ids = Vec::new();
+    for name in ["Warehouse 1", "Warehouse 2"] {
+        let response = server
+            .post("/warehouse")
+            .json(&json!({ "name": name }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        warehouse_ids.push(response.text());
+    }
+    let (from, to) = (&warehouse_ids[0], &warehouse_ids[1]);
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Product 1",
+            "sell_price": 1.00,
+            "sell_price_currency": "epicoin",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let product_id = response.text();
+
+    let response = server
+        .post(&format!("/warehouse/{from}/product/{product_id}"))
+        .json(&json!({ "quantity": 10 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let transfer = |quantity: f64, unit: &str| json!({ "products": [{ "product": product_id, "quantity": quantity, "unit": unit }] });
+
+    let response = server
+        .post(&format!("/warehouse/{from}/transfer/{to}"))
+        .json(&transfer(4.0, "unit"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_forbidden();
+
+    let response = server
+        .post(&format!("/warehouse/{from}/transfer/{to}"))
+        .json(&json!({ "products": [] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "TransferCannotBeEmpty");
+
+    let response = server
+        .post(&format!("/warehouse/{from}/transfer/{to}"))
+        .json(&transfer(0.0, "unit"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "QuantityMustBePositive");
+
+    let response = server
+        .post(&format!("/warehouse/{from}/transfer/{from}"))
+        .json(&transfer(4.0, "unit"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "WarehousesCannotBeTheSame");
+
+    let response = server
+        .post(&format!(
+            "/warehouse/{from}/transfer/{}",
+            uuid::Uuid::new_v4()
+        ))
+        .json(&transfer(4.0, "unit"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "WarehouseDoesntExist");
+
+    let response = server
+        .post(&format!("/warehouse/{from}/transfer/{to}"))
+        .json(&transfer(4.0, "liter"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "UnitDoesntMatch");
+
+    let response = server
+        .post(&format!("/warehouse/{from}/transfer/{to}"))
+        .json(&transfer(11.0, "unit"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "InsufficientStock");
+
+    let response = server
+        .post(&format!("/warehouse/{from}/transfer/{to}"))
+        .json(&transfer(4.0, "unit"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json(&json!({
+        "from": from,
+        "to": to,
+        "products": [
+            { "product": product_id, "from_quantity": "6.00", "to_quantity": "4.00" },
+        ]
+    }));
+
+    let response = server
+        .get(&format!("/warehouse/{to}/movements"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "movements": [{ "product": product_id, "delta": "4.00", "reason": "transfer" }]
+    }));
+}
+
+#[test_log::test(tokio::test)]
+async fn warehouse_low_stock() {
+    let realm = Realm::default();
+    let notifier = MemoryStockNotifier::new();
+    let (mut server, _ids, _nodes) = create_basic_session_with_stock_notifier(
+        realm.clone(),
+        StockNotifierGateway::new(notifier.clone()),
+    )
+    .await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let mut warehouse_ids = Vec::new();
+    for name in ["Warehouse 1", "Warehouse 2"] {
+        let response = server
+            .post("/warehouse")
+            .json(&json!({ "name": name }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        warehouse_ids.push(response.text());
+    }
+    let (from, to) = (&warehouse_ids[0], &warehouse_ids[1]);
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Product 1",
+            "sell_price": 1.00,
+            "sell_price_currency": "epicoin",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let product_id = response.text();
+
+    let response = server
+        .post(&format!("/warehouse/{from}/product/{product_id}"))
+        .json(&json!({ "quantity": 10, "min_quantity": -1 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_bad_request();
+    let json: Value = response.json();
+    assert_eq!(json.get("kind").unwrap(), "ThresholdCannotBeNegative");
+
+    let response = server
+        .post(&format!("/warehouse/{from}/product/{product_id}"))
+        .json(&json!({ "quantity": 10, "min_quantity": 3, "reorder_quantity": 20 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .get(&format!("/warehouse/{from}/low-stock"))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_forbidden();
+
+    let response = server
+        .get(&format!("/warehouse/{from}/low-stock"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json(&json!({ "products": [], "total_page": 1, "current_page": 0 }));
+
+    let transfer = |from: &str, to: &str, quantity: f64| {
+        server
+            .post(&format!("/warehouse/{from}/transfer/{to}"))
+            .json(&json!({ "products": [{ "product": product_id, "quantity": quantity, "unit": "unit" }] }))
+            .add_cookie(cookies[0].clone())
+    };
+
+    transfer(from, to, 8.0).await.assert_status_ok();
+
+    let response = server
+        .get(&format!("/warehouse/{from}/low-stock"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "products": [{ "quantity": "2.00", "min_quantity": "3.00", "reorder_quantity": "20.00" }],
+        "total_page": 1,
+    }));
+
+    let alerts = notifier.wait_alerts(1).await;
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].kind, StockAlertKind::Low);
+    assert_eq!(alerts[0].warehouse_id.to_string(), *from);
+    assert_eq!(alerts[0].product_id.to_string(), product_id);
+
+    // Staying below the threshold doesn't alert again
+    transfer(from, to, 1.0).await.assert_status_ok();
+    assert_eq!(notifier.alerts().len(), 1);
+
+    transfer(to, from, 5.0).await.assert_status_ok();
+
+    let response = server
+        .get(&format!("/warehouse/{from}/low-stock"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json(&json!({ "products": [], "total_page": 1, "current_page": 0 }));
+
+    let alerts = notifier.wait_alerts(2).await;
+    assert_eq!(alerts.len(), 2);
+    assert_eq!(alerts[1].kind, StockAlertKind::Replenished);
+
+    let response = server
+        .get(&format!("/warehouse/{}/low-stock", uuid::Uuid::new_v4()))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_not_found();
+}
+
+#[test_log::test(tokio::test)]
+async fn warehouse_available_quantity() {
+    let realm = Realm::default();
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Product 1",
+            "sell_price": 1.00,
+            "sell_price_currency": "epicoin",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let product_id = response.text();
+
+    let mut warehouse_ids = Vec::new();
+    for (name, quantity) in [("Warehouse 1", 10), ("Warehouse 2", 4)] {
+        let response = server
+            .post("/warehouse")
+            .json(&json!({ "name": name }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        let warehouse_id = response.text();
+
+        let response = server
+            .post(&format!("/warehouse/{warehouse_id}/product/{product_id}"))
+            .json(&json!({ "quantity": quantity }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        warehouse_ids.push(warehouse_id);
+    }
+
+    let response = server
+        .get(&format!("/warehouse/{}/product", warehouse_ids[0]))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "products": [{ "quantity": "10.00", "available_quantity": "10.00" }],
+    }));
+
+    let response = server.get(&format!("/product/{product_id}")).await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({ "available_quantity": "14.00" }));
+
+    // Nothing can be sold from a disabled warehouse
+    let response = server
+        .put(&format!("/warehouse/{}", warehouse_ids[1]))
+        .json(&json!({ "disabled": true }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get(&format!(
+            "/warehouse/{}/product/{product_id}",
+            warehouse_ids[1]
+        ))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({ "quantity": "4.00", "available_quantity": "0" }));
+
+    let response = server.get("/product").await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "products": [{ "id": product_id, "available_quantity": "10.00" }],
+    }));
+}
+
+#[test_log::test(tokio::test)]
+async fn warehouse_recipes() {
+    let realm = Realm::default();
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let mut product_ids = Vec::new();
+    for name in ["Cake", "Eggs"] {
+        let response = server
+            .post("/product")
+            .json(&json!({
+                "name": name,
+                "sell_price": 1.00,
+                "sell_price_currency": "epicoin",
+            }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        product_ids.push(response.text());
+    }
+    let (cake_id, eggs_id) = (&product_ids[0], &product_ids[1]);
+
+    let mut recipe_ids = Vec::new();
+    for quantity in [3, 2] {
+        let response = server
+            .post("/recipe")
+            .json(&json!({
+                "name": "Recipe for a Cake",
+                "product": cake_id,
+                "ingredients": [{ "product": eggs_id, "quantity": quantity }],
+            }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        recipe_ids.push(response.text());
+    }
+
+    let response = server
+        .post("/warehouse")
+        .json(&json!({ "name": "Warehouse 1" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let warehouse_id = response.text();
+
+    let response = server
+        .post(&format!("/warehouse/{warehouse_id}/product/{eggs_id}"))
+        .json(&json!({ "quantity": 7 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .post(&format!(
+            "/warehouse/{warehouse_id}/recipe/{}",
+            recipe_ids[0]
+        ))
+        .json(&json!({ "priority": 10 }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_forbidden();
+
+    let response = server
+        .post(&format!(
+            "/warehouse/{}/recipe/{}",
+            uuid::Uuid::new_v4(),
+            recipe_ids[0]
+        ))
+        .json(&json!({ "priority": 10 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "WarehouseDoesntExist");
+
+    let response = server
+        .post(&format!(
+            "/warehouse/{warehouse_id}/recipe/{}",
+            uuid::Uuid::new_v4()
+        ))
+        .json(&json!({ "priority": 10 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "RecipeDoesntExist");
+
+    let response = server
+        .post(&format!(
+            "/warehouse/{warehouse_id}/recipe/{}",
+            recipe_ids[0]
+        ))
+        .json(&json!({ "priority": 10 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .post(&format!(
+            "/warehouse/{warehouse_id}/recipe/{}",
+            recipe_ids[0]
+        ))
+        .json(&json!({ "priority": 5 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "WarehouseRecipeAlreadyExist");
+
+    let response = server
+        .post(&format!(
+            "/warehouse/{warehouse_id}/recipe/{}",
+            recipe_ids[1]
+        ))
+        .json(&json!({ "priority": 10 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "PriorityAlreadyUsed");
+
+    let response = server
+        .post(&format!(
+            "/warehouse/{warehouse_id}/recipe/{}",
+            recipe_ids[1]
+        ))
+        .json(&json!({ "priority": 5 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/recipe"))
+        .add_query_param("sort", "priority_desc")
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "total_page": 1,
+        "current_page": 0,
+        "recipes": [
+            { "priority": 10, "recipe": { "id": recipe_ids[0], "product": cake_id } },
+            { "priority": 5, "recipe": { "id": recipe_ids[1], "product": cake_id } },
+        ]
+    }));
+
+    // 7 eggs make 2 cakes with the first recipe and 3 with the second one
+    let response = server.get(&format!("/product/{cake_id}")).await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({ "available_quantity": "5" }));
+
+    let response = server
+        .put(&format!(
+            "/warehouse/{warehouse_id}/recipe/{}",
+            recipe_ids[1]
+        ))
+        .json(&json!({ "priority": 10 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "PriorityAlreadyUsed");
+
+    let response = server
+        .put(&format!(
+            "/warehouse/{warehouse_id}/recipe/{}",
+            recipe_ids[1]
+        ))
+        .json(&json!({ "priority": 20 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get(&format!(
+            "/warehouse/{warehouse_id}/recipe/{}",
+            recipe_ids[1]
+        ))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({ "priority": 20, "recipe": { "id": recipe_ids[1] } }));
+
+    let response = server
+        .delete(&format!(
+            "/warehouse/{warehouse_id}/recipe/{}",
+            recipe_ids[1]
+        ))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get(&format!(
+            "/warehouse/{warehouse_id}/recipe/{}",
+            recipe_ids[1]
+        ))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_not_found();
+
+    let response = server
+        .delete(&format!(
+            "/warehouse/{warehouse_id}/recipe/{}",
+            recipe_ids[1]
+        ))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_not_found();
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/recipe"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "total_page": 1,
+        "recipes": [{ "priority": 10, "recipe": { "id": recipe_ids[0] } }]
+    }));
+}
+
+#[test_log::test(tokio::test)]
+async fn warehouse_product_edit_delete() {
+    let realm = Realm::default();
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let response = server
+        .post("/warehouse")
+        .json(&json!({ "name": "Warehouse 1" }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let warehouse_id = response.text();
+
+    let mut product_ids = Vec::new();
+    for name in ["Product 1", "Product 2"] {
+        let response = server
+            .post("/product")
+            .json(&json!({
+                "name": name,
+                "sell_price": 1.00,
+                "sell_price_currency": "epicoin",
+            }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        product_ids.push(response.text());
+    }
+    let (product_1, product_2) = (&product_ids[0], &product_ids[1]);
+
+    let response = server
+        .post(&format!("/warehouse/{warehouse_id}/product/{product_1}"))
+        .json(&json!({ "quantity": 10 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .put(&format!("/warehouse/{warehouse_id}/product/{product_1}"))
+        .json(&json!({ "quantity": 8 }))
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_forbidden();
+
+    let response = server
+        .put(&format!("/warehouse/{warehouse_id}/product/{product_2}"))
+        .json(&json!({ "quantity": 8 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_not_found();
+
+    let response = server
+        .put(&format!("/warehouse/{warehouse_id}/product/{product_1}"))
+        .json(&json!({ "quantity": -1 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "QuantityCannotBeNegative");
+
+    let response = server
+        .put(&format!("/warehouse/{warehouse_id}/product/{product_1}"))
+        .json(&json!({ "quantity": 8, "min_quantity": 2 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/product/{product_1}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({ "quantity": "8.00", "min_quantity": "2.00" }));
+
+    let response = server
+        .put(&format!("/warehouse/{warehouse_id}/product"))
+        .json(&json!({ "products": [] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "ProductsCannotBeEmpty");
+
+    let response = server
+        .put(&format!("/warehouse/{warehouse_id}/product"))
+        .json(&json!({ "products": [
+            { "product": product_1, "quantity": 5 },
+            { "product": product_1, "quantity": 6 },
+        ] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "ProductCannotBeRepeated");
+
+    let response = server
+        .put(&format!("/warehouse/{warehouse_id}/product"))
+        .json(&json!({ "products": [{ "product": uuid::Uuid::new_v4(), "quantity": 5 }] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    assert_error_kind(response, "ProductDoesntExist");
+
+    let response = server
+        .put(&format!("/warehouse/{warehouse_id}/product"))
+        .json(&json!({ "products": [
+            { "product": product_1, "quantity": 5 },
+            { "product": product_2, "quantity": 3 },
+        ] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/product"))
+        .add_query_param("sort", "quantity_desc")
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "products": [
+            { "quantity": "5.00", "min_quantity": "2.00", "product": { "id": product_1 } },
+            { "quantity": "3.00", "product": { "id": product_2 } },
+        ]
+    }));
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/movements"))
+        .add_query_param("reason_eq", "correction")
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    let json: Value = response.json();
+    assert_eq!(json.get("movements").unwrap().as_array().unwrap().len(), 3);
+
+    let response = server
+        .delete(&format!("/warehouse/{warehouse_id}/product/{product_2}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .delete(&format!("/warehouse/{warehouse_id}/product/{product_2}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_not_found();
+
+    let response = server
+        .get(&format!("/warehouse/{warehouse_id}/product/{product_2}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_not_found();
+}
+
+#[test_log::test(tokio::test)]
+async fn warehouse_product_batches() {
+    let realm = Realm::default();
+    let (mut server, _ids, _nodes) = create_basic_session(realm.clone()).await;
+    let cookies = create_realm_session(&mut server, realm.users).await;
+
+    let mut warehouse_ids = Vec::new();
+    for name in ["Warehouse 1", "Warehouse 2"] {
+        let response = server
+            .post("/warehouse")
+            .json(&json!({ "name": name }))
+            .add_cookie(cookies[0].clone())
+            .await;
+        response.assert_status(StatusCode::CREATED);
+        warehouse_ids.push(response.text());
+    }
+    let (from, to) = (&warehouse_ids[0], &warehouse_ids[1]);
+
+    let response = server
+        .post("/product")
+        .json(&json!({
+            "name": "Product 1",
+            "sell_price": 1.00,
+            "sell_price_currency": "epicoin",
+        }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let product_id = response.text();
+
+    // Untracked part of the stock
+    let response = server
+        .post(&format!("/warehouse/{from}/product/{product_id}"))
+        .json(&json!({ "quantity": 2 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+
+    let response = server
+        .post(&format!("/warehouse/{from}/product/{product_id}/batch"))
+        .json(&json!({ "quantity": 0 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_bad_request();
+    let json: Value = response.json();
+    assert_eq!(json.get("kind").unwrap(), "QuantityMustBePositive");
+
+    let response = server
+        .post(&format!("/warehouse/{from}/product/{product_id}/batch"))
+        .json(
+            &json!({ "quantity": 5, "expires_at": chrono::Utc::now() + chrono::Duration::days(3) }),
+        )
+        .add_cookie(cookies[1].clone())
+        .await;
+    response.assert_status_forbidden();
+
+    let response = server
+        .post(&format!("/warehouse/{from}/product/{product_id}/batch"))
+        .json(
+            &json!({ "quantity": 5, "expires_at": chrono::Utc::now() + chrono::Duration::days(3) }),
+        )
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let first_batch = response.text();
+
+    let response = server
+        .post(&format!("/warehouse/{from}/product/{product_id}/batch"))
+        .json(&json!({ "quantity": 4, "expires_at": chrono::Utc::now() + chrono::Duration::days(30) }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status(StatusCode::CREATED);
+    let second_batch = response.text();
+
+    let response = server
+        .get(&format!("/warehouse/{from}/product/{product_id}"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({ "quantity": "11.00" }));
+
+    let response = server
+        .get(&format!("/warehouse/{from}/expiring"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "batches": [{ "id": first_batch, "product": product_id, "quantity": "5.00" }],
+        "total_page": 1,
+        "current_page": 0,
+    }));
+
+    let response = server
+        .get(&format!("/warehouse/{from}/expiring?within=1h"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json(&json!({ "batches": [], "total_page": 1, "current_page": 0 }));
+
+    let response = server
+        .get(&format!("/warehouse/{from}/expiring?within=soon"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_bad_request();
+    let json: Value = response.json();
+    assert_eq!(json.get("kind").unwrap(), "WindowIsInvalid");
+
+    // The oldest batch is consumed first
+    let response = server
+        .post(&format!("/warehouse/{from}/transfer/{to}"))
+        .json(&json!({ "products": [{ "product": product_id, "quantity": 6, "unit": "unit" }] }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get(&format!("/warehouse/{from}/product/{product_id}/batch"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    let json: Value = response.json();
+    let batches = json.get("batches").unwrap().as_array().unwrap();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].get("id").unwrap(), &json!(second_batch));
+    assert_eq!(batches[0].get("quantity").unwrap(), "3.00");
+
+    let response = server
+        .get(&format!("/warehouse/{from}/expiring?within=60d"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "batches": [{ "id": second_batch, "quantity": "3.00" }],
+    }));
+
+    // The batches move with their expiry dates
+    let response = server
+        .get(&format!("/warehouse/{to}/expiring"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "batches": [{ "product": product_id, "quantity": "5.00" }],
+    }));
+
+    let response = server
+        .get(&format!("/warehouse/{to}/expiring?within=60d"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json_contains(&json!({
+        "batches": [
+            { "product": product_id, "quantity": "5.00" },
+            { "product": product_id, "quantity": "1.00" },
+        ],
+    }));
+
+    // Batches never hold more than the stock
+    let response = server
+        .put(&format!("/warehouse/{from}/product/{product_id}"))
+        .json(&json!({ "quantity": 1 }))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+
+    let response = server
+        .get(&format!("/warehouse/{from}/product/{product_id}/batch"))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_ok();
+    response.assert_json(&json!({ "batches": [] }));
+
+    let response = server
+        .get(&format!(
+            "/warehouse/{to}/product/{}/batch",
+            uuid::Uuid::new_v4()
+        ))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_not_found();
+
+    let response = server
+        .get(&format!("/warehouse/{}/expiring", uuid::Uuid::new_v4()))
+        .add_cookie(cookies[0].clone())
+        .await;
+    response.assert_status_not_found();
+}